@@ -0,0 +1,320 @@
+//! Arbitrary precision backend built on `num-bigint`, enabled with the `bigint` feature.
+//!
+//! `BigUint` and `BigInt` never overflow, so every decimal operation on them is exact unless it
+//! divides by zero or the result is negative for `BigUint`. They are much slower than the
+//! primitive integers and meant as a fallback for reconciliation and audit jobs.
+
+use core::cmp::Ordering;
+
+use num_bigint::{BigInt, BigUint, Sign};
+
+use crate::core::{
+    CheckedAbs, CheckedAdd, CheckedDiv, CheckedMul, CheckedMulDiv, CheckedMulDivRem, CheckedNeg,
+    CheckedRem, CheckedSub, DecimalOperationError, PowerOfTen, RoundingMode, Signedness, Zero,
+};
+
+impl CheckedAdd for BigUint {
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        Some(self + v)
+    }
+}
+
+impl CheckedSub for BigUint {
+    fn checked_sub(&self, v: &Self) -> Option<Self> {
+        (self >= v).then(|| self - v)
+    }
+}
+
+impl CheckedMul for BigUint {
+    fn checked_mul(&self, v: &Self) -> Option<Self> {
+        Some(self * v)
+    }
+}
+
+impl CheckedDiv for BigUint {
+    fn checked_div(&self, v: &Self) -> Option<Self> {
+        (!Zero::is_zero(v)).then(|| self / v)
+    }
+}
+
+impl CheckedRem for BigUint {
+    fn checked_rem(&self, v: &Self) -> Option<Self> {
+        (!Zero::is_zero(v)).then(|| self % v)
+    }
+}
+
+impl CheckedNeg for BigUint {
+    fn checked_neg(&self) -> Option<Self> {
+        Zero::is_zero(self).then(|| self.clone())
+    }
+}
+
+impl CheckedAbs for BigUint {
+    fn checked_abs(&self) -> Option<Self> {
+        Some(self.clone())
+    }
+}
+
+impl Signedness for BigUint {
+    fn is_signed() -> bool {
+        false
+    }
+    fn is_negative(&self) -> bool {
+        false
+    }
+}
+
+impl Zero for BigUint {
+    fn zero() -> Self {
+        BigUint::default()
+    }
+    fn is_zero(&self) -> bool {
+        self.bits() == 0
+    }
+}
+
+impl CheckedAdd for BigInt {
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        Some(self + v)
+    }
+}
+
+impl CheckedSub for BigInt {
+    fn checked_sub(&self, v: &Self) -> Option<Self> {
+        Some(self - v)
+    }
+}
+
+impl CheckedMul for BigInt {
+    fn checked_mul(&self, v: &Self) -> Option<Self> {
+        Some(self * v)
+    }
+}
+
+impl CheckedDiv for BigInt {
+    fn checked_div(&self, v: &Self) -> Option<Self> {
+        (!Zero::is_zero(v)).then(|| self / v)
+    }
+}
+
+impl CheckedRem for BigInt {
+    fn checked_rem(&self, v: &Self) -> Option<Self> {
+        (!Zero::is_zero(v)).then(|| self % v)
+    }
+}
+
+impl CheckedNeg for BigInt {
+    fn checked_neg(&self) -> Option<Self> {
+        Some(-self)
+    }
+}
+
+impl CheckedAbs for BigInt {
+    fn checked_abs(&self) -> Option<Self> {
+        Some(BigInt::from(self.magnitude().clone()))
+    }
+}
+
+impl Signedness for BigInt {
+    fn is_signed() -> bool {
+        true
+    }
+    fn is_negative(&self) -> bool {
+        self.sign() == Sign::Minus
+    }
+}
+
+impl Zero for BigInt {
+    fn zero() -> Self {
+        BigInt::default()
+    }
+    fn is_zero(&self) -> bool {
+        self.sign() == Sign::NoSign
+    }
+}
+
+/// The largest exponent of the powers of ten computed for `BigUint` and `BigInt`.
+///
+/// The integers themselves never overflow, but a scale read from untrusted input could ask for
+/// `10^u32::MAX`, billions of digits. Larger powers are handled like an overflow: the checked
+/// power is `None`, so the decimal operations report `ScaleTooLarge`.
+pub const MAX_BIG_SCALE: u32 = 4_096;
+
+macro_rules! impl_big_power_of_ten {
+    ($($t:ty)*) => ($(
+        impl PowerOfTen for $t {
+            fn checked_power_of_ten(exponent: u32) -> Option<Self> {
+                (exponent <= MAX_BIG_SCALE).then(|| <$t>::from(10u8).pow(exponent))
+            }
+            fn wrapping_power_of_ten(exponent: u32) -> Self {
+                Self::overflowing_power_of_ten(exponent).0
+            }
+            fn overflowing_power_of_ten(exponent: u32) -> (Self, bool) {
+                match Self::checked_power_of_ten(exponent) {
+                    Some(power) => (power, false),
+                    None => (<$t>::default(), true),
+                }
+            }
+            #[cfg(not(feature = "strict-no-panic"))]
+            fn power_of_ten(exponent: u32) -> Self {
+                Self::checked_power_of_ten(exponent).expect("attempt to multiply with overflow")
+            }
+        }
+    )*)
+}
+
+impl_big_power_of_ten! { BigUint BigInt }
+
+/// Computes `|a * b / denominator|` rounded with `rounding`, where `negative` is the sign of the
+/// exact result.
+fn mul_div_magnitude(
+    a: &BigUint,
+    b: &BigUint,
+    denominator: &BigUint,
+    negative: bool,
+    rounding: RoundingMode,
+) -> Result<BigUint, DecimalOperationError> {
+    if Zero::is_zero(denominator) {
+        return Err(DecimalOperationError::DivisionByZero);
+    }
+    let product = a * b;
+    let quotient = &product / denominator;
+    let remainder = &product % denominator;
+    let discarded: Option<Ordering> =
+        (!Zero::is_zero(&remainder)).then(|| remainder.cmp(&(denominator - &remainder)));
+    let is_odd = quotient.bit(0);
+    if rounding.rounds_away_from_zero(negative, is_odd, discarded)? {
+        Ok(quotient + 1u8)
+    } else {
+        Ok(quotient)
+    }
+}
+
+impl CheckedMulDiv for BigUint {
+    fn checked_mul_div(
+        self,
+        multiplier: Self,
+        denominator: Self,
+        rounding: RoundingMode,
+    ) -> Result<Self, DecimalOperationError> {
+        mul_div_magnitude(&self, &multiplier, &denominator, false, rounding)
+    }
+}
+
+impl CheckedMulDiv for BigInt {
+    fn checked_mul_div(
+        self,
+        multiplier: Self,
+        denominator: Self,
+        rounding: RoundingMode,
+    ) -> Result<Self, DecimalOperationError> {
+        let negative = self.is_negative() ^ multiplier.is_negative() ^ denominator.is_negative();
+        let magnitude = mul_div_magnitude(
+            self.magnitude(),
+            multiplier.magnitude(),
+            denominator.magnitude(),
+            negative,
+            rounding,
+        )?;
+        let sign = if negative { Sign::Minus } else { Sign::Plus };
+        Ok(BigInt::from_biguint(sign, magnitude))
+    }
+}
+
+impl CheckedMulDivRem for BigUint {
+    fn checked_mul_div_rem(
+        self,
+        multiplier: Self,
+        denominator: Self,
+    ) -> Result<(Self, Self), DecimalOperationError> {
+        if Zero::is_zero(&denominator) {
+            return Err(DecimalOperationError::DivisionByZero);
+        }
+        let product = self * multiplier;
+        Ok((&product / &denominator, product % denominator))
+    }
+}
+
+impl CheckedMulDivRem for BigInt {
+    fn checked_mul_div_rem(
+        self,
+        multiplier: Self,
+        denominator: Self,
+    ) -> Result<(Self, Self), DecimalOperationError> {
+        if Zero::is_zero(&denominator) {
+            return Err(DecimalOperationError::DivisionByZero);
+        }
+        // `BigInt` division truncates towards zero, like the primitive integers.
+        let product = self * multiplier;
+        Ok((&product / &denominator, product % denominator))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{CheckedDecimalOperations, Decimal, RescaleDecimals};
+
+    #[test]
+    fn test_big_uint_decimal_operations() -> Result<(), DecimalOperationError> {
+        let a = BigUint::from(u128::MAX);
+        let (product, decimals) = a.clone().multiply_decimals_checked(a.clone(), 18, 18)?;
+        assert_eq!(decimals, 36);
+        assert_eq!(product, &a * &a);
+
+        let (sum, decimals) =
+            BigUint::from(1_5u8).add_decimals_checked(BigUint::from(1u8), 1, 40)?;
+        assert_eq!(decimals, 40);
+        assert_eq!(sum.to_string(), format!("15{}1", "0".repeat(38)));
+
+        assert_eq!(
+            BigUint::from(1u8).sub_decimals_checked(BigUint::from(2u8), 0, 0),
+            Err(DecimalOperationError::NegativeResult)
+        );
+        assert_eq!(
+            BigUint::from(1u8).divide_decimals_checked(BigUint::default(), 0, 0),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_big_int_decimal_operations() -> Result<(), DecimalOperationError> {
+        let a = Decimal::new(BigInt::from(i128::MIN), 18);
+        let b = Decimal::new(BigInt::from(-2), 0);
+        let product = a.try_mul(b)?;
+        assert_eq!(product.value_ref(), &(BigInt::from(i128::MIN) * -2));
+
+        let (value, decimals) =
+            BigInt::from(-1_255).rescale_checked(3, 2, RoundingMode::HalfEven)?;
+        assert_eq!((value, decimals), (BigInt::from(-1_26), 2));
+
+        let (quotient, remainder) =
+            BigInt::from(-10).checked_mul_div_rem(BigInt::from(2), BigInt::from(3))?;
+        assert_eq!((quotient, remainder), (BigInt::from(-6), BigInt::from(-2)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_big_power_of_ten_limit() {
+        assert_eq!(
+            BigUint::checked_power_of_ten(MAX_BIG_SCALE).map(|power| power.to_string().len()),
+            Some(MAX_BIG_SCALE as usize + 1)
+        );
+        assert_eq!(BigUint::checked_power_of_ten(MAX_BIG_SCALE + 1), None);
+        assert_eq!(
+            BigInt::overflowing_power_of_ten(u32::MAX),
+            (BigInt::default(), true)
+        );
+        assert_eq!(
+            BigUint::from(1u8).rescale_checked(0, u32::MAX, RoundingMode::Down),
+            Err(DecimalOperationError::ScaleTooLarge)
+        );
+        assert_eq!(
+            BigInt::from(1).rescale_checked(u32::MAX, 0, RoundingMode::Down),
+            Err(DecimalOperationError::ScaleTooLarge)
+        );
+    }
+}
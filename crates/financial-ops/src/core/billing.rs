@@ -0,0 +1,382 @@
+use core::cmp::Ordering;
+
+use crate::core::{
+    daycount::to_date, CheckedDecimalOperations, CheckedMulDiv, CompareDecimals, Date, Decimal,
+    DecimalOperationError, RescaleDecimals, RoundingMode, Zero,
+};
+
+/// How the days of a month weigh in a proration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ProrationBasis {
+    /// Every day of a month weighs the same, so a day is a 31st of January and a 28th of
+    /// February 2023.
+    #[default]
+    CalendarDays,
+    /// Every month has 30 days: the 31st weighs nothing, and the last day of February weighs
+    /// what the days up to the 30th would.
+    ThirtyDay,
+}
+
+impl ProrationBasis {
+    /// Returns the number of days of the month of `date` before it, over the days of the month.
+    const fn position(self, date: Date) -> (u32, u32) {
+        match self {
+            ProrationBasis::CalendarDays => (date.day().saturating_sub(1), date.days_in_month()),
+            ProrationBasis::ThirtyDay if date.day() > 30 => (30, 30),
+            ProrationBasis::ThirtyDay => (date.day().saturating_sub(1), 30),
+        }
+    }
+}
+
+/// Computes the share of a monthly amount billed from `period_start` to `period_end`, excluded.
+///
+/// The period may span several months, each prorated over its own days with the basis. Within a
+/// month, the share is the rounded share of the month up to the end of the period minus the
+/// rounded share up to its start, so consecutive periods covering a month, down to single days,
+/// add up to the monthly amount exactly. Prorated amounts keep the decimals of the monthly
+/// amount.
+///
+/// The dates are [`Date`]s, `(year, month, day)` tuples, or `chrono::NaiveDate`s with the
+/// `chrono` feature.
+///
+/// # Arguments
+///
+/// * `monthly_amount` - The amount billed for a whole month.
+/// * `period_start` - The first day billed.
+/// * `period_end` - The day after the last day billed.
+/// * `basis` - How the days of a month weigh.
+/// * `rounding` - The rounding applied to the cumulative shares of a month.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the prorated amount, or a `DecimalOperationError` if a date
+/// doesn't exist or `period_end` is before `period_start`, both reported as `OutOfDomain`, or
+/// the operation fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{prorate_period, Decimal, ProrationBasis, RoundingMode};
+///
+/// let monthly = Decimal::new(100_00i64, 2);
+/// let prorate = |start, end| {
+///     prorate_period(monthly, start, end, ProrationBasis::CalendarDays, RoundingMode::HalfEven)
+/// };
+///
+/// // 10 of the 31 days of January
+/// assert_eq!(prorate((2024, 1, 22), (2024, 2, 1)).unwrap(), Decimal::new(32_26, 2));
+/// assert_eq!(prorate((2024, 1, 1), (2024, 1, 22)).unwrap(), Decimal::new(67_74, 2));
+/// ```
+pub fn prorate_period<T, D>(
+    monthly_amount: Decimal<T>,
+    period_start: D,
+    period_end: D,
+    basis: ProrationBasis,
+    rounding: RoundingMode,
+) -> Result<Decimal<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations + CheckedMulDiv + Zero + From<u32> + Copy,
+    D: TryInto<Date>,
+{
+    let (start, end) = (to_date(period_start)?, to_date(period_end)?);
+    if end < start {
+        return Err(DecimalOperationError::OutOfDomain);
+    }
+    let scale = monthly_amount.scale();
+    let share = |(days, month_days): (u32, u32)| {
+        monthly_amount
+            .value()
+            .checked_mul_div(T::from(days), T::from(month_days), rounding)
+            .map(|value| Decimal::new(value, scale))
+    };
+
+    let mut total = Decimal::zero(scale);
+    let mut cursor = start;
+    while cursor < end {
+        let next_month = cursor
+            .first_of_next_month()
+            .filter(|&next_month| next_month <= end);
+        let (from, month_days) = basis.position(cursor);
+        let to = match next_month {
+            Some(_) => month_days,
+            None => basis.position(end).0,
+        };
+        total = total.try_add(share((to, month_days))?.try_sub(share((from, month_days))?)?)?;
+        cursor = next_month.unwrap_or(end);
+    }
+    Ok(total)
+}
+
+/// The amounts billed when a subscription changes plans during a billing cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PlanChange<T = i128> {
+    /// The credit for the time left on the old plan.
+    pub credit: Decimal<T>,
+    /// The charge for the time left on the new plan.
+    pub charge: Decimal<T>,
+}
+
+impl<T> PlanChange<T>
+where
+    T: CheckedDecimalOperations + Copy,
+{
+    /// Returns the charge minus the credit, negative when the new plan is cheaper.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the net amount, or a `DecimalOperationError` if the
+    /// operation fails, like a cheaper plan with an unsigned type, reported as `NegativeResult`.
+    pub fn net(&self) -> Result<Decimal<T>, DecimalOperationError> {
+        self.charge.try_sub(self.credit)
+    }
+}
+
+/// Prorates a change of plan on `change_date`, within a billing cycle from `cycle_start` to
+/// `cycle_end`, excluded: the old plan is credited and the new plan charged for the days left in
+/// the cycle, counted in calendar days.
+///
+/// The credit is rounded on its own and the difference between the plans is rounded once, so the
+/// charge absorbs the rounding and the invoice nets to the rounded difference exactly. Neither
+/// goes through a negative amount, so a downgrade works on unsigned types too. Amounts have the
+/// largest number of decimals of the two prices.
+///
+/// The dates are [`Date`]s, `(year, month, day)` tuples, or `chrono::NaiveDate`s with the
+/// `chrono` feature.
+///
+/// # Arguments
+///
+/// * `old_amount` - The price of the old plan for the whole cycle.
+/// * `new_amount` - The price of the new plan for the whole cycle.
+/// * `cycle_start` - The first day of the billing cycle.
+/// * `cycle_end` - The first day of the next billing cycle.
+/// * `change_date` - The first day on the new plan.
+/// * `rounding` - The rounding applied to the credit and the difference between the plans.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the credit and the charge, or a
+/// `DecimalOperationError` if a date doesn't exist, the cycle is empty or `change_date` is
+/// outside it, all reported as `OutOfDomain`, or the operation fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{prorate_plan_change, Decimal, RoundingMode};
+///
+/// // Upgrading from 10 to 25 a month with 10 of the 31 days of March left: the charge of
+/// // 8.0645 is 8.07, so that the invoice nets to 4.84.
+/// let change = prorate_plan_change(
+///     Decimal::new(10_00i64, 2),
+///     Decimal::new(25_00, 2),
+///     (2024, 3, 1),
+///     (2024, 4, 1),
+///     (2024, 3, 22),
+///     RoundingMode::HalfEven,
+/// )
+/// .unwrap();
+/// assert_eq!(change.credit, Decimal::new(3_23, 2));
+/// assert_eq!(change.charge, Decimal::new(8_07, 2));
+/// assert_eq!(change.net().unwrap(), Decimal::new(4_84, 2));
+/// ```
+pub fn prorate_plan_change<T, D>(
+    old_amount: Decimal<T>,
+    new_amount: Decimal<T>,
+    cycle_start: D,
+    cycle_end: D,
+    change_date: D,
+    rounding: RoundingMode,
+) -> Result<PlanChange<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations
+        + CheckedMulDiv
+        + CompareDecimals
+        + RescaleDecimals
+        + From<u32>
+        + Copy,
+    D: TryInto<Date>,
+{
+    let (start, end, change) = (
+        to_date(cycle_start)?,
+        to_date(cycle_end)?,
+        to_date(change_date)?,
+    );
+    if start >= end || change < start || change > end {
+        return Err(DecimalOperationError::OutOfDomain);
+    }
+    let days = |from: Date, to: Date| {
+        u32::try_from(from.days_until(&to))
+            .map(T::from)
+            .map_err(|_| DecimalOperationError::Overflow)
+    };
+    let (left, cycle) = (days(change, end)?, days(start, end)?);
+
+    // Adding decimals is exact, so the rounding mode never applies.
+    let scale = old_amount.scale().max(new_amount.scale());
+    let old_amount = old_amount.try_rescale(scale, RoundingMode::HalfEven)?;
+    let prorate = |amount: Decimal<T>, rounding| {
+        amount
+            .value()
+            .checked_mul_div(left, cycle, rounding)
+            .map(|value| Decimal::new(value, scale))
+    };
+    let credit = prorate(old_amount, rounding)?;
+    // The difference is prorated by its magnitude, the charge going down on a downgrade.
+    let charge = match new_amount.cmp_value(&old_amount) {
+        Ordering::Less => credit.try_sub(prorate(
+            old_amount.try_sub(new_amount)?,
+            rounding.negated(),
+        )?)?,
+        Ordering::Equal | Ordering::Greater => {
+            credit.try_add(prorate(new_amount.try_sub(old_amount)?, rounding)?)?
+        }
+    };
+    Ok(PlanChange { credit, charge })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn daily_total(
+        monthly_amount: Decimal<i64>,
+        year: i32,
+        month: u32,
+        basis: ProrationBasis,
+    ) -> Result<Decimal<i64>, DecimalOperationError> {
+        let first = Date::new(year, month, 1).ok_or(DecimalOperationError::OutOfDomain)?;
+        let mut total = Decimal::new(0, monthly_amount.scale());
+        for day in 1..=first.days_in_month() {
+            let start = Date::new(year, month, day).ok_or(DecimalOperationError::OutOfDomain)?;
+            let end = Date::new(year, month, day + 1)
+                .or(first.first_of_next_month())
+                .ok_or(DecimalOperationError::OutOfDomain)?;
+            let daily = prorate_period(monthly_amount, start, end, basis, RoundingMode::HalfEven)?;
+            total = total.try_add(daily)?;
+        }
+        Ok(total)
+    }
+
+    #[test]
+    fn test_daily_prorations_add_up() -> Result<(), DecimalOperationError> {
+        let monthly = Decimal::new(99_99i64, 2);
+        for basis in [ProrationBasis::CalendarDays, ProrationBasis::ThirtyDay] {
+            for (year, month) in [(2023, 2), (2024, 2), (2024, 4), (2024, 7)] {
+                assert_eq!(daily_total(monthly, year, month, basis)?, monthly);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_prorate_period() -> Result<(), DecimalOperationError> {
+        let monthly = Decimal::new(30_00i64, 2);
+        let prorate =
+            |start, end, basis| prorate_period(monthly, start, end, basis, RoundingMode::HalfEven);
+        // On a 30-day basis the 31st weighs nothing, and the end of February the rest of the month.
+        let thirty = ProrationBasis::ThirtyDay;
+        assert_eq!(
+            prorate((2024, 1, 31), (2024, 2, 1), thirty)?,
+            Decimal::new(0, 2)
+        );
+        assert_eq!(
+            prorate((2023, 2, 28), (2023, 3, 1), thirty)?,
+            Decimal::new(3_00, 2)
+        );
+        // January 20 to March 10 is 12 days of January, February and 9 days of March.
+        let calendar = ProrationBasis::CalendarDays;
+        assert_eq!(
+            prorate((2024, 1, 20), (2024, 3, 10), calendar)?,
+            Decimal::new(5_032, 2)
+        );
+        assert_eq!(
+            prorate((2024, 1, 20), (2024, 3, 10), thirty)?,
+            Decimal::new(50_00, 2)
+        );
+        assert_eq!(
+            prorate((2024, 3, 16), (2024, 3, 16), calendar)?,
+            Decimal::new(0, 2)
+        );
+        assert_eq!(
+            prorate((2024, 3, 16), (2024, 3, 15), calendar),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_prorate_plan_change() -> Result<(), DecimalOperationError> {
+        let change = |old, new, change_date| {
+            prorate_plan_change(
+                Decimal::new(old, 2),
+                Decimal::new(new, 2),
+                (2023, 2, 1),
+                (2023, 3, 1),
+                change_date,
+                RoundingMode::HalfEven,
+            )
+        };
+        // 9 of 28 days: the credit of 3.2142 and the net of 6.4285 are rounded, and the charge
+        // adds them up.
+        let upgrade = change(10_00i64, 30_00, (2023, 2, 20))?;
+        assert_eq!(upgrade.credit, Decimal::new(3_21, 2));
+        assert_eq!(upgrade.net()?, Decimal::new(6_43, 2));
+        assert_eq!(upgrade.charge, Decimal::new(964, 2));
+
+        let downgrade = change(30_00, 10_00, (2023, 2, 20))?;
+        assert_eq!(downgrade.credit, Decimal::new(964, 2));
+        assert_eq!(downgrade.net()?, Decimal::new(-6_43, 2));
+        assert_eq!(downgrade.charge, Decimal::new(3_21, 2));
+
+        // Changing on the first day swaps the plans, and on the next cycle changes nothing.
+        let whole = change(10_00, 30_00, (2023, 2, 1))?;
+        assert_eq!(
+            (whole.credit, whole.charge),
+            (Decimal::new(10_00, 2), Decimal::new(30_00, 2))
+        );
+        assert_eq!(
+            change(10_00, 30_00, (2023, 3, 1))?.net()?,
+            Decimal::new(0, 2)
+        );
+        assert_eq!(
+            change(10_00, 30_00, (2023, 3, 2)),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_prorate_plan_change_unsigned() -> Result<(), DecimalOperationError> {
+        let change = |old, new, rounding| {
+            prorate_plan_change(
+                Decimal::new(old, 2),
+                Decimal::new(new, 2),
+                (2024, 4, 1),
+                (2024, 5, 1),
+                (2024, 4, 16),
+                rounding,
+            )
+        };
+        // Half of April left: downgrading from 30.00 to 10.00 credits 15.00 and charges 5.00.
+        let downgrade = change(30_00u64, 10_00, RoundingMode::HalfEven)?;
+        assert_eq!(downgrade.credit, Decimal::new(15_00, 2));
+        assert_eq!(downgrade.charge, Decimal::new(5_00, 2));
+        assert_eq!(downgrade.net(), Err(DecimalOperationError::NegativeResult));
+
+        // The difference of -6.665 rounds down to -6.67 like on a signed type, so the charge is
+        // 3.33 rather than the 3.34 of the new plan.
+        let downgrade = change(20_01, 6_68, RoundingMode::Floor)?;
+        assert_eq!(downgrade.credit, Decimal::new(10_00, 2));
+        assert_eq!(downgrade.charge, Decimal::new(3_33, 2));
+        let signed = prorate_plan_change(
+            Decimal::new(20_01i64, 2),
+            Decimal::new(6_68, 2),
+            (2024, 4, 1),
+            (2024, 5, 1),
+            (2024, 4, 16),
+            RoundingMode::Floor,
+        )?;
+        assert_eq!(signed.net()?, Decimal::new(-6_67, 2));
+        assert_eq!(signed.charge, Decimal::new(3_33, 2));
+        Ok(())
+    }
+}
@@ -0,0 +1,210 @@
+use crate::core::{
+    bond::quote::period_growth,
+    interest::{annuity::GUARD_DECIMALS, compound::FACTOR_DECIMALS, irr::value_decimals},
+    money::exchange_rate::multiply_to_scale,
+    CheckedDecimalOperations, CheckedDivideToScale, CheckedMulDiv, CheckedPowDecimals,
+    CompareDecimals, Decimal, DecimalOperationError, PowerOfTen, RescaleDecimals, RoundingMode,
+    Zero,
+};
+
+/// The sensitivity of the price of a bond to its yield.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BondRisk<T = i128> {
+    /// The Macaulay duration, the average time to the cash flows weighted by their present
+    /// values, in years.
+    pub macaulay: Decimal<T>,
+    /// The modified duration, the Macaulay duration over `1 + ytm / frequency`: the relative
+    /// change of the price for a change of the yield, in years.
+    pub modified: Decimal<T>,
+    /// The convexity, the second derivative of the price to the yield over the price, in years
+    /// squared.
+    pub convexity: Decimal<T>,
+}
+
+/// Computes the Macaulay duration, the modified duration and the convexity of a schedule of
+/// bond cash flows at a yield to maturity.
+///
+/// Cash flows come at whole numbers of coupon periods, `frequency` a year, and the annual yield is
+/// compounded every period, with the rate convention of the [`interest`](crate::core::interest)
+/// module. The discount factors are computed with 18 decimals, the present values with 6 more
+/// decimals than the flows and their ratios with 6 more than `scale`, before every measure is
+/// rounded to `scale` decimals.
+///
+/// # Arguments
+///
+/// * `cash_flows` - The coupon period and amount of every cash flow.
+/// * `ytm_bps` - The annual yield to maturity, in basis points.
+/// * `frequency` - The number of coupon periods a year.
+/// * `scale` - The number of decimals of the measures.
+/// * `rounding` - The rounding applied to the measures.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the measures, or a `DecimalOperationError` if the yield is at
+/// or below `-100%` a period, reported as `OutOfDomain`, `frequency` or the present value of the
+/// flows is zero, reported as `DivisionByZero`, or the operation fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{bond, Decimal, RoundingMode};
+///
+/// // A 5% semiannual bond with 5 years left, yielding 6%
+/// let mut cash_flows = [(0, Decimal::new(25_00i128, 2)); 10];
+/// for (period, flow) in cash_flows.iter_mut().enumerate() {
+///     flow.0 = period as u32 + 1;
+/// }
+/// cash_flows[9].1 = Decimal::new(1_025_00, 2);
+///
+/// let risk = bond::risk(&cash_flows, Decimal::new(600, 0), 2, 4, RoundingMode::HalfEven).unwrap();
+/// assert_eq!(risk.macaulay, Decimal::new(4_4717, 4));
+/// assert_eq!(risk.modified, Decimal::new(4_3414, 4));
+/// assert_eq!(risk.convexity, Decimal::new(22_3047, 4));
+/// ```
+pub fn risk<T>(
+    cash_flows: &[(u32, Decimal<T>)],
+    ytm_bps: Decimal<T>,
+    frequency: u32,
+    scale: u32,
+    rounding: RoundingMode,
+) -> Result<BondRisk<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations
+        + CheckedDivideToScale
+        + CheckedMulDiv
+        + CheckedPowDecimals
+        + CompareDecimals
+        + RescaleDecimals
+        + PowerOfTen
+        + Zero
+        + From<u32>
+        + Copy,
+{
+    let growth = period_growth(ytm_bps, frequency)?;
+    let discount = Decimal::one(FACTOR_DECIMALS)?.try_div_to_scale(
+        growth,
+        FACTOR_DECIMALS,
+        RoundingMode::HalfEven,
+    )?;
+    // The present values are summed with a few more decimals than the flows, and the ratios
+    // computed with a few more than the measures.
+    let decimals = value_decimals(cash_flows)?;
+    let ratio_decimals = scale
+        .checked_add(GUARD_DECIMALS)
+        .ok_or(DecimalOperationError::ScaleTooLarge)?;
+    let zero = Decimal::zero(decimals);
+    // The present value of the flows, and its sums weighted by `t` and by `t * (t + 1)`.
+    let (mut value, mut weighted, mut squared) = (zero, zero, zero);
+    for &(period, amount) in cash_flows {
+        let factor = discount.try_pow_rounded(period, RoundingMode::HalfEven)?;
+        let present = Decimal::new(
+            multiply_to_scale(amount, factor, decimals, RoundingMode::HalfEven)?,
+            decimals,
+        );
+        let next = period
+            .checked_add(1)
+            .ok_or(DecimalOperationError::Overflow)?;
+        let period = Decimal::new(T::from(period), 0);
+        value = value.try_add(present)?;
+        weighted = weighted.try_add(present.try_mul(period)?)?;
+        squared = squared.try_add(
+            present
+                .try_mul(period)?
+                .try_mul(Decimal::new(T::from(next), 0))?,
+        )?;
+    }
+    let frequency = Decimal::new(T::from(frequency), 0);
+    // The three measures in periods, the modified duration over `1 + y` and the convexity over
+    // `(1 + y)^2`.
+    let periods = weighted.try_div_to_scale(value, ratio_decimals, RoundingMode::HalfEven)?;
+    let modified = periods.try_div_to_scale(growth, ratio_decimals, RoundingMode::HalfEven)?;
+    let convexity = squared
+        .try_div_to_scale(value, ratio_decimals, RoundingMode::HalfEven)?
+        .try_div_to_scale(growth, ratio_decimals, RoundingMode::HalfEven)?
+        .try_div_to_scale(growth, ratio_decimals, RoundingMode::HalfEven)?;
+    Ok(BondRisk {
+        macaulay: periods.try_div_to_scale(frequency, scale, rounding)?,
+        modified: modified.try_div_to_scale(frequency, scale, rounding)?,
+        convexity: convexity.try_div_to_scale(frequency.try_mul(frequency)?, scale, rounding)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_risk() -> Result<(), DecimalOperationError> {
+        let risk = |cash_flows: &[(u32, Decimal<i128>)], ytm| {
+            risk(
+                cash_flows,
+                Decimal::new(ytm, 0),
+                1,
+                4,
+                RoundingMode::HalfEven,
+            )
+        };
+        // A zero-coupon bond lasts until its maturity: 5 / 1.05 and 5 * 6 / 1.05^2.
+        let zero_coupon = risk(&[(5, Decimal::new(100_00, 2))], 500)?;
+        assert_eq!(zero_coupon.macaulay, Decimal::new(5_0000, 4));
+        assert_eq!(zero_coupon.modified, Decimal::new(4_7619, 4));
+        assert_eq!(zero_coupon.convexity, Decimal::new(27_2109, 4));
+
+        let cash_flows = [
+            (1, Decimal::new(10, 0)),
+            (2, Decimal::new(10, 0)),
+            (3, Decimal::new(110, 0)),
+        ];
+        let annual = risk(&cash_flows, 1_000)?;
+        assert_eq!(annual.macaulay, Decimal::new(2_7355, 4));
+        assert_eq!(annual.modified, Decimal::new(2_4869, 4));
+        assert_eq!(annual.convexity, Decimal::new(8_7562, 4));
+        Ok(())
+    }
+
+    #[test]
+    fn test_risk_64_bits() -> Result<(), DecimalOperationError> {
+        let cash_flows = [
+            (1, Decimal::new(10i64, 0)),
+            (2, Decimal::new(10, 0)),
+            (3, Decimal::new(110, 0)),
+        ];
+        let annual = risk(
+            &cash_flows,
+            Decimal::new(1_000, 0),
+            1,
+            4,
+            RoundingMode::HalfEven,
+        )?;
+        assert_eq!(annual.macaulay, Decimal::new(2_7355, 4));
+        assert_eq!(annual.modified, Decimal::new(2_4869, 4));
+        assert_eq!(annual.convexity, Decimal::new(8_7562, 4));
+        Ok(())
+    }
+
+    #[test]
+    fn test_risk_errors() {
+        let risk = |cash_flows: &[(u32, Decimal<i128>)], ytm, frequency| {
+            risk(
+                cash_flows,
+                Decimal::new(ytm, 0),
+                frequency,
+                4,
+                RoundingMode::HalfEven,
+            )
+        };
+        let cash_flows = [(1, Decimal::new(100, 0))];
+        assert_eq!(
+            risk(&[], 500, 1),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+        assert_eq!(
+            risk(&cash_flows, 500, 0),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+        assert_eq!(
+            risk(&cash_flows, -20_000, 2),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+    }
+}
@@ -0,0 +1,7 @@
+pub mod duration;
+pub mod quote;
+pub mod yield_to_maturity;
+
+pub use duration::*;
+pub use quote::*;
+pub use yield_to_maturity::*;
@@ -0,0 +1,348 @@
+use core::cmp::Ordering;
+
+use crate::core::{
+    interest::{annuity::GUARD_DECIMALS, compound::FACTOR_DECIMALS},
+    percentage::bps::bps_to_fraction,
+    CheckedDecimalOperations, CheckedDivideToScale, CompareDecimals, Decimal,
+    DecimalOperationError, PowerOfTen, RescaleDecimals, RoundingMode, TranscendentalDecimals, Zero,
+    TRANSCENDENTAL_MAX_DECIMALS,
+};
+
+/// How a bond discounts the fraction of a period left until its next coupon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum YieldConvention {
+    /// Compounds the yield over the fraction of the period, `(1 + y)^w`, like the street
+    /// convention and the `PRICE` spreadsheet function.
+    #[default]
+    Compound,
+    /// Applies simple interest over the fraction of the period, `1 + y * w`, like the convention
+    /// used for Treasuries in their last coupon period.
+    Simple,
+}
+
+/// The price of a bond, split into the part quoted by the market and the interest accrued since
+/// the last coupon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BondPrice<T = i128> {
+    /// The price without the accrued interest, as quoted.
+    pub clean: Decimal<T>,
+    /// The share of the next coupon accrued since the last one.
+    pub accrued: Decimal<T>,
+    /// The price paid, the clean price plus the accrued interest.
+    pub dirty: Decimal<T>,
+}
+
+/// Splits a number of coupon periods into the number of coupons left and the fraction of a
+/// period until the next one, in `(0, 1]`.
+pub(crate) fn split_periods<T>(
+    periods: Decimal<T>,
+) -> Result<(u32, Decimal<T>), DecimalOperationError>
+where
+    T: CheckedDecimalOperations + CompareDecimals + PowerOfTen + Zero + Copy,
+{
+    if periods.cmp_value(&Decimal::zero(0)) != Ordering::Greater {
+        return Err(DecimalOperationError::OutOfDomain);
+    }
+    let one = Decimal::one(0)?;
+    let mut coupons = 1u32;
+    let mut fraction = periods;
+    while fraction.cmp_value(&one) == Ordering::Greater {
+        fraction = fraction.try_sub(one)?;
+        coupons = coupons
+            .checked_add(1)
+            .ok_or(DecimalOperationError::Overflow)?;
+    }
+    Ok((coupons, fraction))
+}
+
+/// Computes the coupon of a period, `face * coupon_rate / frequency`, with `decimals` decimals.
+pub(crate) fn coupon<T>(
+    face: Decimal<T>,
+    coupon_rate_bps: Decimal<T>,
+    frequency: u32,
+    decimals: u32,
+    rounding: RoundingMode,
+) -> Result<Decimal<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations + CheckedDivideToScale + From<u32> + Copy,
+{
+    face.try_mul(bps_to_fraction(coupon_rate_bps)?)?
+        .try_div_to_scale(Decimal::new(T::from(frequency), 0), decimals, rounding)
+}
+
+/// Computes the part of the coupon of a period accrued when `fraction` of the period is left,
+/// `face * coupon_rate * (1 - fraction) / frequency`, rounded to the decimals of the face value.
+pub(crate) fn accrued_coupon<T>(
+    face: Decimal<T>,
+    coupon_rate_bps: Decimal<T>,
+    fraction: Decimal<T>,
+    frequency: u32,
+    rounding: RoundingMode,
+) -> Result<Decimal<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations + CheckedDivideToScale + PowerOfTen + From<u32> + Copy,
+{
+    face.try_mul(bps_to_fraction(coupon_rate_bps)?)?
+        .try_mul(Decimal::one(0)?.try_sub(fraction)?)?
+        .try_div_to_scale(Decimal::new(T::from(frequency), 0), face.scale(), rounding)
+}
+
+/// Computes the growth factor of one period at an annual yield, `1 + ytm / frequency`, with 18
+/// decimals.
+pub(crate) fn period_growth<T>(
+    ytm_bps: Decimal<T>,
+    frequency: u32,
+) -> Result<Decimal<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations
+        + CheckedDivideToScale
+        + CompareDecimals
+        + PowerOfTen
+        + Zero
+        + From<u32>
+        + Copy,
+{
+    if frequency == 0 {
+        return Err(DecimalOperationError::DivisionByZero);
+    }
+    let growth =
+        Decimal::one(FACTOR_DECIMALS)?.try_add(bps_to_fraction(ytm_bps)?.try_div_to_scale(
+            Decimal::new(T::from(frequency), 0),
+            FACTOR_DECIMALS,
+            RoundingMode::HalfEven,
+        )?)?;
+    if growth.cmp_value(&Decimal::zero(0)) != Ordering::Greater {
+        return Err(DecimalOperationError::OutOfDomain);
+    }
+    Ok(growth)
+}
+
+/// Computes the dirty price of a bond with `decimals` decimals, discounting the face value and
+/// the coupons left at the growth factor of a period.
+pub(crate) fn dirty_value<T>(
+    face: Decimal<T>,
+    coupon: Decimal<T>,
+    growth: Decimal<T>,
+    periods: Decimal<T>,
+    convention: YieldConvention,
+    decimals: u32,
+) -> Result<Decimal<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations
+        + CheckedDivideToScale
+        + CompareDecimals
+        + RescaleDecimals
+        + TranscendentalDecimals
+        + PowerOfTen
+        + Zero
+        + From<u32>
+        + Copy,
+{
+    let (coupons, fraction) = split_periods(periods)?;
+    // The value of the coupons left and the face value on the date of the next coupon, paid
+    // that day, going back from the maturity.
+    let mut value = face
+        .try_add(coupon)?
+        .try_rescale(decimals, RoundingMode::HalfEven)?;
+    for _ in 1..coupons {
+        value = value
+            .try_div_to_scale(growth, decimals, RoundingMode::HalfEven)?
+            .try_add(coupon)?;
+    }
+    let one = Decimal::one(0)?;
+    let discount = if fraction.eq_value(&one) {
+        growth
+    } else {
+        match convention {
+            YieldConvention::Compound => growth
+                .try_ln(TRANSCENDENTAL_MAX_DECIMALS)?
+                .try_mul(fraction)?
+                .try_rescale(TRANSCENDENTAL_MAX_DECIMALS, RoundingMode::HalfEven)?
+                .try_exp(TRANSCENDENTAL_MAX_DECIMALS)?,
+            YieldConvention::Simple => growth.try_sub(one)?.try_mul(fraction)?.try_add(one)?,
+        }
+    };
+    value.try_div_to_scale(discount, decimals, RoundingMode::HalfEven)
+}
+
+/// Computes the clean price, the accrued interest and the dirty price of a fixed-rate bond from
+/// its yield to maturity.
+///
+/// `periods` is the number of coupon periods from the settlement to the maturity and may be
+/// fractional: `9.25` means 10 coupons left, the next one a quarter of a period away, and three
+/// quarters of a coupon accrued. Both rates are annual numbers of basis points with their own
+/// scale, like in [`simple_interest`](crate::core::simple_interest), paid and compounded
+/// `frequency` times a year. The cash flows are discounted with 6 more decimals than the face
+/// value, the dirty price and the accrued interest are rounded once to the decimals of the face
+/// value, and the clean price is their difference.
+///
+/// # Arguments
+///
+/// * `face` - The face value, repaid at maturity.
+/// * `coupon_rate_bps` - The annual coupon rate, in basis points.
+/// * `ytm_bps` - The annual yield to maturity, in basis points.
+/// * `periods` - The number of coupon periods until maturity.
+/// * `frequency` - The number of coupons a year.
+/// * `convention` - How the fraction of a period until the next coupon is discounted.
+/// * `rounding` - The rounding applied to the dirty price and the accrued interest.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the prices, or a `DecimalOperationError` if `periods` isn't
+/// positive or the yield is at or below `-100%` a period, reported as `OutOfDomain`, `frequency`
+/// is zero, reported as `DivisionByZero`, or the operation fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{bond, Decimal, RoundingMode, YieldConvention};
+///
+/// // A 5% semiannual bond yielding 6%, 5 years from maturity, just after a coupon
+/// let prices = bond::price(
+///     Decimal::new(1_000_00i128, 2),
+///     Decimal::new(500, 0),
+///     Decimal::new(600, 0),
+///     Decimal::new(10, 0),
+///     2,
+///     YieldConvention::Compound,
+///     RoundingMode::HalfEven,
+/// )
+/// .unwrap();
+/// assert_eq!(prices.clean, Decimal::new(957_35, 2));
+/// assert_eq!(prices.accrued, Decimal::new(0, 2));
+///
+/// // Three months later, halfway to the next coupon
+/// let prices = bond::price(
+///     Decimal::new(1_000_00i128, 2),
+///     Decimal::new(500, 0),
+///     Decimal::new(600, 0),
+///     Decimal::new(9_5, 1),
+///     2,
+///     YieldConvention::Compound,
+///     RoundingMode::HalfEven,
+/// )
+/// .unwrap();
+/// assert_eq!(prices.dirty, Decimal::new(971_60, 2));
+/// assert_eq!(prices.accrued, Decimal::new(12_50, 2));
+/// assert_eq!(prices.clean, Decimal::new(959_10, 2));
+/// ```
+pub fn price<T>(
+    face: Decimal<T>,
+    coupon_rate_bps: Decimal<T>,
+    ytm_bps: Decimal<T>,
+    periods: Decimal<T>,
+    frequency: u32,
+    convention: YieldConvention,
+    rounding: RoundingMode,
+) -> Result<BondPrice<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations
+        + CheckedDivideToScale
+        + CompareDecimals
+        + RescaleDecimals
+        + TranscendentalDecimals
+        + PowerOfTen
+        + Zero
+        + From<u32>
+        + Copy,
+{
+    let scale = face.scale();
+    let decimals = scale
+        .checked_add(GUARD_DECIMALS)
+        .ok_or(DecimalOperationError::ScaleTooLarge)?;
+    let growth = period_growth(ytm_bps, frequency)?;
+    let coupon = coupon(
+        face,
+        coupon_rate_bps,
+        frequency,
+        decimals,
+        RoundingMode::HalfEven,
+    )?;
+    let dirty = dirty_value(face, coupon, growth, periods, convention, decimals)?
+        .try_rescale(scale, rounding)?;
+    let (_, fraction) = split_periods(periods)?;
+    let accrued = accrued_coupon(face, coupon_rate_bps, fraction, frequency, rounding)?;
+    Ok(BondPrice {
+        clean: dirty.try_sub(accrued)?,
+        accrued,
+        dirty,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bond_price() -> Result<(), DecimalOperationError> {
+        let face = Decimal::new(100_0000i128, 4);
+        let price = |coupon, ytm, periods, convention| {
+            price(
+                face,
+                Decimal::new(coupon, 0),
+                Decimal::new(ytm, 0),
+                periods,
+                2,
+                convention,
+                RoundingMode::HalfEven,
+            )
+        };
+        // At par when the coupon rate is the yield, on a coupon date.
+        let prices = price(400, 400, Decimal::new(20, 0), YieldConvention::Compound)?;
+        assert_eq!(prices.dirty, face);
+        assert_eq!(prices.clean, face);
+        // A premium bond: 8% coupons yielding 6% over 10 years.
+        let prices = price(800, 600, Decimal::new(20, 0), YieldConvention::Compound)?;
+        assert_eq!(prices.clean, Decimal::new(114_8775, 4));
+
+        // A third of the way into a period: 100 * 0.08 / 2 * 1/3 accrued.
+        let periods = Decimal::new(19_666_666_666, 9);
+        let compound = price(800, 600, periods, YieldConvention::Compound)?;
+        assert_eq!(compound.accrued, Decimal::new(1_3333, 4));
+        assert_eq!(compound.dirty, Decimal::new(116_0149, 4));
+        assert_eq!(compound.clean, Decimal::new(114_6816, 4));
+        let simple = price(800, 600, periods, YieldConvention::Simple)?;
+        assert_eq!(simple.accrued, compound.accrued);
+        assert_eq!(simple.dirty, Decimal::new(116_0037, 4));
+
+        // A single coupon left, a month away.
+        let prices = price(
+            800,
+            600,
+            Decimal::new(1_666_667, 7),
+            YieldConvention::Simple,
+        )?;
+        assert_eq!(prices.dirty, Decimal::new(103_4826, 4));
+        Ok(())
+    }
+
+    #[test]
+    fn test_bond_price_errors() {
+        let price = |periods, frequency| {
+            price(
+                Decimal::new(100_00i128, 2),
+                Decimal::new(500, 0),
+                Decimal::new(500, 0),
+                Decimal::new(periods, 0),
+                frequency,
+                YieldConvention::Compound,
+                RoundingMode::HalfEven,
+            )
+        };
+        assert_eq!(price(0, 2), Err(DecimalOperationError::OutOfDomain));
+        assert_eq!(price(-3, 2), Err(DecimalOperationError::OutOfDomain));
+        assert_eq!(price(3, 0), Err(DecimalOperationError::DivisionByZero));
+        assert_eq!(
+            super::price(
+                Decimal::new(100_00i128, 2),
+                Decimal::new(500, 0),
+                Decimal::new(-20_000, 0),
+                Decimal::new(4, 0),
+                2,
+                YieldConvention::Compound,
+                RoundingMode::HalfEven,
+            ),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+    }
+}
@@ -0,0 +1,217 @@
+use crate::core::{
+    bond::quote::{coupon, dirty_value, split_periods},
+    interest::{annuity::GUARD_DECIMALS, irr::find_rate},
+    percentage::bps::fraction_to_bps,
+    CheckedDecimalOperations, CheckedDivideToScale, CompareDecimals, Decimal,
+    DecimalOperationError, PowerOfTen, RescaleDecimals, RoundingMode, TranscendentalDecimals,
+    YieldConvention, YieldError, Zero,
+};
+
+/// Computes the yield to maturity of a fixed-rate bond from its clean price: the annual yield at
+/// which [`price`](crate::core::bond::price) returns it.
+///
+/// The arguments follow [`price`](crate::core::bond::price): `periods` is the number of coupon
+/// periods until maturity and may be fractional, and the coupon rate is an annual number of
+/// basis points paid `frequency` times a year. The interest accrued since the last coupon is
+/// added to the clean price, and the yield per period is searched like the
+/// [`irr`](fn@crate::core::interest::irr), bracketing a sign change between `-99.9999%` and
+/// `655,360%` a period and bisecting it down to `10^-18`. The annual yield, `frequency` times
+/// the yield per period, is rounded once to a number of basis points with `scale` decimals.
+///
+/// # Arguments
+///
+/// * `clean_price` - The price without the accrued interest, with the decimals of the face value.
+/// * `face` - The face value, repaid at maturity.
+/// * `coupon_rate_bps` - The annual coupon rate, in basis points.
+/// * `periods` - The number of coupon periods until maturity.
+/// * `frequency` - The number of coupons a year.
+/// * `convention` - How the fraction of a period until the next coupon is discounted.
+/// * `scale` - The number of decimals of the yield, in basis points.
+/// * `rounding` - The rounding applied to the yield.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the annual yield in basis points, or a `YieldError` if no yield
+/// in the range searched gives the price, reported as `NotBracketed` with the number of yields
+/// tried, the search doesn't converge, reported as `NoConvergence` with the number of
+/// bisections made, or the operation fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{bond, Decimal, RoundingMode, YieldConvention};
+///
+/// // A 5% semiannual bond quoted at 957.35, 5 years from maturity, yields 6%.
+/// let ytm = bond::ytm(
+///     Decimal::new(957_35i128, 2),
+///     Decimal::new(1_000_00, 2),
+///     Decimal::new(500, 0),
+///     Decimal::new(10, 0),
+///     2,
+///     YieldConvention::Compound,
+///     2,
+///     RoundingMode::HalfEven,
+/// )
+/// .unwrap();
+/// assert_eq!(ytm, Decimal::new(600_00, 2));
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn ytm<T>(
+    clean_price: Decimal<T>,
+    face: Decimal<T>,
+    coupon_rate_bps: Decimal<T>,
+    periods: Decimal<T>,
+    frequency: u32,
+    convention: YieldConvention,
+    scale: u32,
+    rounding: RoundingMode,
+) -> Result<Decimal<T>, YieldError>
+where
+    T: CheckedDecimalOperations
+        + CheckedDivideToScale
+        + CompareDecimals
+        + RescaleDecimals
+        + TranscendentalDecimals
+        + PowerOfTen
+        + Zero
+        + From<u32>
+        + Copy,
+{
+    let decimals = face
+        .scale()
+        .max(clean_price.scale())
+        .checked_add(GUARD_DECIMALS)
+        .ok_or(DecimalOperationError::ScaleTooLarge)?;
+    let coupon = coupon(
+        face,
+        coupon_rate_bps,
+        frequency,
+        decimals,
+        RoundingMode::HalfEven,
+    )?;
+    let (_, fraction) = split_periods(periods)?;
+    let accrued = coupon.try_mul(Decimal::one(0)?.try_sub(fraction)?)?;
+    let dirty_price = clean_price.try_add(accrued)?;
+    let one = Decimal::one(0)?;
+    let rate = find_rate(|rate: Decimal<T>| {
+        dirty_value(
+            face,
+            coupon,
+            one.try_add(rate)?,
+            periods,
+            convention,
+            decimals,
+        )?
+        .try_sub(dirty_price)
+    })?;
+    let rate = rate.try_mul(Decimal::new(T::from(frequency), 0))?;
+    Ok(fraction_to_bps(rate, scale, rounding)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::bond::price;
+
+    #[test]
+    fn test_ytm() -> Result<(), YieldError> {
+        let ytm = |clean, face, coupon, periods, frequency, convention| {
+            ytm(
+                clean,
+                face,
+                Decimal::new(coupon, 0),
+                periods,
+                frequency,
+                convention,
+                2,
+                RoundingMode::HalfEven,
+            )
+        };
+        let face = Decimal::new(1_000_00i128, 2);
+        assert_eq!(
+            ytm(
+                Decimal::new(959_10, 2),
+                face,
+                500,
+                Decimal::new(9_5, 1),
+                2,
+                YieldConvention::Compound
+            )?,
+            Decimal::new(600_01, 2)
+        );
+        assert_eq!(
+            ytm(
+                Decimal::new(98_50, 2),
+                Decimal::new(100_00, 2),
+                450,
+                Decimal::new(7_25, 2),
+                4,
+                YieldConvention::Simple
+            )?,
+            Decimal::new(537_23, 2)
+        );
+        // At par, the yield is the coupon rate.
+        assert_eq!(
+            ytm(
+                face,
+                face,
+                725,
+                Decimal::new(12, 0),
+                12,
+                YieldConvention::Compound
+            )?,
+            Decimal::new(725_00, 2)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ytm_prices_back() -> Result<(), YieldError> {
+        let face = Decimal::new(100_0000i128, 4);
+        let periods = Decimal::new(19_4, 1);
+        let yield_bps = ytm(
+            Decimal::new(87_6543, 4),
+            face,
+            Decimal::new(350, 0),
+            periods,
+            2,
+            YieldConvention::Compound,
+            8,
+            RoundingMode::HalfEven,
+        )?;
+        let prices = price(
+            face,
+            Decimal::new(350, 0),
+            yield_bps,
+            periods,
+            2,
+            YieldConvention::Compound,
+            RoundingMode::HalfEven,
+        )?;
+        assert_eq!(prices.clean, Decimal::new(87_6543, 4));
+        Ok(())
+    }
+
+    #[test]
+    fn test_ytm_errors() {
+        let ytm = |clean, frequency| {
+            ytm(
+                Decimal::new(clean, 2),
+                Decimal::new(100_00i128, 2),
+                Decimal::new(500, 0),
+                Decimal::new(4, 0),
+                frequency,
+                YieldConvention::Compound,
+                2,
+                RoundingMode::HalfEven,
+            )
+        };
+        // No yield gives a price of zero: 1 + 17 + 20 yields are tried.
+        assert_eq!(ytm(0, 2), Err(YieldError::NotBracketed { evaluations: 38 }));
+        assert_eq!(
+            ytm(95_00, 0),
+            Err(YieldError::Decimal(DecimalOperationError::DivisionByZero))
+        );
+    }
+}
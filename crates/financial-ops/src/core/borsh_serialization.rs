@@ -0,0 +1,100 @@
+//! Borsh support, enabled with the `borsh` feature.
+//!
+//! A [`Decimal`] is encoded as its raw value followed by its scale as a little-endian `u32`, so
+//! it can be embedded in Solana account state and instruction data. [`Money`] is encoded like its
+//! decimal amount, since the currency is part of its type. [`U256`] and [`I256`] are
+//! encoded as 32 little-endian bytes, matching the layout of other 256-bit integer crates.
+
+use borsh::{
+    io::{Read, Result, Write},
+    BorshDeserialize, BorshSerialize,
+};
+
+use crate::core::{Decimal, Money, I256, U256};
+
+impl<T: BorshSerialize> BorshSerialize for Decimal<T> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.value_ref().serialize(writer)?;
+        self.scale().serialize(writer)
+    }
+}
+
+impl<T: BorshDeserialize> BorshDeserialize for Decimal<T> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let value = T::deserialize_reader(reader)?;
+        let scale = u32::deserialize_reader(reader)?;
+        Ok(Decimal::new(value, scale))
+    }
+}
+
+impl<C, T: BorshSerialize> BorshSerialize for Money<C, T> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.amount_ref().serialize(writer)?;
+        self.decimals().serialize(writer)
+    }
+}
+
+impl<C, T: BorshDeserialize> BorshDeserialize for Money<C, T> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        Decimal::deserialize_reader(reader).map(Money::from_decimal)
+    }
+}
+
+macro_rules! impl_wide_borsh {
+    ($($t:ty)*) => ($(
+        impl BorshSerialize for $t {
+            fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+                writer.write_all(&self.to_le_bytes())
+            }
+        }
+
+        impl BorshDeserialize for $t {
+            fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+                <[u8; 32]>::deserialize_reader(reader).map(<$t>::from_le_bytes)
+            }
+        }
+    )*)
+}
+
+impl_wide_borsh! { U256 I256 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Usd;
+
+    #[test]
+    fn test_decimal_borsh_layout() {
+        let value = Decimal::new(1_50u64, 2);
+        let bytes = borsh::to_vec(&value).unwrap();
+        assert_eq!(bytes, [150, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0]);
+        assert_eq!(borsh::from_slice::<Decimal<u64>>(&bytes).unwrap(), value);
+    }
+
+    #[test]
+    fn test_wide_borsh_round_trip() {
+        let value = Decimal::new(I256::MIN, 18);
+        let bytes = borsh::to_vec(&value).unwrap();
+        assert_eq!(bytes.len(), 36);
+        assert_eq!(borsh::from_slice::<Decimal<I256>>(&bytes).unwrap(), value);
+
+        let value = U256::from_parts(7, u128::MAX);
+        assert_eq!(
+            borsh::from_slice::<U256>(&borsh::to_vec(&value).unwrap()).unwrap(),
+            value
+        );
+    }
+
+    #[test]
+    fn test_money_borsh_layout() {
+        let value = Money::<Usd, u64>::new(1_50, 2);
+        let bytes = borsh::to_vec(&value).unwrap();
+        assert_eq!(bytes, borsh::to_vec(&value.decimal()).unwrap());
+        assert_eq!(borsh::from_slice::<Money<Usd, u64>>(&bytes).unwrap(), value);
+    }
+
+    #[test]
+    fn test_decimal_borsh_truncated_input() {
+        assert!(borsh::from_slice::<Decimal<u64>>(&[150, 0, 0, 0, 0, 0, 0, 0, 2]).is_err());
+    }
+}
@@ -0,0 +1,120 @@
+use crate::core::{
+    CheckedDecimalOperations, CheckedMulDiv, Decimal, DecimalOperationError, PowerOfTen,
+    RescaleDecimals, RoundingMode,
+};
+
+/// A trait for rounding amounts to the increments cash can be paid in, like `0.05` for Swiss
+/// francs.
+pub trait CashRoundingDecimals {
+    /// Rounds a value with `decimals` decimals to a multiple of `increment`.
+    ///
+    /// The difference is reported separately so point-of-sale systems can book it. With an
+    /// unsigned type, rounding down fails with `NegativeResult` because the difference is
+    /// negative.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The value to round.
+    /// * `decimals` - The number of decimals in the value.
+    /// * `increment` - The positive increment, which must be representable with `decimals`
+    ///   decimals.
+    /// * `rounding` - The rounding applied to the number of increments.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the rounded value and the difference `rounded - self`, both
+    /// with `decimals` decimals, or a `DecimalOperationError` if the increment is zero, has more
+    /// decimals than the value or the operation overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use financial_ops::{CashRoundingDecimals, Decimal, RoundingMode};
+    ///
+    /// let (total, difference) = 12_32i64
+    ///     .round_to_cash_increment(2, Decimal::new(5, 2), RoundingMode::HalfEven)
+    ///     .unwrap();
+    /// assert_eq!((total, difference), (12_30, -0_02));
+    /// ```
+    fn round_to_cash_increment(
+        self,
+        decimals: u32,
+        increment: Decimal<Self>,
+        rounding: RoundingMode,
+    ) -> Result<(Self, Self), DecimalOperationError>
+    where
+        Self: Sized;
+}
+
+// Blanket implementation of the CashRoundingDecimals trait for all types supporting the checked
+// decimal operations and rescaling
+impl<T> CashRoundingDecimals for T
+where
+    T: CheckedDecimalOperations + CheckedMulDiv + RescaleDecimals + PowerOfTen + Copy,
+{
+    fn round_to_cash_increment(
+        self,
+        decimals: u32,
+        increment: Decimal<Self>,
+        rounding: RoundingMode,
+    ) -> Result<(Self, Self), DecimalOperationError> {
+        let (step, _) = increment.value().rescale_checked(
+            increment.scale(),
+            decimals,
+            RoundingMode::Unnecessary,
+        )?;
+        let one = T::checked_power_of_ten(0).ok_or(DecimalOperationError::ScaleTooLarge)?;
+        let increments = self.checked_mul_div(one, step, rounding)?;
+        let (rounded, _) = increments.multiply_decimals_checked(step, 0, 0)?;
+        let (difference, _) = rounded.sub_decimals_checked(self, 0, 0)?;
+        Ok((rounded, difference))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_to_cash_increment() -> Result<(), DecimalOperationError> {
+        let five_cents = Decimal::new(5i64, 2);
+        assert_eq!(
+            12_32i64.round_to_cash_increment(2, five_cents, RoundingMode::HalfEven)?,
+            (12_30, -0_02)
+        );
+        assert_eq!(
+            12_33i64.round_to_cash_increment(2, five_cents, RoundingMode::HalfEven)?,
+            (12_35, 0_02)
+        );
+        assert_eq!(
+            (-12_33i64).round_to_cash_increment(2, five_cents, RoundingMode::HalfEven)?,
+            (-12_35, -0_02)
+        );
+        // 12.375 is 247.5 increments of 0.050.
+        assert_eq!(
+            12_375i64.round_to_cash_increment(3, five_cents, RoundingMode::HalfEven)?,
+            (12_400, 0_025)
+        );
+        assert_eq!(
+            12_32u64.round_to_cash_increment(2, Decimal::new(10, 2), RoundingMode::Up)?,
+            (12_40, 0_08)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_to_cash_increment_errors() {
+        assert_eq!(
+            12_32u64.round_to_cash_increment(2, Decimal::new(5, 2), RoundingMode::Down),
+            Err(DecimalOperationError::NegativeResult)
+        );
+        assert_eq!(
+            12_32i64.round_to_cash_increment(2, Decimal::new(0, 2), RoundingMode::Down),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+        assert_eq!(
+            12_32i64.round_to_cash_increment(2, Decimal::new(5, 3), RoundingMode::Down),
+            Err(DecimalOperationError::PrecisionLoss)
+        );
+    }
+}
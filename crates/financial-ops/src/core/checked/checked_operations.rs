@@ -1,5 +1,8 @@
 use crate::{
-    core::{CheckedAdd, CheckedDiv, CheckedMul, CheckedRem, CheckedSub, DecimalOperationError},
+    core::{
+        CheckedAdd, CheckedDiv, CheckedMul, CheckedRem, CheckedSub, DecimalOperationError,
+        PowerOfTen, Signedness,
+    },
     impl_checked_arithmetic,
 };
 
@@ -118,10 +121,72 @@ pub trait CheckedDecimalOperations {
         Self: Sized;
 }
 
+/// Returns the error describing a result that fell outside the range of the type,
+/// based on the sign the exact result would have had.
+fn out_of_range_error(negative: bool) -> DecimalOperationError {
+    if negative {
+        DecimalOperationError::Underflow
+    } else {
+        DecimalOperationError::Overflow
+    }
+}
+
+/// Scales `value` by `factor`, reporting an underflow when a negative value falls below the
+/// minimum of the type.
+fn scale_checked<T>(value: &T, factor: &T) -> Result<T, DecimalOperationError>
+where
+    T: CheckedMul + Signedness,
+{
+    value
+        .checked_mul(factor)
+        .ok_or_else(|| out_of_range_error(value.is_negative()))
+}
+
+/// Adds two aligned values. Two values can only leave the range of the type when both have
+/// the same sign, so the sign of `other` tells overflows apart from underflows.
+fn add_aligned<T>(value: T, other: T) -> Result<T, DecimalOperationError>
+where
+    T: CheckedAdd + Signedness,
+{
+    value
+        .checked_add(&other)
+        .ok_or_else(|| out_of_range_error(other.is_negative()))
+}
+
+/// Subtracts two aligned values. Unsigned types can only fail when the result would be negative,
+/// while signed types underflow when subtracting a positive value and overflow otherwise.
+fn sub_aligned<T>(value: T, other: T) -> Result<T, DecimalOperationError>
+where
+    T: CheckedSub + Signedness,
+{
+    value.checked_sub(&other).ok_or_else(|| {
+        if !T::is_signed() {
+            DecimalOperationError::NegativeResult
+        } else {
+            out_of_range_error(!other.is_negative())
+        }
+    })
+}
+
+/// Maps a failed checked division or remainder to an error. The only failing division with a
+/// negative divisor is `MIN / -1`, which overflows; every other failure is a division by zero.
+fn division_error<T: Signedness>(divisor: &T) -> DecimalOperationError {
+    if divisor.is_negative() {
+        DecimalOperationError::Overflow
+    } else {
+        DecimalOperationError::DivisionByZero
+    }
+}
+
+/// Computes the factor `10^exponent` used to align decimals in the target type.
+fn scale_factor<T: PowerOfTen>(exponent: u32) -> Result<T, DecimalOperationError> {
+    T::checked_power_of_ten(exponent).ok_or(DecimalOperationError::ScaleTooLarge)
+}
+
 // Blanket implementation of the DecimalOps trait for all types implementing numeric operations
 impl<T> CheckedDecimalOperations for T
 where
-    T: CheckedAdd + CheckedSub + CheckedMul + CheckedDiv + CheckedRem + From<u32>,
+    T: CheckedAdd + CheckedSub + CheckedMul + CheckedDiv + CheckedRem + Signedness + PowerOfTen,
 {
     fn add_decimals_checked(
         self,
@@ -130,24 +195,13 @@ where
         other_decimals: u32,
     ) -> Result<(Self, u32), DecimalOperationError> {
         if self_decimals > other_decimals {
-            let factor = T::from(10u32.pow(self_decimals - other_decimals));
-            match self.checked_add(
-                &other
-                    .checked_mul(&factor)
-                    .ok_or(DecimalOperationError::Overflow)?,
-            ) {
-                Some(value) => Ok((value, self_decimals)),
-                None => Err(DecimalOperationError::Overflow),
-            }
+            let factor = scale_factor::<T>(self_decimals - other_decimals)?;
+            let other = scale_checked(&other, &factor)?;
+            Ok((add_aligned(self, other)?, self_decimals))
         } else {
-            let factor = T::from(10u32.pow(other_decimals - self_decimals));
-            match self
-                .checked_mul(&factor)
-                .and_then(|x| x.checked_add(&other))
-            {
-                Some(value) => Ok((value, other_decimals)),
-                None => Err(DecimalOperationError::Overflow),
-            }
+            let factor = scale_factor::<T>(other_decimals - self_decimals)?;
+            let value = scale_checked(&self, &factor)?;
+            Ok((add_aligned(value, other)?, other_decimals))
         }
     }
 
@@ -158,24 +212,13 @@ where
         other_decimals: u32,
     ) -> Result<(Self, u32), DecimalOperationError> {
         if self_decimals > other_decimals {
-            let factor = T::from(10u32.pow(self_decimals - other_decimals));
-            match self.checked_sub(
-                &other
-                    .checked_mul(&factor)
-                    .ok_or(DecimalOperationError::Overflow)?,
-            ) {
-                Some(value) => Ok((value, self_decimals)),
-                None => Err(DecimalOperationError::Overflow),
-            }
+            let factor = scale_factor::<T>(self_decimals - other_decimals)?;
+            let other = scale_checked(&other, &factor)?;
+            Ok((sub_aligned(self, other)?, self_decimals))
         } else {
-            let factor = T::from(10u32.pow(other_decimals - self_decimals));
-            match self
-                .checked_mul(&factor)
-                .and_then(|x| x.checked_sub(&other))
-            {
-                Some(value) => Ok((value, other_decimals)),
-                None => Err(DecimalOperationError::Overflow),
-            }
+            let factor = scale_factor::<T>(other_decimals - self_decimals)?;
+            let value = scale_checked(&self, &factor)?;
+            Ok((sub_aligned(value, other)?, other_decimals))
         }
     }
 
@@ -185,9 +228,14 @@ where
         self_decimals: u32,
         other_decimals: u32,
     ) -> Result<(Self, u32), DecimalOperationError> {
+        let decimals = self_decimals
+            .checked_add(other_decimals)
+            .ok_or(DecimalOperationError::ScaleTooLarge)?;
         match self.checked_mul(&other) {
-            Some(value) => Ok((value, self_decimals + other_decimals)),
-            None => Err(DecimalOperationError::Overflow),
+            Some(value) => Ok((value, decimals)),
+            None => Err(out_of_range_error(
+                self.is_negative() != other.is_negative(),
+            )),
         }
     }
 
@@ -197,13 +245,11 @@ where
         self_decimals: u32,
         other_decimals: u32,
     ) -> Result<(Self, u32), DecimalOperationError> {
-        let factor = T::from(10u32.pow(other_decimals));
-        let adjusted_value = self
-            .checked_mul(&factor)
-            .ok_or(DecimalOperationError::Overflow)?;
+        let factor = scale_factor::<T>(other_decimals)?;
+        let adjusted_value = scale_checked(&self, &factor)?;
         match adjusted_value.checked_div(&other) {
             Some(value) => Ok((value, self_decimals)),
-            None => Err(DecimalOperationError::DivisionByZero),
+            None => Err(division_error(&other)),
         }
     }
 
@@ -213,13 +259,11 @@ where
         self_decimals: u32,
         _other_decimals: u32,
     ) -> Result<(Self, u32), DecimalOperationError> {
-        let factor = T::from(10u32.pow(self_decimals));
-        let adjusted_value = self
-            .checked_mul(&factor)
-            .ok_or(DecimalOperationError::Overflow)?;
+        let factor = scale_factor::<T>(self_decimals)?;
+        let adjusted_value = scale_checked(&self, &factor)?;
         match adjusted_value.checked_rem(&other) {
             Some(value) => Ok((value, self_decimals)),
-            None => Err(DecimalOperationError::DivisionByZero),
+            None => Err(division_error(&other)),
         }
     }
 }
@@ -342,4 +386,149 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_signed_decimals() -> Result<(), Box<dyn std::error::Error>> {
+        let (result, decimals) = (-1_50i64).add_decimals_checked(2_0, 2, 1)?;
+        assert_eq!(result, 50);
+        assert_eq!(decimals, 2);
+
+        let (result, decimals) = 1_0i64.sub_decimals_checked(2_25, 1, 2)?;
+        assert_eq!(result, -1_25);
+        assert_eq!(decimals, 2);
+
+        let (result, decimals) = (-1_5i64).multiply_decimals_checked(2_0, 1, 1)?;
+        assert_eq!(result, -3_00);
+        assert_eq!(decimals, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sub_decimals_errors() {
+        assert_eq!(
+            1_00u64.sub_decimals_checked(2_00, 2, 2),
+            Err(DecimalOperationError::NegativeResult)
+        );
+        assert_eq!(
+            i64::MIN.sub_decimals_checked(1, 0, 0),
+            Err(DecimalOperationError::Underflow)
+        );
+        assert_eq!(
+            i64::MAX.sub_decimals_checked(-1, 0, 0),
+            Err(DecimalOperationError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_signed_overflow_errors() {
+        assert_eq!(
+            i64::MIN.add_decimals_checked(-1, 0, 0),
+            Err(DecimalOperationError::Underflow)
+        );
+        assert_eq!(
+            i64::MAX.add_decimals_checked(1, 0, 0),
+            Err(DecimalOperationError::Overflow)
+        );
+        assert_eq!(
+            (i64::MIN / 2).add_decimals_checked(0, 0, 1),
+            Err(DecimalOperationError::Underflow)
+        );
+        assert_eq!(
+            i64::MAX.multiply_decimals_checked(-2, 0, 0),
+            Err(DecimalOperationError::Underflow)
+        );
+        assert_eq!(
+            i64::MIN.multiply_decimals_checked(-1, 0, 0),
+            Err(DecimalOperationError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_division_errors() {
+        assert_eq!(
+            1i64.divide_decimals_checked(0, 0, 0),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+        assert_eq!(
+            i64::MIN.divide_decimals_checked(-1, 0, 0),
+            Err(DecimalOperationError::Overflow)
+        );
+        assert_eq!(
+            1u64.rem_decimals_checked(0, 0, 0),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_small_integer_decimals() -> Result<(), Box<dyn std::error::Error>> {
+        let (result, decimals) = 1_5u8.add_decimals_checked(0_25, 1, 2)?;
+        assert_eq!(result, 1_75);
+        assert_eq!(decimals, 2);
+
+        let (result, decimals) = (-1_2i8).sub_decimals_checked(0_05, 1, 2)?;
+        assert_eq!(result, -1_25);
+        assert_eq!(decimals, 2);
+
+        let (result, decimals) = 12_34u16.multiply_decimals_checked(2, 2, 0)?;
+        assert_eq!(result, 24_68);
+        assert_eq!(decimals, 2);
+
+        let (result, decimals) = (-12_00i16).divide_decimals_checked(4_0, 2, 1)?;
+        assert_eq!(result, -3_00);
+        assert_eq!(decimals, 2);
+
+        assert_eq!(
+            (-1_2i8).sub_decimals_checked(0_10, 1, 2),
+            Err(DecimalOperationError::Underflow)
+        );
+        assert_eq!(
+            1u8.add_decimals_checked(1, 0, 3),
+            Err(DecimalOperationError::ScaleTooLarge)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_large_scale_factors() -> Result<(), Box<dyn std::error::Error>> {
+        let a: u128 = 1_000000000000000000;
+        let a_decimals = 18;
+        let b: u128 = 2;
+        let b_decimals = 0;
+
+        let (result, decimals) = a.add_decimals_checked(b, a_decimals, b_decimals)?;
+        assert_eq!(result, 3_000000000000000000);
+        assert_eq!(decimals, 18);
+
+        let (result, decimals) = b.sub_decimals_checked(a, b_decimals, a_decimals)?;
+        assert_eq!(result, 1_000000000000000000);
+        assert_eq!(decimals, 18);
+
+        let (result, decimals) = 1_0000000000u128.divide_decimals_checked(2_0000000000, 10, 10)?;
+        assert_eq!(result, 5000000000);
+        assert_eq!(decimals, 10);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scale_too_large() {
+        assert_eq!(
+            1u32.add_decimals_checked(1, 0, 10),
+            Err(DecimalOperationError::ScaleTooLarge)
+        );
+        assert_eq!(
+            1u128.sub_decimals_checked(1, 39, 0),
+            Err(DecimalOperationError::ScaleTooLarge)
+        );
+        assert_eq!(
+            1u64.divide_decimals_checked(1, 0, 20),
+            Err(DecimalOperationError::ScaleTooLarge)
+        );
+        assert_eq!(
+            1u64.multiply_decimals_checked(1, u32::MAX, 1),
+            Err(DecimalOperationError::ScaleTooLarge)
+        );
+    }
 }
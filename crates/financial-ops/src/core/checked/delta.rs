@@ -0,0 +1,179 @@
+use crate::core::{
+    percentage::percent::fraction_decimals, CheckedDecimalOperations, CheckedDivideToScale,
+    CheckedSignDecimals, DecimalOperationError, RoundingMode,
+};
+
+/// A trait for the relative change between two values with decimals, like the move of a price
+/// or a P&L between two reports.
+pub trait PercentChangeDecimals {
+    /// Computes the change from `self` to `new` as a percentage of `self`.
+    ///
+    /// The change is divided by the magnitude of `self`, so it is negative whenever `new` is
+    /// smaller, even when both values are negative.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The old value.
+    /// * `new` - The new value.
+    /// * `self_decimals` - The number of decimals in the old value.
+    /// * `new_decimals` - The number of decimals in the new value.
+    /// * `scale` - The number of decimals of the percentage.
+    /// * `rounding` - The rounding applied to the percentage.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the percentage and `scale`, or a `DecimalOperationError` if
+    /// the old value is zero or the operation fails, like a decrease with an unsigned type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use financial_ops::{PercentChangeDecimals, RoundingMode};
+    ///
+    /// // From 80.00 to 92.5: +15.625%
+    /// let change = 80_00i64.percent_change_checked(92_5, 2, 1, 2, RoundingMode::HalfEven);
+    /// assert_eq!(change, Ok((15_62, 2)));
+    /// ```
+    fn percent_change_checked(
+        self,
+        new: Self,
+        self_decimals: u32,
+        new_decimals: u32,
+        scale: u32,
+        rounding: RoundingMode,
+    ) -> Result<(Self, u32), DecimalOperationError>
+    where
+        Self: Sized;
+}
+
+// Blanket implementation of the PercentChangeDecimals trait for all types supporting the checked
+// decimal operations, signs and division to a scale
+impl<T> PercentChangeDecimals for T
+where
+    T: CheckedDecimalOperations + CheckedDivideToScale + CheckedSignDecimals + Copy,
+{
+    fn percent_change_checked(
+        self,
+        new: Self,
+        self_decimals: u32,
+        new_decimals: u32,
+        scale: u32,
+        rounding: RoundingMode,
+    ) -> Result<(Self, u32), DecimalOperationError> {
+        let (change, change_decimals) =
+            new.sub_decimals_checked(self, new_decimals, self_decimals)?;
+        let (base, _) = self.abs_decimals_checked(self_decimals)?;
+        let (percent, _) = change.divide_decimals_to_scale_checked(
+            base,
+            change_decimals,
+            self_decimals,
+            fraction_decimals(scale)?,
+            rounding,
+        )?;
+        Ok((percent, scale))
+    }
+}
+
+/// A trait for the distance between two values with decimals, without the sign handling of a
+/// subtraction.
+pub trait AbsDiffDecimals {
+    /// Computes `|self - other|`, aligning the decimals of both values.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The first value.
+    /// * `other` - The second value.
+    /// * `self_decimals` - The number of decimals in the first value.
+    /// * `other_decimals` - The number of decimals in the second value.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the distance and its number of decimals, or a
+    /// `DecimalOperationError` if it doesn't fit in the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use financial_ops::AbsDiffDecimals;
+    ///
+    /// assert_eq!(1_50u64.abs_diff_decimals(2_125, 2, 3), Ok((0_625, 3)));
+    /// assert_eq!((-1_50i64).abs_diff_decimals(2_00, 2, 2), Ok((3_50, 2)));
+    /// ```
+    fn abs_diff_decimals(
+        self,
+        other: Self,
+        self_decimals: u32,
+        other_decimals: u32,
+    ) -> Result<(Self, u32), DecimalOperationError>
+    where
+        Self: Sized;
+}
+
+// Blanket implementation of the AbsDiffDecimals trait for all types supporting the checked
+// decimal operations and signs
+impl<T> AbsDiffDecimals for T
+where
+    T: CheckedDecimalOperations + CheckedSignDecimals + Copy,
+{
+    fn abs_diff_decimals(
+        self,
+        other: Self,
+        self_decimals: u32,
+        other_decimals: u32,
+    ) -> Result<(Self, u32), DecimalOperationError> {
+        match self.sub_decimals_checked(other, self_decimals, other_decimals) {
+            Ok((difference, decimals)) => difference.abs_decimals_checked(decimals),
+            // Unsigned types can only subtract the smaller value from the larger one.
+            Err(DecimalOperationError::NegativeResult) => {
+                other.sub_decimals_checked(self, other_decimals, self_decimals)
+            }
+            Err(error) => Err(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percent_change_checked() -> Result<(), DecimalOperationError> {
+        assert_eq!(
+            100_00i64.percent_change_checked(75_00, 2, 2, 2, RoundingMode::HalfEven)?,
+            (-25_00, 2)
+        );
+        assert_eq!(
+            (-50i64).percent_change_checked(-40, 0, 0, 1, RoundingMode::HalfEven)?,
+            (20_0, 1)
+        );
+        assert_eq!(
+            3u64.percent_change_checked(4, 0, 0, 3, RoundingMode::HalfEven)?,
+            (33_333, 3)
+        );
+        assert_eq!(
+            0i64.percent_change_checked(1, 2, 2, 2, RoundingMode::HalfEven),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+        assert_eq!(
+            4u64.percent_change_checked(3, 0, 0, 2, RoundingMode::HalfEven),
+            Err(DecimalOperationError::NegativeResult)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_abs_diff_decimals() -> Result<(), DecimalOperationError> {
+        assert_eq!(2_00u64.abs_diff_decimals(1_5, 2, 1)?, (0_50, 2));
+        assert_eq!(1_5u64.abs_diff_decimals(2_00, 1, 2)?, (0_50, 2));
+        assert_eq!((-3i8).abs_diff_decimals(4, 0, 0)?, (7, 0));
+        assert_eq!(
+            i8::MIN.abs_diff_decimals(1, 0, 0),
+            Err(DecimalOperationError::Underflow)
+        );
+        assert_eq!(
+            i8::MIN.abs_diff_decimals(0, 0, 0),
+            Err(DecimalOperationError::Overflow)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+use crate::core::{CheckedMul, CheckedMulDiv, DecimalOperationError, PowerOfTen, RoundingMode};
+
+/// A trait for dividing values with decimals into a quotient with a chosen number of decimals.
+///
+/// `divide_decimals_checked` keeps the decimals of the dividend, which throws away precision for
+/// quotients like `1 / 3`. This trait lets callers request the number of decimals of the result,
+/// rounding the quotient once with the given [`RoundingMode`].
+pub trait CheckedDivideToScale {
+    /// Divides two values with decimals and returns the quotient with `target_decimals` decimals.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The value to divide.
+    /// * `other` - The value to divide by.
+    /// * `self_decimals` - The number of decimals in the first value.
+    /// * `other_decimals` - The number of decimals in the second value.
+    /// * `target_decimals` - The number of decimals of the quotient.
+    /// * `rounding` - The rounding applied to the quotient.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the quotient and `target_decimals`, or a `DecimalOperationError`
+    /// if the operation fails. The factor bringing the quotient to `target_decimals` is part of
+    /// the intermediate product, so only the quotient has to fit in the type.
+    fn divide_decimals_to_scale_checked(
+        self,
+        other: Self,
+        self_decimals: u32,
+        other_decimals: u32,
+        target_decimals: u32,
+        rounding: RoundingMode,
+    ) -> Result<(Self, u32), DecimalOperationError>
+    where
+        Self: Sized;
+}
+
+// Blanket implementation of the CheckedDivideToScale trait for all types supporting a widened multiply and divide
+impl<T> CheckedDivideToScale for T
+where
+    T: CheckedMulDiv + CheckedMul + PowerOfTen,
+{
+    fn divide_decimals_to_scale_checked(
+        self,
+        other: Self,
+        self_decimals: u32,
+        other_decimals: u32,
+        target_decimals: u32,
+        rounding: RoundingMode,
+    ) -> Result<(Self, u32), DecimalOperationError> {
+        // quotient = self * 10^(target_decimals + other_decimals - self_decimals) / other, with the
+        // power of ten kept in the intermediate product.
+        let exponent =
+            i64::from(target_decimals) + i64::from(other_decimals) - i64::from(self_decimals);
+        let one = T::checked_power_of_ten(0).ok_or(DecimalOperationError::ScaleTooLarge)?;
+        let value = self.checked_mul_div_power_of_ten(one, other, exponent, rounding)?;
+        Ok((value, target_decimals))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_divide_decimals_to_scale() -> Result<(), Box<dyn std::error::Error>> {
+        let (result, decimals) =
+            1u128.divide_decimals_to_scale_checked(3, 0, 0, 18, RoundingMode::Down)?;
+        assert_eq!(result, 333_333_333_333_333_333);
+        assert_eq!(decimals, 18);
+
+        let (result, decimals) =
+            2_00u64.divide_decimals_to_scale_checked(3_0, 2, 1, 4, RoundingMode::HalfUp)?;
+        assert_eq!(result, 0_6667);
+        assert_eq!(decimals, 4);
+
+        let (result, decimals) =
+            123_456789u64.divide_decimals_to_scale_checked(2, 6, 0, 2, RoundingMode::HalfEven)?;
+        assert_eq!(result, 61_73);
+        assert_eq!(decimals, 2);
+
+        let (result, decimals) =
+            (-1i64).divide_decimals_to_scale_checked(3, 0, 0, 2, RoundingMode::Floor)?;
+        assert_eq!(result, -0_34);
+        assert_eq!(decimals, 2);
+
+        // Neither 10^20 nor 3 * 10^20 fits in a u64, the quotient does.
+        let (result, _) =
+            1u64.divide_decimals_to_scale_checked(3_00, 0, 2, 18, RoundingMode::HalfEven)?;
+        assert_eq!(result, 333_333_333_333_333_333);
+        let (result, _) =
+            2u64.divide_decimals_to_scale_checked(3, 20, 0, 0, RoundingMode::HalfEven)?;
+        assert_eq!(result, 0);
+        let (result, _) =
+            (-2i64).divide_decimals_to_scale_checked(3, 19, 0, 19, RoundingMode::Floor)?;
+        assert_eq!(result, -1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_divide_decimals_to_scale_errors() {
+        assert_eq!(
+            1u64.divide_decimals_to_scale_checked(0, 0, 0, 2, RoundingMode::Down),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+        assert_eq!(
+            1u64.divide_decimals_to_scale_checked(3, 0, 0, 20, RoundingMode::Down),
+            Err(DecimalOperationError::Overflow)
+        );
+        assert_eq!(
+            u64::MAX.divide_decimals_to_scale_checked(1, 0, 0, 2, RoundingMode::Down),
+            Err(DecimalOperationError::Overflow)
+        );
+    }
+}
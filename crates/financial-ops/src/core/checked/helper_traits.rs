@@ -17,3 +17,21 @@ pub trait CheckedDiv: Sized {
 pub trait CheckedRem: Sized {
     fn checked_rem(&self, v: &Self) -> Option<Self>;
 }
+
+pub trait CheckedNeg: Sized {
+    fn checked_neg(&self) -> Option<Self>;
+}
+
+pub trait CheckedAbs: Sized {
+    fn checked_abs(&self) -> Option<Self>;
+}
+
+pub trait Signedness: Sized {
+    fn is_signed() -> bool;
+    fn is_negative(&self) -> bool;
+}
+
+pub trait Zero: Sized {
+    fn zero() -> Self;
+    fn is_zero(&self) -> bool;
+}
@@ -1,10 +1,11 @@
 /// Implements checked arithmetic operations for the specified types.
 ///
 /// This macro generates implementations of the `CheckedAdd`, `CheckedSub`,
-/// `CheckedMul`, `CheckedDiv`, and `CheckedRem` traits for the given types.
-/// These traits provide methods for performing arithmetic operations that
+/// `CheckedMul`, `CheckedDiv`, `CheckedRem`, `CheckedNeg` and `CheckedAbs`
+/// traits for the given types. These traits provide methods for performing arithmetic operations that
 /// return an `Option` containing the result, instead of panicking on overflow
-/// or division by zero.
+/// or division by zero. It also implements `Signedness`, which is used to tell
+/// overflows apart from underflows and negative results, and `Zero`.
 ///
 /// # Examples
 ///
@@ -34,30 +35,60 @@
 #[macro_export]
 macro_rules! impl_checked_arithmetic {
     ($($t:ty)*) => ($(
-        impl crate::core::CheckedAdd for $t {
+        impl $crate::core::CheckedAdd for $t {
             fn checked_add(&self, v: &Self) -> Option<Self> {
                 <$t>::checked_add(*self, *v)
             }
         }
-        impl crate::core::CheckedSub for $t {
+        impl $crate::core::CheckedSub for $t {
             fn checked_sub(&self, v: &Self) -> Option<Self> {
                 <$t>::checked_sub(*self, *v)
             }
         }
-        impl crate::core::CheckedMul for $t {
+        impl $crate::core::CheckedMul for $t {
             fn checked_mul(&self, v: &Self) -> Option<Self> {
                 <$t>::checked_mul(*self, *v)
             }
         }
-        impl crate::core::CheckedDiv for $t {
+        impl $crate::core::CheckedDiv for $t {
             fn checked_div(&self, v: &Self) -> Option<Self> {
                 <$t>::checked_div(*self, *v)
             }
         }
-        impl crate::core::CheckedRem for $t {
+        impl $crate::core::CheckedRem for $t {
             fn checked_rem(&self, v: &Self) -> Option<Self> {
                 <$t>::checked_rem(*self, *v)
             }
         }
+        impl $crate::core::CheckedNeg for $t {
+            fn checked_neg(&self) -> Option<Self> {
+                <$t>::checked_neg(*self)
+            }
+        }
+        impl $crate::core::CheckedAbs for $t {
+            fn checked_abs(&self) -> Option<Self> {
+                if $crate::core::Signedness::is_negative(self) {
+                    <$t>::checked_neg(*self)
+                } else {
+                    Some(*self)
+                }
+            }
+        }
+        impl $crate::core::Signedness for $t {
+            fn is_signed() -> bool {
+                <$t>::MIN != 0
+            }
+            fn is_negative(&self) -> bool {
+                *self < (0 as $t)
+            }
+        }
+        impl $crate::core::Zero for $t {
+            fn zero() -> Self {
+                0
+            }
+            fn is_zero(&self) -> bool {
+                *self == 0
+            }
+        }
     )*)
 }
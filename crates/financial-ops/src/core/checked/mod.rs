@@ -1,6 +1,26 @@
+pub mod cash_rounding;
 pub mod checked_operations;
+pub mod delta;
+pub mod divide_to_scale;
 pub mod helper_traits;
 pub mod impl_checked_arithmetic_macro;
+pub mod mul_div;
+pub mod pow;
+pub mod rescale;
+pub mod sign;
+pub mod sqrt;
+pub mod sum_product;
+pub mod token_amount;
 
+pub use cash_rounding::*;
 pub use checked_operations::*;
+pub use delta::*;
+pub use divide_to_scale::*;
 pub use helper_traits::*;
+pub use mul_div::*;
+pub use pow::*;
+pub use rescale::*;
+pub use sign::*;
+pub use sqrt::*;
+pub use sum_product::*;
+pub use token_amount::*;
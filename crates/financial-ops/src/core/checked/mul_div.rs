@@ -0,0 +1,507 @@
+use core::cmp::Ordering;
+
+use crate::core::{wide::U256, DecimalOperationError, PowerOfTen, RoundingMode};
+
+/// A trait for computing `self * multiplier / denominator` without overflowing in the
+/// intermediate product.
+///
+/// Expressions like `amount * price / scale` overflow as soon as the product doesn't fit in the
+/// type, even when the final result does. The product is computed in a wider type instead
+/// (`u128` for the 64-bit and smaller integers, and a 256-bit intermediate for `u128` and `i128`),
+/// and the quotient is rounded once with the requested [`RoundingMode`].
+pub trait CheckedMulDiv {
+    /// Multiplies `self` by `multiplier` and divides the product by `denominator`.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The value to multiply.
+    /// * `multiplier` - The value to multiply by.
+    /// * `denominator` - The value to divide the product by.
+    /// * `rounding` - The rounding applied to the quotient.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the rounded quotient, or a `DecimalOperationError` if the
+    /// denominator is zero or the quotient doesn't fit in the type.
+    fn checked_mul_div(
+        self,
+        multiplier: Self,
+        denominator: Self,
+        rounding: RoundingMode,
+    ) -> Result<Self, DecimalOperationError>
+    where
+        Self: Sized;
+
+    /// Multiplies `self` by `multiplier` and by `10^exponent`, and divides the product by
+    /// `denominator`. A negative exponent divides the product by `10^-exponent` instead.
+    ///
+    /// The power of ten is part of the intermediate product, so it doesn't have to fit in the
+    /// type, only the quotient does: scaling a value down by more decimals than the type can hold
+    /// rounds it to zero or one unit. The default implementation computes the power of ten in the
+    /// type and reports `ScaleTooLarge` when it can't.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The value to multiply.
+    /// * `multiplier` - The value to multiply by.
+    /// * `denominator` - The value to divide the product by.
+    /// * `exponent` - The power of ten to multiply the product by, or to divide it by if negative.
+    /// * `rounding` - The rounding applied to the quotient.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the rounded quotient, or a `DecimalOperationError` if the
+    /// denominator is zero or the quotient doesn't fit in the type.
+    fn checked_mul_div_power_of_ten(
+        self,
+        multiplier: Self,
+        denominator: Self,
+        exponent: i64,
+        rounding: RoundingMode,
+    ) -> Result<Self, DecimalOperationError>
+    where
+        Self: PowerOfTen + Sized,
+    {
+        let one = Self::checked_power_of_ten(0).ok_or(DecimalOperationError::ScaleTooLarge)?;
+        let factor = u32::try_from(exponent.unsigned_abs())
+            .ok()
+            .and_then(Self::checked_power_of_ten)
+            .ok_or(DecimalOperationError::ScaleTooLarge)?;
+        // A product with a denominator of one is exact, or overflows.
+        if exponent >= 0 {
+            let multiplier = multiplier
+                .checked_mul_div(factor, one, RoundingMode::Unnecessary)
+                .map_err(|_| DecimalOperationError::ScaleTooLarge)?;
+            self.checked_mul_div(multiplier, denominator, rounding)
+        } else {
+            let denominator = denominator
+                .checked_mul_div(factor, one, RoundingMode::Unnecessary)
+                .map_err(|_| DecimalOperationError::ScaleTooLarge)?;
+            self.checked_mul_div(multiplier, denominator, rounding)
+        }
+    }
+}
+
+/// A trait for computing the quotient and the remainder of `self * multiplier / denominator`
+/// without overflowing in the intermediate product.
+///
+/// Like `/` and `%`, the quotient is truncated towards zero and the remainder has the sign of the
+/// product, so `quotient * denominator + remainder == self * multiplier`.
+pub trait CheckedMulDivRem {
+    /// Multiplies `self` by `multiplier` and divides the product by `denominator`.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The value to multiply.
+    /// * `multiplier` - The value to multiply by.
+    /// * `denominator` - The value to divide the product by.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the truncated quotient and the remainder, or a
+    /// `DecimalOperationError` if the denominator is zero or the quotient doesn't fit in the type.
+    fn checked_mul_div_rem(
+        self,
+        multiplier: Self,
+        denominator: Self,
+    ) -> Result<(Self, Self), DecimalOperationError>
+    where
+        Self: Sized;
+}
+
+/// Computes `|a * b|` divided by `denominator`, returning the truncated quotient and the
+/// remainder.
+fn mul_div_rem_magnitude(
+    a: u128,
+    b: u128,
+    denominator: u128,
+) -> Result<(U256, u128), DecimalOperationError> {
+    U256::widening_mul(a, b)
+        .div_rem_u128(denominator)
+        .ok_or(DecimalOperationError::DivisionByZero)
+}
+
+/// Computes `|a * b / denominator|` rounded with `rounding`, where `negative` is the sign of the
+/// exact result.
+pub(crate) fn mul_div_magnitude(
+    a: u128,
+    b: u128,
+    denominator: u128,
+    negative: bool,
+    rounding: RoundingMode,
+) -> Result<u128, DecimalOperationError> {
+    let (quotient, remainder) = mul_div_rem_magnitude(a, b, denominator)?;
+    let discarded = (remainder != 0).then(|| remainder.cmp(&denominator.saturating_sub(remainder)));
+    round_magnitude(quotient, discarded, negative, rounding)
+}
+
+/// Computes `|a * b * 10^exponent / denominator|`, or `|a * b / (denominator * 10^-exponent)|`
+/// for a negative exponent, rounded with `rounding`, where `negative` is the sign of the exact
+/// result.
+///
+/// The product and the power of ten are computed in a [`U256`]. A numerator that doesn't fit in
+/// it gives a quotient above `u128::MAX`, and a divisor that doesn't fit in it is above the
+/// product, leaving a quotient of zero.
+pub(crate) fn mul_div_power_of_ten_magnitude(
+    a: u128,
+    b: u128,
+    denominator: u128,
+    exponent: i64,
+    negative: bool,
+    rounding: RoundingMode,
+) -> Result<u128, DecimalOperationError> {
+    if exponent == 0 || denominator == 0 {
+        return mul_div_magnitude(a, b, denominator, negative, rounding);
+    }
+    let product = U256::widening_mul(a, b);
+    let denominator = U256::from_u128(denominator);
+    let power = |exponent: u64| {
+        u32::try_from(exponent)
+            .ok()
+            .and_then(U256::checked_power_of_ten)
+    };
+    let (quotient, remainder, divisor) = if exponent > 0 {
+        let Some(numerator) = power(exponent.unsigned_abs()).and_then(|p| product.checked_mul(p))
+        else {
+            return match product.is_zero() {
+                true => Ok(0),
+                false if negative => Err(DecimalOperationError::Underflow),
+                false => Err(DecimalOperationError::Overflow),
+            };
+        };
+        let (quotient, remainder) = numerator
+            .div_rem(denominator)
+            .ok_or(DecimalOperationError::DivisionByZero)?;
+        (quotient, remainder, denominator)
+    } else {
+        let divisor = power(exponent.unsigned_abs()).and_then(|p| p.checked_mul(denominator));
+        let Some(divisor) = divisor else {
+            // The product is compared with half the divisor, `5 * 10^(-exponent - 1) * denominator`.
+            let half = power(exponent.unsigned_abs() - 1)
+                .and_then(|p| p.checked_mul(U256::from_u128(5)))
+                .and_then(|half| product.div_rem(half));
+            let discarded = (!product.is_zero()).then(|| match half {
+                Some((quotient, remainder)) => {
+                    quotient.cmp(&denominator).then(if remainder.is_zero() {
+                        Ordering::Equal
+                    } else {
+                        Ordering::Greater
+                    })
+                }
+                None => Ordering::Less,
+            });
+            return round_magnitude(U256::ZERO, discarded, negative, rounding);
+        };
+        let (quotient, remainder) = product
+            .div_rem(divisor)
+            .ok_or(DecimalOperationError::DivisionByZero)?;
+        (quotient, remainder, divisor)
+    };
+    let discarded = (!remainder.is_zero()).then(|| remainder.cmp(&divisor.wrapping_sub(remainder)));
+    round_magnitude(quotient, discarded, negative, rounding)
+}
+
+/// Rounds a quotient truncated towards zero, given how its discarded remainder compares to half
+/// a unit, and narrows it to a `u128`.
+fn round_magnitude(
+    quotient: U256,
+    discarded: Option<Ordering>,
+    negative: bool,
+    rounding: RoundingMode,
+) -> Result<u128, DecimalOperationError> {
+    let quotient = if rounding.rounds_away_from_zero(negative, quotient.is_odd(), discarded)? {
+        quotient.checked_increment()
+    } else {
+        Some(quotient)
+    };
+    quotient.and_then(U256::to_u128).ok_or(if negative {
+        DecimalOperationError::Underflow
+    } else {
+        DecimalOperationError::Overflow
+    })
+}
+
+macro_rules! impl_unsigned_mul_div {
+    ($($t:ty)*) => ($(
+        impl CheckedMulDiv for $t {
+            fn checked_mul_div(
+                self,
+                multiplier: Self,
+                denominator: Self,
+                rounding: RoundingMode,
+            ) -> Result<Self, DecimalOperationError> {
+                self.checked_mul_div_power_of_ten(multiplier, denominator, 0, rounding)
+            }
+
+            fn checked_mul_div_power_of_ten(
+                self,
+                multiplier: Self,
+                denominator: Self,
+                exponent: i64,
+                rounding: RoundingMode,
+            ) -> Result<Self, DecimalOperationError> {
+                let magnitude = mul_div_power_of_ten_magnitude(
+                    self as u128,
+                    multiplier as u128,
+                    denominator as u128,
+                    exponent,
+                    false,
+                    rounding,
+                )?;
+                <$t>::try_from(magnitude).map_err(|_| DecimalOperationError::Overflow)
+            }
+        }
+
+        impl CheckedMulDivRem for $t {
+            fn checked_mul_div_rem(
+                self,
+                multiplier: Self,
+                denominator: Self,
+            ) -> Result<(Self, Self), DecimalOperationError> {
+                let (quotient, remainder) =
+                    mul_div_rem_magnitude(self as u128, multiplier as u128, denominator as u128)?;
+                let quotient = quotient
+                    .to_u128()
+                    .and_then(|quotient| <$t>::try_from(quotient).ok())
+                    .ok_or(DecimalOperationError::Overflow)?;
+                // The remainder is below the denominator.
+                let remainder =
+                    <$t>::try_from(remainder).map_err(|_| DecimalOperationError::Overflow)?;
+                Ok((quotient, remainder))
+            }
+        }
+    )*)
+}
+
+macro_rules! impl_signed_mul_div {
+    ($($t:ty)*) => ($(
+        impl CheckedMulDiv for $t {
+            fn checked_mul_div(
+                self,
+                multiplier: Self,
+                denominator: Self,
+                rounding: RoundingMode,
+            ) -> Result<Self, DecimalOperationError> {
+                self.checked_mul_div_power_of_ten(multiplier, denominator, 0, rounding)
+            }
+
+            fn checked_mul_div_power_of_ten(
+                self,
+                multiplier: Self,
+                denominator: Self,
+                exponent: i64,
+                rounding: RoundingMode,
+            ) -> Result<Self, DecimalOperationError> {
+                let negative = (self < 0) ^ (multiplier < 0) ^ (denominator < 0);
+                let magnitude = mul_div_power_of_ten_magnitude(
+                    self.unsigned_abs() as u128,
+                    multiplier.unsigned_abs() as u128,
+                    denominator.unsigned_abs() as u128,
+                    exponent,
+                    negative,
+                    rounding,
+                )?;
+                if !negative {
+                    <$t>::try_from(magnitude).map_err(|_| DecimalOperationError::Overflow)
+                } else if magnitude == <$t>::MIN.unsigned_abs() as u128 {
+                    Ok(<$t>::MIN)
+                } else {
+                    <$t>::try_from(magnitude)
+                        .map(|value| -value)
+                        .map_err(|_| DecimalOperationError::Underflow)
+                }
+            }
+        }
+
+        impl CheckedMulDivRem for $t {
+            fn checked_mul_div_rem(
+                self,
+                multiplier: Self,
+                denominator: Self,
+            ) -> Result<(Self, Self), DecimalOperationError> {
+                let product_negative = (self < 0) ^ (multiplier < 0);
+                let negative = product_negative ^ (denominator < 0);
+                let (quotient, remainder) = mul_div_rem_magnitude(
+                    self.unsigned_abs() as u128,
+                    multiplier.unsigned_abs() as u128,
+                    denominator.unsigned_abs() as u128,
+                )?;
+                let quotient = match quotient.to_u128() {
+                    Some(magnitude) if negative && magnitude == <$t>::MIN.unsigned_abs() as u128 => {
+                        <$t>::MIN
+                    }
+                    Some(magnitude) => match <$t>::try_from(magnitude) {
+                        Ok(value) if negative => -value,
+                        Ok(value) => value,
+                        Err(_) if negative => return Err(DecimalOperationError::Underflow),
+                        Err(_) => return Err(DecimalOperationError::Overflow),
+                    },
+                    None if negative => return Err(DecimalOperationError::Underflow),
+                    None => return Err(DecimalOperationError::Overflow),
+                };
+                // The remainder is below the magnitude of the denominator, so it fits either way.
+                let remainder =
+                    <$t>::try_from(remainder).map_err(|_| DecimalOperationError::Overflow)?;
+                let remainder = if product_negative { -remainder } else { remainder };
+                Ok((quotient, remainder))
+            }
+        }
+    )*)
+}
+
+impl_unsigned_mul_div! { u8 u16 u32 u64 u128 usize }
+impl_signed_mul_div! { i8 i16 i32 i64 i128 isize }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checked_mul_div() -> Result<(), Box<dyn std::error::Error>> {
+        let amount: u64 = 1_500_000;
+        let price: u64 = 2_50;
+        assert_eq!(
+            amount.checked_mul_div(price, 100, RoundingMode::Down)?,
+            3_750_000
+        );
+
+        // The intermediate product doesn't fit in a u64.
+        assert_eq!(
+            u64::MAX.checked_mul_div(3, 3, RoundingMode::Down)?,
+            u64::MAX
+        );
+        assert_eq!(
+            u128::MAX.checked_mul_div(1_000_000, 1_000_000, RoundingMode::Down)?,
+            u128::MAX
+        );
+        assert_eq!(
+            i128::MIN.checked_mul_div(10, 10, RoundingMode::Down)?,
+            i128::MIN
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_checked_mul_div_rounding() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(10u32.checked_mul_div(1, 3, RoundingMode::Down)?, 3);
+        assert_eq!(10u32.checked_mul_div(1, 3, RoundingMode::Up)?, 4);
+        assert_eq!(10u32.checked_mul_div(1, 4, RoundingMode::HalfEven)?, 2);
+        assert_eq!(14u32.checked_mul_div(1, 4, RoundingMode::HalfEven)?, 4);
+        assert_eq!(10u32.checked_mul_div(1, 4, RoundingMode::HalfUp)?, 3);
+        assert_eq!(10u32.checked_mul_div(1, 4, RoundingMode::HalfDown)?, 2);
+
+        assert_eq!((-10i64).checked_mul_div(1, 3, RoundingMode::Down)?, -3);
+        assert_eq!((-10i64).checked_mul_div(1, 3, RoundingMode::Floor)?, -4);
+        assert_eq!((-10i64).checked_mul_div(1, 3, RoundingMode::Ceiling)?, -3);
+        assert_eq!(10i64.checked_mul_div(-1, -3, RoundingMode::Ceiling)?, 4);
+
+        let big = u128::MAX / 3;
+        assert_eq!(
+            big.checked_mul_div(7, 11, RoundingMode::Up)?,
+            (big / 11) * 7 + ((big % 11) * 7).div_ceil(11)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_checked_mul_div_power_of_ten() -> Result<(), Box<dyn std::error::Error>> {
+        // 12.5 * 3 / 2 with the product scaled by 10^20, which doesn't fit in a u64
+        assert_eq!(
+            1_25u64.checked_mul_div_power_of_ten(3, 2_00, 16, RoundingMode::Down)?,
+            18_750_000_000_000_000
+        );
+        assert_eq!(
+            (-5i64).checked_mul_div_power_of_ten(2, 1, 20, RoundingMode::Down),
+            Err(DecimalOperationError::Underflow)
+        );
+        assert_eq!(
+            0u8.checked_mul_div_power_of_ten(1, 1, 100, RoundingMode::Down)?,
+            0
+        );
+
+        // u128::MAX^2 / 10^40 is about 1.158 * 10^37.
+        assert_eq!(
+            u128::MAX.checked_mul_div_power_of_ten(u128::MAX, 1, -40, RoundingMode::Down)?,
+            11_579_208_923_731_619_542_357_098_500_868_790_785
+        );
+        // The divisor 2 * 10^77 doesn't fit in 256 bits, the product is exactly half of it.
+        let (a, b) = (3_125 * 10u128.pow(35), 32 * 10u128.pow(37));
+        assert_eq!(
+            a.checked_mul_div_power_of_ten(b, 2, -77, RoundingMode::HalfUp)?,
+            1
+        );
+        assert_eq!(
+            a.checked_mul_div_power_of_ten(b, 2, -77, RoundingMode::HalfEven)?,
+            0
+        );
+        assert_eq!(
+            (a + 1).checked_mul_div_power_of_ten(b, 2, -77, RoundingMode::HalfDown)?,
+            1
+        );
+        assert_eq!(
+            (-1i128).checked_mul_div_power_of_ten(1, 1, -1_000, RoundingMode::Floor)?,
+            -1
+        );
+        assert_eq!(
+            1u64.checked_mul_div_power_of_ten(1, 0, -1_000, RoundingMode::Floor),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_checked_mul_div_rem() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(10u32.checked_mul_div_rem(2, 3)?, (6, 2));
+        assert_eq!((-10i64).checked_mul_div_rem(2, 3)?, (-6, -2));
+        assert_eq!(10i64.checked_mul_div_rem(2, -3)?, (-6, 2));
+        assert_eq!(
+            u64::MAX.checked_mul_div_rem(u64::MAX - 2, u64::MAX - 1)?,
+            (u64::MAX - 2, u64::MAX - 2)
+        );
+        assert_eq!(
+            u128::MAX.checked_mul_div_rem(3, 4)?,
+            (u128::MAX / 4 * 3 + 2, 1)
+        );
+        assert_eq!(i8::MIN.checked_mul_div_rem(2, 2)?, (i8::MIN, 0));
+
+        assert_eq!(
+            1u64.checked_mul_div_rem(1, 0),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+        assert_eq!(
+            u64::MAX.checked_mul_div_rem(2, 1),
+            Err(DecimalOperationError::Overflow)
+        );
+        assert_eq!(
+            i8::MIN.checked_mul_div_rem(2, 1),
+            Err(DecimalOperationError::Underflow)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_checked_mul_div_errors() {
+        assert_eq!(
+            1u64.checked_mul_div(1, 0, RoundingMode::Down),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+        assert_eq!(
+            u64::MAX.checked_mul_div(2, 1, RoundingMode::Down),
+            Err(DecimalOperationError::Overflow)
+        );
+        assert_eq!(
+            i8::MIN.checked_mul_div(-1, 1, RoundingMode::Down),
+            Err(DecimalOperationError::Overflow)
+        );
+        assert_eq!(
+            i8::MIN.checked_mul_div(2, 1, RoundingMode::Down),
+            Err(DecimalOperationError::Underflow)
+        );
+        assert_eq!(
+            u128::MAX.checked_mul_div(2, 1, RoundingMode::Down),
+            Err(DecimalOperationError::Overflow)
+        );
+    }
+}
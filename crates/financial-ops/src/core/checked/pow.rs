@@ -0,0 +1,204 @@
+use crate::core::{
+    CheckedDecimalOperations, CheckedMulDiv, DecimalOperationError, PowerOfTen, RoundingMode,
+    Signedness,
+};
+
+/// A trait for raising values with decimals to an integer power.
+///
+/// Compounding factors like `(1 + r)^n` need repeated multiplication with the scale tracked at
+/// every step. The exact variant lets the number of decimals grow with every multiplication,
+/// while the rounded variant keeps the number of decimals of the base.
+pub trait CheckedPowDecimals {
+    /// Raises a value with decimals to the power of `exponent` without rounding.
+    ///
+    /// The result carries `decimals * exponent` decimals, so the raw value grows quickly; use
+    /// [`CheckedPowDecimals::pow_decimals_rounded_checked`] to keep the scale fixed.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The base.
+    /// * `exponent` - The power to raise the base to.
+    /// * `self_decimals` - The number of decimals in the base.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the power and the number of decimals in the result, or a
+    /// `DecimalOperationError` if the operation fails.
+    fn pow_decimals_checked(
+        self,
+        exponent: u32,
+        self_decimals: u32,
+    ) -> Result<(Self, u32), DecimalOperationError>
+    where
+        Self: Sized;
+
+    /// Raises a value with decimals to the power of `exponent`, keeping `self_decimals` decimals.
+    ///
+    /// Every intermediate product is rounded back to `self_decimals` decimals with `rounding`, so
+    /// the rounding error can accumulate over the `O(log exponent)` multiplications.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The base.
+    /// * `exponent` - The power to raise the base to.
+    /// * `self_decimals` - The number of decimals in the base and in the result.
+    /// * `rounding` - The rounding applied to every intermediate product.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the power and `self_decimals`, or a `DecimalOperationError`
+    /// if the operation fails.
+    fn pow_decimals_rounded_checked(
+        self,
+        exponent: u32,
+        self_decimals: u32,
+        rounding: RoundingMode,
+    ) -> Result<(Self, u32), DecimalOperationError>
+    where
+        Self: Sized;
+}
+
+/// Computes `base^exponent` by squaring, multiplying with `multiply`.
+fn square_and_multiply<V: Clone>(
+    base: V,
+    one: V,
+    exponent: u32,
+    multiply: impl Fn(V, V) -> Result<V, DecimalOperationError>,
+) -> Result<V, DecimalOperationError> {
+    let mut result = one;
+    let mut base = base;
+    let mut exponent = exponent;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = multiply(result, base.clone())?;
+        }
+        exponent >>= 1;
+        if exponent > 0 {
+            base = multiply(base.clone(), base)?;
+        }
+    }
+    Ok(result)
+}
+
+/// Squaring always reports `Overflow`; negative powers fall below the minimum instead.
+fn out_of_range_error<T: Signedness>(
+    base: &T,
+    exponent: u32,
+) -> impl Fn(DecimalOperationError) -> DecimalOperationError {
+    let negative = base.is_negative() && exponent & 1 == 1;
+    move |error| match error {
+        DecimalOperationError::Overflow if negative => DecimalOperationError::Underflow,
+        error => error,
+    }
+}
+
+// Blanket implementation of the CheckedPowDecimals trait for all types supporting checked decimal multiplication
+impl<T> CheckedPowDecimals for T
+where
+    T: CheckedDecimalOperations + CheckedMulDiv + PowerOfTen + Signedness + Clone,
+{
+    fn pow_decimals_checked(
+        self,
+        exponent: u32,
+        self_decimals: u32,
+    ) -> Result<(Self, u32), DecimalOperationError> {
+        let one = T::checked_power_of_ten(0).ok_or(DecimalOperationError::ScaleTooLarge)?;
+        let map_error = out_of_range_error(&self, exponent);
+        square_and_multiply(
+            (self, self_decimals),
+            (one, 0),
+            exponent,
+            |(a, a_decimals), (b, b_decimals)| {
+                a.multiply_decimals_checked(b, a_decimals, b_decimals)
+            },
+        )
+        .map_err(map_error)
+    }
+
+    fn pow_decimals_rounded_checked(
+        self,
+        exponent: u32,
+        self_decimals: u32,
+        rounding: RoundingMode,
+    ) -> Result<(Self, u32), DecimalOperationError> {
+        let scale =
+            T::checked_power_of_ten(self_decimals).ok_or(DecimalOperationError::ScaleTooLarge)?;
+        let map_error = out_of_range_error(&self, exponent);
+        square_and_multiply(self, scale.clone(), exponent, |a, b| {
+            a.checked_mul_div(b, scale.clone(), rounding)
+        })
+        .map(|value| (value, self_decimals))
+        .map_err(map_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pow_decimals_checked() -> Result<(), DecimalOperationError> {
+        assert_eq!(1_5u64.pow_decimals_checked(2, 1)?, (2_25, 2));
+        assert_eq!(1_01u64.pow_decimals_checked(3, 2)?, (1_030301, 6));
+        assert_eq!((-1_5i64).pow_decimals_checked(3, 1)?, (-3_375, 3));
+        assert_eq!(7_25u32.pow_decimals_checked(0, 2)?, (1, 0));
+        assert_eq!(0u8.pow_decimals_checked(5, 1)?, (0, 5));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pow_decimals_checked_errors() {
+        assert_eq!(
+            10u8.pow_decimals_checked(3, 0),
+            Err(DecimalOperationError::Overflow)
+        );
+        assert_eq!(
+            (-1_00i16).pow_decimals_checked(3, 2),
+            Err(DecimalOperationError::Underflow)
+        );
+        assert_eq!(
+            (-1_00i16).pow_decimals_checked(4, 2),
+            Err(DecimalOperationError::Overflow)
+        );
+        assert_eq!(
+            1u64.pow_decimals_checked(2, u32::MAX),
+            Err(DecimalOperationError::ScaleTooLarge)
+        );
+    }
+
+    #[test]
+    fn test_pow_decimals_rounded_checked() -> Result<(), DecimalOperationError> {
+        // Monthly compounding of 1% over a year.
+        assert_eq!(
+            1_010000u64.pow_decimals_rounded_checked(12, 6, RoundingMode::HalfEven)?,
+            (1_126825, 6)
+        );
+        assert_eq!(
+            1_05i64.pow_decimals_rounded_checked(2, 2, RoundingMode::Down)?,
+            (1_10, 2)
+        );
+        assert_eq!(
+            1_05i64.pow_decimals_rounded_checked(2, 2, RoundingMode::Up)?,
+            (1_11, 2)
+        );
+        assert_eq!(
+            (-2_0i32).pow_decimals_rounded_checked(3, 1, RoundingMode::Down)?,
+            (-8_0, 1)
+        );
+        assert_eq!(
+            1_000000000000000000u128.pow_decimals_rounded_checked(
+                1000,
+                18,
+                RoundingMode::Unnecessary
+            )?,
+            (1_000000000000000000, 18)
+        );
+        assert_eq!(
+            1_05u8.pow_decimals_rounded_checked(2, 2, RoundingMode::Unnecessary),
+            Err(DecimalOperationError::PrecisionLoss)
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,163 @@
+use crate::core::{
+    CheckedMul, CheckedMulDiv, DecimalOperationError, PowerOfTen, RoundingMode, Signedness,
+};
+
+/// A trait for changing the number of decimals a value is expressed with.
+pub trait RescaleDecimals {
+    /// Rescales a value from `from_decimals` to `to_decimals` decimals.
+    ///
+    /// Scaling up is always exact. Scaling down rounds the discarded digits with `rounding`; use
+    /// [`RoundingMode::Unnecessary`] to fail with `PrecisionLoss` instead of rounding. Scaling
+    /// down by more decimals than the type can hold rounds to zero or one unit.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The value to rescale.
+    /// * `from_decimals` - The number of decimals in the value.
+    /// * `to_decimals` - The number of decimals of the result.
+    /// * `rounding` - The rounding applied when scaling down.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the rescaled value and `to_decimals`, or a `DecimalOperationError`
+    /// if the operation fails.
+    fn rescale_checked(
+        self,
+        from_decimals: u32,
+        to_decimals: u32,
+        rounding: RoundingMode,
+    ) -> Result<(Self, u32), DecimalOperationError>
+    where
+        Self: Sized;
+}
+
+// Blanket implementation of the RescaleDecimals trait for all types supporting a widened multiply and divide
+impl<T> RescaleDecimals for T
+where
+    T: CheckedMul + CheckedMulDiv + PowerOfTen + Signedness,
+{
+    fn rescale_checked(
+        self,
+        from_decimals: u32,
+        to_decimals: u32,
+        rounding: RoundingMode,
+    ) -> Result<(Self, u32), DecimalOperationError> {
+        if to_decimals >= from_decimals {
+            let factor = T::checked_power_of_ten(to_decimals - from_decimals)
+                .ok_or(DecimalOperationError::ScaleTooLarge)?;
+            match self.checked_mul(&factor) {
+                Some(value) => Ok((value, to_decimals)),
+                None if self.is_negative() => Err(DecimalOperationError::Underflow),
+                None => Err(DecimalOperationError::Overflow),
+            }
+        } else {
+            // The factor doesn't need to fit in the type: a value scaled down by more decimals
+            // than the type holds rounds to zero or one unit.
+            let one = || T::checked_power_of_ten(0).ok_or(DecimalOperationError::ScaleTooLarge);
+            let exponent = -i64::from(from_decimals - to_decimals);
+            let value = self.checked_mul_div_power_of_ten(one()?, one()?, exponent, rounding)?;
+            Ok((value, to_decimals))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rescale_up() -> Result<(), Box<dyn std::error::Error>> {
+        let (value, decimals) = 1_25u64.rescale_checked(2, 6, RoundingMode::Unnecessary)?;
+        assert_eq!(value, 1_250000);
+        assert_eq!(decimals, 6);
+
+        assert_eq!(
+            u64::MAX.rescale_checked(0, 1, RoundingMode::Down),
+            Err(DecimalOperationError::Overflow)
+        );
+        assert_eq!(
+            i64::MIN.rescale_checked(0, 1, RoundingMode::Down),
+            Err(DecimalOperationError::Underflow)
+        );
+        assert_eq!(
+            1u32.rescale_checked(0, 10, RoundingMode::Down),
+            Err(DecimalOperationError::ScaleTooLarge)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rescale_down() -> Result<(), Box<dyn std::error::Error>> {
+        let (value, decimals) = 1_255u64.rescale_checked(3, 2, RoundingMode::HalfEven)?;
+        assert_eq!(value, 1_26);
+        assert_eq!(decimals, 2);
+
+        let (value, _) = 1_245u64.rescale_checked(3, 2, RoundingMode::HalfEven)?;
+        assert_eq!(value, 1_24);
+
+        let (value, _) = (-1_245i64).rescale_checked(3, 2, RoundingMode::Floor)?;
+        assert_eq!(value, -1_25);
+
+        let (value, _) =
+            1_000000000000000000u128.rescale_checked(18, 6, RoundingMode::Unnecessary)?;
+        assert_eq!(value, 1_000000);
+
+        assert_eq!(
+            1_255u64.rescale_checked(3, 2, RoundingMode::Unnecessary),
+            Err(DecimalOperationError::PrecisionLoss)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rescale_down_beyond_type() -> Result<(), Box<dyn std::error::Error>> {
+        // 10^19 doesn't fit in an i64, the value is 0.6 or -0.6 of it.
+        let value = 6_000_000_000_000_000_000i64;
+        assert_eq!(
+            value.rescale_checked(21, 2, RoundingMode::HalfEven)?,
+            (1, 2)
+        );
+        assert_eq!(
+            (-value).rescale_checked(21, 2, RoundingMode::HalfEven)?,
+            (-1, 2)
+        );
+        assert_eq!(value.rescale_checked(21, 2, RoundingMode::Down)?, (0, 2));
+        assert_eq!(
+            (-value).rescale_checked(21, 2, RoundingMode::Ceiling)?,
+            (0, 2)
+        );
+        assert_eq!(
+            (-value).rescale_checked(21, 2, RoundingMode::Floor)?,
+            (-1, 2)
+        );
+
+        // Exactly half of 10^19, and well below half of 10^40
+        let half = 5_000_000_000_000_000_000i64;
+        assert_eq!(half.rescale_checked(19, 0, RoundingMode::HalfEven)?, (0, 0));
+        assert_eq!(half.rescale_checked(19, 0, RoundingMode::HalfUp)?, (1, 0));
+        assert_eq!(
+            (half + 1).rescale_checked(19, 0, RoundingMode::HalfDown)?,
+            (1, 0)
+        );
+        assert_eq!(
+            u64::MAX.rescale_checked(40, 0, RoundingMode::HalfUp)?,
+            (0, 0)
+        );
+        assert_eq!(
+            u64::MAX.rescale_checked(u32::MAX, 0, RoundingMode::Up)?,
+            (1, 0)
+        );
+        assert_eq!(
+            0u8.rescale_checked(9, 0, RoundingMode::Unnecessary)?,
+            (0, 0)
+        );
+        assert_eq!(
+            1u8.rescale_checked(9, 0, RoundingMode::Unnecessary),
+            Err(DecimalOperationError::PrecisionLoss)
+        );
+
+        Ok(())
+    }
+}
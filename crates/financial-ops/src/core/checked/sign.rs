@@ -0,0 +1,88 @@
+use crate::core::{CheckedAbs, CheckedNeg, DecimalOperationError, Signedness};
+
+/// A trait for changing the sign of values with decimals without panicking or wrapping.
+///
+/// Negating `i64::MIN` has no representable result; these methods report it as an error instead.
+pub trait CheckedSignDecimals {
+    /// Negates a value with decimals.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The value to negate.
+    /// * `decimals` - The number of decimals in the value.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the negated value and `decimals`, or a `DecimalOperationError`
+    /// if the operation fails. Negating the minimum of a signed type returns `Overflow`, and negating
+    /// a non-zero unsigned value returns `NegativeResult`.
+    fn neg_decimals_checked(self, decimals: u32) -> Result<(Self, u32), DecimalOperationError>
+    where
+        Self: Sized;
+
+    /// Computes the absolute value of a value with decimals.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The value.
+    /// * `decimals` - The number of decimals in the value.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the absolute value and `decimals`, or `Overflow` for the
+    /// minimum of a signed type.
+    fn abs_decimals_checked(self, decimals: u32) -> Result<(Self, u32), DecimalOperationError>
+    where
+        Self: Sized;
+}
+
+// Blanket implementation of the CheckedSignDecimals trait for all types supporting checked negation
+impl<T> CheckedSignDecimals for T
+where
+    T: CheckedNeg + CheckedAbs + Signedness,
+{
+    fn neg_decimals_checked(self, decimals: u32) -> Result<(Self, u32), DecimalOperationError> {
+        match self.checked_neg() {
+            Some(value) => Ok((value, decimals)),
+            None if T::is_signed() => Err(DecimalOperationError::Overflow),
+            None => Err(DecimalOperationError::NegativeResult),
+        }
+    }
+
+    fn abs_decimals_checked(self, decimals: u32) -> Result<(Self, u32), DecimalOperationError> {
+        self.checked_abs()
+            .map(|value| (value, decimals))
+            .ok_or(DecimalOperationError::Overflow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_neg_decimals_checked() {
+        assert_eq!((1_25i64).neg_decimals_checked(2), Ok((-1_25, 2)));
+        assert_eq!((-1_25i64).neg_decimals_checked(2), Ok((1_25, 2)));
+        assert_eq!(0u64.neg_decimals_checked(2), Ok((0, 2)));
+        assert_eq!(
+            i64::MIN.neg_decimals_checked(2),
+            Err(DecimalOperationError::Overflow)
+        );
+        assert_eq!(
+            1u64.neg_decimals_checked(2),
+            Err(DecimalOperationError::NegativeResult)
+        );
+    }
+
+    #[test]
+    fn test_abs_decimals_checked() {
+        assert_eq!((-1_25i32).abs_decimals_checked(2), Ok((1_25, 2)));
+        assert_eq!(1_25i32.abs_decimals_checked(2), Ok((1_25, 2)));
+        assert_eq!(u8::MAX.abs_decimals_checked(0), Ok((u8::MAX, 0)));
+        assert_eq!(
+            i128::MIN.abs_decimals_checked(6),
+            Err(DecimalOperationError::Overflow)
+        );
+    }
+}
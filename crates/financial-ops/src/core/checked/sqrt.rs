@@ -0,0 +1,140 @@
+use crate::core::{wide::U256, DecimalOperationError};
+
+/// A trait for computing the square root of values with decimals.
+pub trait CheckedSqrtDecimals {
+    /// Computes the square root of a value with decimals, with `target_decimals` decimals.
+    ///
+    /// The root is computed with an integer Newton iteration on a 256-bit intermediate and is
+    /// always rounded down, so squaring the result never exceeds the input.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The value.
+    /// * `decimals` - The number of decimals in the value.
+    /// * `target_decimals` - The number of decimals of the root.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the root and `target_decimals`, or a `DecimalOperationError`
+    /// if the operation fails. Negative values return `OutOfDomain`.
+    fn sqrt_decimals_checked(
+        self,
+        decimals: u32,
+        target_decimals: u32,
+    ) -> Result<(Self, u32), DecimalOperationError>
+    where
+        Self: Sized;
+}
+
+/// Computes `floor(sqrt(value / 10^decimals) * 10^target_decimals)`.
+fn sqrt_magnitude(
+    value: u128,
+    decimals: u32,
+    target_decimals: u32,
+) -> Result<u128, DecimalOperationError> {
+    // root = sqrt(value * 10^(2 * target_decimals - decimals))
+    let numerator_exponent = 2 * u64::from(target_decimals);
+    let denominator_exponent = u64::from(decimals);
+    let radicand = if value == 0 {
+        U256::ZERO
+    } else if numerator_exponent >= denominator_exponent {
+        let factor = u32::try_from(numerator_exponent - denominator_exponent)
+            .ok()
+            .and_then(|exponent| 10u128.checked_pow(exponent))
+            .ok_or(DecimalOperationError::ScaleTooLarge)?;
+        U256::widening_mul(value, factor)
+    } else {
+        // Truncating before the root doesn't change the rounded down result.
+        let divisor = u32::try_from(denominator_exponent - numerator_exponent)
+            .ok()
+            .and_then(|exponent| 10u128.checked_pow(exponent));
+        match divisor {
+            Some(divisor) => U256::from_u128(value / divisor),
+            None => U256::ZERO,
+        }
+    };
+    Ok(radicand.isqrt())
+}
+
+macro_rules! impl_unsigned_sqrt {
+    ($($t:ty)*) => ($(
+        impl CheckedSqrtDecimals for $t {
+            fn sqrt_decimals_checked(
+                self,
+                decimals: u32,
+                target_decimals: u32,
+            ) -> Result<(Self, u32), DecimalOperationError> {
+                let root = sqrt_magnitude(self as u128, decimals, target_decimals)?;
+                <$t>::try_from(root)
+                    .map(|root| (root, target_decimals))
+                    .map_err(|_| DecimalOperationError::Overflow)
+            }
+        }
+    )*)
+}
+
+macro_rules! impl_signed_sqrt {
+    ($($t:ty)*) => ($(
+        impl CheckedSqrtDecimals for $t {
+            fn sqrt_decimals_checked(
+                self,
+                decimals: u32,
+                target_decimals: u32,
+            ) -> Result<(Self, u32), DecimalOperationError> {
+                if self < 0 {
+                    return Err(DecimalOperationError::OutOfDomain);
+                }
+                let root = sqrt_magnitude(self as u128, decimals, target_decimals)?;
+                <$t>::try_from(root)
+                    .map(|root| (root, target_decimals))
+                    .map_err(|_| DecimalOperationError::Overflow)
+            }
+        }
+    )*)
+}
+
+impl_unsigned_sqrt! { u8 u16 u32 u64 u128 usize }
+impl_signed_sqrt! { i8 i16 i32 i64 i128 isize }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sqrt_decimals_checked() -> Result<(), DecimalOperationError> {
+        assert_eq!(2u64.sqrt_decimals_checked(0, 6)?, (1_414213, 6));
+        assert_eq!(2_25u32.sqrt_decimals_checked(2, 1)?, (1_5, 1));
+        assert_eq!(0i64.sqrt_decimals_checked(4, 4)?, (0, 4));
+        assert_eq!(1_000000u64.sqrt_decimals_checked(6, 0)?, (1, 0));
+        assert_eq!(0_0001u64.sqrt_decimals_checked(4, 2)?, (0_01, 2));
+        assert_eq!(0_0001u64.sqrt_decimals_checked(4, 1)?, (0_0, 1));
+        assert_eq!(
+            i64::MAX.sqrt_decimals_checked(0, 6)?,
+            (3037000499_976049, 6)
+        );
+
+        // The radicand doesn't fit in a u128.
+        assert_eq!(
+            u128::MAX.sqrt_decimals_checked(18, 18)?,
+            (18446744073_709551615999999999, 18)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sqrt_decimals_checked_errors() {
+        assert_eq!(
+            (-1i32).sqrt_decimals_checked(0, 2),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        assert_eq!(
+            u8::MAX.sqrt_decimals_checked(0, 2),
+            Err(DecimalOperationError::Overflow)
+        );
+        assert_eq!(
+            1u64.sqrt_decimals_checked(0, 20),
+            Err(DecimalOperationError::ScaleTooLarge)
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use crate::core::{CheckedDecimalOperations, DecimalOperationError, PowerOfTen, Zero};
+
+/// A value that can be read as an operand of type `T`, implemented for `T` and `&T` so the
+/// iterator adapters below accept both `iter()` and `into_iter()`.
+pub trait DecimalOperand<T> {
+    /// Returns the value as an operand.
+    fn into_operand(self) -> T;
+}
+
+impl<T: CheckedDecimalOperations> DecimalOperand<T> for T {
+    fn into_operand(self) -> T {
+        self
+    }
+}
+
+impl<T: CheckedDecimalOperations + Copy> DecimalOperand<T> for &T {
+    fn into_operand(self) -> T {
+        *self
+    }
+}
+
+/// An extension trait summing an iterator of values that all carry the same number of decimals,
+/// failing instead of overflowing.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{CheckedSumDecimals, DecimalOperationError};
+///
+/// let amounts = [19_99u64, 5_01, 75_00];
+/// assert_eq!(amounts.iter().checked_sum_decimals(2), Ok((100_00, 2)));
+///
+/// let amounts = [u64::MAX, 1];
+/// assert_eq!(
+///     amounts.iter().checked_sum_decimals(2),
+///     Err(DecimalOperationError::Overflow)
+/// );
+/// ```
+pub trait CheckedSumDecimals<T>: Iterator {
+    /// Adds up the values, each with `decimals` decimals.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the sum, zero for an empty iterator, and its number of
+    /// decimals, or a `DecimalOperationError` if an addition fails.
+    fn checked_sum_decimals(self, decimals: u32) -> Result<(T, u32), DecimalOperationError>;
+}
+
+impl<T, I> CheckedSumDecimals<T> for I
+where
+    T: CheckedDecimalOperations + Zero,
+    I: Iterator,
+    I::Item: DecimalOperand<T>,
+{
+    fn checked_sum_decimals(mut self, decimals: u32) -> Result<(T, u32), DecimalOperationError> {
+        self.try_fold((T::zero(), decimals), |(sum, _), value| {
+            sum.add_decimals_checked(value.into_operand(), decimals, decimals)
+        })
+    }
+}
+
+/// An extension trait multiplying an iterator of values that all carry the same number of
+/// decimals, failing instead of overflowing.
+///
+/// Like [`CheckedDecimalOperations::multiply_decimals_checked`], the decimals of the product add
+/// up, so the product of `n` values has `n * decimals` decimals.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::CheckedProductDecimals;
+///
+/// // 1.10 * 1.20 * 0.50
+/// let factors = [1_10u64, 1_20, 0_50];
+/// assert_eq!(factors.iter().checked_product_decimals(2), Ok((0_660000, 6)));
+/// ```
+pub trait CheckedProductDecimals<T>: Iterator {
+    /// Multiplies the values, each with `decimals` decimals.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the product, one with no decimals for an empty iterator,
+    /// and its number of decimals, or a `DecimalOperationError` if a multiplication fails.
+    fn checked_product_decimals(self, decimals: u32) -> Result<(T, u32), DecimalOperationError>;
+}
+
+impl<T, I> CheckedProductDecimals<T> for I
+where
+    T: CheckedDecimalOperations + PowerOfTen,
+    I: Iterator,
+    I::Item: DecimalOperand<T>,
+{
+    fn checked_product_decimals(
+        mut self,
+        decimals: u32,
+    ) -> Result<(T, u32), DecimalOperationError> {
+        let one = T::checked_power_of_ten(0).ok_or(DecimalOperationError::ScaleTooLarge)?;
+        self.try_fold((one, 0), |(product, product_decimals), value| {
+            product.multiply_decimals_checked(value.into_operand(), product_decimals, decimals)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checked_sum_decimals() -> Result<(), DecimalOperationError> {
+        let amounts = [1_50i64, -2_25, 10_00];
+        assert_eq!(amounts.iter().checked_sum_decimals(2)?, (9_25, 2));
+        assert_eq!(amounts.into_iter().checked_sum_decimals(2)?, (9_25, 2));
+        assert_eq!(
+            amounts
+                .iter()
+                .map(|amount| amount * 10)
+                .checked_sum_decimals(3)?,
+            (9_250, 3)
+        );
+        assert_eq!(core::iter::empty::<u8>().checked_sum_decimals(2)?, (0, 2));
+
+        assert_eq!(
+            [i8::MIN, -1].iter().checked_sum_decimals(0),
+            Err(DecimalOperationError::Underflow)
+        );
+        assert_eq!(
+            [200u8, 100].iter().checked_sum_decimals(0),
+            Err(DecimalOperationError::Overflow)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_checked_product_decimals() -> Result<(), DecimalOperationError> {
+        let factors = [1_5i32, -2_0, 0_5];
+        assert_eq!(factors.iter().checked_product_decimals(1)?, (-1_500, 3));
+        assert_eq!(
+            core::iter::empty::<u8>().checked_product_decimals(2)?,
+            (1, 0)
+        );
+
+        assert_eq!(
+            [u64::MAX, 2].iter().checked_product_decimals(0),
+            Err(DecimalOperationError::Overflow)
+        );
+        assert_eq!(
+            [i64::MAX, -2].iter().checked_product_decimals(0),
+            Err(DecimalOperationError::Underflow)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+use crate::core::{DecimalOperationError, RescaleDecimals, RoundingMode};
+
+/// The rounding applied when a token amount moves between mints with different decimals.
+///
+/// Bridges and pools must never hand out more than they received, so amounts credited to a user
+/// round down and amounts debited from a user round up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenRounding {
+    /// Rounds an amount credited to a user towards negative infinity, so the dust stays with the
+    /// protocol.
+    Credit,
+    /// Rounds an amount debited from a user towards positive infinity, so the dust is charged to
+    /// the user.
+    Debit,
+    /// Rounds with the given mode.
+    Mode(RoundingMode),
+}
+
+impl TokenRounding {
+    /// Returns the rounding mode applied to the converted amount.
+    pub const fn rounding_mode(self) -> RoundingMode {
+        match self {
+            TokenRounding::Credit => RoundingMode::Floor,
+            TokenRounding::Debit => RoundingMode::Ceiling,
+            TokenRounding::Mode(rounding) => rounding,
+        }
+    }
+}
+
+impl From<RoundingMode> for TokenRounding {
+    fn from(rounding: RoundingMode) -> Self {
+        TokenRounding::Mode(rounding)
+    }
+}
+
+/// A trait for converting raw token amounts between mints with different decimals, like a
+/// 6-decimal stablecoin and its 9-decimal wrapped version.
+pub trait ConvertTokenAmount {
+    /// Converts a raw amount of a mint with `from_decimals` decimals into the raw amount of a
+    /// mint with `to_decimals` decimals.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The raw amount to convert.
+    /// * `from_decimals` - The decimals of the source mint.
+    /// * `to_decimals` - The decimals of the destination mint.
+    /// * `rounding` - Whether the amount is credited to or debited from the user, or the
+    ///   rounding mode to apply when dropping decimals.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the converted amount, or a `DecimalOperationError` if the
+    /// operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use financial_ops::{ConvertTokenAmount, TokenRounding};
+    ///
+    /// let deposit = 1_234_567_891u64;
+    /// assert_eq!(deposit.convert_token_amount(9, 6, TokenRounding::Credit), Ok(1_234_567));
+    /// assert_eq!(deposit.convert_token_amount(9, 6, TokenRounding::Debit), Ok(1_234_568));
+    /// ```
+    fn convert_token_amount(
+        self,
+        from_decimals: u32,
+        to_decimals: u32,
+        rounding: TokenRounding,
+    ) -> Result<Self, DecimalOperationError>
+    where
+        Self: Sized;
+}
+
+// Blanket implementation of the ConvertTokenAmount trait for all types that can be rescaled
+impl<T: RescaleDecimals> ConvertTokenAmount for T {
+    fn convert_token_amount(
+        self,
+        from_decimals: u32,
+        to_decimals: u32,
+        rounding: TokenRounding,
+    ) -> Result<Self, DecimalOperationError> {
+        let (amount, _) =
+            self.rescale_checked(from_decimals, to_decimals, rounding.rounding_mode())?;
+        Ok(amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_token_amount() -> Result<(), DecimalOperationError> {
+        assert_eq!(
+            1_500000u64.convert_token_amount(6, 9, TokenRounding::Credit)?,
+            1_500000000
+        );
+        assert_eq!(
+            1_999_999_999u64.convert_token_amount(9, 6, TokenRounding::Credit)?,
+            1_999_999
+        );
+        assert_eq!(
+            1_000_000_001u64.convert_token_amount(9, 6, TokenRounding::Debit)?,
+            1_000_001
+        );
+        assert_eq!(
+            (-1_000_000_001i64).convert_token_amount(9, 6, TokenRounding::Credit)?,
+            -1_000_001
+        );
+        assert_eq!(
+            1_000_000_500u64.convert_token_amount(9, 6, RoundingMode::HalfEven.into())?,
+            1_000_000
+        );
+        assert_eq!(
+            1_000_000_001u64.convert_token_amount(
+                9,
+                6,
+                TokenRounding::Mode(RoundingMode::Unnecessary)
+            ),
+            Err(DecimalOperationError::PrecisionLoss)
+        );
+        assert_eq!(
+            u64::MAX.convert_token_amount(6, 9, TokenRounding::Debit),
+            Err(DecimalOperationError::Overflow)
+        );
+        Ok(())
+    }
+}
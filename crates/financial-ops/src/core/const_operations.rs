@@ -0,0 +1,250 @@
+//! `const fn` versions of the checked decimal operations.
+//!
+//! Trait methods can't be called in const contexts, so these functions are generated for every
+//! primitive width, named after the operation and the type (`const_add_decimals_u64`,
+//! `const_mul_decimals_i128`, ...). They follow the same rules and report the same errors as
+//! [`CheckedDecimalOperations`](crate::core::CheckedDecimalOperations), so protocol constants like
+//! fee factors can be computed at compile time.
+//!
+//! # Examples
+//!
+//! ```
+//! use financial_ops::const_mul_decimals_u64;
+//!
+//! // A 0.30% fee applied to a 1.5 multiplier, computed at compile time.
+//! const FEE_FACTOR: (u64, u32) = match const_mul_decimals_u64(1_5, 0_0030, 1, 4) {
+//!     Ok(value) => value,
+//!     Err(_) => panic!("fee factor overflows"),
+//! };
+//!
+//! assert_eq!(FEE_FACTOR, (0_00450, 5));
+//! ```
+
+use crate::core::DecimalOperationError;
+
+const fn out_of_range_error(negative: bool) -> DecimalOperationError {
+    if negative {
+        DecimalOperationError::Underflow
+    } else {
+        DecimalOperationError::Overflow
+    }
+}
+
+/// Scales a value by `10^exponent`, returning the error from the enclosing function like `?`.
+macro_rules! const_scale {
+    ($t:ty, $value:expr, $exponent:expr) => {{
+        let value: $t = $value;
+        let factor = match <$t>::checked_pow(10, $exponent) {
+            Some(factor) => factor,
+            None => return Err(DecimalOperationError::ScaleTooLarge),
+        };
+        match value.checked_mul(factor) {
+            Some(value) => value,
+            None => return Err(out_of_range_error(value < (0 as $t))),
+        }
+    }};
+}
+
+macro_rules! impl_const_decimal_operations {
+    ($($t:ty => $add:ident $sub:ident $mul:ident $div:ident $rem:ident;)*) => ($(
+        #[doc = concat!("Adds two `", stringify!($t), "` values with decimals in a const context.")]
+        ///
+        /// See [`CheckedDecimalOperations::add_decimals_checked`](crate::core::CheckedDecimalOperations::add_decimals_checked).
+        pub const fn $add(
+            value: $t,
+            other: $t,
+            value_decimals: u32,
+            other_decimals: u32,
+        ) -> Result<($t, u32), DecimalOperationError> {
+            let (value, other, decimals) = if value_decimals > other_decimals {
+                let other = const_scale!($t, other, value_decimals - other_decimals);
+                (value, other, value_decimals)
+            } else {
+                let value = const_scale!($t, value, other_decimals - value_decimals);
+                (value, other, other_decimals)
+            };
+            match value.checked_add(other) {
+                Some(result) => Ok((result, decimals)),
+                None => Err(out_of_range_error(other < (0 as $t))),
+            }
+        }
+
+        #[doc = concat!("Subtracts two `", stringify!($t), "` values with decimals in a const context.")]
+        ///
+        /// See [`CheckedDecimalOperations::sub_decimals_checked`](crate::core::CheckedDecimalOperations::sub_decimals_checked).
+        pub const fn $sub(
+            value: $t,
+            other: $t,
+            value_decimals: u32,
+            other_decimals: u32,
+        ) -> Result<($t, u32), DecimalOperationError> {
+            let (value, other, decimals) = if value_decimals > other_decimals {
+                let other = const_scale!($t, other, value_decimals - other_decimals);
+                (value, other, value_decimals)
+            } else {
+                let value = const_scale!($t, value, other_decimals - value_decimals);
+                (value, other, other_decimals)
+            };
+            match value.checked_sub(other) {
+                Some(result) => Ok((result, decimals)),
+                None if <$t>::MIN == 0 => Err(DecimalOperationError::NegativeResult),
+                None => Err(out_of_range_error(!(other < (0 as $t)))),
+            }
+        }
+
+        #[doc = concat!("Multiplies two `", stringify!($t), "` values with decimals in a const context.")]
+        ///
+        /// See [`CheckedDecimalOperations::multiply_decimals_checked`](crate::core::CheckedDecimalOperations::multiply_decimals_checked).
+        pub const fn $mul(
+            value: $t,
+            other: $t,
+            value_decimals: u32,
+            other_decimals: u32,
+        ) -> Result<($t, u32), DecimalOperationError> {
+            let decimals = match value_decimals.checked_add(other_decimals) {
+                Some(decimals) => decimals,
+                None => return Err(DecimalOperationError::ScaleTooLarge),
+            };
+            match value.checked_mul(other) {
+                Some(result) => Ok((result, decimals)),
+                None => Err(out_of_range_error((value < (0 as $t)) != (other < (0 as $t)))),
+            }
+        }
+
+        #[doc = concat!("Divides two `", stringify!($t), "` values with decimals in a const context.")]
+        ///
+        /// See [`CheckedDecimalOperations::divide_decimals_checked`](crate::core::CheckedDecimalOperations::divide_decimals_checked).
+        pub const fn $div(
+            value: $t,
+            other: $t,
+            value_decimals: u32,
+            other_decimals: u32,
+        ) -> Result<($t, u32), DecimalOperationError> {
+            let adjusted_value = const_scale!($t, value, other_decimals);
+            match adjusted_value.checked_div(other) {
+                Some(result) => Ok((result, value_decimals)),
+                None if other < (0 as $t) => Err(DecimalOperationError::Overflow),
+                None => Err(DecimalOperationError::DivisionByZero),
+            }
+        }
+
+        #[doc = concat!("Calculates the remainder of two `", stringify!($t), "` values with decimals in a const context.")]
+        ///
+        /// See [`CheckedDecimalOperations::rem_decimals_checked`](crate::core::CheckedDecimalOperations::rem_decimals_checked).
+        pub const fn $rem(
+            value: $t,
+            other: $t,
+            value_decimals: u32,
+            _other_decimals: u32,
+        ) -> Result<($t, u32), DecimalOperationError> {
+            let adjusted_value = const_scale!($t, value, value_decimals);
+            match adjusted_value.checked_rem(other) {
+                Some(result) => Ok((result, value_decimals)),
+                None if other < (0 as $t) => Err(DecimalOperationError::Overflow),
+                None => Err(DecimalOperationError::DivisionByZero),
+            }
+        }
+    )*)
+}
+
+impl_const_decimal_operations! {
+    u8 => const_add_decimals_u8 const_sub_decimals_u8 const_mul_decimals_u8 const_div_decimals_u8 const_rem_decimals_u8;
+    u16 => const_add_decimals_u16 const_sub_decimals_u16 const_mul_decimals_u16 const_div_decimals_u16 const_rem_decimals_u16;
+    u32 => const_add_decimals_u32 const_sub_decimals_u32 const_mul_decimals_u32 const_div_decimals_u32 const_rem_decimals_u32;
+    u64 => const_add_decimals_u64 const_sub_decimals_u64 const_mul_decimals_u64 const_div_decimals_u64 const_rem_decimals_u64;
+    u128 => const_add_decimals_u128 const_sub_decimals_u128 const_mul_decimals_u128 const_div_decimals_u128 const_rem_decimals_u128;
+    usize => const_add_decimals_usize const_sub_decimals_usize const_mul_decimals_usize const_div_decimals_usize const_rem_decimals_usize;
+    i8 => const_add_decimals_i8 const_sub_decimals_i8 const_mul_decimals_i8 const_div_decimals_i8 const_rem_decimals_i8;
+    i16 => const_add_decimals_i16 const_sub_decimals_i16 const_mul_decimals_i16 const_div_decimals_i16 const_rem_decimals_i16;
+    i32 => const_add_decimals_i32 const_sub_decimals_i32 const_mul_decimals_i32 const_div_decimals_i32 const_rem_decimals_i32;
+    i64 => const_add_decimals_i64 const_sub_decimals_i64 const_mul_decimals_i64 const_div_decimals_i64 const_rem_decimals_i64;
+    i128 => const_add_decimals_i128 const_sub_decimals_i128 const_mul_decimals_i128 const_div_decimals_i128 const_rem_decimals_i128;
+    isize => const_add_decimals_isize const_sub_decimals_isize const_mul_decimals_isize const_div_decimals_isize const_rem_decimals_isize;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::CheckedDecimalOperations;
+
+    const SCALED: (u64, u32) = match const_add_decimals_u64(1_0000, 2_00, 4, 2) {
+        Ok(value) => value,
+        Err(_) => panic!("addition overflows"),
+    };
+
+    #[test]
+    fn test_const_context() {
+        assert_eq!(SCALED, (3_0000, 4));
+    }
+
+    #[test]
+    fn test_const_operations() {
+        assert_eq!(const_sub_decimals_u64(3_0000, 2_00, 4, 2), Ok((1_0000, 4)));
+        assert_eq!(const_mul_decimals_u32(3_0, 2_00, 1, 2), Ok((6_000, 3)));
+        assert_eq!(const_div_decimals_i64(-6_00, 2_0, 2, 1), Ok((-3_00, 2)));
+        assert_eq!(const_rem_decimals_u128(7, 2, 0, 0), Ok((1, 0)));
+    }
+
+    #[test]
+    fn test_const_operations_errors() {
+        assert_eq!(
+            const_add_decimals_u8(u8::MAX, 1, 0, 0),
+            Err(DecimalOperationError::Overflow)
+        );
+        assert_eq!(
+            const_add_decimals_i8(i8::MIN, -1, 0, 0),
+            Err(DecimalOperationError::Underflow)
+        );
+        assert_eq!(
+            const_sub_decimals_u16(1, 2, 0, 0),
+            Err(DecimalOperationError::NegativeResult)
+        );
+        assert_eq!(
+            const_mul_decimals_i16(i16::MIN, 2, 0, 0),
+            Err(DecimalOperationError::Underflow)
+        );
+        assert_eq!(
+            const_add_decimals_u32(1, 1, 0, 10),
+            Err(DecimalOperationError::ScaleTooLarge)
+        );
+        assert_eq!(
+            const_div_decimals_isize(1, 0, 0, 0),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+        assert_eq!(
+            const_div_decimals_i32(i32::MIN, -1, 0, 0),
+            Err(DecimalOperationError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_const_operations_match_checked_operations() {
+        let values: [i64; 6] = [0, 1, -1_5, 123_45, i64::MAX, i64::MIN];
+        for &a in &values {
+            for &b in &values {
+                for (a_decimals, b_decimals) in [(0, 0), (2, 1), (1, 3), (0, 19)] {
+                    assert_eq!(
+                        const_add_decimals_i64(a, b, a_decimals, b_decimals),
+                        a.add_decimals_checked(b, a_decimals, b_decimals)
+                    );
+                    assert_eq!(
+                        const_sub_decimals_i64(a, b, a_decimals, b_decimals),
+                        a.sub_decimals_checked(b, a_decimals, b_decimals)
+                    );
+                    assert_eq!(
+                        const_mul_decimals_i64(a, b, a_decimals, b_decimals),
+                        a.multiply_decimals_checked(b, a_decimals, b_decimals)
+                    );
+                    assert_eq!(
+                        const_div_decimals_i64(a, b, a_decimals, b_decimals),
+                        a.divide_decimals_checked(b, a_decimals, b_decimals)
+                    );
+                    assert_eq!(
+                        const_rem_decimals_i64(a, b, a_decimals, b_decimals),
+                        a.rem_decimals_checked(b, a_decimals, b_decimals)
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,206 @@
+use alloc::format;
+use core::fmt::Display;
+
+use crate::core::{conversion::parse::MAGNITUDE_DIGITS, DecimalOperationError, I256, U256};
+
+/// A trait for converting a float into a value with a specified number of decimals.
+pub trait TryFromF64Decimals: Sized {
+    /// Converts a float into a raw value with `decimals` decimals.
+    ///
+    /// The float is read as the shortest decimal that round-trips to it, so `0.1` converts to
+    /// `1` with one decimal even though it isn't exactly representable in binary.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The float to convert.
+    /// * `decimals` - The number of decimals of the result.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the raw value, or a `DecimalOperationError` if:
+    ///
+    /// * `value` is NaN or infinite (`OutOfDomain`).
+    /// * `value` is negative and the type is unsigned (`NegativeResult`).
+    /// * `value` has more than `decimals` decimals (`PrecisionLoss`).
+    /// * the scaled value doesn't fit in the type (`Overflow` or `Underflow`).
+    fn try_from_f64_decimals(value: f64, decimals: u32) -> Result<Self, DecimalOperationError>;
+}
+
+/// A trait for converting a value with decimals into a float.
+pub trait ToF64Decimals {
+    /// Converts a raw value with `decimals` decimals into the nearest float.
+    ///
+    /// The conversion rounds to the nearest representable float, so it may lose precision.
+    fn to_f64_lossy(self, decimals: u32) -> f64;
+}
+
+/// Returns the sign and the magnitude of `value * 10^decimals`, failing if it isn't an integer.
+fn scaled_magnitude(value: f64, decimals: u32) -> Result<(bool, u128), DecimalOperationError> {
+    if !value.is_finite() {
+        return Err(DecimalOperationError::OutOfDomain);
+    }
+    let negative = value.is_sign_negative();
+    // `Display` never uses exponent notation and prints the shortest round-trip digits.
+    let digits = format!("{}", value.abs());
+    let (integer, fraction) = digits.split_once('.').unwrap_or((&digits, ""));
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.len() > decimals as usize {
+        return Err(DecimalOperationError::PrecisionLoss);
+    }
+    // More padding than the digits of a `u128` can only overflow, or keep a zero.
+    let padding = (decimals as usize - fraction.len()).min(MAGNITUDE_DIGITS);
+    let out_of_range = if negative {
+        DecimalOperationError::Underflow
+    } else {
+        DecimalOperationError::Overflow
+    };
+    let magnitude = integer
+        .bytes()
+        .chain(fraction.bytes())
+        .chain(core::iter::repeat_n(b'0', padding))
+        .try_fold(0u128, |magnitude, digit| {
+            magnitude
+                .checked_mul(10)?
+                .checked_add(u128::from(digit.checked_sub(b'0')?))
+        })
+        .ok_or(out_of_range)?;
+    Ok((negative, magnitude))
+}
+
+/// Parses `{value}e-{decimals}`, which the standard library rounds correctly to the nearest float.
+///
+/// Integer digits with an exponent always parse, so the `NAN` fallback is never returned.
+fn to_f64_lossy<T: Display>(value: T, decimals: u32) -> f64 {
+    format!("{}e-{}", value, decimals)
+        .parse()
+        .unwrap_or(f64::NAN)
+}
+
+macro_rules! impl_unsigned_f64_conversion {
+    ($($t:ty)*) => ($(
+        impl TryFromF64Decimals for $t {
+            fn try_from_f64_decimals(value: f64, decimals: u32) -> Result<Self, DecimalOperationError> {
+                match scaled_magnitude(value, decimals)? {
+                    (_, 0) => Ok(0),
+                    (true, _) => Err(DecimalOperationError::NegativeResult),
+                    (false, magnitude) => <$t>::try_from(magnitude).map_err(|_| DecimalOperationError::Overflow),
+                }
+            }
+        }
+
+        impl ToF64Decimals for $t {
+            fn to_f64_lossy(self, decimals: u32) -> f64 {
+                to_f64_lossy(self, decimals)
+            }
+        }
+    )*)
+}
+
+macro_rules! impl_signed_f64_conversion {
+    ($($t:ty)*) => ($(
+        impl TryFromF64Decimals for $t {
+            fn try_from_f64_decimals(value: f64, decimals: u32) -> Result<Self, DecimalOperationError> {
+                match scaled_magnitude(value, decimals)? {
+                    (_, 0) => Ok(0),
+                    // Going through `magnitude - 1` keeps `MIN` representable.
+                    (true, magnitude) => <$t>::try_from(magnitude - 1)
+                        .map(|value| -value - 1)
+                        .map_err(|_| DecimalOperationError::Underflow),
+                    (false, magnitude) => <$t>::try_from(magnitude).map_err(|_| DecimalOperationError::Overflow),
+                }
+            }
+        }
+
+        impl ToF64Decimals for $t {
+            fn to_f64_lossy(self, decimals: u32) -> f64 {
+                to_f64_lossy(self, decimals)
+            }
+        }
+    )*)
+}
+
+impl_unsigned_f64_conversion! { u8 u16 u32 u64 u128 usize }
+impl_signed_f64_conversion! { i8 i16 i32 i64 i128 isize }
+
+impl ToF64Decimals for U256 {
+    fn to_f64_lossy(self, decimals: u32) -> f64 {
+        to_f64_lossy(self, decimals)
+    }
+}
+
+impl ToF64Decimals for I256 {
+    fn to_f64_lossy(self, decimals: u32) -> f64 {
+        to_f64_lossy(self, decimals)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_f64_decimals() {
+        assert_eq!(u64::try_from_f64_decimals(0.1, 2), Ok(10));
+        assert_eq!(u64::try_from_f64_decimals(1234.5678, 4), Ok(1234_5678));
+        assert_eq!(i64::try_from_f64_decimals(-2.5, 1), Ok(-2_5));
+        assert_eq!(i32::try_from_f64_decimals(-0.0, 3), Ok(0));
+        assert_eq!(u8::try_from_f64_decimals(2.55, 2), Ok(255));
+        assert_eq!(i8::try_from_f64_decimals(-1.28, 2), Ok(i8::MIN));
+        assert_eq!(u128::try_from_f64_decimals(1e30, 0), Ok(10u128.pow(30)));
+    }
+
+    #[test]
+    fn test_try_from_f64_decimals_errors() {
+        assert_eq!(
+            u64::try_from_f64_decimals(f64::NAN, 2),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        assert_eq!(
+            i64::try_from_f64_decimals(f64::NEG_INFINITY, 2),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        assert_eq!(
+            u64::try_from_f64_decimals(-1.5, 1),
+            Err(DecimalOperationError::NegativeResult)
+        );
+        assert_eq!(
+            u64::try_from_f64_decimals(0.125, 2),
+            Err(DecimalOperationError::PrecisionLoss)
+        );
+        assert_eq!(
+            u8::try_from_f64_decimals(2.56, 2),
+            Err(DecimalOperationError::Overflow)
+        );
+        assert_eq!(
+            i8::try_from_f64_decimals(-1.29, 2),
+            Err(DecimalOperationError::Underflow)
+        );
+        assert_eq!(
+            i128::try_from_f64_decimals(-1e300, 0),
+            Err(DecimalOperationError::Underflow)
+        );
+        assert_eq!(
+            i128::try_from_f64_decimals(-1.0, u32::MAX),
+            Err(DecimalOperationError::Underflow)
+        );
+        assert_eq!(u128::try_from_f64_decimals(0.0, u32::MAX), Ok(0));
+    }
+
+    #[test]
+    fn test_to_f64_lossy() {
+        assert_eq!(1234_5678u64.to_f64_lossy(4), 1234.5678);
+        assert_eq!((-2_5i32).to_f64_lossy(1), -2.5);
+        assert_eq!(1u8.to_f64_lossy(1), 0.1);
+        assert_eq!(0u64.to_f64_lossy(400), 0.0);
+        assert_eq!(U256::MAX.to_f64_lossy(0), 1.157920892373162e77);
+        assert_eq!(I256::MIN.to_f64_lossy(77), -0.5789604461865809);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for value in [0.01, 0.1, 19.99, 1234.56, 9_999_999.99] {
+            let raw = u64::try_from_f64_decimals(value, 2).unwrap();
+            assert_eq!(raw.to_f64_lossy(2), value);
+        }
+    }
+}
@@ -0,0 +1,7 @@
+#[cfg(feature = "alloc")]
+pub mod float;
+pub mod parse;
+
+#[cfg(feature = "alloc")]
+pub use float::*;
+pub use parse::*;
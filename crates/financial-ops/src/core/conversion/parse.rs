@@ -0,0 +1,253 @@
+use crate::core::ParseDecimalError;
+
+/// A trait for parsing a string into a value with decimals, the inverse of `ToStringDecimals`.
+///
+/// Strings may start with a `+` or `-` sign and use single `_` between digits to separate
+/// groups, like `"1_000.25"`.
+pub trait FromStrDecimals: Sized {
+    /// Parses a string, keeping as many decimals as it has fractional digits.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The string to parse.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a tuple with the raw value and its number of decimals, or a
+    /// `ParseDecimalError` if the string is invalid or the value doesn't fit in the type.
+    fn from_str_decimals(s: &str) -> Result<(Self, u32), ParseDecimalError>;
+
+    /// Parses a string into a raw value with exactly `decimals` decimals.
+    ///
+    /// Trailing fractional zeros beyond `decimals` are accepted, any other extra digit returns
+    /// `ParseDecimalError::TooManyDecimals`.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The string to parse.
+    /// * `decimals` - The number of decimals of the result.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the raw value, or a `ParseDecimalError` if the operation fails.
+    fn from_str_decimals_with_scale(s: &str, decimals: u32) -> Result<Self, ParseDecimalError>;
+}
+
+/// The sign, integer digits and fractional digits of a validated decimal string.
+struct ParsedDecimal<'a> {
+    negative: bool,
+    integer: &'a str,
+    fraction: &'a str,
+}
+
+/// Returns the digits of a validated part, skipping `_` separators.
+fn digits(part: &str) -> impl Iterator<Item = u32> + '_ {
+    part.bytes()
+        .filter_map(|byte| char::from(byte).to_digit(10))
+}
+
+/// The number of digits of `u128::MAX`, the widest magnitude parsed: padding a value with more
+/// zeros than that overflows it, unless the value is zero.
+pub(crate) const MAGNITUDE_DIGITS: usize = 39;
+
+/// Checks that a part only has digits, with every `_` separator between two digits.
+fn validate_digits(part: &str) -> Result<(), ParseDecimalError> {
+    let separators_between_digits = part
+        .split(['_'])
+        .all(|group| !group.is_empty() && group.bytes().all(|byte| byte.is_ascii_digit()));
+    match part.is_empty() || separators_between_digits {
+        true => Ok(()),
+        false => Err(ParseDecimalError::InvalidDigit),
+    }
+}
+
+fn parse_decimal(s: &str) -> Result<ParsedDecimal<'_>, ParseDecimalError> {
+    let (negative, unsigned) = match (s.strip_prefix('-'), s.strip_prefix('+')) {
+        (Some(unsigned), _) => (true, unsigned),
+        (None, Some(unsigned)) => (false, unsigned),
+        (None, None) => (false, s),
+    };
+    // Splitting on a `char` array rather than a `char` keeps the `memchr` search of `core`, and
+    // its bounds checks, out of the parser.
+    let (integer, fraction) = unsigned.split_once(['.']).unwrap_or((unsigned, ""));
+    validate_digits(integer)?;
+    validate_digits(fraction)?;
+    if digits(integer).chain(digits(fraction)).next().is_none() {
+        return Err(ParseDecimalError::Empty);
+    }
+    Ok(ParsedDecimal {
+        negative,
+        integer,
+        fraction,
+    })
+}
+
+impl ParsedDecimal<'_> {
+    /// Returns the magnitude of the value scaled to `decimals` decimals.
+    fn magnitude(&self, decimals: u32) -> Result<u128, ParseDecimalError> {
+        let decimals = decimals as usize;
+        if digits(self.fraction).skip(decimals).any(|digit| digit != 0) {
+            return Err(ParseDecimalError::TooManyDecimals);
+        }
+        let kept = self.fraction_digits().min(decimals);
+        let out_of_range = if self.negative {
+            ParseDecimalError::Underflow
+        } else {
+            ParseDecimalError::Overflow
+        };
+        // More padding than the digits of a `u128` can only overflow, or keep a zero.
+        let padding = (decimals - kept).min(MAGNITUDE_DIGITS);
+        digits(self.integer)
+            .chain(digits(self.fraction).take(kept))
+            .chain(core::iter::repeat_n(0, padding))
+            .try_fold(0u128, |magnitude, digit| {
+                magnitude.checked_mul(10)?.checked_add(u128::from(digit))
+            })
+            .ok_or(out_of_range)
+    }
+
+    fn fraction_digits(&self) -> usize {
+        digits(self.fraction).fold(0, |count, _| count.saturating_add(1))
+    }
+
+    fn decimals(&self) -> Result<u32, ParseDecimalError> {
+        u32::try_from(self.fraction_digits()).map_err(|_| ParseDecimalError::TooManyDecimals)
+    }
+}
+
+/// Builds a value of the target type from a parsed string.
+trait FromParsed: Sized {
+    fn from_parsed(parsed: &ParsedDecimal, decimals: u32) -> Result<Self, ParseDecimalError>;
+}
+
+macro_rules! impl_unsigned_from_str_decimals {
+    ($($t:ty)*) => ($(
+        impl FromStrDecimals for $t {
+            fn from_str_decimals(s: &str) -> Result<(Self, u32), ParseDecimalError> {
+                let parsed = parse_decimal(s)?;
+                let decimals = parsed.decimals()?;
+                Ok((Self::from_parsed(&parsed, decimals)?, decimals))
+            }
+            fn from_str_decimals_with_scale(s: &str, decimals: u32) -> Result<Self, ParseDecimalError> {
+                Self::from_parsed(&parse_decimal(s)?, decimals)
+            }
+        }
+
+        impl FromParsed for $t {
+            fn from_parsed(parsed: &ParsedDecimal, decimals: u32) -> Result<Self, ParseDecimalError> {
+                match (parsed.negative, parsed.magnitude(decimals)?) {
+                    (_, 0) => Ok(0),
+                    (true, _) => Err(ParseDecimalError::NegativeValue),
+                    (false, magnitude) => <$t>::try_from(magnitude).map_err(|_| ParseDecimalError::Overflow),
+                }
+            }
+        }
+    )*)
+}
+
+macro_rules! impl_signed_from_str_decimals {
+    ($($t:ty)*) => ($(
+        impl FromStrDecimals for $t {
+            fn from_str_decimals(s: &str) -> Result<(Self, u32), ParseDecimalError> {
+                let parsed = parse_decimal(s)?;
+                let decimals = parsed.decimals()?;
+                Ok((Self::from_parsed(&parsed, decimals)?, decimals))
+            }
+            fn from_str_decimals_with_scale(s: &str, decimals: u32) -> Result<Self, ParseDecimalError> {
+                Self::from_parsed(&parse_decimal(s)?, decimals)
+            }
+        }
+
+        impl FromParsed for $t {
+            fn from_parsed(parsed: &ParsedDecimal, decimals: u32) -> Result<Self, ParseDecimalError> {
+                match (parsed.negative, parsed.magnitude(decimals)?) {
+                    (_, 0) => Ok(0),
+                    // Going through `magnitude - 1` keeps `MIN` representable.
+                    (true, magnitude) => <$t>::try_from(magnitude - 1)
+                        .map(|value| -value - 1)
+                        .map_err(|_| ParseDecimalError::Underflow),
+                    (false, magnitude) => <$t>::try_from(magnitude).map_err(|_| ParseDecimalError::Overflow),
+                }
+            }
+        }
+    )*)
+}
+
+impl_unsigned_from_str_decimals! { u8 u16 u32 u64 u128 usize }
+impl_signed_from_str_decimals! { i8 i16 i32 i64 i128 isize }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str_decimals() {
+        assert_eq!(u64::from_str_decimals("1234.56"), Ok((1234_56, 2)));
+        assert_eq!(i64::from_str_decimals("-0.005"), Ok((-5, 3)));
+        assert_eq!(u32::from_str_decimals("1_000.25"), Ok((1_000_25, 2)));
+        assert_eq!(i32::from_str_decimals("+42"), Ok((42, 0)));
+        assert_eq!(u8::from_str_decimals(".5"), Ok((5, 1)));
+        assert_eq!(u8::from_str_decimals("-0.0"), Ok((0, 1)));
+        assert_eq!(i8::from_str_decimals("-1.28"), Ok((i8::MIN, 2)));
+        assert_eq!(
+            u128::from_str_decimals("340282366920938463463374607431768211455"),
+            Ok((u128::MAX, 0))
+        );
+    }
+
+    #[test]
+    fn test_from_str_decimals_with_scale() {
+        assert_eq!(u64::from_str_decimals_with_scale("1.5", 4), Ok(1_5000));
+        // Padding stops once it can only overflow.
+        assert_eq!(u128::from_str_decimals_with_scale("0.0", u32::MAX), Ok(0));
+        assert_eq!(
+            u128::from_str_decimals_with_scale("1", u32::MAX),
+            Err(ParseDecimalError::Overflow)
+        );
+        assert_eq!(i64::from_str_decimals_with_scale("-2.500", 1), Ok(-2_5));
+        assert_eq!(
+            u64::from_str_decimals_with_scale("1.505", 2),
+            Err(ParseDecimalError::TooManyDecimals)
+        );
+    }
+
+    #[test]
+    fn test_from_str_decimals_errors() {
+        assert_eq!(u64::from_str_decimals(""), Err(ParseDecimalError::Empty));
+        assert_eq!(u64::from_str_decimals("-."), Err(ParseDecimalError::Empty));
+        assert_eq!(
+            u64::from_str_decimals("12a.5"),
+            Err(ParseDecimalError::InvalidDigit)
+        );
+        assert_eq!(
+            u64::from_str_decimals("1.2.3"),
+            Err(ParseDecimalError::InvalidDigit)
+        );
+        assert_eq!(
+            u64::from_str_decimals("_1"),
+            Err(ParseDecimalError::InvalidDigit)
+        );
+        for s in ["1_", "1__000", "1_.5", "1._5", "0.5_", "_"] {
+            assert_eq!(
+                u64::from_str_decimals(s),
+                Err(ParseDecimalError::InvalidDigit)
+            );
+        }
+        assert_eq!(
+            u64::from_str_decimals("--1"),
+            Err(ParseDecimalError::InvalidDigit)
+        );
+        assert_eq!(
+            u8::from_str_decimals("2.56"),
+            Err(ParseDecimalError::Overflow)
+        );
+        assert_eq!(
+            i8::from_str_decimals("-1.29"),
+            Err(ParseDecimalError::Underflow)
+        );
+        assert_eq!(
+            u64::from_str_decimals("-1"),
+            Err(ParseDecimalError::NegativeValue)
+        );
+    }
+}
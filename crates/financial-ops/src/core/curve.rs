@@ -0,0 +1,408 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::core::{
+    bond::quote::{accrued_coupon, coupon, split_periods},
+    interest::{annuity::GUARD_DECIMALS, compound::FACTOR_DECIMALS},
+    money::exchange_rate::multiply_to_scale,
+    BondPrice, CheckedDecimalOperations, CheckedDivideToScale, CheckedMulDiv, CompareDecimals,
+    Decimal, DecimalOperationError, PowerOfTen, RescaleDecimals, RoundingMode,
+    TranscendentalDecimals, Zero, TRANSCENDENTAL_MAX_DECIMALS,
+};
+
+/// How a [`DiscountCurve`] interpolates between two of its points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Interpolation {
+    /// Interpolates the discount factors on a straight line.
+    Linear,
+    /// Interpolates the logarithms of the discount factors on a straight line, which keeps the
+    /// forward rate constant between two points.
+    #[default]
+    LogLinear,
+}
+
+/// A discount curve: discount factors at a set of tenors, interpolated in between.
+///
+/// Tenors are numbers of years from today, and the curve starts with a discount factor of one
+/// at a tenor of zero. Tenors between two points are interpolated with 18 decimals, and tenors
+/// past the last point are outside the curve.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{curve::DiscountCurve, Decimal, Interpolation};
+///
+/// let curve = DiscountCurve::new()
+///     .point(Decimal::new(1i128, 0), Decimal::new(95, 2))
+///     .point(Decimal::new(2, 0), Decimal::new(90, 2))
+///     .point(Decimal::new(5, 0), Decimal::new(75, 2));
+///
+/// // 0.9 * (0.75 / 0.9)^(1 / 3)
+/// let factor = curve.discount_factor(Decimal::new(3, 0)).unwrap();
+/// assert_eq!(factor, Decimal::new(846_932_425_992_925_642, 18));
+///
+/// let factor = curve
+///     .interpolation(Interpolation::Linear)
+///     .discount_factor(Decimal::new(3, 0))
+///     .unwrap();
+/// assert_eq!(factor, Decimal::new(850_000_000_000_000_000, 18));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DiscountCurve<T = i128> {
+    points: Vec<(Decimal<T>, Decimal<T>)>,
+    interpolation: Interpolation,
+}
+
+impl<T> Default for DiscountCurve<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> DiscountCurve<T> {
+    /// Creates a curve without points, interpolating log-linearly.
+    pub const fn new() -> Self {
+        DiscountCurve {
+            points: Vec::new(),
+            interpolation: Interpolation::LogLinear,
+        }
+    }
+
+    /// Sets how the curve interpolates between its points.
+    pub fn interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Returns the tenor and the discount factor of every point, by increasing tenor.
+    pub fn points(&self) -> &[(Decimal<T>, Decimal<T>)] {
+        &self.points
+    }
+
+    /// Returns how the curve interpolates between its points.
+    pub fn interpolation_method(&self) -> Interpolation {
+        self.interpolation
+    }
+}
+
+impl<T: CompareDecimals + Copy> DiscountCurve<T> {
+    /// Adds the discount factor at a tenor in years, replacing the one already at that tenor.
+    pub fn point(mut self, tenor: Decimal<T>, factor: Decimal<T>) -> Self {
+        let index = self
+            .points
+            .partition_point(|(point, _)| point.cmp_value(&tenor) == Ordering::Less);
+        match self.points.get_mut(index) {
+            Some(point) if point.0.cmp_value(&tenor) == Ordering::Equal => *point = (tenor, factor),
+            _ => self.points.insert(index, (tenor, factor)),
+        }
+        self
+    }
+}
+
+impl<T> DiscountCurve<T>
+where
+    T: CheckedDecimalOperations
+        + CheckedDivideToScale
+        + CheckedMulDiv
+        + CompareDecimals
+        + RescaleDecimals
+        + TranscendentalDecimals
+        + PowerOfTen
+        + Zero
+        + From<u32>
+        + Copy,
+{
+    /// Computes the discount factor at a tenor in years, with 18 decimals.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the discount factor, or a `DecimalOperationError` if the
+    /// tenor is negative or past the last point, or a factor interpolated log-linearly isn't
+    /// positive, reported as `OutOfDomain`, or the operation fails.
+    pub fn discount_factor(&self, tenor: Decimal<T>) -> Result<Decimal<T>, DecimalOperationError> {
+        let mut previous = (Decimal::zero(0), Decimal::one(0)?);
+        if tenor.cmp_value(&previous.0) == Ordering::Less {
+            return Err(DecimalOperationError::OutOfDomain);
+        }
+        for &point in &self.points {
+            match tenor.cmp_value(&point.0) {
+                Ordering::Greater => previous = point,
+                Ordering::Equal => {
+                    return point.1.try_rescale(FACTOR_DECIMALS, RoundingMode::HalfEven)
+                }
+                Ordering::Less => return self.interpolate(previous, point, tenor),
+            }
+        }
+        if tenor.is_zero() {
+            return Decimal::one(FACTOR_DECIMALS);
+        }
+        Err(DecimalOperationError::OutOfDomain)
+    }
+
+    /// Interpolates the discount factor at `tenor` between two points.
+    fn interpolate(
+        &self,
+        (start, start_factor): (Decimal<T>, Decimal<T>),
+        (end, end_factor): (Decimal<T>, Decimal<T>),
+        tenor: Decimal<T>,
+    ) -> Result<Decimal<T>, DecimalOperationError> {
+        let weight = tenor.try_sub(start)?.try_div_to_scale(
+            end.try_sub(start)?,
+            FACTOR_DECIMALS,
+            RoundingMode::HalfEven,
+        )?;
+        match self.interpolation {
+            Interpolation::Linear => {
+                let step = multiply_to_scale(
+                    end_factor.try_sub(start_factor)?,
+                    weight,
+                    FACTOR_DECIMALS,
+                    RoundingMode::HalfEven,
+                )?;
+                start_factor
+                    .try_rescale(FACTOR_DECIMALS, RoundingMode::HalfEven)?
+                    .try_add(Decimal::new(step, FACTOR_DECIMALS))
+            }
+            Interpolation::LogLinear => {
+                let start_log = start_factor.try_ln(TRANSCENDENTAL_MAX_DECIMALS)?;
+                let end_log = end_factor.try_ln(TRANSCENDENTAL_MAX_DECIMALS)?;
+                let step = multiply_to_scale(
+                    end_log.try_sub(start_log)?,
+                    weight,
+                    TRANSCENDENTAL_MAX_DECIMALS,
+                    RoundingMode::HalfEven,
+                )?;
+                start_log
+                    .try_add(Decimal::new(step, TRANSCENDENTAL_MAX_DECIMALS))?
+                    .try_exp(TRANSCENDENTAL_MAX_DECIMALS)?
+                    .try_rescale(FACTOR_DECIMALS, RoundingMode::HalfEven)
+            }
+        }
+    }
+
+    /// Computes the net present value of cash flows at tenors in years on the curve, like
+    /// [`npv`](fn@crate::core::interest::npv): every discounted flow is rounded to `scale`
+    /// decimals and the sum is exact.
+    ///
+    /// # Arguments
+    ///
+    /// * `cash_flows` - The tenor and amount of every cash flow.
+    /// * `scale` - The number of decimals of the discounted flows and of the result.
+    /// * `rounding` - The rounding applied to every discounted flow.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the net present value, or a `DecimalOperationError` if a
+    /// tenor is outside the curve, reported as `OutOfDomain`, or the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use financial_ops::{curve::DiscountCurve, Decimal, Interpolation, RoundingMode};
+    ///
+    /// let curve = DiscountCurve::new()
+    ///     .interpolation(Interpolation::Linear)
+    ///     .point(Decimal::new(1i128, 0), Decimal::new(95, 2))
+    ///     .point(Decimal::new(2, 0), Decimal::new(90, 2));
+    ///
+    /// // -1,000 + 600 * 0.925 + 500 * 0.9
+    /// let cash_flows = [
+    ///     (Decimal::new(0, 0), Decimal::new(-1_000_00, 2)),
+    ///     (Decimal::new(1_5, 1), Decimal::new(600_00, 2)),
+    ///     (Decimal::new(2, 0), Decimal::new(500_00, 2)),
+    /// ];
+    /// let value = curve.npv(&cash_flows, 2, RoundingMode::HalfEven).unwrap();
+    /// assert_eq!(value, Decimal::new(5_00, 2));
+    /// ```
+    pub fn npv(
+        &self,
+        cash_flows: &[(Decimal<T>, Decimal<T>)],
+        scale: u32,
+        rounding: RoundingMode,
+    ) -> Result<Decimal<T>, DecimalOperationError> {
+        cash_flows
+            .iter()
+            .try_fold(Decimal::zero(scale), |total, &(tenor, amount)| {
+                let factor = self.discount_factor(tenor)?;
+                total.try_add(Decimal::new(
+                    multiply_to_scale(amount, factor, scale, rounding)?,
+                    scale,
+                ))
+            })
+    }
+
+    /// Computes the clean price, the accrued interest and the dirty price of a fixed-rate bond
+    /// discounted on the curve rather than at its yield to maturity.
+    ///
+    /// The arguments and the rounding follow [`price`](crate::core::bond::price): the coupon
+    /// `k` of the periods left comes `(k - 1 + w) / frequency` years from today, `w` being the
+    /// fraction of a period until the next coupon.
+    ///
+    /// # Arguments
+    ///
+    /// * `face` - The face value, repaid at maturity.
+    /// * `coupon_rate_bps` - The annual coupon rate, in basis points.
+    /// * `periods` - The number of coupon periods until maturity.
+    /// * `frequency` - The number of coupons a year.
+    /// * `rounding` - The rounding applied to the dirty price and the accrued interest.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the prices, or a `DecimalOperationError` if `periods` isn't
+    /// positive or the maturity is past the curve, reported as `OutOfDomain`, `frequency` is
+    /// zero, reported as `DivisionByZero`, or the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use financial_ops::{curve::DiscountCurve, Decimal, Interpolation, RoundingMode};
+    ///
+    /// let curve = DiscountCurve::new()
+    ///     .interpolation(Interpolation::Linear)
+    ///     .point(Decimal::new(1i128, 0), Decimal::new(95, 2))
+    ///     .point(Decimal::new(2, 0), Decimal::new(90, 2))
+    ///     .point(Decimal::new(5, 0), Decimal::new(75, 2));
+    ///
+    /// // 5 * 0.95 + 5 * 0.9 + 105 * 0.85
+    /// let prices = curve
+    ///     .bond_price(Decimal::new(100_00, 2), Decimal::new(500, 0), Decimal::new(3, 0), 1, RoundingMode::HalfEven)
+    ///     .unwrap();
+    /// assert_eq!(prices.dirty, Decimal::new(98_50, 2));
+    /// ```
+    pub fn bond_price(
+        &self,
+        face: Decimal<T>,
+        coupon_rate_bps: Decimal<T>,
+        periods: Decimal<T>,
+        frequency: u32,
+        rounding: RoundingMode,
+    ) -> Result<BondPrice<T>, DecimalOperationError> {
+        if frequency == 0 {
+            return Err(DecimalOperationError::DivisionByZero);
+        }
+        let scale = face.scale();
+        let decimals = scale
+            .checked_add(GUARD_DECIMALS)
+            .ok_or(DecimalOperationError::ScaleTooLarge)?;
+        let coupon = coupon(
+            face,
+            coupon_rate_bps,
+            frequency,
+            decimals,
+            RoundingMode::HalfEven,
+        )?;
+        let (coupons, fraction) = split_periods(periods)?;
+        let per_year = Decimal::new(T::from(frequency), 0);
+        let mut dirty = Decimal::zero(decimals);
+        for elapsed in 0..coupons {
+            let tenor = Decimal::new(T::from(elapsed), 0)
+                .try_add(fraction)?
+                .try_div_to_scale(per_year, FACTOR_DECIMALS, RoundingMode::HalfEven)?;
+            let amount = if elapsed + 1 == coupons {
+                face.try_add(coupon)?
+            } else {
+                coupon
+            };
+            let factor = self.discount_factor(tenor)?;
+            dirty = dirty.try_add(Decimal::new(
+                multiply_to_scale(amount, factor, decimals, RoundingMode::HalfEven)?,
+                decimals,
+            ))?;
+        }
+        let dirty = dirty.try_rescale(scale, rounding)?;
+        let accrued = accrued_coupon(face, coupon_rate_bps, fraction, frequency, rounding)?;
+        Ok(BondPrice {
+            clean: dirty.try_sub(accrued)?,
+            accrued,
+            dirty,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn curve(interpolation: Interpolation) -> DiscountCurve<i128> {
+        // Added out of order, with the 2-year point replaced.
+        DiscountCurve::new()
+            .interpolation(interpolation)
+            .point(Decimal::new(5, 0), Decimal::new(75, 2))
+            .point(Decimal::new(2, 0), Decimal::new(80, 2))
+            .point(Decimal::new(1, 0), Decimal::new(950_000, 6))
+            .point(Decimal::new(2_0, 1), Decimal::new(90, 2))
+    }
+
+    #[test]
+    fn test_discount_factor() -> Result<(), DecimalOperationError> {
+        let linear = curve(Interpolation::Linear);
+        let log_linear = curve(Interpolation::LogLinear);
+        assert_eq!(linear.points().len(), 3);
+        assert_eq!(log_linear.interpolation_method(), Interpolation::LogLinear);
+        let factor = |curve: &DiscountCurve<i128>, tenor| curve.discount_factor(tenor);
+
+        // From a factor of one today, and on the points themselves.
+        assert_eq!(factor(&linear, Decimal::new(0, 0))?, Decimal::one(18)?);
+        assert_eq!(
+            factor(&log_linear, Decimal::new(2, 0))?,
+            Decimal::new(900_000_000_000_000_000, 18)
+        );
+        // Half a year: 1 - 0.05 / 2, and 0.95^0.5.
+        assert_eq!(
+            factor(&linear, Decimal::new(5, 1))?,
+            Decimal::new(975_000_000_000_000_000, 18)
+        );
+        assert_eq!(
+            factor(&log_linear, Decimal::new(5, 1))?,
+            Decimal::new(974_679_434_480_896_391, 18)
+        );
+
+        assert_eq!(
+            factor(&linear, Decimal::new(5_1, 1)),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        assert_eq!(
+            factor(&linear, Decimal::new(-1, 0)),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        assert_eq!(
+            DiscountCurve::<i128>::new().discount_factor(Decimal::new(0, 0))?,
+            Decimal::one(18)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_curve_bond_price() -> Result<(), DecimalOperationError> {
+        let face = Decimal::new(1_000_00, 2);
+        let price = |interpolation| {
+            // 4% semiannual coupons, two years and a quarter from maturity
+            curve(interpolation).bond_price(
+                face,
+                Decimal::new(400, 0),
+                Decimal::new(4_5, 1),
+                2,
+                RoundingMode::HalfEven,
+            )
+        };
+        let linear = price(Interpolation::Linear)?;
+        assert_eq!(linear.dirty, Decimal::new(981_25, 2));
+        assert_eq!(linear.accrued, Decimal::new(10_00, 2));
+        assert_eq!(linear.clean, Decimal::new(971_25, 2));
+        assert_eq!(
+            price(Interpolation::LogLinear)?.dirty,
+            Decimal::new(980_14, 2)
+        );
+        assert_eq!(
+            curve(Interpolation::Linear).bond_price(
+                face,
+                Decimal::new(400, 0),
+                Decimal::new(11, 0),
+                2,
+                RoundingMode::HalfEven
+            ),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,391 @@
+use crate::core::{CheckedDivideToScale, Decimal, DecimalOperationError, RoundingMode};
+
+/// A date of the proleptic Gregorian calendar, ordered chronologically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    /// Creates a date, or returns `None` if the month or the day doesn't exist, like February 30.
+    pub const fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        if month < 1 || month > 12 || day < 1 || day > days_in_month(year, month) {
+            return None;
+        }
+        Some(Date { year, month, day })
+    }
+
+    /// Returns the year.
+    pub const fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month, from 1 to 12.
+    pub const fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Returns the day of the month, from 1.
+    pub const fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Returns `true` if the year of the date has a February 29.
+    pub const fn is_leap_year(&self) -> bool {
+        is_leap_year(self.year)
+    }
+
+    /// Returns `true` if the date is the last day of February.
+    pub const fn is_end_of_february(&self) -> bool {
+        self.month == 2 && self.day == days_in_month(self.year, 2)
+    }
+
+    /// Returns the number of days in the month of the date.
+    pub const fn days_in_month(&self) -> u32 {
+        days_in_month(self.year, self.month)
+    }
+
+    /// Returns the first day of the month after the date, or `None` after December of `i32::MAX`.
+    pub(crate) const fn first_of_next_month(&self) -> Option<Date> {
+        let year = if self.month == 12 {
+            self.year.checked_add(1)
+        } else {
+            Some(self.year)
+        };
+        match year {
+            Some(year) => Some(Date {
+                year,
+                month: self.month % 12 + 1,
+                day: 1,
+            }),
+            None => None,
+        }
+    }
+
+    /// Returns the number of days from 1970-01-01 to the date.
+    const fn days_from_epoch(&self) -> i64 {
+        // Counts from March, so the leap day is the last day of the shifted year.
+        let year = self.year as i64 - if self.month <= 2 { 1 } else { 0 };
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let month = (self.month as i64 + 9) % 12;
+        let day_of_year = (153 * month + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Returns the number of days from `self` to `end`, negative if `end` is earlier.
+    pub const fn days_until(&self, end: &Date) -> i64 {
+        end.days_from_epoch() - self.days_from_epoch()
+    }
+
+    /// Returns January 1 of `year`.
+    const fn january_first(year: i32) -> Date {
+        Date {
+            year,
+            month: 1,
+            day: 1,
+        }
+    }
+}
+
+/// Returns `true` if `year` has a February 29.
+const fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Returns the number of days in a month of `year`, or zero for a month outside 1 to 12.
+const fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// Returns the number of days in `year`.
+const fn days_in_year(year: i32) -> i64 {
+    if is_leap_year(year) {
+        366
+    } else {
+        365
+    }
+}
+
+/// A day-count convention, which decides how many days separate two dates and how many of them
+/// make a year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DayCount {
+    /// Actual days over 360, used by money markets.
+    Act360,
+    /// Actual days over a fixed 365, used by sterling markets.
+    Act365Fixed,
+    /// Actual days over the actual length of each year they fall in (ISDA), so the days of a
+    /// leap year count for `1 / 366` and the others for `1 / 365`.
+    ActAct,
+    /// 30 days in every month and 360 in a year, with the US (SIA) adjustments for month ends and
+    /// the end of February, used by US corporate bonds.
+    Thirty360Us,
+    /// 30 days in every month and 360 in a year, with a 31st counted as a 30th (30E/360), used by
+    /// eurobonds.
+    Thirty360European,
+}
+
+impl DayCount {
+    /// Returns the number of days from `start` to `end` under the convention, negative if `end`
+    /// is earlier.
+    pub const fn days(self, start: Date, end: Date) -> i64 {
+        match self {
+            DayCount::Act360 | DayCount::Act365Fixed | DayCount::ActAct => start.days_until(&end),
+            DayCount::Thirty360Us => {
+                let mut start_day = start.day;
+                let mut end_day = end.day;
+                if start.is_end_of_february() && end.is_end_of_february() {
+                    end_day = 30;
+                }
+                if start.is_end_of_february() {
+                    start_day = 30;
+                }
+                if end_day == 31 && start_day >= 30 {
+                    end_day = 30;
+                }
+                if start_day == 31 {
+                    start_day = 30;
+                }
+                thirty_360(start, end, start_day, end_day)
+            }
+            DayCount::Thirty360European => {
+                let start_day = if start.day == 31 { 30 } else { start.day };
+                let end_day = if end.day == 31 { 30 } else { end.day };
+                thirty_360(start, end, start_day, end_day)
+            }
+        }
+    }
+
+    /// Computes the fraction of a year from `start` to `end` under the convention.
+    ///
+    /// The fraction is a single division of whole numbers of days, rounded once to `scale`
+    /// decimals, including for `ActAct` when the period spans several years.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The first day of the period.
+    /// * `end` - The day after the last day of the period.
+    /// * `scale` - The number of decimals of the fraction.
+    /// * `rounding` - The rounding applied to the fraction.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the year fraction, or a `DecimalOperationError` if `end` is
+    /// before `start` or the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use financial_ops::{Date, DayCount, Decimal, RoundingMode};
+    ///
+    /// let start = Date::new(2024, 1, 31).unwrap();
+    /// let end = Date::new(2024, 7, 31).unwrap();
+    ///
+    /// // 182 actual days, or 180 days of 30/360
+    /// let fraction = DayCount::Act360.year_fraction::<u64>(start, end, 6, RoundingMode::HalfEven);
+    /// assert_eq!(fraction, Ok(Decimal::new(0_505556, 6)));
+    /// let fraction = DayCount::Thirty360Us.year_fraction::<u64>(start, end, 6, RoundingMode::HalfEven);
+    /// assert_eq!(fraction, Ok(Decimal::new(0_500000, 6)));
+    /// ```
+    pub fn year_fraction<T>(
+        self,
+        start: Date,
+        end: Date,
+        scale: u32,
+        rounding: RoundingMode,
+    ) -> Result<Decimal<T>, DecimalOperationError>
+    where
+        T: CheckedDivideToScale + From<u32>,
+    {
+        let (days, year) = self.year_parts(start, end)?;
+        days.try_div_to_scale(year, scale, rounding)
+    }
+
+    /// Returns the year fraction from `start` to `end` as a number of days over the number of
+    /// days in a year, or `OutOfDomain` if `end` is before `start`.
+    pub(crate) fn year_parts<T: From<u32>>(
+        self,
+        start: Date,
+        end: Date,
+    ) -> Result<(Decimal<T>, Decimal<T>), DecimalOperationError> {
+        if end < start {
+            return Err(DecimalOperationError::OutOfDomain);
+        }
+        let (days, year) = match self {
+            DayCount::Act360 | DayCount::Thirty360Us | DayCount::Thirty360European => {
+                (self.days(start, end), 360)
+            }
+            DayCount::Act365Fixed => (self.days(start, end), 365),
+            DayCount::ActAct if start.year == end.year => {
+                (start.days_until(&end), days_in_year(start.year))
+            }
+            DayCount::ActAct => {
+                // first / first_year + whole years + last / last_year, over a common denominator
+                let first_year = days_in_year(start.year);
+                let last_year = days_in_year(end.year);
+                let first = first_year - Date::january_first(start.year).days_until(&start);
+                let last = Date::january_first(end.year).days_until(&end);
+                let whole_years = i64::from(end.year) - i64::from(start.year) - 1;
+                let days = first
+                    .checked_mul(last_year)
+                    .zip(last.checked_mul(first_year))
+                    .and_then(|(first, last)| first.checked_add(last))
+                    .zip(whole_years.checked_mul(first_year * last_year))
+                    .and_then(|(parts, whole)| parts.checked_add(whole))
+                    .ok_or(DecimalOperationError::Overflow)?;
+                (days, first_year * last_year)
+            }
+        };
+        let to_decimal = |value: i64| {
+            u32::try_from(value)
+                .map(|value| Decimal::new(T::from(value), 0))
+                .map_err(|_| DecimalOperationError::Overflow)
+        };
+        Ok((to_decimal(days)?, to_decimal(year)?))
+    }
+}
+
+/// Converts a [`Date`], a `(year, month, day)` tuple or, with the `chrono` feature, a
+/// `chrono::NaiveDate` into a date, reporting a day that doesn't exist as `OutOfDomain`.
+pub(crate) fn to_date<D: TryInto<Date>>(date: D) -> Result<Date, DecimalOperationError> {
+    date.try_into()
+        .map_err(|_| DecimalOperationError::OutOfDomain)
+}
+
+impl TryFrom<(i32, u32, u32)> for Date {
+    type Error = DecimalOperationError;
+
+    /// Converts a `(year, month, day)` tuple, or returns `OutOfDomain` if the date doesn't exist.
+    fn try_from((year, month, day): (i32, u32, u32)) -> Result<Self, Self::Error> {
+        Date::new(year, month, day).ok_or(DecimalOperationError::OutOfDomain)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDate> for Date {
+    fn from(date: chrono::NaiveDate) -> Self {
+        use chrono::Datelike;
+
+        Date {
+            year: date.year(),
+            month: date.month(),
+            day: date.day(),
+        }
+    }
+}
+
+/// Counts the days between two dates with 30-day months, from adjusted days of the month.
+const fn thirty_360(start: Date, end: Date, start_day: u32, end_day: u32) -> i64 {
+    360 * (end.year as i64 - start.year as i64)
+        + 30 * (end.month as i64 - start.month as i64)
+        + (end_day as i64 - start_day as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> Date {
+        Date::new(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_date() {
+        assert_eq!(Date::new(2023, 2, 29), None);
+        assert_eq!(Date::new(2024, 13, 1), None);
+        assert_eq!(Date::new(2024, 4, 0), None);
+        assert!(Date::new(2000, 2, 29).is_some() && Date::new(1900, 2, 29).is_none());
+        assert!(date(2024, 1, 1).is_leap_year() && !date(2100, 1, 1).is_leap_year());
+        assert_eq!(Date::try_from((2024, 2, 29)), Ok(date(2024, 2, 29)));
+        assert_eq!(
+            Date::try_from((2024, 2, 30)),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        assert_eq!(date(1970, 1, 1).days_until(&date(2000, 3, 1)), 11_017);
+        assert_eq!(date(2024, 3, 1).days_until(&date(2024, 2, 28)), -2);
+        assert!(date(2023, 12, 31) < date(2024, 1, 1));
+        assert!(date(2024, 2, 29).is_end_of_february() && !date(2024, 2, 28).is_end_of_february());
+        assert_eq!(date(2023, 2, 10).days_in_month(), 28);
+        assert_eq!(
+            date(2024, 12, 31).first_of_next_month(),
+            Some(date(2025, 1, 1))
+        );
+        assert_eq!(date(i32::MAX, 12, 31).first_of_next_month(), None);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_date_from_chrono() {
+        let naive = chrono::NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        assert_eq!(Date::from(naive), date(2024, 2, 29));
+    }
+
+    #[test]
+    fn test_thirty_360_days() {
+        let us = DayCount::Thirty360Us;
+        let european = DayCount::Thirty360European;
+        // The end of February counts as a 30th only in the US convention.
+        assert_eq!(us.days(date(2024, 2, 29), date(2024, 3, 31)), 30);
+        assert_eq!(european.days(date(2024, 2, 29), date(2024, 3, 31)), 31);
+        assert_eq!(us.days(date(2024, 1, 31), date(2024, 2, 29)), 29);
+        assert_eq!(us.days(date(2023, 2, 28), date(2024, 2, 29)), 360);
+        assert_eq!(us.days(date(2024, 3, 15), date(2024, 3, 31)), 16);
+        assert_eq!(european.days(date(2024, 3, 15), date(2024, 3, 31)), 15);
+    }
+
+    #[test]
+    fn test_year_fraction() -> Result<(), DecimalOperationError> {
+        let start = date(2023, 7, 1);
+        let end = date(2024, 7, 1);
+        let fraction = |convention: DayCount| {
+            convention.year_fraction::<u64>(start, end, 9, RoundingMode::HalfEven)
+        };
+        // 366 days
+        assert_eq!(fraction(DayCount::Act360)?, Decimal::new(1_016666667, 9));
+        assert_eq!(
+            fraction(DayCount::Act365Fixed)?,
+            Decimal::new(1_002739726, 9)
+        );
+        // 184 / 365 + 182 / 366
+        assert_eq!(fraction(DayCount::ActAct)?, Decimal::new(1_001377349, 9));
+        assert_eq!(
+            fraction(DayCount::Thirty360Us)?,
+            Decimal::new(1_000000000, 9)
+        );
+
+        // Whole years in between count for one each.
+        assert_eq!(
+            DayCount::ActAct.year_fraction::<u64>(
+                date(2020, 1, 1),
+                date(2023, 1, 1),
+                4,
+                RoundingMode::HalfEven
+            )?,
+            Decimal::new(3_0000, 4)
+        );
+        assert_eq!(
+            DayCount::ActAct.year_fraction::<u64>(
+                date(2024, 3, 1),
+                date(2024, 3, 1),
+                4,
+                RoundingMode::HalfEven
+            )?,
+            Decimal::new(0, 4)
+        );
+        assert_eq!(
+            DayCount::Act360.year_fraction::<u64>(end, start, 4, RoundingMode::HalfEven),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        Ok(())
+    }
+}
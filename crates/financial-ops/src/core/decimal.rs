@@ -0,0 +1,596 @@
+#[cfg(not(feature = "strict-no-panic"))]
+use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use core::{cmp::Ordering, str::FromStr};
+
+use crate::core::{
+    CheckedAdd, CheckedDecimalOperations, CheckedDiv, CheckedDivideToScale, CheckedMul,
+    CheckedPowDecimals, CheckedRem, CheckedSignDecimals, CheckedSqrtDecimals, CheckedSub,
+    CompareDecimals, DecimalOperationError, FromStrDecimals, NormalizeDecimals, ParseDecimalError,
+    PowerOfTen, RescaleDecimals, RoundingMode, Signedness, TranscendentalDecimals, Zero,
+};
+#[cfg(feature = "alloc")]
+use crate::core::{ToF64Decimals, TryFromF64Decimals};
+
+/// A value paired with the number of decimals it carries.
+///
+/// `Decimal` keeps the raw integer and its scale together so the scale can't be dropped or
+/// swapped with another value's scale while moving through a computation. Arithmetic delegates
+/// to [`CheckedDecimalOperations`], so the scale of every result follows the same rules as the
+/// tuple based API.
+///
+/// Equality is structural: `Decimal::new(1_00, 2)` and `Decimal::new(1_0000, 4)` are not equal
+/// even though they represent the same quantity.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::Decimal;
+///
+/// let a = Decimal::new(1_0000u64, 4);
+/// let b = Decimal::new(2_00u64, 2);
+///
+/// let sum = a.try_add(b).unwrap();
+/// assert_eq!(sum.value(), 3_0000);
+/// assert_eq!(sum.scale(), 4);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Decimal<T> {
+    value: T,
+    scale: u32,
+}
+
+impl<T> Decimal<T> {
+    /// Creates a new decimal from a raw value and the number of decimals it carries.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The raw integer value.
+    /// * `scale` - The number of decimals in `value`.
+    pub const fn new(value: T, scale: u32) -> Self {
+        Decimal { value, scale }
+    }
+
+    /// Returns the number of decimals carried by the value.
+    pub const fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// Returns a reference to the raw integer value.
+    pub const fn value_ref(&self) -> &T {
+        &self.value
+    }
+
+    /// Splits the decimal into its raw value and number of decimals.
+    pub fn into_parts(self) -> (T, u32) {
+        (self.value, self.scale)
+    }
+}
+
+impl<T: Copy> Decimal<T> {
+    /// Returns the raw integer value.
+    pub fn value(&self) -> T {
+        self.value
+    }
+}
+
+impl<T: Zero> Decimal<T> {
+    /// Creates a zero with `scale` decimals.
+    pub fn zero(scale: u32) -> Self {
+        Decimal::new(T::zero(), scale)
+    }
+
+    /// Returns `true` if the decimal is zero, at any scale.
+    pub fn is_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+}
+
+impl<T: PowerOfTen> Decimal<T> {
+    /// Creates a one with `scale` decimals, whose raw value is `10^scale`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the decimal, or a `DecimalOperationError` if `10^scale`
+    /// doesn't fit in the type.
+    pub fn one(scale: u32) -> Result<Self, DecimalOperationError> {
+        T::checked_power_of_ten(scale)
+            .map(|value| Decimal::new(value, scale))
+            .ok_or(DecimalOperationError::ScaleTooLarge)
+    }
+}
+
+impl<T: Signedness + Zero> Decimal<T> {
+    /// Returns `true` if the decimal is greater than zero.
+    pub fn is_positive(&self) -> bool {
+        !self.value.is_negative() && !self.value.is_zero()
+    }
+
+    /// Returns `true` if the decimal is less than zero, which is never the case for unsigned
+    /// types.
+    pub fn is_negative(&self) -> bool {
+        self.value.is_negative()
+    }
+}
+
+impl<T> From<(T, u32)> for Decimal<T> {
+    fn from((value, scale): (T, u32)) -> Self {
+        Decimal::new(value, scale)
+    }
+}
+
+impl<T> From<Decimal<T>> for (T, u32) {
+    fn from(decimal: Decimal<T>) -> Self {
+        decimal.into_parts()
+    }
+}
+
+impl<T: CheckedDecimalOperations> Decimal<T> {
+    /// Adds two decimals, aligning their scales.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the sum, or a `DecimalOperationError` if the operation fails.
+    pub fn try_add(self, other: Self) -> Result<Self, DecimalOperationError> {
+        self.value
+            .add_decimals_checked(other.value, self.scale, other.scale)
+            .map(Decimal::from)
+    }
+
+    /// Subtracts `other` from `self`, aligning their scales.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the difference, or a `DecimalOperationError` if the operation fails.
+    pub fn try_sub(self, other: Self) -> Result<Self, DecimalOperationError> {
+        self.value
+            .sub_decimals_checked(other.value, self.scale, other.scale)
+            .map(Decimal::from)
+    }
+
+    /// Multiplies two decimals. The scale of the result is the sum of both scales.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the product, or a `DecimalOperationError` if the operation fails.
+    pub fn try_mul(self, other: Self) -> Result<Self, DecimalOperationError> {
+        self.value
+            .multiply_decimals_checked(other.value, self.scale, other.scale)
+            .map(Decimal::from)
+    }
+
+    /// Divides `self` by `other`. The scale of the result is the scale of `self`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the quotient, or a `DecimalOperationError` if the operation fails.
+    pub fn try_div(self, other: Self) -> Result<Self, DecimalOperationError> {
+        self.value
+            .divide_decimals_checked(other.value, self.scale, other.scale)
+            .map(Decimal::from)
+    }
+
+    /// Calculates the remainder of dividing `self` by `other`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the remainder, or a `DecimalOperationError` if the operation fails.
+    pub fn try_rem(self, other: Self) -> Result<Self, DecimalOperationError> {
+        self.value
+            .rem_decimals_checked(other.value, self.scale, other.scale)
+            .map(Decimal::from)
+    }
+}
+
+impl<T: CheckedDivideToScale> Decimal<T> {
+    /// Divides `self` by `other`, returning a quotient with `target_scale` decimals.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The value to divide by.
+    /// * `target_scale` - The number of decimals of the quotient.
+    /// * `rounding` - The rounding applied to the quotient.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the quotient, or a `DecimalOperationError` if the operation fails.
+    pub fn try_div_to_scale(
+        self,
+        other: Self,
+        target_scale: u32,
+        rounding: RoundingMode,
+    ) -> Result<Self, DecimalOperationError> {
+        self.value
+            .divide_decimals_to_scale_checked(
+                other.value,
+                self.scale,
+                other.scale,
+                target_scale,
+                rounding,
+            )
+            .map(Decimal::from)
+    }
+}
+
+impl<T: RescaleDecimals> Decimal<T> {
+    /// Rescales the decimal to `scale` decimals, rounding with `rounding` when scaling down.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the rescaled decimal, or a `DecimalOperationError` if the operation fails.
+    pub fn try_rescale(
+        self,
+        scale: u32,
+        rounding: RoundingMode,
+    ) -> Result<Self, DecimalOperationError> {
+        self.value
+            .rescale_checked(self.scale, scale, rounding)
+            .map(Decimal::from)
+    }
+}
+
+impl<T: CheckedPowDecimals> Decimal<T> {
+    /// Raises the decimal to the power of `exponent` exactly. The scale of the result is the scale
+    /// of `self` multiplied by `exponent`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the power, or a `DecimalOperationError` if the operation fails.
+    pub fn try_pow(self, exponent: u32) -> Result<Self, DecimalOperationError> {
+        self.value
+            .pow_decimals_checked(exponent, self.scale)
+            .map(Decimal::from)
+    }
+
+    /// Raises the decimal to the power of `exponent`, rounding every step back to the scale of `self`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the power, or a `DecimalOperationError` if the operation fails.
+    pub fn try_pow_rounded(
+        self,
+        exponent: u32,
+        rounding: RoundingMode,
+    ) -> Result<Self, DecimalOperationError> {
+        self.value
+            .pow_decimals_rounded_checked(exponent, self.scale, rounding)
+            .map(Decimal::from)
+    }
+}
+
+impl<T: CheckedSqrtDecimals> Decimal<T> {
+    /// Computes the square root with `target_scale` decimals, rounded down.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the root, or a `DecimalOperationError` if the operation fails.
+    pub fn try_sqrt(self, target_scale: u32) -> Result<Self, DecimalOperationError> {
+        self.value
+            .sqrt_decimals_checked(self.scale, target_scale)
+            .map(Decimal::from)
+    }
+}
+
+impl<T: TranscendentalDecimals> Decimal<T> {
+    /// Computes the natural logarithm with `target_scale` decimals.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the logarithm, or a `DecimalOperationError` if the operation fails.
+    pub fn try_ln(self, target_scale: u32) -> Result<Self, DecimalOperationError> {
+        self.value
+            .ln_decimals(self.scale, target_scale)
+            .map(Decimal::from)
+    }
+
+    /// Computes `e` raised to the power of the decimal, with `target_scale` decimals.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the power, or a `DecimalOperationError` if the operation fails.
+    pub fn try_exp(self, target_scale: u32) -> Result<Self, DecimalOperationError> {
+        self.value
+            .exp_decimals(self.scale, target_scale)
+            .map(Decimal::from)
+    }
+
+    /// Raises the decimal to a fractional power, with `target_scale` decimals.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the power, or a `DecimalOperationError` if the operation fails.
+    pub fn try_powf(
+        self,
+        exponent: Self,
+        target_scale: u32,
+    ) -> Result<Self, DecimalOperationError> {
+        self.value
+            .powf_decimals(exponent.value, self.scale, exponent.scale, target_scale)
+            .map(Decimal::from)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: TryFromF64Decimals> Decimal<T> {
+    /// Converts a float into a decimal with `scale` decimals.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the decimal, or a `DecimalOperationError` if the float is NaN,
+    /// infinite, out of range or can't be represented exactly with `scale` decimals.
+    pub fn try_from_f64(value: f64, scale: u32) -> Result<Self, DecimalOperationError> {
+        T::try_from_f64_decimals(value, scale).map(|value| Decimal::new(value, scale))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ToF64Decimals> Decimal<T> {
+    /// Converts the decimal into the nearest float.
+    pub fn to_f64_lossy(self) -> f64 {
+        self.value.to_f64_lossy(self.scale)
+    }
+}
+
+impl<T: FromStrDecimals> FromStr for Decimal<T> {
+    type Err = ParseDecimalError;
+
+    /// Parses a string like `"1234.56"`, keeping as many decimals as it has fractional digits.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        T::from_str_decimals(s).map(Decimal::from)
+    }
+}
+
+impl<T: NormalizeDecimals> Decimal<T> {
+    /// Removes trailing zero digits, reducing the scale accordingly.
+    pub fn normalize(self) -> Self {
+        Decimal::from(self.value.normalize_decimals(self.scale))
+    }
+}
+
+impl<T: CompareDecimals> Decimal<T> {
+    /// Compares the quantities represented by two decimals, aligning their scales.
+    ///
+    /// Unlike `==`, this considers `Decimal::new(1_00, 2)` and `Decimal::new(1_0000, 4)` equal.
+    pub fn cmp_value(&self, other: &Self) -> Ordering {
+        self.value
+            .cmp_decimals(&other.value, self.scale, other.scale)
+    }
+
+    /// Returns `true` if both decimals represent the same quantity, regardless of their scales.
+    pub fn eq_value(&self, other: &Self) -> bool {
+        self.cmp_value(other) == Ordering::Equal
+    }
+}
+
+impl<T: CheckedSignDecimals> Decimal<T> {
+    /// Negates the decimal, keeping its scale.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the negated decimal, or a `DecimalOperationError` if the operation fails.
+    pub fn try_neg(self) -> Result<Self, DecimalOperationError> {
+        self.value
+            .neg_decimals_checked(self.scale)
+            .map(Decimal::from)
+    }
+
+    /// Returns the absolute value of the decimal, keeping its scale.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the absolute value, or a `DecimalOperationError` if the operation fails.
+    pub fn try_abs(self) -> Result<Self, DecimalOperationError> {
+        self.value
+            .abs_decimals_checked(self.scale)
+            .map(Decimal::from)
+    }
+}
+
+#[cfg(not(feature = "strict-no-panic"))]
+impl<T: CheckedSignDecimals> Neg for Decimal<T> {
+    type Output = Decimal<T>;
+
+    /// # Panics
+    ///
+    /// Panics if the value can't be negated.
+    fn neg(self) -> Self::Output {
+        match self.try_neg() {
+            Ok(value) => value,
+            Err(error) => panic!("attempt to negate decimals failed: {}", error),
+        }
+    }
+}
+
+macro_rules! impl_decimal_ops {
+    ($($op:ident $method:ident $checked_trait:ident $checked_method:ident $try_method:ident $verb:literal;)*) => ($(
+        #[cfg(not(feature = "strict-no-panic"))]
+        impl<T: CheckedDecimalOperations> $op for Decimal<T> {
+            type Output = Decimal<T>;
+
+            /// # Panics
+            ///
+            /// Panics if the underlying checked operation fails.
+            fn $method(self, other: Self) -> Self::Output {
+                match self.$try_method(other) {
+                    Ok(value) => value,
+                    Err(error) => panic!("attempt to {} decimals failed: {}", $verb, error),
+                }
+            }
+        }
+
+        impl<T: CheckedDecimalOperations + Clone> $checked_trait for Decimal<T> {
+            fn $checked_method(&self, v: &Self) -> Option<Self> {
+                self.clone().$try_method(v.clone()).ok()
+            }
+        }
+    )*)
+}
+
+impl_decimal_ops! {
+    Add add CheckedAdd checked_add try_add "add";
+    Sub sub CheckedSub checked_sub try_sub "subtract";
+    Mul mul CheckedMul checked_mul try_mul "multiply";
+    Div div CheckedDiv checked_div try_div "divide";
+    Rem rem CheckedRem checked_rem try_rem "calculate the remainder of";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decimal_zero_and_one() -> Result<(), DecimalOperationError> {
+        let zero = Decimal::<i64>::zero(2);
+        assert_eq!(zero.into_parts(), (0, 2));
+        assert!(zero.is_zero() && !zero.is_positive() && !zero.is_negative());
+        assert_eq!(Decimal::<i64>::one(2)?.into_parts(), (1_00, 2));
+        assert!(Decimal::new(1u8, 0).is_positive());
+        assert!(Decimal::new(-1_5i32, 1).is_negative());
+        assert_eq!(
+            Decimal::<u8>::one(3),
+            Err(DecimalOperationError::ScaleTooLarge)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_decimal_parts() {
+        let decimal = Decimal::new(123_45u64, 2);
+        assert_eq!(decimal.value(), 123_45);
+        assert_eq!(decimal.scale(), 2);
+        assert_eq!(decimal.into_parts(), (123_45, 2));
+        assert_eq!(Decimal::from((5u32, 1)), Decimal::new(5, 1));
+    }
+
+    #[test]
+    fn test_decimal_value_comparison() {
+        let a = Decimal::new(1_00u64, 2);
+        let b = Decimal::new(1_0000u64, 4);
+
+        assert_ne!(a, b);
+        assert!(a.eq_value(&b));
+        assert_eq!(a.cmp_value(&Decimal::new(1_0001, 4)), Ordering::Less);
+    }
+
+    #[test]
+    #[cfg(not(feature = "strict-no-panic"))]
+    fn test_decimal_std_ops() {
+        let a = Decimal::new(6_0000u64, 4);
+        let b = Decimal::new(2_00u64, 2);
+
+        assert_eq!(a + b, Decimal::new(8_0000, 4));
+        assert_eq!(a - b, Decimal::new(4_0000, 4));
+        assert_eq!(a * b, Decimal::new(12_000000, 6));
+        assert_eq!((a * b).normalize(), Decimal::new(12, 0));
+        assert_eq!(a / b, Decimal::new(3_0000, 4));
+        assert_eq!(a % b, Decimal::new(0, 4));
+    }
+
+    #[test]
+    fn test_decimal_checked_ops() {
+        let a = Decimal::new(u64::MAX, 0);
+        let b = Decimal::new(1u64, 0);
+
+        assert_eq!(a.checked_add(&b), None);
+        assert_eq!(b.checked_sub(&a), None);
+        assert_eq!(b.checked_div(&Decimal::new(0, 0)), None);
+        assert_eq!(b.checked_mul(&b), Some(Decimal::new(1, 0)));
+        assert!(matches!(a.try_add(b), Err(DecimalOperationError::Overflow)));
+    }
+
+    #[test]
+    fn test_decimal_div_to_scale() -> Result<(), DecimalOperationError> {
+        let one = Decimal::new(1_00u64, 2);
+        let three = Decimal::new(3u64, 0);
+
+        let third = one.try_div_to_scale(three, 6, RoundingMode::HalfUp)?;
+        assert_eq!(third, Decimal::new(0_333333, 6));
+        assert_eq!(
+            third.try_rescale(2, RoundingMode::Up)?,
+            Decimal::new(0_34, 2)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decimal_pow() -> Result<(), DecimalOperationError> {
+        let rate = Decimal::new(1_05u64, 2);
+
+        assert_eq!(rate.try_pow(2)?, Decimal::new(1_1025, 4));
+        assert_eq!(
+            rate.try_pow_rounded(2, RoundingMode::HalfUp)?,
+            Decimal::new(1_10, 2)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decimal_sqrt() {
+        assert_eq!(
+            Decimal::new(2_00u64, 2).try_sqrt(4),
+            Ok(Decimal::new(1_4142, 4))
+        );
+        assert_eq!(
+            Decimal::new(-4i64, 0).try_sqrt(0),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+    }
+
+    #[test]
+    fn test_decimal_transcendental() -> Result<(), DecimalOperationError> {
+        let root = Decimal::new(4u128, 0).try_powf(Decimal::new(0_5, 1), 2)?;
+        assert_eq!(root, Decimal::new(2_00, 2));
+
+        let growth = Decimal::new(1_05i128, 2).try_ln(6)?;
+        assert_eq!(growth, Decimal::new(0_048790, 6));
+        assert_eq!(growth.try_exp(2)?, Decimal::new(1_05, 2));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decimal_sign() {
+        let value = Decimal::new(-1_50i64, 2);
+
+        assert_eq!(value.try_neg(), Ok(Decimal::new(1_50, 2)));
+        assert_eq!(value.try_abs(), Ok(Decimal::new(1_50, 2)));
+        assert_eq!(
+            Decimal::new(i64::MIN, 2).try_neg(),
+            Err(DecimalOperationError::Overflow)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_decimal_f64_conversion() {
+        let value = Decimal::<i64>::try_from_f64(-19.99, 4);
+
+        assert_eq!(value, Ok(Decimal::new(-19_9900, 4)));
+        assert_eq!(value.unwrap().to_f64_lossy(), -19.99);
+        assert_eq!(
+            Decimal::<u64>::try_from_f64(0.001, 2),
+            Err(DecimalOperationError::PrecisionLoss)
+        );
+    }
+
+    #[test]
+    fn test_decimal_from_str() {
+        assert_eq!("-1_000.25".parse(), Ok(Decimal::new(-1_000_25i64, 2)));
+        assert_eq!(
+            "1.5".parse::<Decimal<u8>>().map(Decimal::normalize),
+            Ok(Decimal::new(1_5, 1))
+        );
+        assert_eq!(
+            "abc".parse::<Decimal<u64>>(),
+            Err(ParseDecimalError::InvalidDigit)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(not(feature = "strict-no-panic"))]
+    fn test_decimal_add_overflow_panics() {
+        let _ = Decimal::new(u32::MAX, 0) + Decimal::new(1u32, 0);
+    }
+}
@@ -0,0 +1,280 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::core::{
+    CheckedDecimalOperations, CheckedMulDiv, CompareDecimals, Decimal, DecimalOperationError,
+    RescaleDecimals, RoundingMode, Zero,
+};
+
+/// How the depreciable amount of an asset, its cost less its salvage value, is spread over its
+/// useful life.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DepreciationMethod {
+    /// The same expense every period.
+    #[default]
+    StraightLine,
+    /// Twice the straight-line rate applied to the book value left, never going below the salvage
+    /// value. The last period expenses whatever is left above the salvage value.
+    DoubleDecliningBalance,
+    /// A share of the depreciable amount decreasing every period: `life - period + 1` over the
+    /// sum of the digits from 1 to `life`.
+    SumOfYearsDigits,
+}
+
+/// One period of a [`Depreciation`] schedule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DepreciationRow<T = i128> {
+    /// The number of the period, from 1.
+    pub period: u32,
+    /// The depreciation expensed in the period.
+    pub expense: Decimal<T>,
+    /// The depreciation expensed up to the end of the period.
+    pub accumulated: Decimal<T>,
+    /// The cost less the accumulated depreciation at the end of the period.
+    pub book_value: Decimal<T>,
+}
+
+/// The depreciation of an asset over its useful life, down to its salvage value.
+///
+/// Amounts have the decimals of the cost or of the salvage value, whichever has more. The
+/// straight-line and sum-of-years-digits methods round the accumulated depreciation of every
+/// period rather than each expense, and the double-declining-balance method expenses whatever is
+/// left in the last period, so every schedule closes exactly to the salvage value.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{Decimal, Depreciation, DepreciationMethod};
+///
+/// let asset = Depreciation::new(Decimal::new(10_000_00i64, 2), Decimal::new(1_000_00, 2), 5);
+///
+/// let schedule = asset.schedule().unwrap();
+/// assert_eq!(schedule[0].expense, Decimal::new(1_800_00, 2));
+///
+/// let schedule = asset.method(DepreciationMethod::SumOfYearsDigits).schedule().unwrap();
+/// assert_eq!(schedule[0].expense, Decimal::new(3_000_00, 2));
+/// assert_eq!(schedule[4].expense, Decimal::new(600_00, 2));
+///
+/// let schedule = asset.method(DepreciationMethod::DoubleDecliningBalance).schedule().unwrap();
+/// assert_eq!(schedule[0].expense, Decimal::new(4_000_00, 2));
+/// assert_eq!(schedule[3].book_value, Decimal::new(1_296_00, 2));
+/// assert_eq!(schedule[4].expense, Decimal::new(296_00, 2));
+/// assert_eq!(schedule[4].book_value, Decimal::new(1_000_00, 2));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Depreciation<T = i128> {
+    cost: Decimal<T>,
+    salvage: Decimal<T>,
+    life: u32,
+    method: DepreciationMethod,
+    rounding: RoundingMode,
+}
+
+impl<T> Depreciation<T> {
+    /// Creates an asset bought for `cost` and worth `salvage` after `life` periods, depreciated
+    /// on a straight line and rounded half to even.
+    pub const fn new(cost: Decimal<T>, salvage: Decimal<T>, life: u32) -> Self {
+        Depreciation {
+            cost,
+            salvage,
+            life,
+            method: DepreciationMethod::StraightLine,
+            rounding: RoundingMode::HalfEven,
+        }
+    }
+
+    /// Sets the depreciation method.
+    pub fn method(mut self, method: DepreciationMethod) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// Sets the rounding applied to the depreciation of every period.
+    pub fn rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Returns the useful life of the asset, in periods.
+    pub fn life(&self) -> u32 {
+        self.life
+    }
+}
+
+impl<T> Depreciation<T>
+where
+    T: CheckedDecimalOperations
+        + CheckedMulDiv
+        + CompareDecimals
+        + RescaleDecimals
+        + Zero
+        + From<u32>
+        + Copy,
+{
+    /// Computes the schedule of the asset, one row per period of its useful life.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the rows, or a `DecimalOperationError` if the life is zero,
+    /// or the salvage value is negative or greater than the cost, reported as `OutOfDomain`, or
+    /// the operation fails.
+    pub fn schedule(&self) -> Result<Vec<DepreciationRow<T>>, DecimalOperationError> {
+        let zero = Decimal::zero(0);
+        if self.life == 0
+            || self.salvage.cmp_value(&zero) == Ordering::Less
+            || self.salvage.cmp_value(&self.cost) == Ordering::Greater
+        {
+            return Err(DecimalOperationError::OutOfDomain);
+        }
+        // Scaling up to the larger number of decimals is exact.
+        let scale = self.cost.scale().max(self.salvage.scale());
+        let cost = self.cost.try_rescale(scale, self.rounding)?;
+        let salvage = self.salvage.try_rescale(scale, self.rounding)?;
+        let depreciable = cost.try_sub(salvage)?;
+        let life = T::from(self.life);
+        let mut accumulated = Decimal::zero(scale);
+        let mut rows = Vec::with_capacity(self.life as usize);
+        for period in 1..=self.life {
+            let book_value = cost.try_sub(accumulated)?;
+            let expense = if period == self.life {
+                book_value.try_sub(salvage)?
+            } else {
+                match self.method {
+                    DepreciationMethod::StraightLine => Decimal::new(
+                        depreciable.value().checked_mul_div(
+                            T::from(period),
+                            life,
+                            self.rounding,
+                        )?,
+                        scale,
+                    )
+                    .try_sub(accumulated)?,
+                    DepreciationMethod::SumOfYearsDigits => Decimal::new(
+                        depreciable.value().checked_mul_div(
+                            T::from(sum_of_digits(period, self.life)?),
+                            T::from(sum_of_digits(self.life, self.life)?),
+                            self.rounding,
+                        )?,
+                        scale,
+                    )
+                    .try_sub(accumulated)?,
+                    DepreciationMethod::DoubleDecliningBalance => {
+                        let expense = Decimal::new(
+                            book_value
+                                .value()
+                                .checked_mul_div(T::from(2), life, self.rounding)?,
+                            scale,
+                        );
+                        let left = book_value.try_sub(salvage)?;
+                        match expense.cmp_value(&left) {
+                            Ordering::Greater => left,
+                            _ => expense,
+                        }
+                    }
+                }
+            };
+            accumulated = accumulated.try_add(expense)?;
+            rows.push(DepreciationRow {
+                period,
+                expense,
+                accumulated,
+                book_value: cost.try_sub(accumulated)?,
+            });
+        }
+        Ok(rows)
+    }
+}
+
+/// Returns the sum of the `count` largest digits from 1 to `digits`, the sum of all of them when
+/// `count` is `digits`.
+pub(crate) fn sum_of_digits(count: u32, digits: u32) -> Result<u32, DecimalOperationError> {
+    let (count, digits) = (u64::from(count), u64::from(digits));
+    count
+        .checked_mul(digits)
+        .zip(count.checked_mul(count.saturating_sub(1)))
+        .and_then(|(all, pairs)| all.checked_sub(pairs / 2))
+        .and_then(|sum| u32::try_from(sum).ok())
+        .ok_or(DecimalOperationError::Overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_depreciation() -> Result<(), DecimalOperationError> {
+        let asset = Depreciation::new(Decimal::new(1_000_00i128, 2), Decimal::new(1, 0), 3);
+        let expenses = |method| -> Result<Vec<_>, DecimalOperationError> {
+            Ok(asset
+                .method(method)
+                .schedule()?
+                .iter()
+                .map(|row| row.expense)
+                .collect())
+        };
+        // 999 to depreciate: a third every period, or 3/6, 2/6 and 1/6 of it.
+        assert_eq!(
+            expenses(DepreciationMethod::StraightLine)?,
+            [Decimal::new(333_00, 2); 3]
+        );
+        assert_eq!(
+            expenses(DepreciationMethod::SumOfYearsDigits)?,
+            [
+                Decimal::new(499_50, 2),
+                Decimal::new(333_00, 2),
+                Decimal::new(166_50, 2)
+            ]
+        );
+        // 666.67, then 2/3 of 333.33 = 222.22, then the 110.11 left above the salvage value.
+        let schedule = asset
+            .method(DepreciationMethod::DoubleDecliningBalance)
+            .schedule()?;
+        assert_eq!(schedule[0].expense, Decimal::new(666_67, 2));
+        assert_eq!(schedule[1].expense, Decimal::new(222_22, 2));
+        assert_eq!(schedule[2].expense, Decimal::new(110_11, 2));
+        assert_eq!(schedule[2].accumulated, Decimal::new(999_00, 2));
+        assert_eq!(schedule[2].book_value, Decimal::new(1_00, 2));
+
+        // 100 over 7 periods: 14.29, 14.28, 14.29, ... with the rounding kept cumulative.
+        let asset = Depreciation::new(Decimal::new(100_00i64, 2), Decimal::new(0, 0), 7);
+        let schedule = asset.schedule()?;
+        assert_eq!(schedule[0].expense, Decimal::new(14_29, 2));
+        assert_eq!(schedule[1].expense, Decimal::new(14_28, 2));
+        assert_eq!(schedule[6].book_value, Decimal::new(0, 2));
+        let schedule = asset.rounding(RoundingMode::Down).schedule()?;
+        assert_eq!(schedule[0].expense, Decimal::new(14_28, 2));
+        assert_eq!(schedule[6].accumulated, Decimal::new(100_00, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_double_declining_balance_stops_at_salvage() -> Result<(), DecimalOperationError> {
+        // The book value reaches the salvage value in the second period.
+        let schedule = Depreciation::new(Decimal::new(1_000u64, 0), Decimal::new(400, 0), 4)
+            .method(DepreciationMethod::DoubleDecliningBalance)
+            .schedule()?;
+        let expenses: Vec<_> = schedule.iter().map(|row| row.expense).collect();
+        assert_eq!(
+            expenses,
+            [
+                Decimal::new(500, 0),
+                Decimal::new(100, 0),
+                Decimal::new(0, 0),
+                Decimal::new(0, 0)
+            ]
+        );
+        assert_eq!(schedule[3].book_value, Decimal::new(400, 0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_depreciation_errors() {
+        let schedule = |salvage, life| {
+            Depreciation::new(Decimal::new(1_000i64, 0), Decimal::new(salvage, 0), life).schedule()
+        };
+        assert_eq!(schedule(100, 0), Err(DecimalOperationError::OutOfDomain));
+        assert_eq!(schedule(-100, 5), Err(DecimalOperationError::OutOfDomain));
+        assert_eq!(schedule(1_001, 5), Err(DecimalOperationError::OutOfDomain));
+        assert!(schedule(1_000, 5).is_ok());
+    }
+}
@@ -1,15 +1,25 @@
-use std::{
+use core::{
     error::Error,
     fmt::{self, Display, Formatter},
 };
 
 /// Represents the possible errors that can occur during decimal operations.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DecimalOperationError {
     /// Indicates that an overflow occurred during the operation.
     Overflow,
+    /// Indicates that the result of the operation is below the minimum value of a signed type.
+    Underflow,
+    /// Indicates that the result of the operation is negative and can't be represented by an unsigned type.
+    NegativeResult,
     /// Indicates that a division by zero occurred during the operation.
     DivisionByZero,
+    /// Indicates that the scale factor needed to align the decimals can't be represented by the type.
+    ScaleTooLarge,
+    /// Indicates that the result can't be represented exactly and rounding was not allowed.
+    PrecisionLoss,
+    /// Indicates that the operand is outside the domain of the operation, like the square root of a negative value.
+    OutOfDomain,
 }
 
 impl Display for DecimalOperationError {
@@ -18,11 +28,366 @@ impl Display for DecimalOperationError {
             DecimalOperationError::Overflow => {
                 write!(f, "An overflow occurred during the operation.")
             }
+            DecimalOperationError::Underflow => {
+                write!(f, "An underflow occurred during the operation.")
+            }
+            DecimalOperationError::NegativeResult => {
+                write!(
+                    f,
+                    "The result of the operation is negative and can't be represented."
+                )
+            }
             DecimalOperationError::DivisionByZero => {
                 write!(f, "A division by zero occurred during the operation.")
             }
+            DecimalOperationError::ScaleTooLarge => {
+                write!(f, "The scale factor is too large to be represented.")
+            }
+            DecimalOperationError::PrecisionLoss => {
+                write!(
+                    f,
+                    "The result can't be represented without losing precision."
+                )
+            }
+            DecimalOperationError::OutOfDomain => {
+                write!(f, "The operand is outside the domain of the operation.")
+            }
         }
     }
 }
 
 impl Error for DecimalOperationError {}
+
+/// Represents the possible errors that can occur while parsing a decimal string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseDecimalError {
+    /// Indicates that the string doesn't contain any digits.
+    Empty,
+    /// Indicates that the string contains a character that isn't a digit, a sign, a decimal point or a `_` separator.
+    InvalidDigit,
+    /// Indicates that the string has more fractional digits than the requested number of decimals.
+    TooManyDecimals,
+    /// Indicates that the value is above the maximum value of the type.
+    Overflow,
+    /// Indicates that the value is below the minimum value of a signed type.
+    Underflow,
+    /// Indicates that the value is negative and can't be represented by an unsigned type.
+    NegativeValue,
+}
+
+impl Display for ParseDecimalError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ParseDecimalError::Empty => write!(f, "Cannot parse a decimal from an empty string."),
+            ParseDecimalError::InvalidDigit => write!(f, "Invalid digit found in the string."),
+            ParseDecimalError::TooManyDecimals => {
+                write!(f, "The string has more decimals than requested.")
+            }
+            ParseDecimalError::Overflow => {
+                write!(f, "The value is too large to be represented.")
+            }
+            ParseDecimalError::Underflow => {
+                write!(f, "The value is too small to be represented.")
+            }
+            ParseDecimalError::NegativeValue => {
+                write!(f, "The value is negative and can't be represented.")
+            }
+        }
+    }
+}
+
+impl Error for ParseDecimalError {}
+
+/// Represents the possible errors that can occur while looking up or applying exchange rates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FxError {
+    /// Indicates that no rate between the currencies is known, directly, inverted or through another currency.
+    MissingRate {
+        /// The code of the base currency.
+        base: &'static str,
+        /// The code of the quote currency.
+        quote: &'static str,
+    },
+    /// Indicates that the rate between the currencies is older than the allowed age.
+    StaleRate {
+        /// The code of the base currency.
+        base: &'static str,
+        /// The code of the quote currency.
+        quote: &'static str,
+        /// The timestamp of the oldest rate used.
+        timestamp: u64,
+    },
+    /// Indicates that a decimal operation failed while deriving or applying the rate.
+    Decimal(DecimalOperationError),
+}
+
+impl Display for FxError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            FxError::MissingRate { base, quote } => {
+                write!(f, "No exchange rate is known from {} to {}.", base, quote)
+            }
+            FxError::StaleRate {
+                base,
+                quote,
+                timestamp,
+            } => {
+                write!(
+                    f,
+                    "The exchange rate from {} to {} dates from {} and is stale.",
+                    base, quote, timestamp
+                )
+            }
+            FxError::Decimal(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for FxError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FxError::Decimal(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<DecimalOperationError> for FxError {
+    fn from(error: DecimalOperationError) -> Self {
+        FxError::Decimal(error)
+    }
+}
+
+/// Represents the possible errors that can occur while allocating an amount under constraints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AllocationError {
+    /// Indicates that the floor of the recipient at `index` is above its cap.
+    FloorAboveCap {
+        /// The position of the recipient.
+        index: usize,
+    },
+    /// Indicates that the floors add up to more than the amount, or that the caps and weights can't absorb all of it.
+    Infeasible,
+    /// Indicates that a decimal operation failed while allocating.
+    Decimal(DecimalOperationError),
+}
+
+impl Display for AllocationError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            AllocationError::FloorAboveCap { index } => {
+                write!(f, "The floor of recipient {} is above its cap.", index)
+            }
+            AllocationError::Infeasible => {
+                write!(
+                    f,
+                    "The amount can't be allocated within the floors and caps."
+                )
+            }
+            AllocationError::Decimal(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for AllocationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AllocationError::Decimal(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<DecimalOperationError> for AllocationError {
+    fn from(error: DecimalOperationError) -> Self {
+        AllocationError::Decimal(error)
+    }
+}
+
+/// Represents the possible errors that can occur while parsing a formatted amount of money.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseMoneyError {
+    /// Indicates that the string carries a symbol or code other than the ones of the expected currency.
+    CurrencyMismatch {
+        /// The code of the expected currency.
+        expected: &'static str,
+    },
+    /// Indicates that the group separators don't split the integer digits into valid groups, like `1,23,4.00`.
+    InvalidGrouping,
+    /// Indicates that the digits of the amount couldn't be parsed.
+    Decimal(ParseDecimalError),
+}
+
+impl Display for ParseMoneyError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ParseMoneyError::CurrencyMismatch { expected } => {
+                write!(f, "The amount isn't in the expected currency {}.", expected)
+            }
+            ParseMoneyError::InvalidGrouping => {
+                write!(f, "The digits of the amount are grouped incorrectly.")
+            }
+            ParseMoneyError::Decimal(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for ParseMoneyError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseMoneyError::Decimal(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseDecimalError> for ParseMoneyError {
+    fn from(error: ParseDecimalError) -> Self {
+        ParseMoneyError::Decimal(error)
+    }
+}
+
+/// Represents the possible errors that can occur while valuing a perpetuity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PerpetuityError {
+    /// Indicates that the discount rate isn't greater than the growth rate, so the payments are worth an unbounded amount.
+    RateNotGreaterThanGrowth,
+    /// Indicates that a decimal operation failed while valuing the perpetuity.
+    Decimal(DecimalOperationError),
+}
+
+impl Display for PerpetuityError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            PerpetuityError::RateNotGreaterThanGrowth => {
+                write!(f, "The discount rate isn't greater than the growth rate.")
+            }
+            PerpetuityError::Decimal(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for PerpetuityError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PerpetuityError::Decimal(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<DecimalOperationError> for PerpetuityError {
+    fn from(error: DecimalOperationError) -> Self {
+        PerpetuityError::Decimal(error)
+    }
+}
+
+/// Represents the possible errors that can occur while solving for a yield or a rate of return.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YieldError {
+    /// Indicates that no rate in the range searched changes the sign of the value, after evaluating it `evaluations` times.
+    NotBracketed {
+        /// The number of rates the value was evaluated at.
+        evaluations: u32,
+    },
+    /// Indicates that the bisection didn't narrow the rate down to the tolerance within `iterations` iterations.
+    NoConvergence {
+        /// The number of bisections made.
+        iterations: u32,
+    },
+    /// Indicates that a decimal operation failed while solving.
+    Decimal(DecimalOperationError),
+}
+
+impl Display for YieldError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            YieldError::NotBracketed { evaluations } => write!(
+                f,
+                "No rate in the range searched solves the equation, after {} evaluations.",
+                evaluations
+            ),
+            YieldError::NoConvergence { iterations } => write!(
+                f,
+                "The search didn't converge within {} iterations.",
+                iterations
+            ),
+            YieldError::Decimal(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for YieldError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            YieldError::Decimal(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<DecimalOperationError> for YieldError {
+    fn from(error: DecimalOperationError) -> Self {
+        YieldError::Decimal(error)
+    }
+}
+
+/// Represents the possible errors that can occur while valuing positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValuationError {
+    /// Indicates that the value of the position at `index` can't be represented at the scale of the valuation.
+    PositionOverflow {
+        /// The position of the offending entry.
+        index: usize,
+    },
+    /// Indicates that adding the value of the position at `index` overflowed the total.
+    TotalOverflow {
+        /// The position of the offending entry.
+        index: usize,
+    },
+    /// Indicates that valuing the position at `index` failed otherwise.
+    Position {
+        /// The position of the offending entry.
+        index: usize,
+        /// The error of the operation that failed.
+        error: DecimalOperationError,
+    },
+    /// Indicates that a decimal operation failed while valuing.
+    Decimal(DecimalOperationError),
+}
+
+impl Display for ValuationError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ValuationError::PositionOverflow { index } => {
+                write!(f, "The value of position {} overflows.", index)
+            }
+            ValuationError::TotalOverflow { index } => {
+                write!(
+                    f,
+                    "Adding the value of position {} overflows the total.",
+                    index
+                )
+            }
+            ValuationError::Position { index, error } => {
+                write!(f, "Valuing position {} failed: {}", index, error)
+            }
+            ValuationError::Decimal(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for ValuationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ValuationError::Position { error, .. } | ValuationError::Decimal(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<DecimalOperationError> for ValuationError {
+    fn from(error: DecimalOperationError) -> Self {
+        ValuationError::Decimal(error)
+    }
+}
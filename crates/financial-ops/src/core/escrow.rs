@@ -0,0 +1,287 @@
+use alloc::vec::Vec;
+
+use crate::core::{
+    interest::annuity::GUARD_DECIMALS, money::exchange_rate::multiply_divide_to_scale,
+    percentage::bps::bps_to_fraction, CheckedDecimalOperations, CheckedMulDiv, CompareDecimals,
+    Date, DayCount, Decimal, DecimalOperationError, Percent, PowerOfTen, RescaleDecimals,
+    RoundingMode, Zero,
+};
+
+/// One release of an [`EscrowSchedule`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EscrowRelease<T = i128> {
+    /// The date of the release.
+    pub date: Date,
+    /// The part of the principal released.
+    pub principal: Decimal<T>,
+    /// The interest the principal released earned since the deposit.
+    pub interest: Decimal<T>,
+    /// The amount released, principal and interest.
+    pub amount: Decimal<T>,
+}
+
+/// The releases of an [`Escrow`], with their totals.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EscrowSchedule<T = i128> {
+    /// The releases, by date.
+    pub releases: Vec<EscrowRelease<T>>,
+    /// The interest released in total.
+    pub interest: Decimal<T>,
+    /// The amount released in total, the principal plus the interest.
+    pub total: Decimal<T>,
+}
+
+/// An amount held in escrow, earning simple interest until it is released in parts on dates or
+/// milestones.
+///
+/// Every release pays out a percentage of the principal with the interest that part earned from
+/// the deposit to the release, counted with a day-count convention. A release on a milestone is
+/// scheduled on the date the milestone is met. The principal released and the interest earned up
+/// to every release are rounded cumulatively, so the releases add up exactly to the principal
+/// plus the total interest, rounded once.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{Date, Decimal, Escrow, Percent};
+///
+/// let date = |year, month, day| Date::new(year, month, day).unwrap();
+/// // 100,000 at 4% a year, released in thirds
+/// let escrow = Escrow::new(Decimal::new(100_000_00i64, 2), Decimal::new(400, 0), date(2024, 1, 1))
+///     .release(date(2024, 4, 1), Percent::new(33_3333, 4))
+///     .release(date(2024, 7, 1), Percent::new(33_3333, 4))
+///     .release(date(2024, 12, 31), Percent::new(33_3334, 4));
+///
+/// let schedule = escrow.schedule().unwrap();
+/// assert_eq!(schedule.releases[0].principal, Decimal::new(33_333_30, 2));
+/// assert_eq!(schedule.releases[0].interest, Decimal::new(332_42, 2));
+/// assert_eq!(schedule.interest, Decimal::new(2_330_60, 2));
+/// assert_eq!(schedule.total, Decimal::new(102_330_60, 2));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Escrow<T = i128> {
+    principal: Decimal<T>,
+    annual_rate_bps: Decimal<T>,
+    deposited: Date,
+    releases: Vec<(Date, Percent<T>)>,
+    convention: DayCount,
+    rounding: RoundingMode,
+}
+
+impl<T> Escrow<T> {
+    /// Creates an escrow of `principal` deposited on `deposited` and earning `annual_rate_bps`,
+    /// counting days with ACT/365 Fixed and rounding half to even.
+    pub const fn new(principal: Decimal<T>, annual_rate_bps: Decimal<T>, deposited: Date) -> Self {
+        Escrow {
+            principal,
+            annual_rate_bps,
+            deposited,
+            releases: Vec::new(),
+            convention: DayCount::Act365Fixed,
+            rounding: RoundingMode::HalfEven,
+        }
+    }
+
+    /// Adds a release of a percentage of the principal on a date.
+    pub fn release(mut self, date: Date, share: Percent<T>) -> Self {
+        self.releases.push((date, share));
+        self
+    }
+
+    /// Sets the day-count convention the interest accrues with.
+    pub fn day_count(mut self, convention: DayCount) -> Self {
+        self.convention = convention;
+        self
+    }
+
+    /// Sets the rounding applied to the principal and the interest released.
+    pub fn rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Returns the date and the share of the principal of every release, in the order added.
+    pub fn releases(&self) -> &[(Date, Percent<T>)] {
+        &self.releases
+    }
+}
+
+impl<T> Escrow<T>
+where
+    T: CheckedDecimalOperations
+        + CheckedMulDiv
+        + CompareDecimals
+        + RescaleDecimals
+        + PowerOfTen
+        + Zero
+        + From<u32>
+        + Copy,
+{
+    /// Computes the releases of the escrow, by date.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the schedule, or a `DecimalOperationError` if the shares of
+    /// the releases don't add up to 100% or a release is before the deposit, reported as
+    /// `OutOfDomain`, or the operation fails.
+    pub fn schedule(&self) -> Result<EscrowSchedule<T>, DecimalOperationError> {
+        let scale = self.principal.scale();
+        let decimals = scale
+            .checked_add(GUARD_DECIMALS)
+            .ok_or(DecimalOperationError::ScaleTooLarge)?;
+        let rate = bps_to_fraction(self.annual_rate_bps)?;
+
+        let mut releases = self.releases.clone();
+        releases.sort_by_key(|&(date, _)| date);
+        let total_share = releases
+            .iter()
+            .try_fold(Decimal::zero(0), |total, (_, share)| {
+                total.try_add(share.percent())
+            })?;
+        if !total_share.eq_value(&Decimal::new(T::from(100), 0)) {
+            return Err(DecimalOperationError::OutOfDomain);
+        }
+
+        let zero = Decimal::zero(scale);
+        let mut schedule = EscrowSchedule {
+            releases: Vec::with_capacity(releases.len()),
+            interest: zero,
+            total: zero,
+        };
+        // The shares and the exact interest released so far, and their rounded amounts.
+        let (mut share, mut earned) = (Decimal::zero(0), Decimal::zero(decimals));
+        let mut released = zero;
+        for (date, release_share) in releases {
+            share = share.try_add(release_share.percent())?;
+            let (value, share_decimals) = share.into_parts();
+            let principal = Percent::new(value, share_decimals)
+                .of(self.principal, self.rounding)?
+                .try_sub(released)?;
+            released = released.try_add(principal)?;
+            let (days, year) = self.convention.year_parts(self.deposited, date)?;
+            let accrued = multiply_divide_to_scale(
+                principal,
+                rate.try_mul(days)?,
+                year,
+                decimals,
+                RoundingMode::HalfEven,
+            )?;
+            earned = earned.try_add(Decimal::new(accrued, decimals))?;
+            let interest = earned
+                .try_rescale(scale, self.rounding)?
+                .try_sub(schedule.interest)?;
+            schedule.interest = schedule.interest.try_add(interest)?;
+            let amount = principal.try_add(interest)?;
+            schedule.total = schedule.total.try_add(amount)?;
+            schedule.releases.push(EscrowRelease {
+                date,
+                principal,
+                interest,
+                amount,
+            });
+        }
+        Ok(schedule)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escrow_schedule() -> Result<(), DecimalOperationError> {
+        let principal = Decimal::new(10_000_00i128, 2);
+        // Added out of order, and released on the same day as the deposit for the first part.
+        let escrow = Escrow::new(
+            principal,
+            Decimal::new(500, 0),
+            Date::try_from((2024, 1, 1))?,
+        )
+        .day_count(DayCount::Act360)
+        .release(Date::try_from((2024, 12, 31))?, Percent::new(50, 0))
+        .release(Date::try_from((2024, 1, 1))?, Percent::new(20, 0))
+        .release(Date::try_from((2024, 3, 31))?, Percent::new(30, 0));
+        let schedule = escrow.schedule()?;
+        let dates: Vec<_> = schedule
+            .releases
+            .iter()
+            .map(|release| release.date)
+            .collect();
+        assert_eq!(
+            dates,
+            [
+                Date::try_from((2024, 1, 1))?,
+                Date::try_from((2024, 3, 31))?,
+                Date::try_from((2024, 12, 31))?
+            ]
+        );
+        assert_eq!(schedule.releases[0].interest, Decimal::new(0, 2));
+        // 3,000 * 5% * 90 / 360 and 5,000 * 5% * 365 / 360
+        assert_eq!(schedule.releases[1].interest, Decimal::new(37_50, 2));
+        assert_eq!(schedule.releases[2].interest, Decimal::new(253_47, 2));
+        assert_eq!(schedule.releases[2].amount, Decimal::new(5_253_47, 2));
+        assert_eq!(schedule.interest, Decimal::new(290_97, 2));
+        assert_eq!(schedule.total, Decimal::new(10_290_97, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_escrow_releases_add_up() -> Result<(), DecimalOperationError> {
+        // Seven sevenths of 1,000.00, a month apart at 3% a year
+        let mut escrow = Escrow::new(
+            Decimal::new(1_000_00i64, 2),
+            Decimal::new(300, 0),
+            Date::try_from((2024, 1, 1))?,
+        );
+        for month in 2..=8 {
+            escrow = escrow.release(
+                Date::try_from((2024, month, 1))?,
+                Percent::new(14_285_714_285_714_3, 13),
+            );
+        }
+        // 100.0000000000001% in total
+        assert_eq!(escrow.schedule(), Err(DecimalOperationError::OutOfDomain));
+        escrow.releases[6].1 = Percent::new(14_285_714_285_714_2, 13);
+        let schedule = escrow.schedule()?;
+        let principal = schedule
+            .releases
+            .iter()
+            .try_fold(Decimal::zero(2), |total, release| {
+                total.try_add(release.principal)
+            })?;
+        assert_eq!(principal, Decimal::new(1_000_00, 2));
+        let amounts = schedule
+            .releases
+            .iter()
+            .try_fold(Decimal::zero(2), |total, release| {
+                total.try_add(release.amount)
+            })?;
+        assert_eq!(amounts, schedule.total);
+        assert_eq!(schedule.total, principal.try_add(schedule.interest)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_escrow_errors() -> Result<(), DecimalOperationError> {
+        let escrow = Escrow::new(
+            Decimal::new(100i64, 0),
+            Decimal::new(100, 0),
+            Date::try_from((2024, 6, 1))?,
+        );
+        assert_eq!(escrow.schedule(), Err(DecimalOperationError::OutOfDomain));
+        assert_eq!(
+            escrow
+                .clone()
+                .release(Date::try_from((2024, 7, 1))?, Percent::new(60, 0))
+                .schedule(),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        assert_eq!(
+            escrow
+                .release(Date::try_from((2024, 5, 31))?, Percent::new(100, 0))
+                .schedule(),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+//! `Arbitrary` implementations for fuzzing, enabled with the `arbitrary` feature.
+//!
+//! Generated decimals and money amounts only use scales whose factor `10^scale` fits in the value
+//! type, so fuzz targets exercise the arithmetic instead of failing early with `ScaleTooLarge`.
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::core::{power_of_ten::max_scale, Decimal, Money, PowerOfTen, RoundingMode, I256, U256};
+
+impl<'a, T: Arbitrary<'a> + PowerOfTen> Arbitrary<'a> for Decimal<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let value = T::arbitrary(u)?;
+        let scale = u.int_in_range(0..=max_scale::<T>())?;
+        Ok(Decimal::new(value, scale))
+    }
+}
+
+impl<'a, C, T: Arbitrary<'a> + PowerOfTen> Arbitrary<'a> for Money<C, T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Decimal::arbitrary(u).map(Money::from_decimal)
+    }
+}
+
+impl<'a> Arbitrary<'a> for RoundingMode {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.choose(&[
+            RoundingMode::Down,
+            RoundingMode::Up,
+            RoundingMode::Floor,
+            RoundingMode::Ceiling,
+            RoundingMode::HalfUp,
+            RoundingMode::HalfDown,
+            RoundingMode::HalfEven,
+            RoundingMode::Unnecessary,
+        ])
+        .copied()
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (1, Some(4))
+    }
+}
+
+macro_rules! impl_wide_arbitrary {
+    ($($t:ty)*) => ($(
+        impl<'a> Arbitrary<'a> for $t {
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                <[u8; 32]>::arbitrary(u).map(<$t>::from_le_bytes)
+            }
+
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                <[u8; 32]>::size_hint(depth)
+            }
+        }
+    )*)
+}
+
+impl_wide_arbitrary! { U256 I256 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Usd;
+
+    #[test]
+    fn test_arbitrary_decimals_have_valid_scales() {
+        let data: Vec<u8> = (0..=255).cycle().take(4096).collect();
+        let mut u = Unstructured::new(&data);
+        while !u.is_empty() {
+            let decimal = Decimal::<u16>::arbitrary(&mut u).unwrap();
+            assert!(decimal.scale() <= 4);
+            let _ = RoundingMode::arbitrary(&mut u).unwrap();
+            let money = Money::<Usd, u8>::arbitrary(&mut u).unwrap();
+            assert!(money.decimals() <= 2);
+        }
+    }
+}
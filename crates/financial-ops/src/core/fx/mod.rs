@@ -0,0 +1,3 @@
+pub mod rate_table;
+
+pub use rate_table::*;
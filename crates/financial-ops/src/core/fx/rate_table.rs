@@ -0,0 +1,351 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use crate::core::{
+    money::exchange_rate::{multiply_to_scale, reciprocal},
+    CheckedDivideToScale, CheckedMulDiv, Currency, Decimal, DecimalOperationError, ExchangeRate,
+    FxError, Money, PowerOfTen, RescaleDecimals, RoundingMode,
+};
+
+/// The codes of a currency pair, base first.
+pub type CurrencyPair = (&'static str, &'static str);
+
+/// A rate and the timestamp it was quoted at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct TimestampedRate<T> {
+    rate: Decimal<T>,
+    timestamp: u64,
+}
+
+/// A table of exchange rates keyed by currency pair, each stored with the timestamp it was
+/// quoted at.
+///
+/// Looking up a pair uses the stored rate when there is one, then the reciprocal of the reverse
+/// pair, then a cross rate through a currency quoted against both. Derived rates carry the
+/// decimals and rounding of the table, and the timestamp of the oldest rate they were derived
+/// from. Timestamps are opaque `u64`s, like seconds since the Unix epoch, so staleness is checked
+/// against a `now` supplied by the caller.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{Eur, ExchangeRate, Jpy, Money, RateTable, RoundingMode, Usd};
+///
+/// let mut rates = RateTable::<i64>::new(6);
+/// rates.insert(ExchangeRate::<Eur, Usd, i64>::new(1_0825, 4), 1_700_000_000);
+/// rates.insert(ExchangeRate::<Usd, Jpy, i64>::new(151_25, 2), 1_700_000_060);
+///
+/// // Through the dollar, dated by the older of the two rates.
+/// let (eur_jpy, timestamp) = rates.rate::<Eur, Jpy>().unwrap();
+/// assert_eq!(eur_jpy.rate().into_parts(), (163_728125, 6));
+/// assert_eq!(timestamp, 1_700_000_000);
+///
+/// let yen = rates
+///     .convert_all::<Eur, Jpy>(&[Money::new(10_00, 2)], RoundingMode::HalfEven)
+///     .unwrap();
+/// assert_eq!(yen[0].into_parts(), (1637, 0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateTable<T = i128> {
+    rates: BTreeMap<CurrencyPair, TimestampedRate<T>>,
+    decimals: u32,
+    rounding: RoundingMode,
+}
+
+impl<T> RateTable<T> {
+    /// Creates an empty table whose inverted and cross rates carry `decimals` decimals, rounded
+    /// half to even.
+    pub const fn new(decimals: u32) -> Self {
+        RateTable {
+            rates: BTreeMap::new(),
+            decimals,
+            rounding: RoundingMode::HalfEven,
+        }
+    }
+
+    /// Sets the rounding applied to inverted and cross rates.
+    pub fn rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Returns the number of stored rates.
+    pub fn len(&self) -> usize {
+        self.rates.len()
+    }
+
+    /// Returns `true` if the table doesn't store any rate.
+    pub fn is_empty(&self) -> bool {
+        self.rates.is_empty()
+    }
+
+    /// Stores a rate quoted at `timestamp`, replacing the rate stored for the same pair unless
+    /// that one is more recent.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the rate was stored, or `false` if the table already holds a more recent
+    /// rate for the pair.
+    pub fn insert<Base: Currency, Quote: Currency>(
+        &mut self,
+        rate: ExchangeRate<Base, Quote, T>,
+        timestamp: u64,
+    ) -> bool {
+        let pair = (Base::CODE, Quote::CODE);
+        if self
+            .rates
+            .get(&pair)
+            .is_some_and(|stored| stored.timestamp > timestamp)
+        {
+            return false;
+        }
+        let rate = rate.into_decimal();
+        self.rates.insert(pair, TimestampedRate { rate, timestamp });
+        true
+    }
+
+    /// Removes the rate stored for the pair, returning `true` if there was one.
+    ///
+    /// The reverse pair is left untouched.
+    pub fn remove<Base: Currency, Quote: Currency>(&mut self) -> bool {
+        self.rates.remove(&(Base::CODE, Quote::CODE)).is_some()
+    }
+
+    /// Returns the stored pairs whose rate is more than `max_age` older than `now`.
+    pub fn stale_pairs(&self, now: u64, max_age: u64) -> Vec<CurrencyPair> {
+        self.rates
+            .iter()
+            .filter(|(_, stored)| is_stale(stored.timestamp, now, max_age))
+            .map(|(&pair, _)| pair)
+            .collect()
+    }
+}
+
+impl<T> RateTable<T>
+where
+    T: CheckedMulDiv + CheckedDivideToScale + RescaleDecimals + PowerOfTen + Copy,
+{
+    /// Looks up the rate from `Base` to `Quote`, inverting the reverse pair or crossing through
+    /// another currency when the pair isn't stored.
+    ///
+    /// Cross rates go through the first currency, in code order, that is quoted against both.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the rate and the timestamp of the oldest rate it was derived
+    /// from, or a `FxError` if no rate is known or deriving it fails.
+    pub fn rate<Base: Currency, Quote: Currency>(
+        &self,
+    ) -> Result<(ExchangeRate<Base, Quote, T>, u64), FxError> {
+        let (rate, timestamp) = self.rate_by_code(Base::CODE, Quote::CODE)?;
+        Ok((ExchangeRate::from_decimal(rate), timestamp))
+    }
+
+    /// Looks up the rate between two currency codes like [`RateTable::rate`].
+    pub(crate) fn rate_by_code(
+        &self,
+        base: &'static str,
+        quote: &'static str,
+    ) -> Result<(Decimal<T>, u64), FxError> {
+        let found = self
+            .lookup(base, quote)
+            .ok_or(FxError::MissingRate { base, quote })??;
+        Ok((found.rate, found.timestamp))
+    }
+
+    /// Looks up the rate from `Base` to `Quote` like [`RateTable::rate`], rejecting it when it
+    /// was derived from a rate more than `max_age` older than `now`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the rate, or a `FxError` if no rate is known, deriving it
+    /// fails or it is stale.
+    pub fn fresh_rate<Base: Currency, Quote: Currency>(
+        &self,
+        now: u64,
+        max_age: u64,
+    ) -> Result<ExchangeRate<Base, Quote, T>, FxError> {
+        let (rate, timestamp) = self.rate::<Base, Quote>()?;
+        if is_stale(timestamp, now, max_age) {
+            return Err(FxError::StaleRate {
+                base: Base::CODE,
+                quote: Quote::CODE,
+                timestamp,
+            });
+        }
+        Ok(rate)
+    }
+
+    /// Converts every amount into the reporting currency `To`, looking the rate up once.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the converted amounts in order, or a `FxError` if no rate is
+    /// known or a conversion fails.
+    pub fn convert_all<From: Currency, To: Currency>(
+        &self,
+        amounts: &[Money<From, T>],
+        rounding: RoundingMode,
+    ) -> Result<Vec<Money<To, T>>, FxError> {
+        let (rate, _) = self.rate::<From, To>()?;
+        amounts
+            .iter()
+            .map(|&amount| rate.convert(amount, rounding).map_err(FxError::from))
+            .collect()
+    }
+
+    /// Returns the stored, inverted or crossed rate for the pair, or `None` if none is known.
+    fn lookup(
+        &self,
+        base: &'static str,
+        quote: &'static str,
+    ) -> Option<Result<TimestampedRate<T>, DecimalOperationError>> {
+        if let Some(leg) = self.leg(base, quote) {
+            return Some(leg);
+        }
+        let (first, second) = self.rates.keys().find_map(|&(from, to)| {
+            let pivot = if from == base {
+                to
+            } else if to == base {
+                from
+            } else {
+                return None;
+            };
+            if pivot == quote {
+                return None;
+            }
+            Some((self.leg(base, pivot)?, self.leg(pivot, quote)?))
+        })?;
+        Some(first.and_then(|first| self.cross(first, second?)))
+    }
+
+    /// Returns the stored rate for the pair, or the reciprocal of the reverse pair.
+    fn leg(
+        &self,
+        base: &'static str,
+        quote: &'static str,
+    ) -> Option<Result<TimestampedRate<T>, DecimalOperationError>> {
+        if let Some(&stored) = self.rates.get(&(base, quote)) {
+            return Some(Ok(stored));
+        }
+        let reverse = self.rates.get(&(quote, base))?;
+        Some(
+            reciprocal(reverse.rate, self.decimals, self.rounding).map(|rate| TimestampedRate {
+                rate,
+                timestamp: reverse.timestamp,
+            }),
+        )
+    }
+
+    /// Multiplies the rates from the base currency to a pivot and from the pivot to the quote
+    /// currency.
+    fn cross(
+        &self,
+        first: TimestampedRate<T>,
+        second: TimestampedRate<T>,
+    ) -> Result<TimestampedRate<T>, DecimalOperationError> {
+        let value = multiply_to_scale(first.rate, second.rate, self.decimals, self.rounding)?;
+        Ok(TimestampedRate {
+            rate: Decimal::new(value, self.decimals),
+            timestamp: first.timestamp.min(second.timestamp),
+        })
+    }
+}
+
+fn is_stale(timestamp: u64, now: u64, max_age: u64) -> bool {
+    now.saturating_sub(timestamp) > max_age
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Eur, Gbp, Jpy, Usd};
+
+    fn table() -> RateTable<i64> {
+        let mut rates = RateTable::new(6);
+        rates.insert(ExchangeRate::<Eur, Usd, i64>::new(1_0825, 4), 100);
+        rates.insert(ExchangeRate::<Usd, Jpy, i64>::new(151_25, 2), 200);
+        rates
+    }
+
+    #[test]
+    fn test_rate_table_lookup() -> Result<(), FxError> {
+        let rates = table();
+        assert_eq!(rates.len(), 2);
+
+        let (eur_usd, timestamp) = rates.rate::<Eur, Usd>()?;
+        assert_eq!(eur_usd.rate(), Decimal::new(1_0825, 4));
+        assert_eq!(timestamp, 100);
+
+        // Inverted
+        let (usd_eur, timestamp) = rates.rate::<Usd, Eur>()?;
+        assert_eq!(usd_eur.rate(), Decimal::new(0_923788, 6));
+        assert_eq!(timestamp, 100);
+
+        // Crossed through the dollar
+        let (eur_jpy, timestamp) = rates.rate::<Eur, Jpy>()?;
+        assert_eq!(eur_jpy.rate(), Decimal::new(163_728125, 6));
+        assert_eq!(timestamp, 100);
+        let (jpy_eur, _) = rates.rate::<Jpy, Eur>()?;
+        // 0.006612 * 0.923788
+        assert_eq!(jpy_eur.rate(), Decimal::new(0_006108, 6));
+
+        assert_eq!(
+            rates.rate::<Eur, Gbp>(),
+            Err(FxError::MissingRate {
+                base: "EUR",
+                quote: "GBP"
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_rate_table_insert_keeps_most_recent() -> Result<(), FxError> {
+        let mut rates = table();
+        assert!(!rates.insert(ExchangeRate::<Eur, Usd, i64>::new(1_0700, 4), 50));
+        assert!(rates.insert(ExchangeRate::<Eur, Usd, i64>::new(1_0900, 4), 150));
+        assert_eq!(rates.rate::<Eur, Usd>()?.0.rate(), Decimal::new(1_0900, 4));
+
+        assert!(rates.remove::<Eur, Usd>());
+        assert!(!rates.remove::<Eur, Usd>());
+        assert_eq!(rates.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rate_table_staleness() -> Result<(), FxError> {
+        let rates = table();
+        assert_eq!(rates.stale_pairs(250, 100), [("EUR", "USD")]);
+        assert!(rates.stale_pairs(200, 100).is_empty());
+
+        assert!(rates.fresh_rate::<Usd, Jpy>(250, 100).is_ok());
+        // The cross rate is as old as the euro rate.
+        assert_eq!(
+            rates.fresh_rate::<Eur, Jpy>(250, 100),
+            Err(FxError::StaleRate {
+                base: "EUR",
+                quote: "JPY",
+                timestamp: 100
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_rate_table_convert_all() -> Result<(), FxError> {
+        let rates = table();
+        let amounts = [Money::<Eur, i64>::new(250_00, 2), Money::new(29_99, 2)];
+        let dollars = rates.convert_all::<Eur, Usd>(&amounts, RoundingMode::HalfEven)?;
+        assert_eq!(dollars, [Money::new(270_62, 2), Money::new(32_46, 2)]);
+
+        let empty = RateTable::<i64>::new(6);
+        assert_eq!(
+            empty.convert_all::<Usd, Eur>(&[], RoundingMode::HalfEven),
+            Err(FxError::MissingRate {
+                base: "USD",
+                quote: "EUR"
+            })
+        );
+        Ok(())
+    }
+}
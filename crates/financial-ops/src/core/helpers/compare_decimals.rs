@@ -0,0 +1,115 @@
+use core::cmp::Ordering;
+
+use crate::core::{CheckedMul, PowerOfTen, Signedness, Zero};
+
+/// A trait for comparing values with different numbers of decimals.
+///
+/// Comparing `(1_00, 2)` and `(1_0000, 4)` with `==` compares the raw integers and gives the wrong
+/// answer. These methods align the decimals before comparing. Aligning never fails: when scaling
+/// a value would overflow the type, its magnitude is known to exceed any value of the type, so the
+/// ordering is decided by its sign.
+pub trait CompareDecimals {
+    /// Compares two values with decimals.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The first value to compare.
+    /// * `other` - The second value to compare.
+    /// * `self_decimals` - The number of decimals in the first value.
+    /// * `other_decimals` - The number of decimals in the second value.
+    ///
+    /// # Returns
+    ///
+    /// The `Ordering` between the quantities represented by both values.
+    fn cmp_decimals(&self, other: &Self, self_decimals: u32, other_decimals: u32) -> Ordering;
+
+    /// Returns `true` if both values represent the same quantity.
+    fn eq_decimals(&self, other: &Self, self_decimals: u32, other_decimals: u32) -> bool {
+        self.cmp_decimals(other, self_decimals, other_decimals) == Ordering::Equal
+    }
+
+    /// Returns `true` if `self` represents a smaller quantity than `other`.
+    fn lt_decimals(&self, other: &Self, self_decimals: u32, other_decimals: u32) -> bool {
+        self.cmp_decimals(other, self_decimals, other_decimals) == Ordering::Less
+    }
+
+    /// Returns `true` if `self` represents a smaller or equal quantity than `other`.
+    fn le_decimals(&self, other: &Self, self_decimals: u32, other_decimals: u32) -> bool {
+        self.cmp_decimals(other, self_decimals, other_decimals) != Ordering::Greater
+    }
+
+    /// Returns `true` if `self` represents a greater quantity than `other`.
+    fn gt_decimals(&self, other: &Self, self_decimals: u32, other_decimals: u32) -> bool {
+        self.cmp_decimals(other, self_decimals, other_decimals) == Ordering::Greater
+    }
+
+    /// Returns `true` if `self` represents a greater or equal quantity than `other`.
+    fn ge_decimals(&self, other: &Self, self_decimals: u32, other_decimals: u32) -> bool {
+        self.cmp_decimals(other, self_decimals, other_decimals) != Ordering::Less
+    }
+}
+
+/// Compares `value` with `other * 10^exponent`.
+fn cmp_scaled<T>(value: &T, other: &T, exponent: u32) -> Ordering
+where
+    T: CheckedMul + PowerOfTen + Signedness + Zero + Ord,
+{
+    if other.is_zero() {
+        return value.cmp(&T::zero());
+    }
+    match T::checked_power_of_ten(exponent).and_then(|factor| other.checked_mul(&factor)) {
+        Some(scaled) => value.cmp(&scaled),
+        // The scaled value doesn't fit in the type, so it lies beyond any value of the type.
+        None if other.is_negative() => Ordering::Greater,
+        None => Ordering::Less,
+    }
+}
+
+// Blanket implementation of the CompareDecimals trait for all ordered types supporting checked multiplication
+impl<T> CompareDecimals for T
+where
+    T: CheckedMul + PowerOfTen + Signedness + Zero + Ord,
+{
+    fn cmp_decimals(&self, other: &Self, self_decimals: u32, other_decimals: u32) -> Ordering {
+        match self_decimals.cmp(&other_decimals) {
+            Ordering::Equal => self.cmp(other),
+            Ordering::Greater => cmp_scaled(self, other, self_decimals.abs_diff(other_decimals)),
+            Ordering::Less => {
+                cmp_scaled(other, self, self_decimals.abs_diff(other_decimals)).reverse()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cmp_decimals() {
+        assert_eq!(1_00u64.cmp_decimals(&1_0000, 2, 4), Ordering::Equal);
+        assert_eq!(1_01u64.cmp_decimals(&1_0000, 2, 4), Ordering::Greater);
+        assert_eq!(1_0000u64.cmp_decimals(&1_01, 4, 2), Ordering::Less);
+        assert_eq!((-1_5i32).cmp_decimals(&-1_49, 1, 2), Ordering::Less);
+        assert_eq!(0u8.cmp_decimals(&0, 0, 30), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_cmp_decimals_overflowing_alignment() {
+        // Aligning 1 to 3 decimals doesn't fit in a u8, but 1 is still greater than 0.255.
+        assert_eq!(255u8.cmp_decimals(&1, 3, 0), Ordering::Less);
+        assert_eq!(1u8.cmp_decimals(&255, 0, 3), Ordering::Greater);
+        assert_eq!(i8::MIN.cmp_decimals(&-1, 3, 0), Ordering::Greater);
+        assert_eq!(u64::MAX.cmp_decimals(&1, 30, 0), Ordering::Less);
+    }
+
+    #[test]
+    fn test_comparison_helpers() {
+        assert!(1_00u64.eq_decimals(&1_0000, 2, 4));
+        assert!(!1_00u64.eq_decimals(&1_0001, 2, 4));
+        assert!(1_00u64.lt_decimals(&1_0001, 2, 4));
+        assert!(1_00u64.le_decimals(&1_0000, 2, 4));
+        assert!(2u64.gt_decimals(&1_9999, 0, 4));
+        assert!(2u64.ge_decimals(&2_0000, 0, 4));
+    }
+}
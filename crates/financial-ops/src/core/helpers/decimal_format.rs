@@ -0,0 +1,320 @@
+use alloc::{format, string::String};
+
+use crate::core::{
+    format_locale::group_digits, Currency, DecimalOperationError, FormatLocale, GroupingStyle,
+    Money, RescaleDecimals, RoundingMode, SymbolPlacement, ToStringDecimals,
+};
+
+/// How negative amounts are rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NegativeStyle {
+    /// A leading minus sign: `-1,234.56`.
+    Minus,
+    /// Parentheses around the amount, as in accounting exports: `(1,234.56)`.
+    Parentheses,
+}
+
+/// A reusable format for rendering values with decimals.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::DecimalFormat;
+///
+/// const USD: DecimalFormat = DecimalFormat::new(2).group_separator(',');
+///
+/// assert_eq!(USD.format(1234567_89u64), "1,234,567.89");
+/// assert_eq!(USD.format(-5_00i64), "-5.00");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DecimalFormat {
+    decimals: u32,
+    group_separator: char,
+    grouping: GroupingStyle,
+    decimal_separator: char,
+    symbol: Option<&'static str>,
+    symbol_placement: SymbolPlacement,
+    negative_style: NegativeStyle,
+    pad_positive: bool,
+    rounding: RoundingMode,
+}
+
+impl DecimalFormat {
+    /// Creates a format for values with `decimals` decimals, without grouping, with a `.`
+    /// decimal separator and without a symbol.
+    pub const fn new(decimals: u32) -> Self {
+        DecimalFormat {
+            decimals,
+            group_separator: ',',
+            grouping: GroupingStyle::None,
+            decimal_separator: '.',
+            symbol: None,
+            symbol_placement: SymbolPlacement::Prefix,
+            negative_style: NegativeStyle::Minus,
+            pad_positive: false,
+            rounding: RoundingMode::HalfEven,
+        }
+    }
+
+    /// Creates a format for amounts in the currency `C`, with its decimals, symbol and default
+    /// locale.
+    pub const fn currency<C: Currency>() -> Self {
+        DecimalFormat::new(C::DECIMALS)
+            .locale(C::LOCALE)
+            .symbol(C::SYMBOL)
+    }
+
+    /// Applies the separators, grouping and symbol placement of a locale.
+    pub const fn locale(mut self, locale: FormatLocale) -> Self {
+        self.group_separator = locale.group_separator;
+        self.grouping = locale.grouping;
+        self.decimal_separator = locale.decimal_separator;
+        self.symbol_placement = locale.symbol_placement;
+        self
+    }
+
+    /// Sets the separator inserted between groups of integer digits, grouping by thousands
+    /// unless another grouping style was set.
+    pub const fn group_separator(mut self, separator: char) -> Self {
+        self.group_separator = separator;
+        if let GroupingStyle::None = self.grouping {
+            self.grouping = GroupingStyle::Thousands;
+        }
+        self
+    }
+
+    /// Sets how integer digits are grouped.
+    pub const fn grouping(mut self, grouping: GroupingStyle) -> Self {
+        self.grouping = grouping;
+        self
+    }
+
+    /// Sets the separator between the integer and the fractional digits.
+    pub const fn decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    /// Sets the currency symbol rendered with the amount.
+    pub const fn symbol(mut self, symbol: &'static str) -> Self {
+        self.symbol = Some(symbol);
+        self
+    }
+
+    /// Sets where the currency symbol is placed.
+    pub const fn symbol_placement(mut self, placement: SymbolPlacement) -> Self {
+        self.symbol_placement = placement;
+        self
+    }
+
+    /// Sets how negative amounts are rendered.
+    pub const fn negative_style(mut self, style: NegativeStyle) -> Self {
+        self.negative_style = style;
+        self
+    }
+
+    /// Sets whether positive amounts get a trailing space, so they line up with negative amounts
+    /// in parentheses.
+    pub const fn pad_positive(mut self, pad: bool) -> Self {
+        self.pad_positive = pad;
+        self
+    }
+
+    /// Renders negative amounts in parentheses and pads positive amounts to align with them, as
+    /// expected by accounting exports.
+    pub const fn accounting(self) -> Self {
+        self.negative_style(NegativeStyle::Parentheses)
+            .pad_positive(true)
+    }
+
+    /// Sets the rounding applied by [`format_money`](DecimalFormat::format_money) to amounts with
+    /// more decimals than the format. Defaults to [`RoundingMode::HalfEven`].
+    pub const fn rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Returns the number of decimals of the values this format renders.
+    pub const fn decimals(&self) -> u32 {
+        self.decimals
+    }
+
+    /// Formats a raw value with the decimals of this format.
+    pub fn format<T: ToStringDecimals>(&self, value: T) -> String {
+        let plain = value.to_string_decimals(self.decimals);
+        let (negative, unsigned) = match plain.strip_prefix('-') {
+            Some(unsigned) => (true, unsigned),
+            None => (false, plain.as_str()),
+        };
+        let (integer, fraction) = match unsigned.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (unsigned, None),
+        };
+        let mut amount = group_digits(integer, self.group_separator, self.grouping);
+        if let Some(fraction) = fraction {
+            amount.push(self.decimal_separator);
+            amount.push_str(fraction);
+        }
+        let amount = match (self.symbol, self.symbol_placement) {
+            (None, _) => amount,
+            (Some(symbol), SymbolPlacement::Prefix) => format!("{symbol}{amount}"),
+            (Some(symbol), SymbolPlacement::PrefixSpaced) => format!("{symbol} {amount}"),
+            (Some(symbol), SymbolPlacement::Suffix) => format!("{amount}{symbol}"),
+            (Some(symbol), SymbolPlacement::SuffixSpaced) => format!("{amount} {symbol}"),
+        };
+        match (negative, self.negative_style) {
+            (true, NegativeStyle::Minus) => format!("-{amount}"),
+            (true, NegativeStyle::Parentheses) => format!("({amount})"),
+            (false, _) if self.pad_positive => format!("{amount} "),
+            (false, _) => amount,
+        }
+    }
+
+    /// Formats an amount of money, rescaling it to the decimals of this format with its rounding.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the formatted amount, or a `DecimalOperationError` if the
+    /// amount can't be rescaled.
+    pub fn format_money<C, T>(&self, money: Money<C, T>) -> Result<String, DecimalOperationError>
+    where
+        T: RescaleDecimals + ToStringDecimals,
+    {
+        let (amount, _) = money
+            .try_rescale(self.decimals, self.rounding)?
+            .into_parts();
+        Ok(self.format(amount))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Eur, Jpy, Usd};
+
+    #[test]
+    fn test_decimal_format() {
+        let format = DecimalFormat::new(2).group_separator(',');
+        assert_eq!(format.format(1234567_89u64), "1,234,567.89");
+        assert_eq!(format.format(123_45u32), "123.45");
+        assert_eq!(format.format(-1234_56i64), "-1,234.56");
+        assert_eq!(format.format(-5i8), "-0.05");
+
+        let format = DecimalFormat::new(0).group_separator('_');
+        assert_eq!(
+            format.format(u128::MAX),
+            "340_282_366_920_938_463_463_374_607_431_768_211_455"
+        );
+    }
+
+    #[test]
+    fn test_decimal_format_separators() {
+        let format = DecimalFormat::new(3)
+            .group_separator('.')
+            .decimal_separator(',');
+        assert_eq!(format.format(1234567_890u64), "1.234.567,890");
+        assert_eq!(DecimalFormat::new(1).format(1234_5u32), "1234.5");
+    }
+
+    #[test]
+    fn test_decimal_format_locales() {
+        let value = 1234567_89u64;
+        let format = |locale| DecimalFormat::new(2).locale(locale);
+
+        assert_eq!(format(FormatLocale::EN_US).format(value), "1,234,567.89");
+        assert_eq!(format(FormatLocale::DE_DE).format(value), "1.234.567,89");
+        assert_eq!(
+            format(FormatLocale::FR_FR).format(value),
+            "1\u{202f}234\u{202f}567,89"
+        );
+        assert_eq!(format(FormatLocale::DE_CH).format(value), "1’234’567.89");
+        assert_eq!(format(FormatLocale::EN_IN).format(value), "12,34,567.89");
+    }
+
+    #[test]
+    fn test_decimal_format_symbols() {
+        let usd = DecimalFormat::new(2)
+            .locale(FormatLocale::EN_US)
+            .symbol("$");
+        assert_eq!(usd.format(1234_56u64), "$1,234.56");
+        assert_eq!(usd.format(-1234_56i64), "-$1,234.56");
+
+        let eur = DecimalFormat::new(2)
+            .locale(FormatLocale::DE_DE)
+            .symbol("€");
+        assert_eq!(eur.format(-1234_56i64), "-1.234,56 €");
+
+        let chf = DecimalFormat::new(2)
+            .locale(FormatLocale::DE_CH)
+            .symbol("CHF");
+        assert_eq!(chf.format(5u8), "CHF 0.05");
+
+        let btc = DecimalFormat::new(8)
+            .symbol("₿")
+            .symbol_placement(SymbolPlacement::Suffix);
+        assert_eq!(btc.format(1u64), "0.00000001₿");
+    }
+
+    #[test]
+    fn test_decimal_format_money() -> Result<(), DecimalOperationError> {
+        let usd = DecimalFormat::currency::<Usd>();
+        assert_eq!(
+            usd.format_money(Money::<Usd, i64>::new(1234_56, 2))?,
+            "$1,234.56"
+        );
+        assert_eq!(usd.format_money(Money::<Usd, i64>::new(-5, 0))?, "-$5.00");
+
+        let eur = DecimalFormat::currency::<Eur>();
+        assert_eq!(
+            eur.format_money(Money::<Eur, u64>::new(1234_56, 2))?,
+            "1.234,56 €"
+        );
+
+        let jpy = DecimalFormat::currency::<Jpy>();
+        assert_eq!(
+            jpy.format_money(Money::<Jpy, u64>::new(1234_56, 2))?,
+            "¥1,235"
+        );
+        assert_eq!(jpy.format_money(Money::<Jpy, u64>::new(2_5, 1))?, "¥2");
+        assert_eq!(
+            jpy.rounding(RoundingMode::HalfUp)
+                .format_money(Money::<Jpy, u64>::new(2_5, 1))?,
+            "¥3"
+        );
+
+        // The locale can be overridden per format
+        let usd_in_germany = DecimalFormat::currency::<Usd>().locale(FormatLocale::DE_DE);
+        assert_eq!(
+            usd_in_germany.format_money(Money::<Usd, u64>::new(1234_56, 2))?,
+            "1.234,56 $"
+        );
+
+        assert_eq!(
+            jpy.rounding(RoundingMode::Unnecessary)
+                .format_money(Money::<Jpy, u64>::new(1_5, 1)),
+            Err(DecimalOperationError::PrecisionLoss)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_decimal_format_accounting() {
+        let format = DecimalFormat::new(2)
+            .locale(FormatLocale::EN_US)
+            .accounting();
+        assert_eq!(format.format(-1234_56i64), "(1,234.56)");
+        assert_eq!(format.format(1234_56i64), "1,234.56 ");
+        assert_eq!(format.format(-5i32), "(0.05)");
+        assert_eq!(format.symbol("$").format(-1_00i64), "($1.00)");
+
+        let format = DecimalFormat::new(2).negative_style(NegativeStyle::Parentheses);
+        assert_eq!(format.format(1_00u64), "1.00");
+
+        // Padded amounts line up in a right aligned column
+        let column: Vec<String> = [1234_56i64, -1234_56]
+            .iter()
+            .map(|&value| format!("{:>12}", DecimalFormat::new(2).accounting().format(value)))
+            .collect();
+        assert_eq!(column, ["    1234.56 ", "   (1234.56)"]);
+    }
+}
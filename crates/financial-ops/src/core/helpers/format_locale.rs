@@ -0,0 +1,136 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// How integer digits are grouped when formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GroupingStyle {
+    /// Digits are not grouped: `1234567`.
+    None,
+    /// Groups of three digits: `1,234,567`.
+    Thousands,
+    /// A group of three digits followed by groups of two, as used in India: `12,34,567`.
+    Indian,
+}
+
+/// Where a currency symbol is placed relative to the amount.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SymbolPlacement {
+    /// Before the amount: `$1.00`.
+    Prefix,
+    /// Before the amount, separated by a space: `CHF 1.00`.
+    PrefixSpaced,
+    /// After the amount: `1.00$`.
+    Suffix,
+    /// After the amount, separated by a space: `1,00 €`.
+    SuffixSpaced,
+}
+
+/// The separators, grouping and symbol placement conventions of a locale.
+///
+/// Apply a profile to a `DecimalFormat` with `DecimalFormat::locale`, or give a currency its
+/// default profile with [`Currency::LOCALE`](crate::core::Currency::LOCALE).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FormatLocale {
+    /// The separator between the integer and the fractional digits.
+    pub decimal_separator: char,
+    /// The separator between groups of integer digits.
+    pub group_separator: char,
+    /// How integer digits are grouped.
+    pub grouping: GroupingStyle,
+    /// Where a currency symbol is placed.
+    pub symbol_placement: SymbolPlacement,
+}
+
+impl FormatLocale {
+    /// English (United States): `$1,234,567.89`.
+    pub const EN_US: FormatLocale = FormatLocale {
+        decimal_separator: '.',
+        group_separator: ',',
+        grouping: GroupingStyle::Thousands,
+        symbol_placement: SymbolPlacement::Prefix,
+    };
+    /// English (United Kingdom): `£1,234,567.89`.
+    pub const EN_GB: FormatLocale = FormatLocale::EN_US;
+    /// English (India): `₹12,34,567.89`.
+    pub const EN_IN: FormatLocale = FormatLocale {
+        grouping: GroupingStyle::Indian,
+        ..FormatLocale::EN_US
+    };
+    /// German (Germany): `1.234.567,89 €`.
+    pub const DE_DE: FormatLocale = FormatLocale {
+        decimal_separator: ',',
+        group_separator: '.',
+        grouping: GroupingStyle::Thousands,
+        symbol_placement: SymbolPlacement::SuffixSpaced,
+    };
+    /// Spanish (Spain): `1.234.567,89 €`.
+    pub const ES_ES: FormatLocale = FormatLocale::DE_DE;
+    /// Italian (Italy): `1.234.567,89 €`.
+    pub const IT_IT: FormatLocale = FormatLocale::DE_DE;
+    /// French (France): `1 234 567,89 €`, grouped with a narrow no-break space.
+    pub const FR_FR: FormatLocale = FormatLocale {
+        group_separator: '\u{202f}',
+        ..FormatLocale::DE_DE
+    };
+    /// German (Switzerland): `CHF 1’234’567.89`.
+    pub const DE_CH: FormatLocale = FormatLocale {
+        decimal_separator: '.',
+        group_separator: '’',
+        grouping: GroupingStyle::Thousands,
+        symbol_placement: SymbolPlacement::PrefixSpaced,
+    };
+    /// Portuguese (Brazil): `R$ 1.234.567,89`.
+    pub const PT_BR: FormatLocale = FormatLocale {
+        symbol_placement: SymbolPlacement::PrefixSpaced,
+        ..FormatLocale::DE_DE
+    };
+    /// Japanese (Japan): `¥1,234,567`.
+    pub const JA_JP: FormatLocale = FormatLocale::EN_US;
+}
+
+/// Inserts `separator` between the groups of `style`, counting from the right.
+#[cfg(feature = "alloc")]
+pub(crate) fn group_digits(digits: &str, separator: char, style: GroupingStyle) -> String {
+    let length = digits.len();
+    let starts_group = |index: usize| {
+        let remaining = length.saturating_sub(index);
+        match style {
+            GroupingStyle::None => false,
+            GroupingStyle::Thousands => remaining.is_multiple_of(3),
+            GroupingStyle::Indian => remaining == 3 || (remaining > 3 && remaining % 2 == 1),
+        }
+    };
+    let mut grouped = String::with_capacity(length.saturating_add(length / 3));
+    // The digits are ASCII, so their byte offsets count them.
+    for (index, digit) in digits.char_indices() {
+        if index > 0 && starts_group(index) {
+            grouped.push(separator);
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_digits() {
+        assert_eq!(
+            group_digits("1234567", ',', GroupingStyle::Thousands),
+            "1,234,567"
+        );
+        assert_eq!(group_digits("123", ',', GroupingStyle::Thousands), "123");
+        assert_eq!(
+            group_digits("1234567", ',', GroupingStyle::Indian),
+            "12,34,567"
+        );
+        assert_eq!(
+            group_digits("123456789", ',', GroupingStyle::Indian),
+            "12,34,56,789"
+        );
+        assert_eq!(group_digits("1234", ',', GroupingStyle::Indian), "1,234");
+        assert_eq!(group_digits("1234567", ',', GroupingStyle::None), "1234567");
+    }
+}
@@ -0,0 +1,112 @@
+use core::cmp::Ordering;
+
+use crate::core::CompareDecimals;
+
+/// A trait for picking the smaller or larger of values with different numbers of decimals.
+///
+/// The values are compared with [`CompareDecimals`], and the winning value is returned unchanged,
+/// together with its own number of decimals.
+pub trait MinMaxDecimals: CompareDecimals + Sized {
+    /// Returns the smaller of two values with decimals, or `self` if both are equal.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The first value.
+    /// * `other` - The second value.
+    /// * `self_decimals` - The number of decimals in the first value.
+    /// * `other_decimals` - The number of decimals in the second value.
+    ///
+    /// # Returns
+    ///
+    /// The smaller value and its number of decimals.
+    fn min_decimals(self, other: Self, self_decimals: u32, other_decimals: u32) -> (Self, u32) {
+        match self.cmp_decimals(&other, self_decimals, other_decimals) {
+            Ordering::Greater => (other, other_decimals),
+            _ => (self, self_decimals),
+        }
+    }
+
+    /// Returns the larger of two values with decimals, or `other` if both are equal.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The first value.
+    /// * `other` - The second value.
+    /// * `self_decimals` - The number of decimals in the first value.
+    /// * `other_decimals` - The number of decimals in the second value.
+    ///
+    /// # Returns
+    ///
+    /// The larger value and its number of decimals.
+    fn max_decimals(self, other: Self, self_decimals: u32, other_decimals: u32) -> (Self, u32) {
+        match self.cmp_decimals(&other, self_decimals, other_decimals) {
+            Ordering::Greater => (self, self_decimals),
+            _ => (other, other_decimals),
+        }
+    }
+
+    /// Restricts a value with decimals to the band between `min` and `max`.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The value to restrict.
+    /// * `decimals` - The number of decimals in the value.
+    /// * `min` - The lower bound and its number of decimals.
+    /// * `max` - The upper bound and its number of decimals.
+    ///
+    /// # Returns
+    ///
+    /// `min` if the value is smaller than it, `max` if the value is greater than it, or the value
+    /// itself otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is greater than `max`, like [`Ord::clamp`].
+    fn clamp_decimals(self, decimals: u32, min: (Self, u32), max: (Self, u32)) -> (Self, u32) {
+        assert!(
+            min.0.le_decimals(&max.0, min.1, max.1),
+            "clamp_decimals called with min greater than max"
+        );
+        if self.lt_decimals(&min.0, decimals, min.1) {
+            min
+        } else if self.gt_decimals(&max.0, decimals, max.1) {
+            max
+        } else {
+            (self, decimals)
+        }
+    }
+}
+
+// Blanket implementation of the MinMaxDecimals trait for all types supporting scale-aware comparison
+impl<T: CompareDecimals> MinMaxDecimals for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_min_max_decimals() {
+        assert_eq!(1_5u64.min_decimals(1_49, 1, 2), (1_49, 2));
+        assert_eq!(1_5u64.max_decimals(1_49, 1, 2), (1_5, 1));
+        assert_eq!((-2i32).min_decimals(-1_999, 0, 3), (-2, 0));
+
+        // Equal values keep the order of `std::cmp::min` and `std::cmp::max`.
+        assert_eq!(1_0u64.min_decimals(1_00, 1, 2), (1_0, 1));
+        assert_eq!(1_0u64.max_decimals(1_00, 1, 2), (1_00, 2));
+    }
+
+    #[test]
+    fn test_clamp_decimals() {
+        let band = ((9_50u64, 2), (10_5u64, 1));
+
+        assert_eq!(9_4999u64.clamp_decimals(4, band.0, band.1), (9_50, 2));
+        assert_eq!(10_0000u64.clamp_decimals(4, band.0, band.1), (10_0000, 4));
+        assert_eq!(11u64.clamp_decimals(0, band.0, band.1), (10_5, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "min greater than max")]
+    fn test_clamp_decimals_inverted_band() {
+        1u64.clamp_decimals(0, (2, 0), (1_99, 2));
+    }
+}
@@ -1,5 +1,27 @@
+pub mod compare_decimals;
+#[cfg(feature = "alloc")]
+pub mod decimal_format;
+pub mod format_locale;
+pub mod min_max_decimals;
+pub mod normalize_decimals;
+#[cfg(feature = "alloc")]
+pub(crate) mod notation;
+#[cfg(feature = "alloc")]
 pub mod pad_to_width;
+#[cfg(feature = "alloc")]
+pub mod receipt;
+#[cfg(feature = "alloc")]
 pub mod to_string_decimals;
 
+pub use compare_decimals::*;
+#[cfg(feature = "alloc")]
+pub use decimal_format::*;
+pub use format_locale::*;
+pub use min_max_decimals::*;
+pub use normalize_decimals::*;
+#[cfg(feature = "alloc")]
 pub use pad_to_width::*;
+#[cfg(feature = "alloc")]
+pub use receipt::*;
+#[cfg(feature = "alloc")]
 pub use to_string_decimals::*;
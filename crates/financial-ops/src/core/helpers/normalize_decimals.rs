@@ -0,0 +1,59 @@
+use crate::core::{CheckedDiv, CheckedRem, PowerOfTen, Zero};
+
+/// A trait for bringing a value with decimals into its canonical form.
+pub trait NormalizeDecimals {
+    /// Removes trailing zero digits from the value, reducing the number of decimals accordingly.
+    ///
+    /// Operations like `multiply_decimals` add up the decimals of both operands, so results tend to
+    /// carry more decimals than needed. Normalizing them allows storing and comparing values in a
+    /// canonical form. Zero is normalized to zero decimals.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The value to normalize.
+    /// * `decimals` - The number of decimals in the value.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the normalized value and its number of decimals.
+    fn normalize_decimals(self, decimals: u32) -> (Self, u32)
+    where
+        Self: Sized;
+}
+
+// Blanket implementation of the NormalizeDecimals trait for all types supporting checked division
+impl<T> NormalizeDecimals for T
+where
+    T: CheckedDiv + CheckedRem + PowerOfTen + Zero,
+{
+    fn normalize_decimals(self, decimals: u32) -> (Self, u32) {
+        let Some(ten) = T::checked_power_of_ten(1) else {
+            return (self, decimals);
+        };
+        let mut value = self;
+        let mut decimals = decimals;
+        while decimals > 0 && value.checked_rem(&ten).is_some_and(|digit| digit.is_zero()) {
+            match value.checked_div(&ten) {
+                Some(quotient) => value = quotient,
+                None => break,
+            }
+            decimals -= 1;
+        }
+        (value, decimals)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_decimals() {
+        assert_eq!(6_000000u64.normalize_decimals(6), (6, 0));
+        assert_eq!(5_555250u64.normalize_decimals(6), (5_55525, 5));
+        assert_eq!(1_05u32.normalize_decimals(2), (1_05, 2));
+        assert_eq!((-1_500i64).normalize_decimals(3), (-1_5, 1));
+        assert_eq!(0u8.normalize_decimals(4), (0, 0));
+        assert_eq!(100u16.normalize_decimals(0), (100, 0));
+    }
+}
@@ -0,0 +1,97 @@
+use alloc::{format, string::String, vec, vec::Vec};
+
+/// Formats a raw integer string with `decimals` decimals as a mantissa and a power of ten.
+///
+/// The mantissa keeps `precision` fractional digits, rounding half away from zero. With
+/// `engineering`, the exponent is a multiple of three and the mantissa has one to three integer
+/// digits.
+pub(crate) fn to_string_with_exponent(
+    raw: &str,
+    decimals: u32,
+    precision: usize,
+    engineering: bool,
+) -> String {
+    let (sign, digits) = match raw.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", raw),
+    };
+    let digits: Vec<u8> = digits
+        .trim_start_matches('0')
+        .bytes()
+        .map(|digit| digit.saturating_sub(b'0'))
+        .collect();
+    let (mantissa, integer_digits, exponent) = if digits.is_empty() {
+        (vec![0; precision.saturating_add(1)], 1, 0)
+    } else {
+        round_to_exponent(digits, -i64::from(decimals), precision, engineering)
+    };
+    let to_string = |digits: &[u8]| -> String {
+        digits
+            .iter()
+            .map(|&digit| char::from(b'0'.saturating_add(digit)))
+            .collect()
+    };
+    let (integer, fraction) = mantissa
+        .split_at_checked(integer_digits)
+        .unwrap_or((&mantissa, &[]));
+    if fraction.is_empty() {
+        format!("{}{}e{}", sign, to_string(integer), exponent)
+    } else {
+        format!(
+            "{}{}.{}e{}",
+            sign,
+            to_string(integer),
+            to_string(fraction),
+            exponent
+        )
+    }
+}
+
+/// Rounds `digits * 10^scale_exponent` to a mantissa, returning its digits, how many of them are
+/// integer digits and the exponent.
+fn round_to_exponent(
+    digits: Vec<u8>,
+    scale_exponent: i64,
+    precision: usize,
+    engineering: bool,
+) -> (Vec<u8>, usize, i64) {
+    let exponent = (digits.len() as i64)
+        .saturating_sub(1)
+        .saturating_add(scale_exponent);
+    let shown_exponent = if engineering {
+        exponent.div_euclid(3).saturating_mul(3)
+    } else {
+        exponent
+    };
+    let integer_digits = exponent.saturating_sub(shown_exponent).saturating_add(1) as usize;
+    let significant = integer_digits.saturating_add(precision);
+    if digits.len() <= significant {
+        let mut digits = digits;
+        digits.resize(significant, 0);
+        return (digits, integer_digits, shown_exponent);
+    }
+    let (kept, dropped) = digits
+        .split_at_checked(significant)
+        .unwrap_or((&digits, &[]));
+    let mut kept = kept.to_vec();
+    if dropped.first().is_some_and(|&digit| digit >= 5) {
+        // Trailing nines become zeros until a digit can be incremented.
+        let carried = kept.iter_mut().rev().all(|digit| {
+            let nine = *digit == 9;
+            *digit = if nine { 0 } else { digit.saturating_add(1) };
+            nine
+        });
+        if carried {
+            // All nines round up to the next power of ten, which may move the exponent.
+            kept.insert(0, 1);
+            let dropped = dropped.len() as i64;
+            return round_to_exponent(
+                kept,
+                scale_exponent.saturating_add(dropped),
+                precision,
+                engineering,
+            );
+        }
+    }
+    (kept, integer_digits, shown_exponent)
+}
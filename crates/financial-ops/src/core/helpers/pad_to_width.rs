@@ -1,6 +1,9 @@
+use alloc::string::String;
+
 /// A trait for padding a string to a specified width.
 pub trait PadToWidth {
-    /// Pads the string to the specified width with the specified padding character.
+    /// Pads the string to the specified width, in characters, with the specified padding
+    /// character.
     ///
     /// # Arguments
     ///
@@ -16,7 +19,8 @@ pub trait PadToWidth {
 impl PadToWidth for String {
     fn pad_to_width(&self, width: usize, pad_char: char) -> String {
         let mut string = self.clone();
-        while string.len() < width {
+        // Counting characters rather than bytes keeps symbols like `€` aligned.
+        for _ in string.chars().count()..width {
             string.insert(0, pad_char);
         }
         string
@@ -40,5 +44,8 @@ mod tests {
 
         let string = String::from("Programming");
         assert_eq!(string.pad_to_width(15, '+'), "++++Programming");
+
+        let string = String::from("€5");
+        assert_eq!(string.pad_to_width(4, ' '), "  €5");
     }
 }
@@ -0,0 +1,177 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::core::{
+    CheckedDecimalOperations, Decimal, DecimalFormat, DecimalOperationError, PadToWidth,
+    RescaleDecimals, RoundingMode, ToStringDecimals, Zero,
+};
+
+/// A plain-text statement of labeled amounts, rendered in two aligned columns for terminal
+/// receipts and text exports.
+///
+/// Every amount is rounded to the decimals of the [`DecimalFormat`] and right-aligned to the
+/// width of the statement. The total, when a label is set for it, is the sum of the rounded
+/// amounts, so it always matches the rows printed above it.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{Decimal, DecimalFormat, Receipt};
+///
+/// let receipt = Receipt::new(20, DecimalFormat::new(2).symbol("$"))
+///     .row("Coffee", Decimal::new(3_5i64, 1))
+///     .row("Bagel", Decimal::new(2_255, 3))
+///     .total("Total");
+///
+/// assert_eq!(
+///     receipt.render().unwrap(),
+///     "Coffee         $3.50\n\
+///      Bagel          $2.26\n\
+///      --------------------\n\
+///      Total          $5.76\n"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Receipt<T = i128> {
+    rows: Vec<(String, Decimal<T>)>,
+    total: Option<String>,
+    width: usize,
+    format: DecimalFormat,
+    rounding: RoundingMode,
+}
+
+impl<T> Receipt<T> {
+    /// Creates an empty statement `width` characters wide, whose amounts are rounded half to
+    /// even and rendered with `format`.
+    pub const fn new(width: usize, format: DecimalFormat) -> Self {
+        Receipt {
+            rows: Vec::new(),
+            total: None,
+            width,
+            format,
+            rounding: RoundingMode::HalfEven,
+        }
+    }
+
+    /// Sets the rounding applied to amounts with more decimals than the format.
+    pub fn rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Adds a labeled amount after the rows already in the statement.
+    pub fn row(mut self, label: &str, amount: Decimal<T>) -> Self {
+        self.push(label, amount);
+        self
+    }
+
+    /// Adds a labeled amount after the rows already in the statement.
+    pub fn push(&mut self, label: &str, amount: Decimal<T>) {
+        self.rows.push((label.to_string(), amount));
+    }
+
+    /// Ends the statement with a separator and the sum of the rows, under `label`.
+    pub fn total(mut self, label: &str) -> Self {
+        self.total = Some(label.to_string());
+        self
+    }
+
+    /// Returns the labeled amounts of the statement, in order.
+    pub fn rows(&self) -> &[(String, Decimal<T>)] {
+        &self.rows
+    }
+}
+
+impl<T> Receipt<T>
+where
+    T: CheckedDecimalOperations + RescaleDecimals + ToStringDecimals + Zero + Copy,
+{
+    /// Renders the statement, one line per row ending with a newline.
+    ///
+    /// A label too long for the width pushes its amount right, keeping a space between them.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the rendered statement, or a `DecimalOperationError` if an
+    /// amount can't be rescaled to the decimals of the format or the total overflows.
+    pub fn render(&self) -> Result<String, DecimalOperationError> {
+        let decimals = self.format.decimals();
+        let mut rendered = String::new();
+        let mut total = T::zero();
+        for (label, amount) in &self.rows {
+            let amount = amount.try_rescale(decimals, self.rounding)?.value();
+            total = total.add_decimals_checked(amount, decimals, decimals)?.0;
+            self.render_row(&mut rendered, label, amount);
+        }
+        if let Some(label) = &self.total {
+            rendered.push_str(&String::new().pad_to_width(self.width, '-'));
+            rendered.push('\n');
+            self.render_row(&mut rendered, label, total);
+        }
+        Ok(rendered)
+    }
+
+    /// Appends a label and its amount, aligned to the right of the statement.
+    fn render_row(&self, rendered: &mut String, label: &str, amount: T) {
+        let amount = self.format.format(amount);
+        let width = self
+            .width
+            .saturating_sub(label.chars().count())
+            .max(amount.chars().count().saturating_add(1));
+        rendered.push_str(label);
+        rendered.push_str(&amount.pad_to_width(width, ' '));
+        rendered.push('\n');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_receipt_render() -> Result<(), DecimalOperationError> {
+        let format = DecimalFormat::new(2).group_separator(',').accounting();
+        let receipt = Receipt::new(24, format)
+            .row("Rent", Decimal::new(1_250i64, 0))
+            .row("Refund", Decimal::new(-40_5, 1))
+            .row("A label longer than the width", Decimal::new(1_00, 2));
+        assert_eq!(receipt.rows().len(), 3);
+        assert_eq!(
+            receipt.render()?,
+            "Rent           1,250.00 \n\
+             Refund           (40.50)\n\
+             A label longer than the width 1.00 \n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_receipt_total() -> Result<(), DecimalOperationError> {
+        let mut receipt = Receipt::new(12, DecimalFormat::new(2).symbol("€"))
+            .rounding(RoundingMode::Down)
+            .total("Total");
+        for _ in 0..3 {
+            receipt.push("Item", Decimal::new(0_339u32, 3));
+        }
+        // The total adds up the printed 0.33, not the exact 0.339.
+        assert_eq!(
+            receipt.render()?,
+            "Item   €0.33\n\
+             Item   €0.33\n\
+             Item   €0.33\n\
+             ------------\n\
+             Total  €0.99\n"
+        );
+        assert_eq!(
+            Receipt::new(10, DecimalFormat::new(0))
+                .row("Max", Decimal::new(u8::MAX, 0))
+                .row("One", Decimal::new(1, 0))
+                .total("Total")
+                .render(),
+            Err(DecimalOperationError::Overflow)
+        );
+        Ok(())
+    }
+}
@@ -1,6 +1,13 @@
-use std::fmt;
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use core::fmt;
 
-use crate::core::PadToWidth;
+use crate::core::{
+    notation::to_string_with_exponent, CheckedDiv, CheckedRem, DecimalFormat, PadToWidth,
+    PowerOfTen, Signedness, Zero,
+};
 
 /// A trait for converting a value to a string representation with a specified number of decimals.
 pub trait ToStringDecimals {
@@ -15,28 +22,108 @@ pub trait ToStringDecimals {
     ///
     /// A string representation of the value with the specified number of decimals.
     fn to_string_decimals(self, decimals: u32) -> String;
+
+    /// Converts the value to a string representation with grouped integer digits, like
+    /// `"1,234,567.89"`.
+    ///
+    /// Use [`DecimalFormat`] to define a format once and reuse it.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The value to convert.
+    /// * `decimals` - The number of decimals to include in the string representation.
+    /// * `group_separator` - The character inserted between groups of three integer digits.
+    /// * `decimal_separator` - The character between the integer and the fractional digits.
+    ///
+    /// # Returns
+    ///
+    /// A string representation of the value with the specified number of decimals.
+    fn to_string_decimals_grouped(
+        self,
+        decimals: u32,
+        group_separator: char,
+        decimal_separator: char,
+    ) -> String
+    where
+        Self: Sized,
+    {
+        DecimalFormat::new(decimals)
+            .group_separator(group_separator)
+            .decimal_separator(decimal_separator)
+            .format(self)
+    }
+
+    /// Converts the value to scientific notation with `precision` fractional digits in the
+    /// mantissa, like `"1.50e-7"`, rounding half away from zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The value to convert.
+    /// * `decimals` - The number of decimals of the value.
+    /// * `precision` - The number of fractional digits of the mantissa.
+    ///
+    /// # Returns
+    ///
+    /// A string representation of the value in scientific notation.
+    fn to_string_scientific(self, decimals: u32, precision: usize) -> String
+    where
+        Self: Sized,
+    {
+        to_string_with_exponent(&self.to_string_decimals(0), decimals, precision, false)
+    }
+
+    /// Converts the value to engineering notation, where the exponent is a multiple of three,
+    /// with `precision` fractional digits in the mantissa, like `"150.00e-9"`.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The value to convert.
+    /// * `decimals` - The number of decimals of the value.
+    /// * `precision` - The number of fractional digits of the mantissa.
+    ///
+    /// # Returns
+    ///
+    /// A string representation of the value in engineering notation.
+    fn to_string_engineering(self, decimals: u32, precision: usize) -> String
+    where
+        Self: Sized,
+    {
+        to_string_with_exponent(&self.to_string_decimals(0), decimals, precision, true)
+    }
 }
+
+/// Returns the digits of a value without its sign.
+fn magnitude_digits<T: fmt::Display>(value: T) -> String {
+    value.to_string().trim_start_matches('-').to_string()
+}
+
 impl<T> ToStringDecimals for T
 where
-    T: Copy + Into<f64> + fmt::Display,
+    T: CheckedDiv + CheckedRem + PowerOfTen + Signedness + Zero + fmt::Display,
 {
     fn to_string_decimals(self, decimals: u32) -> String {
-        let ten = 10f64;
-        let value: f64 = self.into();
-        let integer_part = (value / ten.powi(decimals as i32)) as u64;
-        let fractional_part = (value % ten.powi(decimals as i32)) as u64;
+        if decimals == 0 {
+            return self.to_string();
+        }
+        let sign = if self.is_negative() { "-" } else { "" };
+        // Values always fit below a scale factor the type can't represent.
+        let parts = T::checked_power_of_ten(decimals)
+            .and_then(|factor| Some((self.checked_div(&factor)?, self.checked_rem(&factor)?)));
+        let (integer_part, fractional_part) = parts.unwrap_or_else(|| (T::zero(), self));
+        // Both parts carry the sign of the value, so it's printed once in front of their digits.
+        // Stripping it from the strings keeps `MIN` from overflowing like `abs` would.
         format!(
-            "{}.{}",
-            integer_part,
-            fractional_part
-                .to_string()
-                .pad_to_width(decimals as usize, '0')
+            "{}{}.{}",
+            sign,
+            magnitude_digits(integer_part),
+            magnitude_digits(fractional_part).pad_to_width(decimals as usize, '0')
         )
     }
 }
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::{I256, U256};
 
     #[test]
     fn test_to_string_decimals() {
@@ -47,5 +134,97 @@ mod tests {
         // Test with zero value
         let value4: u32 = 0;
         assert_eq!(value4.to_string_decimals(5), "0.00000");
+
+        // Test without decimals
+        assert_eq!(42u8.to_string_decimals(0), "42");
+    }
+
+    #[test]
+    fn test_to_string_decimals_wide_values() {
+        // Values above 2^53 can't be represented exactly by a float
+        assert_eq!(
+            9_007_199_254_740_993u64.to_string_decimals(2),
+            "90071992547409.93"
+        );
+        assert_eq!(u64::MAX.to_string_decimals(6), "18446744073709.551615");
+        assert_eq!(
+            u128::MAX.to_string_decimals(18),
+            "340282366920938463463.374607431768211455"
+        );
+        assert_eq!(
+            i128::MAX.to_string_decimals(38),
+            "1.70141183460469231731687303715884105727"
+        );
+        assert_eq!(
+            U256::MAX.to_string_decimals(77),
+            "1.15792089237316195423570985008687907853269984665640564039457584007913129639935"
+        );
+    }
+
+    #[test]
+    fn test_to_string_decimals_negative_values() {
+        assert_eq!((-105i8).to_string_decimals(2), "-1.05");
+        assert_eq!((-5i16).to_string_decimals(2), "-0.05");
+        assert_eq!((-1_000_05i32).to_string_decimals(2), "-1000.05");
+        assert_eq!((-1_50i64).to_string_decimals(0), "-150");
+        assert_eq!((-1isize).to_string_decimals(3), "-0.001");
+        assert_eq!(i8::MIN.to_string_decimals(2), "-1.28");
+        assert_eq!(i16::MIN.to_string_decimals(1), "-3276.8");
+        assert_eq!(i32::MIN.to_string_decimals(9), "-2.147483648");
+        assert_eq!(i64::MIN.to_string_decimals(4), "-922337203685477.5808");
+        assert_eq!(
+            i128::MIN.to_string_decimals(20),
+            "-1701411834604692317.31687303715884105728"
+        );
+        assert_eq!(i8::MIN.to_string_decimals(5), "-0.00128");
+        assert_eq!(
+            I256::MIN.to_string_decimals(76),
+            "-5.7896044618658097711785492504343953926634992332820282019728792003956564819968"
+        );
+    }
+
+    #[test]
+    fn test_to_string_decimals_grouped() {
+        assert_eq!(
+            1234567_89u64.to_string_decimals_grouped(2, ',', '.'),
+            "1,234,567.89"
+        );
+        assert_eq!(
+            (-1234567_89i64).to_string_decimals_grouped(2, '.', ','),
+            "-1.234.567,89"
+        );
+        assert_eq!(999u16.to_string_decimals_grouped(0, ',', '.'), "999");
+    }
+
+    #[test]
+    fn test_to_string_scientific() {
+        // 1.5 ether and 1 wei
+        assert_eq!(
+            1_500_000_000_000_000_000u128.to_string_scientific(18, 2),
+            "1.50e0"
+        );
+        assert_eq!(1u128.to_string_scientific(18, 2), "1.00e-18");
+        assert_eq!(1234567_89u64.to_string_scientific(2, 3), "1.235e6");
+        assert_eq!((-1_50i64).to_string_scientific(2, 1), "-1.5e0");
+        assert_eq!(15u8.to_string_scientific(0, 0), "2e1");
+        assert_eq!(0u8.to_string_scientific(2, 2), "0.00e0");
+        assert_eq!(9999u32.to_string_scientific(0, 2), "1.00e4");
+        assert_eq!(U256::MAX.to_string_scientific(18, 4), "1.1579e59");
+    }
+
+    #[test]
+    fn test_to_string_engineering() {
+        assert_eq!(123456789u64.to_string_engineering(1, 3), "12.346e6");
+        assert_eq!(1u32.to_string_engineering(5, 1), "10.0e-6");
+        assert_eq!(150u64.to_string_engineering(9, 2), "150.00e-9");
+        assert_eq!(999_999u32.to_string_engineering(0, 1), "1.0e6");
+        assert_eq!((-1_000i16).to_string_engineering(0, 0), "-1e3");
+        assert_eq!(0i64.to_string_engineering(0, 1), "0.0e0");
+    }
+
+    #[test]
+    fn test_to_string_decimals_beyond_type_range() {
+        assert_eq!(255u8.to_string_decimals(5), "0.00255");
+        assert_eq!(1u64.to_string_decimals(20), "0.00000000000000000001");
     }
 }
@@ -0,0 +1,137 @@
+use crate::core::{
+    daycount::to_date, money::exchange_rate::multiply_divide_to_scale,
+    percentage::bps::bps_to_fraction, CheckedDecimalOperations, CheckedMulDiv, Date, DayCount,
+    Decimal, DecimalOperationError, InterestAmounts, PowerOfTen, RoundingMode,
+};
+
+/// Computes the interest accrued on a principal from `start` to `end` at an annual rate, counting
+/// the days with a day-count convention: `principal * rate * days / days_in_year`.
+///
+/// The dates are [`Date`]s, `(year, month, day)` tuples, or `chrono::NaiveDate`s with the `chrono`
+/// feature. The interest is computed exactly from whole numbers of days and rounded once to the
+/// decimals of the principal, so it doesn't carry the rounding of a year fraction. The product of
+/// the principal by the rate and the days is twice as wide as the type, so only an interest that
+/// doesn't fit fails.
+///
+/// # Arguments
+///
+/// * `principal` - The amount interest accrues on.
+/// * `annual_rate_bps` - The annual rate, in basis points.
+/// * `start` - The first day interest accrues on.
+/// * `end` - The day interest stops accruing, excluded.
+/// * `convention` - The day-count convention counting the days and the length of a year.
+/// * `rounding` - The rounding applied to the interest.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the interest and the total, or a `DecimalOperationError` if a
+/// date doesn't exist or `end` is before `start`, both reported as `OutOfDomain`, or if the
+/// operation fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{interest::accrued_interest, DayCount, Decimal, RoundingMode};
+///
+/// // 1,000,000 at 4.5% for 91 days of ACT/360
+/// let amounts = accrued_interest(
+///     Decimal::new(1_000_000_00i64, 2),
+///     Decimal::new(450, 0),
+///     (2024, 1, 15),
+///     (2024, 4, 15),
+///     DayCount::Act360,
+///     RoundingMode::HalfEven,
+/// )
+/// .unwrap();
+/// assert_eq!(amounts.interest, Decimal::new(11_375_00, 2));
+/// ```
+pub fn accrued_interest<T, D>(
+    principal: Decimal<T>,
+    annual_rate_bps: Decimal<T>,
+    start: D,
+    end: D,
+    convention: DayCount,
+    rounding: RoundingMode,
+) -> Result<InterestAmounts<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations + CheckedMulDiv + PowerOfTen + From<u32> + Copy,
+    D: TryInto<Date>,
+{
+    let (days, year) = convention.year_parts(to_date(start)?, to_date(end)?)?;
+    let rate = bps_to_fraction(annual_rate_bps)?.try_mul(days)?;
+    let interest = Decimal::new(
+        multiply_divide_to_scale(principal, rate, year, principal.scale(), rounding)?,
+        principal.scale(),
+    );
+    Ok(InterestAmounts {
+        interest,
+        total: principal.try_add(interest)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accrued_interest() -> Result<(), DecimalOperationError> {
+        let accrued = |convention| {
+            accrued_interest(
+                Decimal::new(10_000_00u64, 2),
+                Decimal::new(512_5, 1),
+                (2024, 2, 29),
+                (2024, 8, 31),
+                convention,
+                RoundingMode::HalfEven,
+            )
+            .map(|amounts| amounts.interest)
+        };
+        // 184 actual days in a leap year, 180 of 30/360 US and 181 of 30E/360
+        assert_eq!(accrued(DayCount::Act360)?, Decimal::new(261_94, 2));
+        assert_eq!(accrued(DayCount::Act365Fixed)?, Decimal::new(258_36, 2));
+        assert_eq!(accrued(DayCount::ActAct)?, Decimal::new(257_65, 2));
+        assert_eq!(accrued(DayCount::Thirty360Us)?, Decimal::new(256_25, 2));
+        assert_eq!(
+            accrued(DayCount::Thirty360European)?,
+            Decimal::new(257_67, 2)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_accrued_interest_widens() -> Result<(), DecimalOperationError> {
+        // The raw product of 10,000,000.000000 by 5.125% and 184 days overflows an i64.
+        let amounts = accrued_interest(
+            Decimal::new(10_000_000_000_000i64, 6),
+            Decimal::new(512_5, 1),
+            (2024, 2, 29),
+            (2024, 8, 31),
+            DayCount::Act360,
+            RoundingMode::HalfEven,
+        )?;
+        assert_eq!(amounts.interest, Decimal::new(261_944_444_444, 6));
+        Ok(())
+    }
+
+    #[test]
+    fn test_accrued_interest_invalid_dates() {
+        let accrued = |start, end| {
+            accrued_interest(
+                Decimal::new(100_00u64, 2),
+                Decimal::new(100, 0),
+                start,
+                end,
+                DayCount::Act365Fixed,
+                RoundingMode::HalfEven,
+            )
+        };
+        assert_eq!(
+            accrued((2023, 2, 29), (2023, 3, 1)),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        assert_eq!(
+            accrued((2024, 3, 1), (2024, 2, 1)),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+    }
+}
@@ -0,0 +1,525 @@
+use alloc::vec::Vec;
+
+use crate::core::{
+    depreciation::sum_of_digits, interest::compound::FACTOR_DECIMALS,
+    money::exchange_rate::multiply_to_scale, percentage::bps::bps_to_fraction,
+    CheckedDecimalOperations, CheckedDivideToScale, CheckedMulDiv, CheckedPowDecimals, Decimal,
+    DecimalOperationError, Percent, PowerOfTen, RescaleDecimals, RoundingMode, Zero,
+};
+
+/// One period of an [`Amortization`] schedule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmortizationRow<T = i128> {
+    /// The number of the period, from 1.
+    pub period: u32,
+    /// The amount paid at the end of the period.
+    pub payment: Decimal<T>,
+    /// The part of the payment that pays the interest of the period.
+    pub interest: Decimal<T>,
+    /// The part of the payment that repays the principal.
+    pub principal: Decimal<T>,
+    /// The principal left after the payment.
+    pub balance: Decimal<T>,
+}
+
+/// How the interest charged over an [`Amortization`] is allocated to its periods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum InterestMethod {
+    /// Every period is charged the interest on the balance left, at the rate of the loan.
+    #[default]
+    Actuarial,
+    /// The total interest of the actuarial schedule is precomputed and allocated by the sum of
+    /// the digits: period `k` of `n` is charged `(n - k + 1) / (n * (n + 1) / 2)` of it. Early
+    /// periods are charged more interest, so less of the principal is repaid early on.
+    RuleOf78,
+}
+
+/// A penalty charged for repaying a loan early.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PrepaymentPenalty<T = i128> {
+    /// A percentage of the balance repaid.
+    Percent(Percent<T>),
+    /// The interest the balance repaid would accrue over a number of periods, like 6 months of
+    /// interest.
+    PeriodsOfInterest(u32),
+}
+
+/// A loan repaid by level payments, optionally after interest-only periods and with a balloon
+/// payment at maturity.
+///
+/// The rate is per period, so 6% a year paid monthly is 50 basis points. Amounts keep the decimals
+/// of the principal. The interest of every period is rounded on the balance left, and the last
+/// payment repays whatever is left, so the principal parts add up to the principal exactly.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{interest::Amortization, Decimal};
+///
+/// // 200,000 over 30 years at 6% a year, paid monthly
+/// let loan = Amortization::new(Decimal::new(200_000_00i128, 2), Decimal::new(50, 0), 360);
+/// assert_eq!(loan.payment().unwrap(), Decimal::new(1_199_10, 2));
+///
+/// let schedule = loan.schedule().unwrap();
+/// assert_eq!(schedule[0].interest, Decimal::new(1_000_00, 2));
+/// assert_eq!(schedule[0].principal, Decimal::new(199_10, 2));
+/// assert_eq!(schedule[359].balance, Decimal::new(0, 2));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Amortization<T = i128> {
+    principal: Decimal<T>,
+    rate_bps: Decimal<T>,
+    periods: u32,
+    interest_only_periods: u32,
+    balloon: Option<Decimal<T>>,
+    method: InterestMethod,
+    rounding: RoundingMode,
+}
+
+impl<T> Amortization<T> {
+    /// Creates a loan of `principal` repaid over `periods` periods at `rate_bps` per period,
+    /// whose amounts are rounded half to even.
+    pub const fn new(principal: Decimal<T>, rate_bps: Decimal<T>, periods: u32) -> Self {
+        Amortization {
+            principal,
+            rate_bps,
+            periods,
+            interest_only_periods: 0,
+            balloon: None,
+            method: InterestMethod::Actuarial,
+            rounding: RoundingMode::HalfEven,
+        }
+    }
+
+    /// Pays only the interest for the first `periods` periods, then amortizes the principal over
+    /// the periods left.
+    pub fn interest_only(mut self, periods: u32) -> Self {
+        self.interest_only_periods = periods;
+        self
+    }
+
+    /// Leaves `balloon` of the principal to repay with the last payment, on top of the level
+    /// payment.
+    pub fn balloon(mut self, balloon: Decimal<T>) -> Self {
+        self.balloon = Some(balloon);
+        self
+    }
+
+    /// Sets how the interest is allocated to the periods. The payments don't depend on it.
+    pub fn interest_method(mut self, method: InterestMethod) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// Sets the rounding applied to the payment and to the interest of every period.
+    pub fn rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Returns the number of periods of the loan.
+    pub fn periods(&self) -> u32 {
+        self.periods
+    }
+
+    /// Returns the number of interest-only periods at the start of the loan.
+    pub fn interest_only_periods(&self) -> u32 {
+        self.interest_only_periods
+    }
+
+    /// Returns how the interest is allocated to the periods.
+    pub fn method(&self) -> InterestMethod {
+        self.method
+    }
+}
+
+impl<T> Amortization<T>
+where
+    T: CheckedDecimalOperations
+        + CheckedDivideToScale
+        + CheckedMulDiv
+        + CheckedPowDecimals
+        + RescaleDecimals
+        + PowerOfTen
+        + Zero
+        + From<u32>
+        + Copy,
+{
+    /// Returns the rate per period as a fraction.
+    fn rate(&self) -> Result<Decimal<T>, DecimalOperationError> {
+        bps_to_fraction(self.rate_bps)
+    }
+
+    /// Computes the level payment of the amortizing periods, `(P * f - B) * r / (f - 1)` with
+    /// `f = (1 + r)^n`, or `(P - B) / n` without interest.
+    ///
+    /// The growth factor `f` is computed with 18 decimals. With a balloon, the payment on the
+    /// principal and the share of the balloon are rounded separately.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the payment, or a `DecimalOperationError` if the loan has no
+    /// amortizing period, reported as `OutOfDomain`, or the operation fails.
+    pub fn payment(&self) -> Result<Decimal<T>, DecimalOperationError> {
+        let amortizing_periods = self
+            .periods
+            .checked_sub(self.interest_only_periods)
+            .filter(|&periods| periods > 0)
+            .ok_or(DecimalOperationError::OutOfDomain)?;
+        let scale = self.principal.scale();
+        let balloon = self.balloon.unwrap_or(Decimal::zero(scale));
+        let rate = self.rate()?;
+        if rate.is_zero() {
+            let periods = Decimal::new(T::from(amortizing_periods), 0);
+            return self.principal.try_sub(balloon)?.try_div_to_scale(
+                periods,
+                scale,
+                self.rounding,
+            );
+        }
+        let one = Decimal::one(FACTOR_DECIMALS)?;
+        let factor = one
+            .try_add(rate)?
+            .try_pow_rounded(amortizing_periods, RoundingMode::HalfEven)?;
+        let growth = factor.try_sub(one)?;
+        // The payment per unit of principal, r * f / (f - 1), and per unit of balloon, r / (f - 1).
+        let annuity = Decimal::new(
+            multiply_to_scale(rate, factor, FACTOR_DECIMALS, RoundingMode::HalfEven)?,
+            FACTOR_DECIMALS,
+        )
+        .try_div_to_scale(growth, FACTOR_DECIMALS, RoundingMode::HalfEven)?;
+        let payment = Decimal::new(
+            multiply_to_scale(self.principal, annuity, scale, self.rounding)?,
+            scale,
+        );
+        if balloon.is_zero() {
+            return Ok(payment);
+        }
+        let balloon_share = rate
+            .try_rescale(FACTOR_DECIMALS, RoundingMode::HalfEven)?
+            .try_div_to_scale(growth, FACTOR_DECIMALS, RoundingMode::HalfEven)?;
+        payment.try_sub(Decimal::new(
+            multiply_to_scale(balloon, balloon_share, scale, RoundingMode::HalfEven)?,
+            scale,
+        ))
+    }
+
+    /// Computes the schedule of the loan, one row per period.
+    ///
+    /// With [`InterestMethod::RuleOf78`], the payments are the ones of the actuarial schedule
+    /// and its total interest is reallocated, rounding the interest charged up to every period
+    /// rather than the interest of each one, so the principal parts still add up to the
+    /// principal exactly. The interest-only periods keep their interest, and only the interest of
+    /// the amortizing periods is reallocated, by the digits of the amortizing periods.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the rows, or a `DecimalOperationError` if the loan has no
+    /// amortizing period, reported as `OutOfDomain`, or the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use financial_ops::{
+    ///     interest::{Amortization, InterestMethod},
+    ///     Decimal,
+    /// };
+    ///
+    /// let loan = Amortization::new(Decimal::new(10_000_00i64, 2), Decimal::new(100, 0), 12);
+    /// let actuarial = loan.schedule().unwrap();
+    /// let rule_of_78 = loan.interest_method(InterestMethod::RuleOf78).schedule().unwrap();
+    /// // 661.86 of interest in total, 12/78 of it in the first period
+    /// assert_eq!(actuarial[0].interest, Decimal::new(100_00, 2));
+    /// assert_eq!(rule_of_78[0].interest, Decimal::new(101_82, 2));
+    /// assert_eq!(rule_of_78[0].payment, actuarial[0].payment);
+    /// assert_eq!(rule_of_78[11].balance, Decimal::new(0, 2));
+    /// ```
+    pub fn schedule(&self) -> Result<Vec<AmortizationRow<T>>, DecimalOperationError> {
+        let mut rows = self.actuarial_schedule()?;
+        if self.method == InterestMethod::RuleOf78 {
+            let scale = self.principal.scale();
+            // The interest-only periods keep their interest, the amortizing ones share the rest.
+            let skipped = self.interest_only_periods as usize;
+            let amortizing_periods = self.periods.saturating_sub(self.interest_only_periods);
+            let total_interest = rows
+                .iter()
+                .skip(skipped)
+                .try_fold(Decimal::zero(scale), |total, row| {
+                    total.try_add(row.interest)
+                })?;
+            let digits = |period: u32| sum_of_digits(period, amortizing_periods).map(T::from);
+            let all_digits = digits(amortizing_periods)?;
+            let mut charged = Decimal::zero(scale);
+            let mut balance = self.principal;
+            for row in rows.iter_mut().skip(skipped) {
+                let interest = Decimal::new(
+                    total_interest.value().checked_mul_div(
+                        digits(row.period.saturating_sub(self.interest_only_periods))?,
+                        all_digits,
+                        self.rounding,
+                    )?,
+                    scale,
+                )
+                .try_sub(charged)?;
+                charged = charged.try_add(interest)?;
+                row.interest = interest;
+                row.principal = row.payment.try_sub(interest)?;
+                balance = balance.try_sub(row.principal)?;
+                row.balance = balance;
+            }
+        }
+        Ok(rows)
+    }
+
+    /// Computes the schedule charging every period the interest on the balance left.
+    fn actuarial_schedule(&self) -> Result<Vec<AmortizationRow<T>>, DecimalOperationError> {
+        let payment = self.payment()?;
+        let rate = self.rate()?;
+        let scale = self.principal.scale();
+        let mut balance = self.principal;
+        let mut rows = Vec::with_capacity(self.periods as usize);
+        for period in 1..=self.periods {
+            let interest = Decimal::new(
+                multiply_to_scale(balance, rate, scale, self.rounding)?,
+                scale,
+            );
+            let principal = if period == self.periods {
+                balance
+            } else if period <= self.interest_only_periods {
+                Decimal::zero(scale)
+            } else {
+                payment.try_sub(interest)?
+            };
+            balance = balance.try_sub(principal)?;
+            rows.push(AmortizationRow {
+                period,
+                payment: interest.try_add(principal)?,
+                interest,
+                principal,
+                balance,
+            });
+        }
+        Ok(rows)
+    }
+
+    /// Returns the principal left after `period` payments, the amount repaid by repaying the
+    /// loan early at that point.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the balance, or a `DecimalOperationError` if `period` is
+    /// after the end of the loan, reported as `OutOfDomain`, or the schedule can't be computed.
+    pub fn balance_after(&self, period: u32) -> Result<Decimal<T>, DecimalOperationError> {
+        if period > self.periods {
+            return Err(DecimalOperationError::OutOfDomain);
+        }
+        match period.checked_sub(1) {
+            Some(index) => self
+                .schedule()?
+                .get(index as usize)
+                .map(|row| row.balance)
+                .ok_or(DecimalOperationError::OutOfDomain),
+            None => Ok(self.principal),
+        }
+    }
+
+    /// Returns the interest the schedule charges after `period` payments, which repaying the
+    /// balance at that point saves.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the interest saved, or a `DecimalOperationError` if `period`
+    /// is after the end of the loan, reported as `OutOfDomain`, or the schedule can't be
+    /// computed.
+    pub fn interest_saved(&self, period: u32) -> Result<Decimal<T>, DecimalOperationError> {
+        if period > self.periods {
+            return Err(DecimalOperationError::OutOfDomain);
+        }
+        self.schedule()?
+            .iter()
+            .skip(period as usize)
+            .try_fold(Decimal::zero(self.principal.scale()), |total, row| {
+                total.try_add(row.interest)
+            })
+    }
+
+    /// Computes the penalty for repaying the balance left after `period` payments, rounded to
+    /// the decimals of the principal.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the penalty, or a `DecimalOperationError` if `period` is
+    /// after the end of the loan, reported as `OutOfDomain`, or the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use financial_ops::{
+    ///     interest::{Amortization, PrepaymentPenalty},
+    ///     Decimal, Percent,
+    /// };
+    ///
+    /// let loan = Amortization::new(Decimal::new(10_000_00i64, 2), Decimal::new(100, 0), 12);
+    /// assert_eq!(loan.balance_after(6).unwrap(), Decimal::new(5_149_20, 2));
+    /// assert_eq!(loan.interest_saved(6).unwrap(), Decimal::new(181_72, 2));
+    ///
+    /// // 2% of the balance, or 3 periods of interest on it
+    /// let penalty = PrepaymentPenalty::Percent(Percent::new(2, 0));
+    /// assert_eq!(loan.prepayment_penalty(6, penalty).unwrap(), Decimal::new(102_98, 2));
+    /// let penalty = PrepaymentPenalty::PeriodsOfInterest(3);
+    /// assert_eq!(loan.prepayment_penalty(6, penalty).unwrap(), Decimal::new(154_48, 2));
+    /// ```
+    pub fn prepayment_penalty(
+        &self,
+        period: u32,
+        penalty: PrepaymentPenalty<T>,
+    ) -> Result<Decimal<T>, DecimalOperationError> {
+        let balance = self.balance_after(period)?;
+        let scale = balance.scale();
+        let value = match penalty {
+            PrepaymentPenalty::Percent(percent) => percent.of(balance, self.rounding)?.value(),
+            PrepaymentPenalty::PeriodsOfInterest(periods) => {
+                let rate = self.rate()?.try_mul(Decimal::new(T::from(periods), 0))?;
+                multiply_to_scale(balance, rate, scale, self.rounding)?
+            }
+        };
+        Ok(Decimal::new(value, scale))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn total<T: CheckedDecimalOperations + Zero + Copy>(
+        rows: &[AmortizationRow<T>],
+        part: impl Fn(&AmortizationRow<T>) -> Decimal<T>,
+    ) -> Result<Decimal<T>, DecimalOperationError> {
+        rows.iter()
+            .try_fold(Decimal::zero(2), |total, row| total.try_add(part(row)))
+    }
+
+    #[test]
+    fn test_amortization_schedule() -> Result<(), DecimalOperationError> {
+        let principal = Decimal::new(10_000_00i64, 2);
+        let loan = Amortization::new(principal, Decimal::new(100, 0), 12);
+        // 10,000 * 0.01 / (1 - 1.01^-12) = 888.4878...
+        assert_eq!(loan.payment()?, Decimal::new(888_49, 2));
+        let schedule = loan.schedule()?;
+        assert_eq!(schedule.len(), 12);
+        assert_eq!(schedule[0].interest, Decimal::new(100_00, 2));
+        assert_eq!(schedule[11].balance, Decimal::new(0, 2));
+        assert_eq!(total(&schedule, |row| row.principal)?, principal);
+        // The last payment absorbs the rounding of the level payment.
+        assert_eq!(schedule[11].payment, Decimal::new(888_47, 2));
+
+        let free = Amortization::new(principal, Decimal::new(0, 0), 3);
+        assert_eq!(free.payment()?, Decimal::new(3_333_33, 2));
+        assert_eq!(free.schedule()?[2].payment, Decimal::new(3_333_34, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_amortization_interest_only_and_balloon() -> Result<(), DecimalOperationError> {
+        let principal = Decimal::new(10_000_00i64, 2);
+        let rate = Decimal::new(100, 0);
+        let loan = Amortization::new(principal, rate, 12).interest_only(3);
+        // Amortized over the 9 periods left: 10,000 * 0.01 / (1 - 1.01^-9) = 1,167.4037...
+        assert_eq!(loan.payment()?, Decimal::new(1_167_40, 2));
+        let schedule = loan.schedule()?;
+        assert_eq!(schedule[2].payment, Decimal::new(100_00, 2));
+        assert_eq!(schedule[2].balance, principal);
+        assert_eq!(schedule[3].payment, Decimal::new(1_167_40, 2));
+        assert_eq!(schedule[11].balance, Decimal::new(0, 2));
+
+        // Leaving 4,000 for the end: (10,000 * 1.01^12 - 4,000) * 0.01 / (1.01^12 - 1)
+        let loan = Amortization::new(principal, rate, 12).balloon(Decimal::new(4_000_00, 2));
+        assert_eq!(loan.payment()?, Decimal::new(573_09, 2));
+        let schedule = loan.schedule()?;
+        assert_eq!(schedule[10].balance, Decimal::new(4_527_84, 2));
+        assert_eq!(schedule[11].payment, Decimal::new(4_573_12, 2));
+        assert_eq!(total(&schedule, |row| row.principal)?, principal);
+
+        assert_eq!(
+            Amortization::new(principal, rate, 12)
+                .interest_only(12)
+                .payment(),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_amortization_prepayment() -> Result<(), DecimalOperationError> {
+        let principal = Decimal::new(10_000_00i64, 2);
+        let loan = Amortization::new(principal, Decimal::new(100, 0), 12).interest_only(3);
+        assert_eq!(loan.balance_after(0)?, principal);
+        assert_eq!(loan.balance_after(3)?, principal);
+        assert_eq!(loan.balance_after(12)?, Decimal::new(0, 2));
+        assert_eq!(loan.interest_saved(12)?, Decimal::new(0, 2));
+        let total_interest = loan.interest_saved(0)?;
+        assert_eq!(
+            total_interest.try_sub(loan.interest_saved(3)?)?,
+            Decimal::new(300_00, 2)
+        );
+        assert_eq!(
+            loan.prepayment_penalty(3, PrepaymentPenalty::PeriodsOfInterest(6))?,
+            Decimal::new(600_00, 2)
+        );
+        assert_eq!(
+            loan.prepayment_penalty(3, PrepaymentPenalty::Percent(Percent::new(1_5, 1)))?,
+            Decimal::new(150_00, 2)
+        );
+        assert_eq!(
+            loan.balance_after(13),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_amortization_rule_of_78() -> Result<(), DecimalOperationError> {
+        let principal = Decimal::new(10_000_00i128, 2);
+        let loan = Amortization::new(principal, Decimal::new(100, 0), 12)
+            .interest_method(InterestMethod::RuleOf78);
+        assert_eq!(loan.method(), InterestMethod::RuleOf78);
+        let schedule = loan.schedule()?;
+        // 661.86 of interest: 12/78, 11/78, ... of it, rounded cumulatively.
+        assert_eq!(
+            total(&schedule, |row| row.interest)?,
+            Decimal::new(661_86, 2)
+        );
+        assert_eq!(schedule[1].interest, Decimal::new(93_34, 2));
+        assert_eq!(schedule[11].interest, Decimal::new(8_49, 2));
+        assert_eq!(schedule[11].payment, Decimal::new(888_47, 2));
+        assert_eq!(total(&schedule, |row| row.principal)?, principal);
+        // Repaying halfway costs more than under the actuarial method's 5,149.20.
+        assert_eq!(loan.balance_after(6)?, Decimal::new(5_152_73, 2));
+        assert_eq!(loan.interest_saved(6)?, Decimal::new(178_19, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_amortization_rule_of_78_interest_only() -> Result<(), DecimalOperationError> {
+        let principal = Decimal::new(10_000_00i64, 2);
+        let loan = Amortization::new(principal, Decimal::new(100, 0), 12)
+            .interest_only(3)
+            .interest_method(InterestMethod::RuleOf78);
+        let schedule = loan.schedule()?;
+        assert_eq!(schedule[2].interest, Decimal::new(100_00, 2));
+        assert_eq!(schedule[2].balance, principal);
+        // 506.64 of interest over the 9 amortizing periods: 9/45, 8/45, ... of it.
+        assert_eq!(schedule[3].interest, Decimal::new(101_33, 2));
+        assert_eq!(schedule[11].interest, Decimal::new(11_26, 2));
+        assert_eq!(schedule[11].balance, Decimal::new(0, 2));
+        assert_eq!(total(&schedule, |row| row.principal)?, principal);
+
+        let unsigned = Amortization::new(Decimal::new(10_000_00u64, 2), Decimal::new(100, 0), 12)
+            .interest_only(3)
+            .interest_method(InterestMethod::RuleOf78)
+            .schedule()?;
+        assert_eq!(unsigned[3].principal, Decimal::new(1_066_07, 2));
+        assert_eq!(unsigned[11].balance, Decimal::new(0, 2));
+        Ok(())
+    }
+}
@@ -0,0 +1,623 @@
+use crate::core::{
+    interest::compound::FACTOR_DECIMALS, money::exchange_rate::multiply_to_scale,
+    percentage::bps::bps_to_fraction, CheckedDecimalOperations, CheckedDivideToScale,
+    CheckedMulDiv, CheckedPowDecimals, CheckedSignDecimals, Decimal, DecimalOperationError,
+    PowerOfTen, RescaleDecimals, RoundingMode, TranscendentalDecimals, Zero,
+    TRANSCENDENTAL_MAX_DECIMALS,
+};
+
+/// The number of decimals the terms of a result carry beyond its own before it is rounded.
+pub(crate) const GUARD_DECIMALS: u32 = 6;
+
+/// When the payments of an annuity fall in each period, the `type` argument of spreadsheet
+/// functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PaymentTiming {
+    /// At the end of every period, like loan payments: `type = 0`.
+    #[default]
+    End,
+    /// At the beginning of every period, like rent: `type = 1`.
+    Beginning,
+}
+
+/// The rate of an annuity as a fraction with 18 decimals, with its growth factor over the periods
+/// and the factor a payment at the beginning of a period earns.
+struct Factors<T> {
+    rate: Decimal<T>,
+    growth: Decimal<T>,
+    timing: Decimal<T>,
+}
+
+impl<T> Factors<T>
+where
+    T: CheckedDecimalOperations + CheckedPowDecimals + RescaleDecimals + PowerOfTen + Zero + Copy,
+{
+    fn new(
+        rate_bps: Decimal<T>,
+        periods: u32,
+        timing: PaymentTiming,
+    ) -> Result<Self, DecimalOperationError> {
+        let rate =
+            bps_to_fraction(rate_bps)?.try_rescale(FACTOR_DECIMALS, RoundingMode::HalfEven)?;
+        let one = Decimal::one(FACTOR_DECIMALS)?;
+        let base = one.try_add(rate)?;
+        Ok(Factors {
+            rate,
+            growth: base.try_pow_rounded(periods, RoundingMode::HalfEven)?,
+            timing: match timing {
+                PaymentTiming::End => one,
+                PaymentTiming::Beginning => base,
+            },
+        })
+    }
+}
+
+/// Computes `-(a * a_factor + b * b_factor)` with `GUARD_DECIMALS` more decimals than `scale`,
+/// rounded once to `scale` decimals.
+fn negated_sum<T>(
+    a: Decimal<T>,
+    a_factor: Decimal<T>,
+    b: Decimal<T>,
+    b_factor: Decimal<T>,
+    scale: u32,
+    rounding: RoundingMode,
+) -> Result<Decimal<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations
+        + CheckedMulDiv
+        + CheckedSignDecimals
+        + RescaleDecimals
+        + PowerOfTen
+        + Copy,
+{
+    let decimals = scale
+        .checked_add(GUARD_DECIMALS)
+        .ok_or(DecimalOperationError::ScaleTooLarge)?;
+    let term = |amount, factor| {
+        multiply_to_scale(amount, factor, decimals, RoundingMode::HalfEven)
+            .map(|value| Decimal::new(value, decimals))
+    };
+    term(a, a_factor)?
+        .try_add(term(b, b_factor)?)?
+        .try_neg()?
+        .try_rescale(scale, rounding)
+}
+
+/// Computes the present value of an annuity, like the `PV` spreadsheet function:
+/// `-(payment * (1 + rate * type) * ((1 + rate)^periods - 1) / rate + future_value) /
+/// (1 + rate)^periods`.
+///
+/// Amounts follow the sign convention of spreadsheets, money paid out being negative, so the
+/// present value of payments received is negative. The factors are computed with 18 decimals and
+/// the result is rounded once to the decimals of the payment.
+///
+/// # Arguments
+///
+/// * `rate_bps` - The rate per period, in basis points.
+/// * `periods` - The number of payments.
+/// * `payment` - The payment made every period.
+/// * `future_value` - The balance left after the last payment.
+/// * `timing` - When the payments fall in each period.
+/// * `rounding` - The rounding applied to the present value.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the present value, or a `DecimalOperationError` if the
+/// operation fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{
+///     interest::{pv_annuity, PaymentTiming},
+///     Decimal, RoundingMode,
+/// };
+///
+/// // Receiving 500 a month for 20 years at 6% a year is worth 69,790.39 today.
+/// let value = pv_annuity(
+///     Decimal::new(50i128, 0),
+///     240,
+///     Decimal::new(500_00, 2),
+///     Decimal::new(0, 2),
+///     PaymentTiming::End,
+///     RoundingMode::HalfEven,
+/// )
+/// .unwrap();
+/// assert_eq!(value, Decimal::new(-69_790_39, 2));
+/// ```
+pub fn pv_annuity<T>(
+    rate_bps: Decimal<T>,
+    periods: u32,
+    payment: Decimal<T>,
+    future_value: Decimal<T>,
+    timing: PaymentTiming,
+    rounding: RoundingMode,
+) -> Result<Decimal<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations
+        + CheckedDivideToScale
+        + CheckedMulDiv
+        + CheckedPowDecimals
+        + CheckedSignDecimals
+        + RescaleDecimals
+        + PowerOfTen
+        + Zero
+        + From<u32>
+        + Copy,
+{
+    let scale = payment.scale();
+    if rate_bps.is_zero() {
+        return payment
+            .try_mul(Decimal::new(T::from(periods), 0))?
+            .try_add(future_value)?
+            .try_neg()?
+            .try_rescale(scale, rounding);
+    }
+    let factors = Factors::new(rate_bps, periods, timing)?;
+    let one = Decimal::one(FACTOR_DECIMALS)?;
+    // 1 / (1 + rate)^periods and (1 - 1 / (1 + rate)^periods) / rate, below one and the number
+    // of periods for positive rates.
+    let discount = one.try_div_to_scale(factors.growth, FACTOR_DECIMALS, RoundingMode::HalfEven)?;
+    let annuity = one.try_sub(discount)?.try_div_to_scale(
+        factors.rate,
+        FACTOR_DECIMALS,
+        RoundingMode::HalfEven,
+    )?;
+    let annuity = Decimal::new(
+        multiply_to_scale(
+            annuity,
+            factors.timing,
+            FACTOR_DECIMALS,
+            RoundingMode::HalfEven,
+        )?,
+        FACTOR_DECIMALS,
+    );
+    negated_sum(payment, annuity, future_value, discount, scale, rounding)
+}
+
+/// Computes the future value of an annuity, like the `FV` spreadsheet function:
+/// `-(present_value * (1 + rate)^periods + payment * (1 + rate * type) * ((1 + rate)^periods - 1)
+/// / rate)`.
+///
+/// The rate and the signs follow [`pv_annuity`]. The factors are computed with 18 decimals and
+/// the result is rounded once to the decimals of the payment.
+///
+/// # Arguments
+///
+/// * `rate_bps` - The rate per period, in basis points.
+/// * `periods` - The number of payments.
+/// * `payment` - The payment made every period.
+/// * `present_value` - The balance at the start.
+/// * `timing` - When the payments fall in each period.
+/// * `rounding` - The rounding applied to the future value.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the future value, or a `DecimalOperationError` if the operation
+/// fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{
+///     interest::{fv_annuity, PaymentTiming},
+///     Decimal, RoundingMode,
+/// };
+///
+/// // Depositing 500 and then 200 at the start of every month for 10 months at 6% a year
+/// let value = fv_annuity(
+///     Decimal::new(50i128, 0),
+///     10,
+///     Decimal::new(-200_00, 2),
+///     Decimal::new(-500_00, 2),
+///     PaymentTiming::Beginning,
+///     RoundingMode::HalfEven,
+/// )
+/// .unwrap();
+/// assert_eq!(value, Decimal::new(2_581_40, 2));
+/// ```
+pub fn fv_annuity<T>(
+    rate_bps: Decimal<T>,
+    periods: u32,
+    payment: Decimal<T>,
+    present_value: Decimal<T>,
+    timing: PaymentTiming,
+    rounding: RoundingMode,
+) -> Result<Decimal<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations
+        + CheckedDivideToScale
+        + CheckedMulDiv
+        + CheckedPowDecimals
+        + CheckedSignDecimals
+        + RescaleDecimals
+        + PowerOfTen
+        + Zero
+        + From<u32>
+        + Copy,
+{
+    let scale = payment.scale();
+    if rate_bps.is_zero() {
+        return payment
+            .try_mul(Decimal::new(T::from(periods), 0))?
+            .try_add(present_value)?
+            .try_neg()?
+            .try_rescale(scale, rounding);
+    }
+    let factors = Factors::new(rate_bps, periods, timing)?;
+    let one = Decimal::one(FACTOR_DECIMALS)?;
+    let annuity = factors.growth.try_sub(one)?.try_div_to_scale(
+        factors.rate,
+        FACTOR_DECIMALS,
+        RoundingMode::HalfEven,
+    )?;
+    let annuity = Decimal::new(
+        multiply_to_scale(
+            annuity,
+            factors.timing,
+            FACTOR_DECIMALS,
+            RoundingMode::HalfEven,
+        )?,
+        FACTOR_DECIMALS,
+    );
+    negated_sum(
+        present_value,
+        factors.growth,
+        payment,
+        annuity,
+        scale,
+        rounding,
+    )
+}
+
+/// Computes the payment of an annuity, like the `PMT` spreadsheet function:
+/// `-(present_value * (1 + rate)^periods + future_value) * rate / ((1 + rate * type) *
+/// ((1 + rate)^periods - 1))`.
+///
+/// The rate and the signs follow [`pv_annuity`], so borrowing a positive present value takes
+/// negative payments. The factors are computed with 18 decimals and the result is rounded once
+/// to the decimals of the present value.
+///
+/// # Arguments
+///
+/// * `rate_bps` - The rate per period, in basis points.
+/// * `periods` - The number of payments.
+/// * `present_value` - The balance at the start.
+/// * `future_value` - The balance left after the last payment.
+/// * `timing` - When the payments fall in each period.
+/// * `rounding` - The rounding applied to the payment.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the payment, or a `DecimalOperationError` if `periods` is zero,
+/// reported as `DivisionByZero`, or the operation fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{
+///     interest::{pmt, PaymentTiming},
+///     Decimal, RoundingMode,
+/// };
+///
+/// // Borrowing 200,000 over 30 years at 6% a year, paid monthly
+/// let payment = pmt(
+///     Decimal::new(50i128, 0),
+///     360,
+///     Decimal::new(200_000_00, 2),
+///     Decimal::new(0, 2),
+///     PaymentTiming::End,
+///     RoundingMode::HalfEven,
+/// )
+/// .unwrap();
+/// assert_eq!(payment, Decimal::new(-1_199_10, 2));
+/// ```
+pub fn pmt<T>(
+    rate_bps: Decimal<T>,
+    periods: u32,
+    present_value: Decimal<T>,
+    future_value: Decimal<T>,
+    timing: PaymentTiming,
+    rounding: RoundingMode,
+) -> Result<Decimal<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations
+        + CheckedDivideToScale
+        + CheckedMulDiv
+        + CheckedPowDecimals
+        + CheckedSignDecimals
+        + RescaleDecimals
+        + PowerOfTen
+        + Zero
+        + From<u32>
+        + Copy,
+{
+    if periods == 0 {
+        return Err(DecimalOperationError::DivisionByZero);
+    }
+    let scale = present_value.scale();
+    if rate_bps.is_zero() {
+        return present_value
+            .try_add(future_value)?
+            .try_neg()?
+            .try_div_to_scale(Decimal::new(T::from(periods), 0), scale, rounding);
+    }
+    let factors = Factors::new(rate_bps, periods, timing)?;
+    let one = Decimal::one(FACTOR_DECIMALS)?;
+    // rate / ((1 + rate * type) * ((1 + rate)^periods - 1)) pays off a future value, and times
+    // the growth factor a present value.
+    let denominator = Decimal::new(
+        multiply_to_scale(
+            factors.timing,
+            factors.growth.try_sub(one)?,
+            FACTOR_DECIMALS,
+            RoundingMode::HalfEven,
+        )?,
+        FACTOR_DECIMALS,
+    );
+    let sinking =
+        factors
+            .rate
+            .try_div_to_scale(denominator, FACTOR_DECIMALS, RoundingMode::HalfEven)?;
+    let annuity = Decimal::new(
+        multiply_to_scale(
+            sinking,
+            factors.growth,
+            FACTOR_DECIMALS,
+            RoundingMode::HalfEven,
+        )?,
+        FACTOR_DECIMALS,
+    );
+    negated_sum(
+        present_value,
+        annuity,
+        future_value,
+        sinking,
+        scale,
+        rounding,
+    )
+}
+
+/// Computes the number of periods of an annuity, like the `NPER` spreadsheet function:
+/// `ln((payment * (1 + rate * type) - future_value * rate) / (payment * (1 + rate * type) +
+/// present_value * rate)) / ln(1 + rate)`.
+///
+/// The rate and the signs follow [`pv_annuity`]. The number of periods is usually fractional,
+/// the last payment being smaller; the logarithms are computed with 30 decimals and the result
+/// is rounded once to `scale` decimals.
+///
+/// # Arguments
+///
+/// * `rate_bps` - The rate per period, in basis points.
+/// * `payment` - The payment made every period.
+/// * `present_value` - The balance at the start.
+/// * `future_value` - The balance left after the last payment.
+/// * `timing` - When the payments fall in each period.
+/// * `scale` - The number of decimals of the result.
+/// * `rounding` - The rounding applied to the result.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the number of periods, or a `DecimalOperationError` if the
+/// payment never reaches the future value, reported as `OutOfDomain` or `DivisionByZero`, or
+/// the operation fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{
+///     interest::{nper, PaymentTiming},
+///     Decimal, RoundingMode,
+/// };
+///
+/// // Saving 100 at the start of every month at 1% a month, from 1,000 up to 10,000
+/// let periods = nper(
+///     Decimal::new(100i128, 0),
+///     Decimal::new(-100_00, 2),
+///     Decimal::new(-1_000_00, 2),
+///     Decimal::new(10_000_00, 2),
+///     PaymentTiming::Beginning,
+///     4,
+///     RoundingMode::HalfEven,
+/// )
+/// .unwrap();
+/// assert_eq!(periods, Decimal::new(59_6739, 4));
+/// ```
+pub fn nper<T>(
+    rate_bps: Decimal<T>,
+    payment: Decimal<T>,
+    present_value: Decimal<T>,
+    future_value: Decimal<T>,
+    timing: PaymentTiming,
+    scale: u32,
+    rounding: RoundingMode,
+) -> Result<Decimal<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations
+        + CheckedDivideToScale
+        + CheckedPowDecimals
+        + CheckedSignDecimals
+        + RescaleDecimals
+        + TranscendentalDecimals
+        + PowerOfTen
+        + Zero
+        + Copy,
+{
+    if rate_bps.is_zero() {
+        return present_value
+            .try_add(future_value)?
+            .try_neg()?
+            .try_div_to_scale(payment, scale, rounding);
+    }
+    let factors = Factors::new(rate_bps, 0, timing)?;
+    let payment = payment.try_mul(factors.timing)?;
+    let numerator = payment.try_sub(future_value.try_mul(factors.rate)?)?;
+    let denominator = payment.try_add(present_value.try_mul(factors.rate)?)?;
+    let ratio = numerator.try_div_to_scale(
+        denominator,
+        TRANSCENDENTAL_MAX_DECIMALS,
+        RoundingMode::HalfEven,
+    )?;
+    let base = Decimal::one(FACTOR_DECIMALS)?.try_add(factors.rate)?;
+    ratio.try_ln(TRANSCENDENTAL_MAX_DECIMALS)?.try_div_to_scale(
+        base.try_ln(TRANSCENDENTAL_MAX_DECIMALS)?,
+        scale,
+        rounding,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pv_and_fv_annuity() -> Result<(), DecimalOperationError> {
+        let rate = Decimal::new(50i128, 0);
+        let zero = Decimal::new(0, 2);
+        let payment = Decimal::new(500_00, 2);
+        let pv = |rate, periods, payment, future_value, timing| {
+            pv_annuity(
+                rate,
+                periods,
+                payment,
+                future_value,
+                timing,
+                RoundingMode::HalfEven,
+            )
+        };
+        assert_eq!(
+            pv(rate, 240, payment, zero, PaymentTiming::Beginning)?,
+            Decimal::new(-70_139_34, 2)
+        );
+        // Paying 100 a month for a year at 1% and 1,000 at the end
+        assert_eq!(
+            pv(
+                Decimal::new(100, 0),
+                12,
+                Decimal::new(-100_00, 2),
+                Decimal::new(-1_000_00, 2),
+                PaymentTiming::End
+            )?,
+            Decimal::new(2_012_96, 2)
+        );
+        assert_eq!(
+            pv(
+                Decimal::new(0, 0),
+                10,
+                Decimal::new(-100_00, 2),
+                Decimal::new(50_00, 2),
+                PaymentTiming::End
+            )?,
+            Decimal::new(950_00, 2)
+        );
+
+        let fv = |periods, present_value| {
+            fv_annuity(
+                rate,
+                periods,
+                Decimal::new(-100_00, 2),
+                present_value,
+                PaymentTiming::End,
+                RoundingMode::HalfEven,
+            )
+        };
+        assert_eq!(fv(120, zero)?, Decimal::new(16_387_93, 2));
+        assert_eq!(
+            fv(12, Decimal::new(-1_000_00, 2))?,
+            Decimal::new(2_295_23, 2)
+        );
+        assert_eq!(
+            fv(0, Decimal::new(-1_000_00, 2))?,
+            Decimal::new(1_000_00, 2)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_pmt() -> Result<(), DecimalOperationError> {
+        let zero = Decimal::new(0, 2);
+        let payment = |rate, periods, present_value, future_value, timing| {
+            pmt(
+                rate,
+                periods,
+                present_value,
+                future_value,
+                timing,
+                RoundingMode::HalfEven,
+            )
+        };
+        assert_eq!(
+            payment(
+                Decimal::new(50i128, 0),
+                360,
+                Decimal::new(200_000_00, 2),
+                zero,
+                PaymentTiming::Beginning
+            )?,
+            Decimal::new(-1_193_14, 2)
+        );
+        // Saving up to 50,000 over 10 years at 9% a year
+        assert_eq!(
+            payment(
+                Decimal::new(75, 0),
+                120,
+                zero,
+                Decimal::new(50_000_00, 2),
+                PaymentTiming::End
+            )?,
+            Decimal::new(-258_38, 2)
+        );
+        assert_eq!(
+            payment(
+                Decimal::new(0, 0),
+                12,
+                Decimal::new(1_200_00, 2),
+                zero,
+                PaymentTiming::End
+            )?,
+            Decimal::new(-100_00, 2)
+        );
+        assert_eq!(
+            payment(Decimal::new(50, 0), 0, zero, zero, PaymentTiming::End),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_nper() -> Result<(), DecimalOperationError> {
+        let periods = |rate, timing| {
+            nper(
+                rate,
+                Decimal::new(-100_00i128, 2),
+                Decimal::new(-1_000_00, 2),
+                Decimal::new(10_000_00, 2),
+                timing,
+                4,
+                RoundingMode::HalfEven,
+            )
+        };
+        assert_eq!(
+            periods(Decimal::new(100, 0), PaymentTiming::End)?,
+            Decimal::new(60_0821, 4)
+        );
+        assert_eq!(
+            periods(Decimal::new(0, 0), PaymentTiming::End)?,
+            Decimal::new(90_0000, 4)
+        );
+        // A payment that doesn't cover the interest never pays off the balance.
+        assert_eq!(
+            nper(
+                Decimal::new(100i128, 0),
+                Decimal::new(-5_00, 2),
+                Decimal::new(1_000_00, 2),
+                Decimal::new(0, 2),
+                PaymentTiming::End,
+                4,
+                RoundingMode::HalfEven,
+            ),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,165 @@
+use crate::core::{
+    interest::compound::FACTOR_DECIMALS,
+    percentage::bps::{bps_to_fraction, fraction_to_bps},
+    CheckedDecimalOperations, CheckedDivideToScale, CheckedPowDecimals, Decimal,
+    DecimalOperationError, PowerOfTen, RescaleDecimals, RoundingMode, TranscendentalDecimals,
+    TRANSCENDENTAL_MAX_DECIMALS,
+};
+
+/// Converts an annual percentage rate, compounded `compounds_per_year` times a year, into the
+/// annual percentage yield it earns: `(1 + apr / n)^n - 1`.
+///
+/// The yield is computed with 18 decimals and rounded once to `scale` decimals.
+///
+/// # Arguments
+///
+/// * `rate` - The annual percentage rate, in basis points.
+/// * `compounds_per_year` - The number of times interest is compounded in a year.
+/// * `scale` - The number of decimals of the yield.
+/// * `rounding` - The rounding applied to the yield.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the yield, or a `DecimalOperationError` if `compounds_per_year`
+/// is zero or the operation fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{interest::apr_to_apy, Decimal, RoundingMode};
+///
+/// // 5% compounded monthly yields 5.1162%
+/// let apy = apr_to_apy(Decimal::new(500i64, 0), 12, 2, RoundingMode::HalfEven).unwrap();
+/// assert_eq!(apy, Decimal::new(511_62, 2));
+/// ```
+pub fn apr_to_apy<T>(
+    rate: Decimal<T>,
+    compounds_per_year: u32,
+    scale: u32,
+    rounding: RoundingMode,
+) -> Result<Decimal<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations
+        + CheckedDivideToScale
+        + CheckedPowDecimals
+        + RescaleDecimals
+        + PowerOfTen
+        + From<u32>
+        + Copy,
+{
+    let one = Decimal::one(FACTOR_DECIMALS)?;
+    let periodic_rate = bps_to_fraction(rate)?.try_div_to_scale(
+        Decimal::new(T::from(compounds_per_year), 0),
+        FACTOR_DECIMALS,
+        RoundingMode::HalfEven,
+    )?;
+    let apy = one
+        .try_add(periodic_rate)?
+        .try_pow_rounded(compounds_per_year, RoundingMode::HalfEven)?
+        .try_sub(one)?;
+    fraction_to_bps(apy, scale, rounding)
+}
+
+/// Converts an annual percentage yield into the annual percentage rate that earns it when
+/// compounded `compounds_per_year` times a year: `n * ((1 + apy)^(1 / n) - 1)`, the inverse of
+/// [`apr_to_apy`].
+///
+/// The root is computed as `exp(ln(1 + apy) / n)` with 30 decimals, see
+/// [`TranscendentalDecimals`], and the rate is rounded once to `scale` decimals.
+///
+/// # Arguments
+///
+/// * `rate` - The annual percentage yield, in basis points.
+/// * `compounds_per_year` - The number of times interest is compounded in a year.
+/// * `scale` - The number of decimals of the rate.
+/// * `rounding` - The rounding applied to the rate.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the rate, or a `DecimalOperationError` if `compounds_per_year`
+/// is zero, the yield is -100% or lower, or the operation fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{interest::apy_to_apr, Decimal, RoundingMode};
+///
+/// // A 5% yield takes a 4.8889% rate compounded monthly
+/// let apr = apy_to_apr(Decimal::new(500i128, 0), 12, 2, RoundingMode::HalfEven).unwrap();
+/// assert_eq!(apr, Decimal::new(488_89, 2));
+/// ```
+pub fn apy_to_apr<T>(
+    rate: Decimal<T>,
+    compounds_per_year: u32,
+    scale: u32,
+    rounding: RoundingMode,
+) -> Result<Decimal<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations
+        + CheckedDivideToScale
+        + RescaleDecimals
+        + TranscendentalDecimals
+        + PowerOfTen
+        + From<u32>
+        + Copy,
+{
+    let compounds = Decimal::new(T::from(compounds_per_year), 0);
+    let one = Decimal::one(TRANSCENDENTAL_MAX_DECIMALS)?;
+    let apr = one
+        .try_add(bps_to_fraction(rate)?)?
+        .try_ln(TRANSCENDENTAL_MAX_DECIMALS)?
+        .try_div_to_scale(
+            compounds,
+            TRANSCENDENTAL_MAX_DECIMALS,
+            RoundingMode::HalfEven,
+        )?
+        .try_exp(TRANSCENDENTAL_MAX_DECIMALS)?
+        .try_sub(one)?
+        .try_mul(compounds)?;
+    fraction_to_bps(apr, scale, rounding)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apr_to_apy() -> Result<(), DecimalOperationError> {
+        // (1 + 0.1999 / 365)^365 - 1 = 0.22121379...
+        assert_eq!(
+            apr_to_apy(Decimal::new(1_999u64, 0), 365, 4, RoundingMode::HalfEven),
+            Ok(Decimal::new(2_212_1380, 4))
+        );
+        // (1 + 0.0825 / 4)^4 - 1 = 0.0850876194...
+        assert_eq!(
+            apr_to_apy(Decimal::new(825i64, 0), 4, 0, RoundingMode::Down)?,
+            Decimal::new(850, 0)
+        );
+        assert_eq!(
+            apr_to_apy(Decimal::new(825i64, 0), 1, 2, RoundingMode::HalfEven)?,
+            Decimal::new(825_00, 2)
+        );
+        assert_eq!(
+            apr_to_apy(Decimal::new(825i64, 0), 0, 2, RoundingMode::HalfEven),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_apy_to_apr() -> Result<(), DecimalOperationError> {
+        // 365 * ((1 + 0.2212)^(1 / 365) - 1) = 0.19988869...
+        assert_eq!(
+            apy_to_apr(Decimal::new(2_212i128, 0), 365, 4, RoundingMode::HalfEven)?,
+            Decimal::new(1_998_8870, 4)
+        );
+        let apr = Decimal::new(1_234_56i128, 2);
+        let apy = apr_to_apy(apr, 12, 8, RoundingMode::HalfEven)?;
+        assert_eq!(apy_to_apr(apy, 12, 2, RoundingMode::HalfEven)?, apr);
+        assert_eq!(
+            apy_to_apr(Decimal::new(-10_000i128, 0), 12, 2, RoundingMode::HalfEven),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        Ok(())
+    }
+}
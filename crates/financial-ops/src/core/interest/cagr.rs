@@ -0,0 +1,137 @@
+use crate::core::{
+    percentage::bps::fraction_to_bps, CheckedDecimalOperations, CheckedDivideToScale, Decimal,
+    DecimalOperationError, PowerOfTen, RescaleDecimals, RoundingMode, TranscendentalDecimals, Zero,
+    TRANSCENDENTAL_MAX_DECIMALS,
+};
+
+/// Computes the compound annual growth rate from a beginning to an ending value:
+/// `(end_value / begin_value)^(1 / periods) - 1`.
+///
+/// `periods` is the number of years, or of any other period, and may be fractional. The power
+/// goes through the logarithm and exponential of [`TranscendentalDecimals`] with 30 decimals, and
+/// the rate is rounded once to a number of basis points with `scale` decimals. An ending value
+/// of zero is a rate of `-100%`.
+///
+/// # Arguments
+///
+/// * `begin_value` - The value at the start, which must be positive.
+/// * `end_value` - The value at the end, which must not be negative.
+/// * `periods` - The number of periods between the two values.
+/// * `scale` - The number of decimals of the rate, in basis points.
+/// * `rounding` - The rounding applied to the rate.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the growth rate per period in basis points, or a
+/// `DecimalOperationError` if a value is negative, reported as `OutOfDomain`, `begin_value` or
+/// `periods` is zero, reported as `DivisionByZero`, or the operation fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{interest::cagr, Decimal, RoundingMode};
+///
+/// // From 10,000 to 16,105.10 in five years is 10% a year
+/// let rate = cagr(
+///     Decimal::new(10_000_00i128, 2),
+///     Decimal::new(16_105_10, 2),
+///     Decimal::new(5, 0),
+///     2,
+///     RoundingMode::HalfEven,
+/// )
+/// .unwrap();
+/// assert_eq!(rate, Decimal::new(1_000_00, 2));
+/// ```
+pub fn cagr<T>(
+    begin_value: Decimal<T>,
+    end_value: Decimal<T>,
+    periods: Decimal<T>,
+    scale: u32,
+    rounding: RoundingMode,
+) -> Result<Decimal<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations
+        + CheckedDivideToScale
+        + RescaleDecimals
+        + TranscendentalDecimals
+        + PowerOfTen
+        + Zero
+        + Copy,
+{
+    if periods.is_zero() {
+        return Err(DecimalOperationError::DivisionByZero);
+    }
+    let one = Decimal::one(TRANSCENDENTAL_MAX_DECIMALS)?;
+    let growth = if end_value.is_zero() {
+        Decimal::zero(TRANSCENDENTAL_MAX_DECIMALS)
+    } else {
+        end_value
+            .try_div_to_scale(
+                begin_value,
+                TRANSCENDENTAL_MAX_DECIMALS,
+                RoundingMode::HalfEven,
+            )?
+            .try_ln(TRANSCENDENTAL_MAX_DECIMALS)?
+            .try_div_to_scale(periods, TRANSCENDENTAL_MAX_DECIMALS, RoundingMode::HalfEven)?
+            .try_exp(TRANSCENDENTAL_MAX_DECIMALS)?
+    };
+    fraction_to_bps(growth.try_sub(one)?, scale, rounding)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cagr() -> Result<(), DecimalOperationError> {
+        let rate = |begin, end, periods| {
+            cagr(
+                Decimal::new(begin, 0),
+                Decimal::new(end, 0),
+                periods,
+                4,
+                RoundingMode::HalfEven,
+            )
+        };
+        // 2^(1/10) - 1 = 7.1773%
+        assert_eq!(
+            rate(1_000i128, 2_000, Decimal::new(10, 0))?,
+            Decimal::new(717_7346, 4)
+        );
+        // Over two and a half years: 1.5^0.4 - 1 = 17.6079%
+        assert_eq!(
+            rate(100, 150, Decimal::new(2_5, 1))?,
+            Decimal::new(1_760_7902, 4)
+        );
+        // Halving in a year is -50%, and losing everything -100%.
+        assert_eq!(
+            rate(100, 50, Decimal::new(1, 0))?,
+            Decimal::new(-5_000_0000, 4)
+        );
+        assert_eq!(
+            rate(100, 0, Decimal::new(3, 0))?,
+            Decimal::new(-10_000_0000, 4)
+        );
+        assert_eq!(rate(100, 100, Decimal::new(7, 0))?, Decimal::new(0, 4));
+        Ok(())
+    }
+
+    #[test]
+    fn test_cagr_errors() {
+        let rate = |begin, end, periods| {
+            cagr(
+                Decimal::new(begin, 0),
+                Decimal::new(end, 0),
+                Decimal::new(periods, 0),
+                2,
+                RoundingMode::HalfEven,
+            )
+        };
+        assert_eq!(
+            rate(100i128, 200, 0),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+        assert_eq!(rate(100, -200, 1), Err(DecimalOperationError::OutOfDomain));
+        assert_eq!(rate(-100, 200, 1), Err(DecimalOperationError::OutOfDomain));
+    }
+}
@@ -0,0 +1,186 @@
+use crate::core::{
+    money::exchange_rate::multiply_to_scale, percentage::bps::bps_to_fraction,
+    CheckedDecimalOperations, CheckedDivideToScale, CheckedMulDiv, CheckedPowDecimals, Decimal,
+    DecimalOperationError, InterestAmounts, PowerOfTen, RescaleDecimals, RoundingMode,
+};
+
+/// The number of decimals the growth factor of a principal is computed with.
+pub(crate) const FACTOR_DECIMALS: u32 = 18;
+
+/// How often accrued interest is added to the principal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CompoundingFrequency {
+    /// 365 times a year.
+    Daily,
+    /// 12 times a year.
+    Monthly,
+    /// 4 times a year.
+    Quarterly,
+    /// Once a year.
+    Annually,
+}
+
+impl CompoundingFrequency {
+    /// Returns the number of compounding periods in a year.
+    pub const fn periods_per_year(self) -> u32 {
+        match self {
+            CompoundingFrequency::Daily => 365,
+            CompoundingFrequency::Monthly => 12,
+            CompoundingFrequency::Quarterly => 4,
+            CompoundingFrequency::Annually => 1,
+        }
+    }
+}
+
+/// Computes the compound interest of a principal: `principal * (1 + rate / n)^periods`, with `n`
+/// compounding periods a year.
+///
+/// `periods` counts periods of the frequency, so 24 monthly periods are two years. The growth
+/// factor is computed with 18 decimals, so it must fit the type with 18 decimals, like factors
+/// below `9.22` on `i64`, and the ending balance is rounded once to the decimals of the
+/// principal. Continuous compounding, which needs the exponential of
+/// [`TranscendentalDecimals`](crate::core::TranscendentalDecimals), is
+/// [`continuous_compound`](crate::core::continuous_compound).
+///
+/// # Arguments
+///
+/// * `principal` - The amount interest accrues on.
+/// * `annual_rate_bps` - The annual rate, in basis points.
+/// * `frequency` - How often interest is added to the principal.
+/// * `periods` - The number of periods interest accrues for.
+/// * `rounding` - The rounding applied to the ending balance.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the accrued interest and the ending balance, or a
+/// `DecimalOperationError` if the operation fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{
+///     interest::{compound, CompoundingFrequency},
+///     Decimal, RoundingMode,
+/// };
+///
+/// // 10,000 at 5% compounded monthly for a year: 10,000 * (1 + 0.05 / 12)^12
+/// let amounts = compound(
+///     Decimal::new(10_000_00i128, 2),
+///     Decimal::new(500, 0),
+///     CompoundingFrequency::Monthly,
+///     12,
+///     RoundingMode::HalfEven,
+/// )
+/// .unwrap();
+/// assert_eq!(amounts.interest, Decimal::new(511_62, 2));
+/// assert_eq!(amounts.total, Decimal::new(10_511_62, 2));
+/// ```
+pub fn compound<T>(
+    principal: Decimal<T>,
+    annual_rate_bps: Decimal<T>,
+    frequency: CompoundingFrequency,
+    periods: u32,
+    rounding: RoundingMode,
+) -> Result<InterestAmounts<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations
+        + CheckedDivideToScale
+        + CheckedMulDiv
+        + CheckedPowDecimals
+        + RescaleDecimals
+        + PowerOfTen
+        + From<u32>
+        + Copy,
+{
+    let periodic_rate = bps_to_fraction(annual_rate_bps)?.try_div_to_scale(
+        Decimal::new(T::from(frequency.periods_per_year()), 0),
+        FACTOR_DECIMALS,
+        RoundingMode::HalfEven,
+    )?;
+    let factor = Decimal::one(FACTOR_DECIMALS)?
+        .try_add(periodic_rate)?
+        .try_pow_rounded(periods, RoundingMode::HalfEven)?;
+    let total = Decimal::new(
+        multiply_to_scale(principal, factor, principal.scale(), rounding)?,
+        principal.scale(),
+    );
+    Ok(InterestAmounts {
+        interest: total.try_sub(principal)?,
+        total,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compound_frequencies() -> Result<(), DecimalOperationError> {
+        let principal = Decimal::new(10_000_00i128, 2);
+        let rate = Decimal::new(500, 0);
+        let total = |frequency, periods| {
+            compound(principal, rate, frequency, periods, RoundingMode::HalfEven)
+                .map(|amounts| amounts.total)
+        };
+        // (1 + 0.05 / 365)^365 = 1.051267496...
+        assert_eq!(
+            total(CompoundingFrequency::Daily, 365)?,
+            Decimal::new(10_512_67, 2)
+        );
+        // 1.0125^4 = 1.050945336...
+        assert_eq!(
+            total(CompoundingFrequency::Quarterly, 4)?,
+            Decimal::new(10_509_45, 2)
+        );
+        assert_eq!(
+            total(CompoundingFrequency::Annually, 2)?,
+            Decimal::new(11_025_00, 2)
+        );
+        assert_eq!(total(CompoundingFrequency::Monthly, 0)?, principal);
+        Ok(())
+    }
+
+    #[test]
+    fn test_compound_rounding_and_rates() -> Result<(), DecimalOperationError> {
+        // 100 at 12.5 bps a year for three years: 1.00125^3 = 1.003754689...
+        let amounts = compound(
+            Decimal::new(100_00u128, 2),
+            Decimal::new(12_5, 1),
+            CompoundingFrequency::Annually,
+            3,
+            RoundingMode::Up,
+        )?;
+        assert_eq!(amounts.interest, Decimal::new(0_38, 2));
+        assert_eq!(amounts.total, Decimal::new(100_38, 2));
+
+        // A negative rate shrinks the principal: 1,000 * 0.99^2 = 980.1
+        let amounts = compound(
+            Decimal::new(1_000i128, 0),
+            Decimal::new(-100, 0),
+            CompoundingFrequency::Annually,
+            2,
+            RoundingMode::HalfEven,
+        )?;
+        assert_eq!(amounts.interest, Decimal::new(-20, 0));
+        assert_eq!(amounts.total, Decimal::new(980, 0));
+
+        // 64-bit types compound too: 10,000 * (1 + 0.05 / 12)^12
+        let amounts = compound(
+            Decimal::new(10_000_00i64, 2),
+            Decimal::new(500, 0),
+            CompoundingFrequency::Monthly,
+            12,
+            RoundingMode::HalfEven,
+        )?;
+        assert_eq!(amounts.total, Decimal::new(10_511_62, 2));
+        let amounts = compound(
+            Decimal::new(10_000_00u64, 2),
+            Decimal::new(500, 0),
+            CompoundingFrequency::Annually,
+            2,
+            RoundingMode::HalfEven,
+        )?;
+        assert_eq!(amounts.total, Decimal::new(11_025_00, 2));
+        Ok(())
+    }
+}
@@ -0,0 +1,173 @@
+use crate::core::{
+    money::exchange_rate::multiply_to_scale, percentage::bps::bps_to_fraction,
+    CheckedDecimalOperations, CheckedDivideToScale, CheckedMulDiv, Decimal, DecimalOperationError,
+    InterestAmounts, PowerOfTen, RescaleDecimals, RoundingMode, TranscendentalDecimals,
+    TRANSCENDENTAL_MAX_DECIMALS,
+};
+
+/// Computes the growth factor `e^(rate * years)` with [`TRANSCENDENTAL_MAX_DECIMALS`] decimals.
+fn growth_factor<T>(
+    annual_rate_bps: Decimal<T>,
+    years: Decimal<T>,
+) -> Result<Decimal<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations + TranscendentalDecimals + Copy,
+{
+    bps_to_fraction(annual_rate_bps)?
+        .try_mul(years)?
+        .try_exp(TRANSCENDENTAL_MAX_DECIMALS)
+}
+
+/// Computes the continuously compounded interest of a principal: `principal * e^(rate * years)`.
+///
+/// `years` may be fractional. The product `rate * years` must have at most 38 decimals.
+///
+/// # Precision
+///
+/// The growth factor is computed with 30 decimals and a relative error below `10^-28`, see
+/// [`TranscendentalDecimals`]. The ending balance is rounded once to the decimals of the
+/// principal, so it is within one unit in the last place of the exact balance while the balance
+/// has at most 28 significant digits, like `10^26` with 2 decimals.
+///
+/// # Arguments
+///
+/// * `principal` - The amount interest accrues on.
+/// * `annual_rate_bps` - The annual rate, in basis points.
+/// * `years` - The time interest accrues for, in years.
+/// * `rounding` - The rounding applied to the ending balance.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the accrued interest and the ending balance, or a
+/// `DecimalOperationError` if the operation fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{interest::continuous_compound, Decimal, RoundingMode};
+///
+/// // 10,000 at 5% for a year and a half: 10,000 * e^0.075 = 10,778.8415...
+/// let amounts = continuous_compound(
+///     Decimal::new(10_000_00i128, 2),
+///     Decimal::new(500, 0),
+///     Decimal::new(1_5, 1),
+///     RoundingMode::HalfEven,
+/// )
+/// .unwrap();
+/// assert_eq!(amounts.interest, Decimal::new(778_84, 2));
+/// assert_eq!(amounts.total, Decimal::new(10_778_84, 2));
+/// ```
+pub fn continuous_compound<T>(
+    principal: Decimal<T>,
+    annual_rate_bps: Decimal<T>,
+    years: Decimal<T>,
+    rounding: RoundingMode,
+) -> Result<InterestAmounts<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations
+        + CheckedMulDiv
+        + RescaleDecimals
+        + TranscendentalDecimals
+        + PowerOfTen
+        + Copy,
+{
+    let factor = growth_factor(annual_rate_bps, years)?;
+    let total = Decimal::new(
+        multiply_to_scale(principal, factor, principal.scale(), rounding)?,
+        principal.scale(),
+    );
+    Ok(InterestAmounts {
+        interest: total.try_sub(principal)?,
+        total,
+    })
+}
+
+/// Computes the principal that grows to `amount` with continuous compounding, the inverse of
+/// [`continuous_compound`]: `amount / e^(rate * years)`.
+///
+/// # Precision
+///
+/// Dividing by the growth factor keeps its relative error below `10^-28`, so the principal is
+/// within one unit in the last place of the exact principal while `amount` has at most 28
+/// significant digits.
+///
+/// # Arguments
+///
+/// * `amount` - The ending balance.
+/// * `annual_rate_bps` - The annual rate, in basis points.
+/// * `years` - The time interest accrues for, in years.
+/// * `rounding` - The rounding applied to the principal.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the principal, with the decimals of `amount`, or a
+/// `DecimalOperationError` if the operation fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{interest::continuous_discount, Decimal, RoundingMode};
+///
+/// // 10,000 / e^0.05 = 9,512.2942...
+/// let principal = continuous_discount(
+///     Decimal::new(10_000_00i128, 2),
+///     Decimal::new(500, 0),
+///     Decimal::new(1, 0),
+///     RoundingMode::HalfEven,
+/// )
+/// .unwrap();
+/// assert_eq!(principal, Decimal::new(9_512_29, 2));
+/// ```
+pub fn continuous_discount<T>(
+    amount: Decimal<T>,
+    annual_rate_bps: Decimal<T>,
+    years: Decimal<T>,
+    rounding: RoundingMode,
+) -> Result<Decimal<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations + CheckedDivideToScale + TranscendentalDecimals + Copy,
+{
+    let factor = growth_factor(annual_rate_bps, years)?;
+    amount.try_div_to_scale(factor, amount.scale(), rounding)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_continuous_compound() -> Result<(), DecimalOperationError> {
+        // 2,500 at 3.25% for 2.25 years: 2,500 * e^0.073125 = 2,689.6625...
+        let principal = Decimal::new(2_500_00i128, 2);
+        let rate = Decimal::new(325, 0);
+        let years = Decimal::new(2_25, 2);
+        let amounts = continuous_compound(principal, rate, years, RoundingMode::HalfEven)?;
+        assert_eq!(amounts.total, Decimal::new(2_689_66, 2));
+        assert_eq!(amounts.interest, Decimal::new(189_66, 2));
+        let amounts = continuous_compound(principal, rate, years, RoundingMode::Up)?;
+        assert_eq!(amounts.total, Decimal::new(2_689_67, 2));
+
+        // 2,500 / e^0.073125 = 2,323.7115...
+        assert_eq!(
+            continuous_discount(principal, rate, years, RoundingMode::HalfEven)?,
+            Decimal::new(2_323_71, 2)
+        );
+        let zero = Decimal::new(0, 0);
+        assert_eq!(
+            continuous_discount(principal, rate, zero, RoundingMode::HalfEven)?,
+            principal
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_continuous_compound_round_trip() -> Result<(), DecimalOperationError> {
+        let principal = Decimal::new(1_234_567_89i128, 2);
+        let rate = Decimal::new(712_5, 1);
+        let years = Decimal::new(30, 0);
+        let amounts = continuous_compound(principal, rate, years, RoundingMode::HalfEven)?;
+        let back = continuous_discount(amounts.total, rate, years, RoundingMode::HalfEven)?;
+        assert!(back.try_sub(principal)?.try_abs()?.value() <= 1);
+        Ok(())
+    }
+}
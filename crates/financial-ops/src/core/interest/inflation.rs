@@ -0,0 +1,181 @@
+use core::cmp::Ordering;
+
+use crate::core::{
+    percentage::bps::bps_to_fraction, CheckedDecimalOperations, CheckedDivideToScale,
+    CompareDecimals, Decimal, DecimalOperationError, PowerOfTen, RoundingMode, Zero,
+};
+
+/// Computes the real rate of return of a nominal rate under inflation, with the Fisher
+/// equation: `(1 + nominal) / (1 + inflation) - 1`.
+///
+/// Both rates are over the same period. The real rate is computed as
+/// `(nominal - inflation) / (1 + inflation)` and rounded once to a number of basis points with
+/// `scale` decimals, rather than approximated by `nominal - inflation`.
+///
+/// # Arguments
+///
+/// * `nominal_rate_bps` - The nominal rate, in basis points.
+/// * `inflation_rate_bps` - The inflation rate, in basis points.
+/// * `scale` - The number of decimals of the real rate, in basis points.
+/// * `rounding` - The rounding applied to the real rate.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the real rate in basis points, or a `DecimalOperationError` if
+/// the inflation rate is `-100%` or below, reported as `OutOfDomain`, or the operation fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{interest::real_from_nominal, Decimal, RoundingMode};
+///
+/// // 8% nominal with 3% inflation is 4.85% real, not 5%.
+/// let real = real_from_nominal(
+///     Decimal::new(800i64, 0),
+///     Decimal::new(300, 0),
+///     2,
+///     RoundingMode::HalfEven,
+/// )
+/// .unwrap();
+/// assert_eq!(real, Decimal::new(485_44, 2));
+/// ```
+pub fn real_from_nominal<T>(
+    nominal_rate_bps: Decimal<T>,
+    inflation_rate_bps: Decimal<T>,
+    scale: u32,
+    rounding: RoundingMode,
+) -> Result<Decimal<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations + CheckedDivideToScale + CompareDecimals + PowerOfTen + Zero + Copy,
+{
+    let growth = Decimal::one(0)?.try_add(bps_to_fraction(inflation_rate_bps)?)?;
+    if growth.cmp_value(&Decimal::zero(0)) != Ordering::Greater {
+        return Err(DecimalOperationError::OutOfDomain);
+    }
+    nominal_rate_bps
+        .try_sub(inflation_rate_bps)?
+        .try_div_to_scale(growth, scale, rounding)
+}
+
+/// Adjusts an amount for inflation with a price index like the CPI: `amount * current_index /
+/// base_index`.
+///
+/// The amount is expressed in the prices of the period of `base_index`, and the result in the
+/// prices of the period of `current_index`, so swapping the indices deflates it instead. The
+/// result is computed exactly and rounded once to the decimals of the amount.
+///
+/// # Arguments
+///
+/// * `amount` - The amount to adjust.
+/// * `base_index` - The price index of the period of the amount.
+/// * `current_index` - The price index of the period to express the amount in.
+/// * `rounding` - The rounding applied to the adjusted amount.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the adjusted amount, or a `DecimalOperationError` if an index
+/// is negative, reported as `OutOfDomain`, `base_index` is zero, reported as `DivisionByZero`,
+/// or the operation fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{interest::index_adjust, Decimal, RoundingMode};
+///
+/// // 1,000 from when the CPI was 251.107, in prices of when it is 296.808
+/// let adjusted = index_adjust(
+///     Decimal::new(1_000_00i64, 2),
+///     Decimal::new(251_107, 3),
+///     Decimal::new(296_808, 3),
+///     RoundingMode::HalfEven,
+/// )
+/// .unwrap();
+/// assert_eq!(adjusted, Decimal::new(1_182_00, 2));
+/// ```
+pub fn index_adjust<T>(
+    amount: Decimal<T>,
+    base_index: Decimal<T>,
+    current_index: Decimal<T>,
+    rounding: RoundingMode,
+) -> Result<Decimal<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations + CheckedDivideToScale + CompareDecimals + Zero + Copy,
+{
+    let zero = Decimal::zero(0);
+    if base_index.cmp_value(&zero) == Ordering::Less
+        || current_index.cmp_value(&zero) == Ordering::Less
+    {
+        return Err(DecimalOperationError::OutOfDomain);
+    }
+    amount
+        .try_mul(current_index)?
+        .try_div_to_scale(base_index, amount.scale(), rounding)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_real_from_nominal() -> Result<(), DecimalOperationError> {
+        let real = |nominal, inflation| {
+            real_from_nominal(
+                Decimal::new(nominal, 0),
+                Decimal::new(inflation, 0),
+                4,
+                RoundingMode::HalfEven,
+            )
+        };
+        // 5% over 1.03, and -3% over 1.05 when inflation outpaces the nominal rate.
+        assert_eq!(real(800i128, 300)?, Decimal::new(485_4369, 4));
+        assert_eq!(real(200, 500)?, Decimal::new(-285_7143, 4));
+        // Deflation raises the real rate: 6% over 0.98.
+        assert_eq!(real(400, -200)?, Decimal::new(612_2449, 4));
+        assert_eq!(real(300, 300)?, Decimal::new(0, 4));
+        assert_eq!(real(300, -10_000), Err(DecimalOperationError::OutOfDomain));
+        Ok(())
+    }
+
+    #[test]
+    fn test_index_adjust() -> Result<(), DecimalOperationError> {
+        let amount = Decimal::new(50_000u64, 0);
+        // Deflating 50,000 to prices 4.5% lower: 50,000 * 100 / 104.5 = 47,846.889...
+        assert_eq!(
+            index_adjust(
+                amount,
+                Decimal::new(104_5, 1),
+                Decimal::new(100, 0),
+                RoundingMode::HalfEven
+            )?,
+            Decimal::new(47_847, 0)
+        );
+        assert_eq!(
+            index_adjust(
+                amount,
+                Decimal::new(104_5, 1),
+                Decimal::new(100, 0),
+                RoundingMode::Down
+            )?,
+            Decimal::new(47_846, 0)
+        );
+        assert_eq!(
+            index_adjust(
+                amount,
+                Decimal::new(0, 0),
+                Decimal::new(100, 0),
+                RoundingMode::HalfEven
+            ),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+        assert_eq!(
+            index_adjust(
+                Decimal::new(100i64, 0),
+                Decimal::new(100, 0),
+                Decimal::new(-1, 0),
+                RoundingMode::HalfEven
+            ),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        Ok(())
+    }
+}
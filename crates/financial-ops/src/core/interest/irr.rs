@@ -0,0 +1,444 @@
+use core::{cell::Cell, cmp::Ordering};
+
+use crate::core::{
+    daycount::to_date,
+    interest::{annuity::GUARD_DECIMALS, compound::FACTOR_DECIMALS},
+    money::exchange_rate::multiply_to_scale,
+    percentage::bps::fraction_to_bps,
+    CheckedDecimalOperations, CheckedDivideToScale, CheckedMulDiv, CheckedPowDecimals,
+    CheckedSignDecimals, CompareDecimals, Date, DayCount, Decimal, DecimalOperationError,
+    PowerOfTen, RescaleDecimals, RoundingMode, TranscendentalDecimals, YieldError, Zero,
+    TRANSCENDENTAL_MAX_DECIMALS,
+};
+
+/// The number of times the upper bound of the search doubles, from `10%` up to `655,360%`.
+const MAX_DOUBLINGS: u32 = 16;
+
+/// The number of times the lower bound of the search halves its distance to `-100%`, down to
+/// about `-99.9999%`.
+const MAX_HALVINGS: u32 = 20;
+
+/// Returns `OutOfDomain` unless the cash flows have both a positive and a negative amount, which
+/// a rate of return needs.
+fn check_signs<'a, T, I>(amounts: I) -> Result<(), DecimalOperationError>
+where
+    T: CompareDecimals + Zero + 'a,
+    I: Iterator<Item = &'a Decimal<T>>,
+{
+    let zero = Decimal::zero(0);
+    let (mut positive, mut negative) = (false, false);
+    for amount in amounts {
+        match amount.cmp_value(&zero) {
+            Ordering::Greater => positive = true,
+            Ordering::Less => negative = true,
+            Ordering::Equal => {}
+        }
+    }
+    if positive && negative {
+        Ok(())
+    } else {
+        Err(DecimalOperationError::OutOfDomain)
+    }
+}
+
+/// Returns the number of decimals the present values of cash flows are summed with: the most
+/// decimals of an amount, plus [`GUARD_DECIMALS`].
+pub(crate) fn value_decimals<P, T>(
+    cash_flows: &[(P, Decimal<T>)],
+) -> Result<u32, DecimalOperationError> {
+    cash_flows
+        .iter()
+        .map(|(_, amount)| amount.scale())
+        .max()
+        .unwrap_or(0)
+        .checked_add(GUARD_DECIMALS)
+        .ok_or(DecimalOperationError::ScaleTooLarge)
+}
+
+/// The number of times the search bisects its bracket at most, more than the 73 needed to
+/// narrow the widest one down to `10^-18`.
+const MAX_BISECTIONS: u32 = 128;
+
+/// Finds a rate, as a fraction with 18 decimals, at which `npv_at` changes sign.
+///
+/// The search brackets a sign change by moving away from `0%`, first up and then down towards
+/// `-100%`, and bisects the bracket down to one unit in the 18th decimal. It reports how many
+/// times it evaluated `npv_at` when no bracket is found, and how many bisections it made when the
+/// bracket doesn't narrow down.
+pub(crate) fn find_rate<T, F>(npv_at: F) -> Result<Decimal<T>, YieldError>
+where
+    T: CheckedDecimalOperations
+        + CheckedDivideToScale
+        + CompareDecimals
+        + RescaleDecimals
+        + PowerOfTen
+        + Zero
+        + From<u32>
+        + Copy,
+    F: Fn(Decimal<T>) -> Result<Decimal<T>, DecimalOperationError>,
+{
+    let zero = Decimal::zero(FACTOR_DECIMALS);
+    let one = Decimal::one(FACTOR_DECIMALS)?;
+    let evaluations = Cell::new(0u32);
+    let sign = |rate: Decimal<T>| {
+        evaluations.set(evaluations.get().saturating_add(1));
+        npv_at(rate).map(|value| value.cmp_value(&zero))
+    };
+
+    let start = sign(zero)?;
+    if start == Ordering::Equal {
+        return Ok(zero);
+    }
+    let mut bracket = None;
+    let mut previous = zero;
+    for doublings in 0..=MAX_DOUBLINGS {
+        let rate = Decimal::new(T::from(1 << doublings), 1)
+            .try_rescale(FACTOR_DECIMALS, RoundingMode::HalfEven)?;
+        let rate_sign = sign(rate)?;
+        if rate_sign == Ordering::Equal {
+            return Ok(rate);
+        }
+        if rate_sign != start {
+            bracket = Some((previous, rate));
+            break;
+        }
+        previous = rate;
+    }
+    if bracket.is_none() {
+        previous = zero;
+        for halvings in 1..=MAX_HALVINGS {
+            let distance = one.try_div_to_scale(
+                Decimal::new(T::from(1 << halvings), 0),
+                FACTOR_DECIMALS,
+                RoundingMode::HalfEven,
+            )?;
+            let rate = distance.try_sub(one)?;
+            let rate_sign = sign(rate)?;
+            if rate_sign == Ordering::Equal {
+                return Ok(rate);
+            }
+            if rate_sign != start {
+                bracket = Some((previous, rate));
+                break;
+            }
+            previous = rate;
+        }
+    }
+    let (mut low, mut high) = bracket.ok_or(YieldError::NotBracketed {
+        evaluations: evaluations.get(),
+    })?;
+
+    // `low` keeps the sign of the net present value at `0%`, so the rate lies between the two.
+    let two = Decimal::new(T::from(2), 0);
+    let ulp = Decimal::new(T::from(1), FACTOR_DECIMALS);
+    for _ in 0..MAX_BISECTIONS {
+        let width = match low.cmp_value(&high) {
+            Ordering::Less => high.try_sub(low)?,
+            Ordering::Equal | Ordering::Greater => low.try_sub(high)?,
+        };
+        if width.cmp_value(&ulp) != Ordering::Greater {
+            return Ok(low);
+        }
+        let middle =
+            low.try_add(high)?
+                .try_div_to_scale(two, FACTOR_DECIMALS, RoundingMode::Floor)?;
+        match sign(middle)? {
+            Ordering::Equal => return Ok(middle),
+            middle_sign if middle_sign == start => low = middle,
+            _ => high = middle,
+        }
+    }
+    Err(YieldError::NoConvergence {
+        iterations: MAX_BISECTIONS,
+    })
+}
+
+/// Finds a rate at which `npv_at` changes sign like [`find_rate`], and returns it as basis points
+/// with `scale` decimals, or `OutOfDomain` if the search fails.
+fn solve<T, F>(
+    npv_at: F,
+    scale: u32,
+    rounding: RoundingMode,
+) -> Result<Decimal<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations
+        + CheckedDivideToScale
+        + CompareDecimals
+        + RescaleDecimals
+        + PowerOfTen
+        + Zero
+        + From<u32>
+        + Copy,
+    F: Fn(Decimal<T>) -> Result<Decimal<T>, DecimalOperationError>,
+{
+    let rate = find_rate(npv_at).map_err(|error| match error {
+        YieldError::Decimal(error) => error,
+        YieldError::NotBracketed { .. } | YieldError::NoConvergence { .. } => {
+            DecimalOperationError::OutOfDomain
+        }
+    })?;
+    fraction_to_bps(rate, scale, rounding)
+}
+
+/// Computes the internal rate of return of a series of cash flows: the rate per period at which
+/// their [`npv`](fn@crate::core::interest::npv) is zero.
+///
+/// Cash flows are signed, money paid out being negative, and may come in any order. The search
+/// starts from `0%` and looks for a change of sign of the net present value between `-99.9999%`
+/// and `655,360%` per period, then bisects it down to `10^-18`, so it always converges. When the
+/// flows change sign more than once there may be several rates, and the one found is the
+/// closest to `0%` the search brackets. The discount factors are computed with 18 decimals and the
+/// present values with 6 more decimals than the amounts, so the search stops with `Overflow` when
+/// a factor doesn't fit the type with 18 decimals, like rates above `640%` or below `-87.5%` on
+/// `i64`.
+///
+/// # Arguments
+///
+/// * `cash_flows` - The period and amount of every cash flow.
+/// * `scale` - The number of decimals of the rate, in basis points.
+/// * `rounding` - The rounding applied to the rate.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the rate per period in basis points, or a
+/// `DecimalOperationError` if the flows don't have both a positive and a negative amount or no
+/// rate is found in the range, both reported as `OutOfDomain`, or the operation fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{interest::irr, Decimal, RoundingMode};
+///
+/// // Investing 1,000 to get 300, 400 and 500 back over three years
+/// let cash_flows = [
+///     (0, Decimal::new(-1_000_00i128, 2)),
+///     (1, Decimal::new(300_00, 2)),
+///     (2, Decimal::new(400_00, 2)),
+///     (3, Decimal::new(500_00, 2)),
+/// ];
+/// let rate = irr(&cash_flows, 2, RoundingMode::HalfEven).unwrap();
+/// assert_eq!(rate, Decimal::new(889_63, 2));
+/// ```
+pub fn irr<T>(
+    cash_flows: &[(u32, Decimal<T>)],
+    scale: u32,
+    rounding: RoundingMode,
+) -> Result<Decimal<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations
+        + CheckedDivideToScale
+        + CheckedMulDiv
+        + CheckedPowDecimals
+        + CompareDecimals
+        + RescaleDecimals
+        + PowerOfTen
+        + Zero
+        + From<u32>
+        + Copy,
+{
+    check_signs(cash_flows.iter().map(|(_, amount)| amount))?;
+    let decimals = value_decimals(cash_flows)?;
+    let one = Decimal::one(FACTOR_DECIMALS)?;
+    let npv_at = |rate: Decimal<T>| {
+        // Discounting by `(1 / (1 + rate))^period` keeps the factors below one for positive rates.
+        let discount =
+            one.try_div_to_scale(one.try_add(rate)?, FACTOR_DECIMALS, RoundingMode::HalfEven)?;
+        cash_flows
+            .iter()
+            .try_fold(Decimal::zero(decimals), |total, &(period, amount)| {
+                let factor = discount.try_pow_rounded(period, RoundingMode::HalfEven)?;
+                let value = multiply_to_scale(amount, factor, decimals, RoundingMode::HalfEven)?;
+                total.try_add(Decimal::new(value, decimals))
+            })
+    };
+    solve(npv_at, scale, rounding)
+}
+
+/// Computes the internal rate of return of cash flows on irregular dates: the annual rate at
+/// which the sum of `amount / (1 + rate)^years` is zero, with `years` counted from the earliest
+/// flow with a day-count convention.
+///
+/// With [`DayCount::Act365Fixed`] this is the `XIRR` of spreadsheets. The dates are [`Date`]s,
+/// `(year, month, day)` tuples, or `chrono::NaiveDate`s with the `chrono` feature, and may come
+/// in any order. The search works like in [`irr`], with the fractional powers computed through
+/// [`TranscendentalDecimals`] with 30 decimals.
+///
+/// # Arguments
+///
+/// * `cash_flows` - The date and amount of every cash flow.
+/// * `convention` - The day-count convention counting the years between the flows.
+/// * `scale` - The number of decimals of the rate, in basis points.
+/// * `rounding` - The rounding applied to the rate.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the annual rate in basis points, or a `DecimalOperationError` if
+/// a date doesn't exist, the flows don't have both a positive and a negative amount or no rate is
+/// found, all reported as `OutOfDomain`, or the operation fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{interest::xirr, DayCount, Decimal, RoundingMode};
+///
+/// let cash_flows = [
+///     ((2024, 1, 1), Decimal::new(-10_000_00i128, 2)),
+///     ((2024, 3, 1), Decimal::new(2_750_00, 2)),
+///     ((2024, 10, 30), Decimal::new(4_250_00, 2)),
+///     ((2025, 2, 15), Decimal::new(3_250_00, 2)),
+///     ((2025, 4, 1), Decimal::new(2_750_00, 2)),
+/// ];
+/// let rate = xirr(&cash_flows, DayCount::Act365Fixed, 2, RoundingMode::HalfEven).unwrap();
+/// assert_eq!(rate, Decimal::new(3_733_63, 2));
+/// ```
+pub fn xirr<T, D>(
+    cash_flows: &[(D, Decimal<T>)],
+    convention: DayCount,
+    scale: u32,
+    rounding: RoundingMode,
+) -> Result<Decimal<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations
+        + CheckedDivideToScale
+        + CheckedMulDiv
+        + CheckedSignDecimals
+        + CompareDecimals
+        + RescaleDecimals
+        + TranscendentalDecimals
+        + PowerOfTen
+        + Zero
+        + From<u32>
+        + Copy,
+    D: TryInto<Date> + Copy,
+{
+    check_signs(cash_flows.iter().map(|(_, amount)| amount))?;
+    let first = cash_flows
+        .iter()
+        .try_fold(None, |first: Option<Date>, &(day, _)| {
+            let day = to_date(day)?;
+            Ok(Some(first.map_or(day, |first| first.min(day))))
+        })?
+        .ok_or(DecimalOperationError::OutOfDomain)?;
+    let decimals = value_decimals(cash_flows)?;
+    let one = Decimal::one(FACTOR_DECIMALS)?;
+    let npv_at = |rate: Decimal<T>| {
+        let log = one.try_add(rate)?.try_ln(TRANSCENDENTAL_MAX_DECIMALS)?;
+        cash_flows
+            .iter()
+            .try_fold(Decimal::zero(decimals), |total, &(day, amount)| {
+                let (days, year) = convention.year_parts(first, to_date(day)?)?;
+                // e^(-years * ln(1 + rate)) stays below one for positive rates.
+                let factor = log
+                    .try_mul(days)?
+                    .try_div_to_scale(year, TRANSCENDENTAL_MAX_DECIMALS, RoundingMode::HalfEven)?
+                    .try_neg()?
+                    .try_exp(FACTOR_DECIMALS)?;
+                let value = multiply_to_scale(amount, factor, decimals, RoundingMode::HalfEven)?;
+                total.try_add(Decimal::new(value, decimals))
+            })
+    };
+    solve(npv_at, scale, rounding)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_irr() -> Result<(), DecimalOperationError> {
+        // A loan of 10,000 repaid by 12 payments of 888.49 costs 1% a month.
+        let mut cash_flows = [(0, Decimal::new(888_49i128, 2)); 13];
+        for (period, flow) in cash_flows.iter_mut().enumerate() {
+            flow.0 = period as u32;
+        }
+        cash_flows[0].1 = Decimal::new(-10_000_00, 2);
+        assert_eq!(
+            irr(&cash_flows, 2, RoundingMode::HalfEven)?,
+            Decimal::new(100_00, 2)
+        );
+
+        // The borrower sees the same rate, and the order of the flows doesn't matter.
+        let mut borrower =
+            cash_flows.map(|(period, amount)| (period, Decimal::new(-amount.value(), 2)));
+        borrower.reverse();
+        assert_eq!(
+            irr(&borrower, 2, RoundingMode::HalfEven)?,
+            Decimal::new(100_00, 2)
+        );
+
+        // Losing money is a negative rate: -1,000 then 600 and 300 is about -7.55%.
+        let cash_flows = [
+            (0, Decimal::new(-1_000i128, 0)),
+            (1, Decimal::new(600, 0)),
+            (2, Decimal::new(300, 0)),
+        ];
+        assert_eq!(
+            irr(&cash_flows, 0, RoundingMode::HalfEven)?,
+            Decimal::new(-755, 0)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_irr_64_bits() -> Result<(), DecimalOperationError> {
+        let flows = |amounts: [i64; 6]| -> [(u32, Decimal<i64>); 6] {
+            core::array::from_fn(|period| (period as u32, Decimal::new(amounts[period], 0)))
+        };
+        // The example of the IRR spreadsheet function, 8.66%, and flows of only two digits
+        let cash_flows = flows([-70_000, 12_000, 15_000, 18_000, 21_000, 26_000]);
+        assert_eq!(
+            irr(&cash_flows, 4, RoundingMode::HalfEven)?,
+            Decimal::new(866_3095, 4)
+        );
+        let cash_flows = flows([-70, 10, 20, 20, 20, 30]);
+        assert_eq!(
+            irr(&cash_flows, 2, RoundingMode::HalfEven)?,
+            Decimal::new(1_141_60, 2)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_irr_errors() {
+        let cash_flows = [(0, Decimal::new(100i128, 0)), (1, Decimal::new(100, 0))];
+        assert_eq!(
+            irr(&cash_flows, 2, RoundingMode::HalfEven),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        assert_eq!(
+            irr::<i128>(&[], 2, RoundingMode::HalfEven),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+    }
+
+    #[test]
+    fn test_xirr() -> Result<(), DecimalOperationError> {
+        type CashFlow = ((i32, u32, u32), Decimal<i128>);
+        let rate = |cash_flows: &[CashFlow], convention| {
+            xirr(cash_flows, convention, 2, RoundingMode::HalfEven)
+        };
+        // Doubling money in exactly a year of 365 days is 100% a year.
+        let cash_flows = [
+            ((2025, 3, 1), Decimal::new(2_000, 0)),
+            ((2024, 3, 1), Decimal::new(-1_000, 0)),
+        ];
+        assert_eq!(
+            rate(&cash_flows, DayCount::Act365Fixed)?,
+            Decimal::new(10_000_00, 2)
+        );
+        // ACT/ACT counts 306 days of 2024, a leap year, and 59 of 2025: a bit less than a year.
+        assert_eq!(
+            rate(&cash_flows, DayCount::ActAct)?,
+            Decimal::new(10_031_85, 2)
+        );
+        let cash_flows = [
+            ((2024, 2, 30), Decimal::new(-1, 0)),
+            ((2024, 3, 1), Decimal::new(2, 0)),
+        ];
+        assert_eq!(
+            rate(&cash_flows, DayCount::Act365Fixed),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+//! Interest, annuities, discounting and rate conversions.
+//!
+//! Rates are numbers of basis points with their own scale, so `Decimal::new(525, 0)` is 5.25% and
+//! `Decimal::new(12_5, 1)` is 12.5 basis points, over the period named by their argument: per
+//! period for annuities, loans and discounting, per year for compounding and accrual. Rates
+//! computed by the module, like [`irr`](fn@irr) or [`cagr`](fn@cagr), are returned the same way.
+
+pub mod accrual;
+pub mod accrued;
+#[cfg(feature = "alloc")]
+pub mod amortization;
+pub mod annuity;
+pub mod apr;
+pub mod cagr;
+pub mod compound;
+pub mod continuous;
+pub mod inflation;
+pub mod irr;
+pub mod npv;
+pub mod payment;
+pub mod perpetuity;
+pub mod savings;
+pub mod simple;
+
+pub use accrual::*;
+pub use accrued::*;
+#[cfg(feature = "alloc")]
+pub use amortization::*;
+pub use annuity::*;
+pub use apr::*;
+pub use cagr::*;
+pub use compound::*;
+pub use continuous::*;
+pub use inflation::*;
+pub use irr::*;
+pub use npv::*;
+pub use payment::*;
+pub use perpetuity::*;
+pub use savings::*;
+pub use simple::*;
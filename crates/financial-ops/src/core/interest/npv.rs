@@ -0,0 +1,116 @@
+use core::cmp::Ordering;
+
+use crate::core::{
+    interest::compound::FACTOR_DECIMALS, percentage::bps::bps_to_fraction,
+    CheckedDecimalOperations, CheckedDivideToScale, CheckedPowDecimals, CompareDecimals, Decimal,
+    DecimalOperationError, PowerOfTen, RescaleDecimals, RoundingMode, Zero,
+};
+
+/// Computes the net present value of a series of cash flows: the sum of
+/// `amount / (1 + rate)^period` over every flow.
+///
+/// The rate must be above `-100%`. Cash flows are signed, money paid out being negative, and may
+/// come in any order; a flow at period `0` isn't discounted. The discount factors are computed with
+/// 18 decimals, every discounted flow is rounded to `scale` decimals and the sum is exact, so the
+/// result is within one unit in the last place per flow of the exact value.
+///
+/// # Arguments
+///
+/// * `rate_bps` - The discount rate per period, in basis points.
+/// * `cash_flows` - The period and amount of every cash flow.
+/// * `scale` - The number of decimals of the discounted flows and of the result.
+/// * `rounding` - The rounding applied to every discounted flow.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the net present value, or a `DecimalOperationError` if the rate
+/// is `-100%` or below, reported as `OutOfDomain`, or any intermediate value overflows.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{interest::npv, Decimal, RoundingMode};
+///
+/// // Investing 1,000 to get 300, 400 and 500 back over three years, at 8% a year
+/// let cash_flows = [
+///     (0, Decimal::new(-1_000_00i64, 2)),
+///     (1, Decimal::new(300_00, 2)),
+///     (2, Decimal::new(400_00, 2)),
+///     (3, Decimal::new(500_00, 2)),
+/// ];
+/// let value = npv(Decimal::new(800, 0), &cash_flows, 2, RoundingMode::HalfEven).unwrap();
+/// // 277.78 + 342.94 + 396.92 - 1,000, every flow rounded to cents
+/// assert_eq!(value, Decimal::new(17_64, 2));
+/// ```
+pub fn npv<T>(
+    rate_bps: Decimal<T>,
+    cash_flows: &[(u32, Decimal<T>)],
+    scale: u32,
+    rounding: RoundingMode,
+) -> Result<Decimal<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations
+        + CheckedDivideToScale
+        + CheckedPowDecimals
+        + CompareDecimals
+        + RescaleDecimals
+        + PowerOfTen
+        + Zero
+        + Copy,
+{
+    let rate = bps_to_fraction(rate_bps)?.try_rescale(FACTOR_DECIMALS, RoundingMode::HalfEven)?;
+    let base = Decimal::one(FACTOR_DECIMALS)?.try_add(rate)?;
+    if base.cmp_value(&Decimal::zero(FACTOR_DECIMALS)) != Ordering::Greater {
+        return Err(DecimalOperationError::OutOfDomain);
+    }
+    cash_flows
+        .iter()
+        .try_fold(Decimal::zero(scale), |total, &(period, amount)| {
+            let factor = base.try_pow_rounded(period, RoundingMode::HalfEven)?;
+            total.try_add(amount.try_div_to_scale(factor, scale, rounding)?)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_npv() -> Result<(), DecimalOperationError> {
+        let cash_flows = [
+            (2, Decimal::new(600_000i128, 3)),
+            (0, Decimal::new(-1_000, 0)),
+            (1, Decimal::new(500, 0)),
+        ];
+        let value = |rate_bps, scale| npv(rate_bps, &cash_flows, scale, RoundingMode::HalfEven);
+        // -1,000 + 500 / 1.1 + 600 / 1.21 = -49.5867..., with every flow rounded to the scale
+        let rate = Decimal::new(1_000, 0);
+        assert_eq!(value(rate, 4)?, Decimal::new(-49_5867, 4));
+        assert_eq!(value(rate, 0)?, Decimal::new(-49, 0));
+        // A zero rate adds up the flows, and a negative one weighs later flows more:
+        // -1,000 + 500 / 0.95 + 600 / 0.9025 = 191.1357...
+        assert_eq!(value(Decimal::new(0, 0), 2)?, Decimal::new(100_00, 2));
+        assert_eq!(value(Decimal::new(-500_0, 1), 2)?, Decimal::new(191_14, 2));
+        assert_eq!(
+            npv(rate, &[], 2, RoundingMode::HalfEven)?,
+            Decimal::new(0, 2)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_npv_errors() {
+        let value = |rate_bps, period| {
+            let cash_flows = [(period, Decimal::new(100i64, 0))];
+            npv(rate_bps, &cash_flows, 2, RoundingMode::HalfEven)
+        };
+        assert_eq!(
+            value(Decimal::new(-10_000, 0), 1),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        // The output has more decimals than the flows, through a factor with 18 decimals.
+        assert_eq!(value(Decimal::new(0, 0), 1), Ok(Decimal::new(100_00, 2)));
+        // 1.1^30 doesn't fit in an i64 with 18 decimals.
+        assert!(value(Decimal::new(1_000, 0), 30).is_err());
+    }
+}
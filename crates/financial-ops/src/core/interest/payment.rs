@@ -0,0 +1,233 @@
+use core::cmp::Ordering;
+
+use crate::core::{
+    CheckedDecimalOperations, CompareDecimals, Decimal, DecimalOperationError, RescaleDecimals,
+    RoundingMode, Zero,
+};
+
+/// An amount due on a loan that a payment pays off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Bucket {
+    /// Fees and charges.
+    Fees,
+    /// Accrued interest.
+    Interest,
+    /// Principal.
+    Principal,
+}
+
+/// The order in which [`allocate_payment`] pays off the amounts due.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PaymentPolicy {
+    /// Fees, then interest, then principal.
+    #[default]
+    FeesFirst,
+    /// Interest, then fees, then principal.
+    InterestFirst,
+    /// Principal, then interest, then fees.
+    PrincipalFirst,
+    /// A custom order, which must name every bucket once.
+    Custom([Bucket; 3]),
+}
+
+impl PaymentPolicy {
+    /// Returns the buckets in the order they are paid off, or `None` if a custom order doesn't
+    /// name every bucket once.
+    pub fn order(&self) -> Option<[Bucket; 3]> {
+        match *self {
+            PaymentPolicy::FeesFirst => Some([Bucket::Fees, Bucket::Interest, Bucket::Principal]),
+            PaymentPolicy::InterestFirst => {
+                Some([Bucket::Interest, Bucket::Fees, Bucket::Principal])
+            }
+            PaymentPolicy::PrincipalFirst => {
+                Some([Bucket::Principal, Bucket::Interest, Bucket::Fees])
+            }
+            PaymentPolicy::Custom(order) => {
+                let complete = [Bucket::Fees, Bucket::Interest, Bucket::Principal]
+                    .iter()
+                    .all(|bucket| order.contains(bucket));
+                complete.then_some(order)
+            }
+        }
+    }
+}
+
+/// How a payment was applied to the amounts due.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PaymentAllocation<T = i128> {
+    /// The amount applied to fees.
+    pub fees: Decimal<T>,
+    /// The amount applied to interest.
+    pub interest: Decimal<T>,
+    /// The amount applied to principal.
+    pub principal: Decimal<T>,
+    /// The amount left after every bucket is paid off.
+    pub overpayment: Decimal<T>,
+}
+
+impl<T: Copy> PaymentAllocation<T> {
+    /// Returns the amount applied to a bucket.
+    pub fn applied(&self, bucket: Bucket) -> Decimal<T> {
+        match bucket {
+            Bucket::Fees => self.fees,
+            Bucket::Interest => self.interest,
+            Bucket::Principal => self.principal,
+        }
+    }
+}
+
+/// Applies a payment to the fees, interest and principal due, paying off each bucket in the order
+/// of the policy before moving to the next.
+///
+/// Every amount is expressed with the largest number of decimals among the payment and the
+/// amounts due, so nothing is rounded: the applied amounts and the overpayment add up to the
+/// payment exactly.
+///
+/// # Arguments
+///
+/// * `payment` - The amount paid.
+/// * `due` - The fees, interest and principal due, in this order.
+/// * `policy` - The order in which the buckets are paid off.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the amount applied to each bucket and the overpayment, or a
+/// `DecimalOperationError` if an amount is negative or a custom order doesn't name every bucket
+/// once, reported as `OutOfDomain`, or the operation fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{
+///     interest::{allocate_payment, PaymentPolicy},
+///     Decimal,
+/// };
+///
+/// let due = [
+///     Decimal::new(25_00i64, 2),
+///     Decimal::new(310_45, 2),
+///     Decimal::new(1_000_00, 2),
+/// ];
+/// let allocation = allocate_payment(Decimal::new(500_00, 2), &due, PaymentPolicy::FeesFirst)
+///     .unwrap();
+/// assert_eq!(allocation.fees, Decimal::new(25_00, 2));
+/// assert_eq!(allocation.interest, Decimal::new(310_45, 2));
+/// assert_eq!(allocation.principal, Decimal::new(164_55, 2));
+/// assert_eq!(allocation.overpayment, Decimal::new(0, 2));
+/// ```
+pub fn allocate_payment<T>(
+    payment: Decimal<T>,
+    due: &[Decimal<T>; 3],
+    policy: PaymentPolicy,
+) -> Result<PaymentAllocation<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations + CompareDecimals + RescaleDecimals + Zero + Copy,
+{
+    let order = policy.order().ok_or(DecimalOperationError::OutOfDomain)?;
+    let scale = due
+        .iter()
+        .map(Decimal::scale)
+        .fold(payment.scale(), u32::max);
+    let zero = Decimal::zero(scale);
+    // Adding decimals is exact, so the rounding mode never applies.
+    let rescale = |amount: Decimal<T>| {
+        if amount.cmp_value(&zero) == Ordering::Less {
+            return Err(DecimalOperationError::OutOfDomain);
+        }
+        amount.try_rescale(scale, RoundingMode::HalfEven)
+    };
+
+    let mut remaining = rescale(payment)?;
+    let mut allocation = PaymentAllocation {
+        fees: zero,
+        interest: zero,
+        principal: zero,
+        overpayment: zero,
+    };
+    for bucket in order {
+        let owed = rescale(match bucket {
+            Bucket::Fees => due[0],
+            Bucket::Interest => due[1],
+            Bucket::Principal => due[2],
+        })?;
+        let applied = match remaining.cmp_value(&owed) {
+            Ordering::Less => remaining,
+            Ordering::Equal | Ordering::Greater => owed,
+        };
+        remaining = remaining.try_sub(applied)?;
+        match bucket {
+            Bucket::Fees => allocation.fees = applied,
+            Bucket::Interest => allocation.interest = applied,
+            Bucket::Principal => allocation.principal = applied,
+        }
+    }
+    allocation.overpayment = remaining;
+    Ok(allocation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allocate_payment() -> Result<(), DecimalOperationError> {
+        let due = [
+            Decimal::new(15_00u64, 2),
+            Decimal::new(42_125, 3),
+            Decimal::new(200_00, 2),
+        ];
+
+        // A partial payment stops in the middle of a bucket, with every amount in 3 decimals.
+        let allocation = allocate_payment(Decimal::new(50_00, 2), &due, PaymentPolicy::FeesFirst)?;
+        assert_eq!(allocation.fees, Decimal::new(15_000, 3));
+        assert_eq!(allocation.interest, Decimal::new(35_000, 3));
+        assert_eq!(allocation.principal, Decimal::new(0, 3));
+        assert_eq!(allocation.overpayment, Decimal::new(0, 3));
+
+        let allocation =
+            allocate_payment(Decimal::new(50_00, 2), &due, PaymentPolicy::InterestFirst)?;
+        assert_eq!(allocation.interest, Decimal::new(42_125, 3));
+        assert_eq!(allocation.applied(Bucket::Fees), Decimal::new(7_875, 3));
+
+        let allocation =
+            allocate_payment(Decimal::new(50_00, 2), &due, PaymentPolicy::PrincipalFirst)?;
+        assert_eq!(allocation.principal, Decimal::new(50_000, 3));
+        assert_eq!(allocation.interest, Decimal::new(0, 3));
+
+        // Paying more than is due leaves an overpayment, and the parts add up to the payment.
+        let policy = PaymentPolicy::Custom([Bucket::Principal, Bucket::Fees, Bucket::Interest]);
+        let payment = Decimal::new(300_00, 2);
+        let allocation = allocate_payment(payment, &due, policy)?;
+        assert_eq!(allocation.overpayment, Decimal::new(42_875, 3));
+        let total = allocation
+            .fees
+            .try_add(allocation.interest)?
+            .try_add(allocation.principal)?
+            .try_add(allocation.overpayment)?;
+        assert!(total.eq_value(&payment));
+        Ok(())
+    }
+
+    #[test]
+    fn test_allocate_payment_errors() {
+        let due = [Decimal::new(1_00i64, 2); 3];
+        let policy = PaymentPolicy::Custom([Bucket::Fees, Bucket::Fees, Bucket::Principal]);
+        assert_eq!(
+            allocate_payment(Decimal::new(1_00, 2), &due, policy),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        assert_eq!(
+            allocate_payment(Decimal::new(-1_00, 2), &due, PaymentPolicy::FeesFirst),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        let due = [
+            Decimal::new(1_00i64, 2),
+            Decimal::new(-1, 2),
+            Decimal::new(0, 2),
+        ];
+        assert_eq!(
+            allocate_payment(Decimal::new(1_00, 2), &due, PaymentPolicy::FeesFirst),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+    }
+}
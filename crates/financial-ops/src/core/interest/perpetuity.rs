@@ -0,0 +1,160 @@
+use core::cmp::Ordering;
+
+use crate::core::{
+    percentage::bps::bps_to_fraction, CheckedDecimalOperations, CheckedDivideToScale,
+    CompareDecimals, Decimal, PerpetuityError, RoundingMode, Zero,
+};
+
+/// Computes the present value of a perpetuity, a payment received every period forever:
+/// `payment / rate`.
+///
+/// The first payment comes at the end of the first period. The value is computed exactly and
+/// rounded once to the decimals of the payment.
+///
+/// # Arguments
+///
+/// * `payment` - The payment received every period.
+/// * `rate_bps` - The discount rate per period, in basis points.
+/// * `rounding` - The rounding applied to the value.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the present value, or a `PerpetuityError` if the rate isn't
+/// positive, reported as `RateNotGreaterThanGrowth`, or the operation fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{interest::perpetuity, Decimal, RoundingMode};
+///
+/// // 1,000 a year forever at 8% a year
+/// let value = perpetuity(
+///     Decimal::new(1_000_00i64, 2),
+///     Decimal::new(800, 0),
+///     RoundingMode::HalfEven,
+/// )
+/// .unwrap();
+/// assert_eq!(value, Decimal::new(12_500_00, 2));
+/// ```
+pub fn perpetuity<T>(
+    payment: Decimal<T>,
+    rate_bps: Decimal<T>,
+    rounding: RoundingMode,
+) -> Result<Decimal<T>, PerpetuityError>
+where
+    T: CheckedDecimalOperations + CheckedDivideToScale + CompareDecimals + Zero + Copy,
+{
+    growing_perpetuity(payment, rate_bps, Decimal::zero(0), rounding)
+}
+
+/// Computes the present value of a growing perpetuity, a payment received every period forever
+/// and growing at a constant rate: `payment / (rate - growth)`.
+///
+/// The first payment of `payment` comes at the end of the first period. The discount rate must be
+/// greater than the growth rate, or the payments are worth an unbounded amount. The value is
+/// computed exactly and rounded once to the decimals of the payment.
+///
+/// # Arguments
+///
+/// * `payment` - The first payment.
+/// * `rate_bps` - The discount rate per period, in basis points.
+/// * `growth_bps` - The growth of the payments per period, in basis points.
+/// * `rounding` - The rounding applied to the value.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the present value, or a `PerpetuityError` if the discount rate
+/// isn't greater than the growth rate, reported as `RateNotGreaterThanGrowth`, or the operation
+/// fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{interest::growing_perpetuity, Decimal, PerpetuityError, RoundingMode};
+///
+/// // A dividend of 2.50 growing 3% a year, discounted at 8% a year
+/// let value = growing_perpetuity(
+///     Decimal::new(2_50i64, 2),
+///     Decimal::new(800, 0),
+///     Decimal::new(300, 0),
+///     RoundingMode::HalfEven,
+/// )
+/// .unwrap();
+/// assert_eq!(value, Decimal::new(50_00, 2));
+///
+/// let value = growing_perpetuity(
+///     Decimal::new(2_50i64, 2),
+///     Decimal::new(300, 0),
+///     Decimal::new(300, 0),
+///     RoundingMode::HalfEven,
+/// );
+/// assert_eq!(value, Err(PerpetuityError::RateNotGreaterThanGrowth));
+/// ```
+pub fn growing_perpetuity<T>(
+    payment: Decimal<T>,
+    rate_bps: Decimal<T>,
+    growth_bps: Decimal<T>,
+    rounding: RoundingMode,
+) -> Result<Decimal<T>, PerpetuityError>
+where
+    T: CheckedDecimalOperations + CheckedDivideToScale + CompareDecimals + Zero + Copy,
+{
+    if rate_bps.cmp_value(&growth_bps) != Ordering::Greater {
+        return Err(PerpetuityError::RateNotGreaterThanGrowth);
+    }
+    let spread = bps_to_fraction(rate_bps)?.try_sub(bps_to_fraction(growth_bps)?)?;
+    Ok(payment.try_div_to_scale(spread, payment.scale(), rounding)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_perpetuity() -> Result<(), PerpetuityError> {
+        let payment = Decimal::new(100_00i128, 2);
+        // 100 / 0.03 = 3,333.33...
+        assert_eq!(
+            perpetuity(payment, Decimal::new(300, 0), RoundingMode::HalfEven)?,
+            Decimal::new(3_333_33, 2)
+        );
+        assert_eq!(
+            perpetuity(payment, Decimal::new(300, 0), RoundingMode::Up)?,
+            Decimal::new(3_333_34, 2)
+        );
+        assert_eq!(
+            perpetuity(payment, Decimal::new(0, 0), RoundingMode::HalfEven),
+            Err(PerpetuityError::RateNotGreaterThanGrowth)
+        );
+        assert_eq!(
+            perpetuity(payment, Decimal::new(-100, 0), RoundingMode::HalfEven),
+            Err(PerpetuityError::RateNotGreaterThanGrowth)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_growing_perpetuity() -> Result<(), PerpetuityError> {
+        let payment = Decimal::new(100_00u64, 2);
+        // 100 / (0.0725 - 0.0125) = 1,666.66...
+        assert_eq!(
+            growing_perpetuity(
+                payment,
+                Decimal::new(725_0, 1),
+                Decimal::new(125, 0),
+                RoundingMode::HalfEven
+            )?,
+            Decimal::new(1_666_67, 2)
+        );
+        assert_eq!(
+            growing_perpetuity(
+                payment,
+                Decimal::new(125, 0),
+                Decimal::new(725, 0),
+                RoundingMode::HalfEven
+            ),
+            Err(PerpetuityError::RateNotGreaterThanGrowth)
+        );
+        Ok(())
+    }
+}
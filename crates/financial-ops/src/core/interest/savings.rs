@@ -0,0 +1,237 @@
+use core::cmp::Ordering;
+
+use crate::core::{
+    interest::annuity::{nper, pmt, PaymentTiming},
+    CheckedDecimalOperations, CheckedDivideToScale, CheckedMulDiv, CheckedPowDecimals,
+    CheckedSignDecimals, CompareDecimals, Decimal, DecimalOperationError, PowerOfTen,
+    RescaleDecimals, RoundingMode, TranscendentalDecimals, Zero,
+};
+
+/// Computes the contribution to save every period to reach a target, the payment of a sinking
+/// fund: the [`pmt`] of an annuity growing from nothing to `target`.
+///
+/// Unlike [`pmt`], the target and the contribution are both positive. The contribution is rounded
+/// once to the decimals of the target, and rounding up with [`RoundingMode::Up`] makes sure the
+/// target is reached.
+///
+/// # Arguments
+///
+/// * `target` - The amount to save.
+/// * `rate_bps` - The rate the savings earn per period, in basis points.
+/// * `periods` - The number of contributions.
+/// * `timing` - When the contributions fall in each period.
+/// * `rounding` - The rounding applied to the contribution.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the contribution, or a `DecimalOperationError` if the target is
+/// negative, reported as `OutOfDomain`, `periods` is zero, reported as `DivisionByZero`, or the
+/// operation fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{
+///     interest::{required_periodic_contribution, PaymentTiming},
+///     Decimal, RoundingMode,
+/// };
+///
+/// // Saving 10,000 in two years at 6% a year, compounded monthly
+/// let contribution = required_periodic_contribution(
+///     Decimal::new(10_000_00i128, 2),
+///     Decimal::new(50, 0),
+///     24,
+///     PaymentTiming::End,
+///     RoundingMode::Up,
+/// )
+/// .unwrap();
+/// assert_eq!(contribution, Decimal::new(393_21, 2));
+/// ```
+pub fn required_periodic_contribution<T>(
+    target: Decimal<T>,
+    rate_bps: Decimal<T>,
+    periods: u32,
+    timing: PaymentTiming,
+    rounding: RoundingMode,
+) -> Result<Decimal<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations
+        + CheckedDivideToScale
+        + CheckedMulDiv
+        + CheckedPowDecimals
+        + CheckedSignDecimals
+        + CompareDecimals
+        + RescaleDecimals
+        + PowerOfTen
+        + Zero
+        + From<u32>
+        + Copy,
+{
+    if target.cmp_value(&Decimal::zero(0)) == Ordering::Less {
+        return Err(DecimalOperationError::OutOfDomain);
+    }
+    pmt(
+        rate_bps,
+        periods,
+        Decimal::zero(target.scale()),
+        target.try_neg()?,
+        timing,
+        rounding,
+    )
+}
+
+/// Computes the number of periods it takes to reach a target by saving a contribution every
+/// period: the [`nper`] of an annuity growing from nothing to `target`.
+///
+/// The rate and the signs follow [`required_periodic_contribution`]. The number of periods is
+/// usually fractional and is rounded once to `scale` decimals; a scale of zero with
+/// [`RoundingMode::Up`] gives the number of contributions needed.
+///
+/// # Arguments
+///
+/// * `target` - The amount to save.
+/// * `contribution` - The amount saved every period.
+/// * `rate_bps` - The rate the savings earn per period, in basis points.
+/// * `timing` - When the contributions fall in each period.
+/// * `scale` - The number of decimals of the result.
+/// * `rounding` - The rounding applied to the result.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the number of periods, or a `DecimalOperationError` if the
+/// target is negative or the contribution isn't positive, reported as `OutOfDomain`, or the
+/// operation fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{
+///     interest::{periods_to_goal, PaymentTiming},
+///     Decimal, RoundingMode,
+/// };
+///
+/// // Saving 400 a month towards 10,000 at 6% a year takes 24 contributions.
+/// let periods = periods_to_goal(
+///     Decimal::new(10_000_00i128, 2),
+///     Decimal::new(400_00, 2),
+///     Decimal::new(50, 0),
+///     PaymentTiming::End,
+///     0,
+///     RoundingMode::Up,
+/// )
+/// .unwrap();
+/// assert_eq!(periods, Decimal::new(24, 0));
+/// ```
+pub fn periods_to_goal<T>(
+    target: Decimal<T>,
+    contribution: Decimal<T>,
+    rate_bps: Decimal<T>,
+    timing: PaymentTiming,
+    scale: u32,
+    rounding: RoundingMode,
+) -> Result<Decimal<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations
+        + CheckedDivideToScale
+        + CheckedPowDecimals
+        + CheckedSignDecimals
+        + CompareDecimals
+        + RescaleDecimals
+        + TranscendentalDecimals
+        + PowerOfTen
+        + Zero
+        + Copy,
+{
+    let zero = Decimal::zero(0);
+    if target.cmp_value(&zero) == Ordering::Less
+        || contribution.cmp_value(&zero) != Ordering::Greater
+    {
+        return Err(DecimalOperationError::OutOfDomain);
+    }
+    nper(
+        rate_bps,
+        contribution.try_neg()?,
+        Decimal::zero(target.scale()),
+        target,
+        timing,
+        scale,
+        rounding,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_required_periodic_contribution() -> Result<(), DecimalOperationError> {
+        let contribution = |rate, periods, timing, rounding| {
+            required_periodic_contribution(
+                Decimal::new(10_000_00i128, 2),
+                Decimal::new(rate, 0),
+                periods,
+                timing,
+                rounding,
+            )
+        };
+        // Contributing at the start of the months earns one more month: 393.2061 / 1.005
+        assert_eq!(
+            contribution(50, 24, PaymentTiming::End, RoundingMode::HalfEven)?,
+            Decimal::new(393_21, 2)
+        );
+        assert_eq!(
+            contribution(50, 24, PaymentTiming::Beginning, RoundingMode::Up)?,
+            Decimal::new(391_25, 2)
+        );
+        assert_eq!(
+            contribution(0, 3, PaymentTiming::End, RoundingMode::Up)?,
+            Decimal::new(3_333_34, 2)
+        );
+        assert_eq!(
+            contribution(50, 0, PaymentTiming::End, RoundingMode::Up),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+        assert_eq!(
+            required_periodic_contribution(
+                Decimal::new(-100i64, 0),
+                Decimal::new(50, 0),
+                12,
+                PaymentTiming::End,
+                RoundingMode::Up,
+            ),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_periods_to_goal() -> Result<(), DecimalOperationError> {
+        let periods = |contribution, rate, timing| {
+            periods_to_goal(
+                Decimal::new(10_000_00i128, 2),
+                Decimal::new(contribution, 2),
+                Decimal::new(rate, 0),
+                timing,
+                4,
+                RoundingMode::HalfEven,
+            )
+        };
+        assert_eq!(
+            periods(400_00, 50, PaymentTiming::End)?,
+            Decimal::new(23_6154, 4)
+        );
+        assert_eq!(
+            periods(400_00, 50, PaymentTiming::Beginning)?,
+            Decimal::new(23_5046, 4)
+        );
+        assert_eq!(
+            periods(400_00, 0, PaymentTiming::End)?,
+            Decimal::new(25_0000, 4)
+        );
+        assert_eq!(
+            periods(0, 50, PaymentTiming::End),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+use crate::core::{
+    money::exchange_rate::multiply_to_scale, percentage::bps::bps_to_fraction,
+    CheckedDecimalOperations, CheckedMulDiv, Decimal, DecimalOperationError, PowerOfTen,
+    RescaleDecimals, RoundingMode,
+};
+
+/// The interest accrued on a principal and the balance it leaves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InterestAmounts<T = i128> {
+    /// The interest, rounded to the decimals of the principal.
+    pub interest: Decimal<T>,
+    /// The principal plus the interest.
+    pub total: Decimal<T>,
+}
+
+/// Computes the simple interest of a principal: `principal * rate * periods`, without
+/// compounding.
+///
+/// The rate is per period, in basis points like every rate of the
+/// [`interest`](crate::core::interest) module. The periods may be fractional, like `1.5` years. The
+/// interest is computed exactly and rounded once to the decimals of the principal.
+///
+/// # Arguments
+///
+/// * `principal` - The amount interest accrues on.
+/// * `rate_bps` - The rate per period, in basis points.
+/// * `periods` - The number of periods interest accrues for.
+/// * `rounding` - The rounding applied to the interest.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the interest and the total, or a `DecimalOperationError` if the
+/// operation fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{interest::simple_interest, Decimal, RoundingMode};
+///
+/// // 10,000 at 5.25% a year for a year and a half
+/// let amounts = simple_interest(
+///     Decimal::new(10_000_00i64, 2),
+///     Decimal::new(525, 0),
+///     Decimal::new(1_5, 1),
+///     RoundingMode::HalfEven,
+/// )
+/// .unwrap();
+/// assert_eq!(amounts.interest, Decimal::new(787_50, 2));
+/// assert_eq!(amounts.total, Decimal::new(10_787_50, 2));
+/// ```
+pub fn simple_interest<T>(
+    principal: Decimal<T>,
+    rate_bps: Decimal<T>,
+    periods: Decimal<T>,
+    rounding: RoundingMode,
+) -> Result<InterestAmounts<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations + CheckedMulDiv + RescaleDecimals + PowerOfTen + Copy,
+{
+    let rate_scale = rate_bps
+        .scale()
+        .checked_add(periods.scale())
+        .ok_or(DecimalOperationError::ScaleTooLarge)?;
+    // Multiplying to the sum of the scales is exact.
+    let rate = multiply_to_scale(rate_bps, periods, rate_scale, rounding)?;
+    let interest = Decimal::new(
+        multiply_to_scale(
+            principal,
+            bps_to_fraction(Decimal::new(rate, rate_scale))?,
+            principal.scale(),
+            rounding,
+        )?,
+        principal.scale(),
+    );
+    Ok(InterestAmounts {
+        interest,
+        total: principal.try_add(interest)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simple_interest() -> Result<(), DecimalOperationError> {
+        // 1,234.56 * 0.00125 * 7 = 10.8024
+        let amounts = simple_interest(
+            Decimal::new(1_234_56u64, 2),
+            Decimal::new(12_5, 1),
+            Decimal::new(7, 0),
+            RoundingMode::HalfEven,
+        )?;
+        assert_eq!(amounts.interest, Decimal::new(10_80, 2));
+        assert_eq!(amounts.total, Decimal::new(1_245_36, 2));
+
+        let amounts = simple_interest(
+            Decimal::new(0_99i32, 2),
+            Decimal::new(100, 0),
+            Decimal::new(1, 0),
+            RoundingMode::Up,
+        )?;
+        assert_eq!(amounts.interest, Decimal::new(0_01, 2));
+
+        let none = simple_interest(
+            Decimal::new(500i64, 0),
+            Decimal::new(300, 0),
+            Decimal::new(0, 0),
+            RoundingMode::HalfEven,
+        )?;
+        assert_eq!(none.interest, Decimal::new(0, 0));
+        assert_eq!(none.total, Decimal::new(500, 0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_simple_interest_overflow() {
+        assert_eq!(
+            simple_interest(
+                Decimal::new(100u8, 0),
+                Decimal::new(200, 0),
+                Decimal::new(10, 0),
+                RoundingMode::HalfEven,
+            ),
+            Err(DecimalOperationError::Overflow)
+        );
+    }
+}
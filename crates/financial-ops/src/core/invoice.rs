@@ -0,0 +1,384 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::core::{
+    CheckedDecimalOperations, CheckedMulDiv, CompareDecimals, Decimal, DecimalOperationError,
+    Discount, DiscountStack, Line, Percent, PowerOfTen, PricedLine, RescaleDecimals, RoundingMode,
+    StackingPolicy, Zero,
+};
+
+/// A line item of an [`Invoice`]: units at a unit price, the discounts taken off them and the
+/// tax rate charged on what is left.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InvoiceLine<T = i128> {
+    line: Line<T>,
+    discounts: Vec<Discount<T>>,
+    tax_rate: Percent<T>,
+}
+
+impl<T> InvoiceLine<T> {
+    /// Creates a line item without discounts.
+    pub const fn new(unit_price: Decimal<T>, quantity: T, tax_rate: Percent<T>) -> Self {
+        InvoiceLine {
+            line: Line::new(unit_price, quantity),
+            discounts: Vec::new(),
+            tax_rate,
+        }
+    }
+
+    /// Adds a discount after the ones already on the line.
+    pub fn discount(mut self, discount: Discount<T>) -> Self {
+        self.discounts.push(discount);
+        self
+    }
+
+    /// Returns the unit price and quantity of the line.
+    pub fn line(&self) -> &Line<T> {
+        &self.line
+    }
+
+    /// Returns the discounts of the line, in order.
+    pub fn discounts(&self) -> &[Discount<T>] {
+        &self.discounts
+    }
+}
+
+impl<T: Copy> InvoiceLine<T> {
+    /// Returns the tax rate of the line.
+    pub fn tax_rate(&self) -> Percent<T> {
+        self.tax_rate
+    }
+}
+
+/// Where the amounts of an [`Invoice`] are rounded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum InvoiceRounding {
+    /// Rounds the net amount and the tax of every line, and adds the rounded amounts up.
+    #[default]
+    PerLine,
+    /// Rounds the subtotal and the tax of the invoice once, then rounds the lines so that they
+    /// add up to them.
+    PerInvoice,
+}
+
+/// The amounts of one line of an [`InvoiceTotals`], rounded to the decimals of the invoice.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InvoiceLineTotal<T = i128> {
+    /// The line with the audit trail of its discounts.
+    pub priced: PricedLine<T>,
+    /// The amount of the line after discounts and before tax.
+    pub net: Decimal<T>,
+    /// The tax of the line.
+    pub tax: Decimal<T>,
+    /// The amount of the line including tax.
+    pub total: Decimal<T>,
+}
+
+/// The amounts of an [`Invoice`]. The lines always add up to the subtotal, tax and total.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InvoiceTotals<T = i128> {
+    /// The amounts of the lines, in order.
+    pub lines: Vec<InvoiceLineTotal<T>>,
+    /// The sum of the net amounts of the lines.
+    pub subtotal: Decimal<T>,
+    /// The sum of the taxes of the lines.
+    pub tax: Decimal<T>,
+    /// The amount due, including tax.
+    pub total: Decimal<T>,
+}
+
+/// An invoice of line items, with the policy combining their percentage discounts and the
+/// rounding of its amounts.
+///
+/// Whatever the [`InvoiceRounding`], the displayed lines add up to the displayed totals. With
+/// per-invoice rounding, the units the rounded lines are short of, or over, the rounded totals
+/// go one each to the lines whose rounding moved them the most.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{Decimal, Invoice, InvoiceLine, InvoiceRounding, Percent};
+///
+/// let line = InvoiceLine::new(Decimal::new(0_10i64, 2), 1, Percent::new(15, 0));
+/// let invoice = Invoice::new(2).line(line.clone()).line(line.clone()).line(line);
+///
+/// // Every line has 0.015 of tax.
+/// let totals = invoice.clone().totals().unwrap();
+/// assert_eq!(totals.tax, Decimal::new(0_06, 2));
+///
+/// let totals = invoice.strategy(InvoiceRounding::PerInvoice).totals().unwrap();
+/// assert_eq!(totals.tax, Decimal::new(0_04, 2));
+/// assert_eq!(totals.lines[0].tax, Decimal::new(0_01, 2));
+/// assert_eq!(totals.lines[2].tax, Decimal::new(0_02, 2));
+/// assert_eq!(totals.total, Decimal::new(0_34, 2));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Invoice<T = i128> {
+    lines: Vec<InvoiceLine<T>>,
+    policy: StackingPolicy,
+    strategy: InvoiceRounding,
+    decimals: u32,
+    rounding: RoundingMode,
+}
+
+impl<T> Invoice<T> {
+    /// Creates an empty invoice rounded per line, half to even, to `decimals` decimals.
+    pub const fn new(decimals: u32) -> Self {
+        Invoice {
+            lines: Vec::new(),
+            policy: StackingPolicy::Compounding,
+            strategy: InvoiceRounding::PerLine,
+            decimals,
+            rounding: RoundingMode::HalfEven,
+        }
+    }
+
+    /// Sets how the percentage discounts of a line combine.
+    pub fn policy(mut self, policy: StackingPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Sets where the amounts of the invoice are rounded.
+    pub fn strategy(mut self, strategy: InvoiceRounding) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Sets the rounding applied to the amounts of the invoice.
+    pub fn rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Adds a line after the ones already on the invoice.
+    pub fn line(mut self, line: InvoiceLine<T>) -> Self {
+        self.push(line);
+        self
+    }
+
+    /// Adds a line after the ones already on the invoice.
+    pub fn push(&mut self, line: InvoiceLine<T>) {
+        self.lines.push(line);
+    }
+
+    /// Returns the lines of the invoice, in order.
+    pub fn lines(&self) -> &[InvoiceLine<T>] {
+        &self.lines
+    }
+}
+
+impl<T> Invoice<T>
+where
+    T: CheckedDecimalOperations
+        + CheckedMulDiv
+        + RescaleDecimals
+        + PowerOfTen
+        + CompareDecimals
+        + Zero
+        + Copy,
+{
+    /// Computes the amounts of every line and of the invoice.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the totals, or a `DecimalOperationError` if the operation
+    /// fails.
+    pub fn totals(&self) -> Result<InvoiceTotals<T>, DecimalOperationError> {
+        let mut priced = Vec::with_capacity(self.lines.len());
+        let mut nets = Vec::with_capacity(self.lines.len());
+        let mut taxes = Vec::with_capacity(self.lines.len());
+        for line in &self.lines {
+            let mut stack = DiscountStack::new(self.decimals)
+                .policy(self.policy)
+                .rounding(self.rounding);
+            for &discount in &line.discounts {
+                stack.push(discount);
+            }
+            let priced_line = stack.apply(&line.line)?;
+            let net = match self.strategy {
+                InvoiceRounding::PerLine => priced_line
+                    .total
+                    .try_rescale(self.decimals, self.rounding)?,
+                InvoiceRounding::PerInvoice => priced_line.total,
+            };
+            taxes.push(net.try_mul(line.tax_rate.to_fraction()?)?);
+            nets.push(net);
+            priced.push(priced_line);
+        }
+        let (nets, subtotal) = self.round_lines(&nets)?;
+        let (taxes, tax) = self.round_lines(&taxes)?;
+        let mut lines = Vec::with_capacity(priced.len());
+        for ((priced, net), tax) in priced.into_iter().zip(nets).zip(taxes) {
+            lines.push(InvoiceLineTotal {
+                priced,
+                net,
+                tax,
+                total: net.try_add(tax)?,
+            });
+        }
+        Ok(InvoiceTotals {
+            lines,
+            subtotal,
+            tax,
+            total: subtotal.try_add(tax)?,
+        })
+    }
+
+    /// Rounds the amounts of the lines and returns them with their rounded sum, adjusting the
+    /// lines with per-invoice rounding so that they add up to the rounded sum of the exact
+    /// amounts.
+    fn round_lines(
+        &self,
+        amounts: &[Decimal<T>],
+    ) -> Result<(Vec<Decimal<T>>, Decimal<T>), DecimalOperationError> {
+        let zero = Decimal::new(T::zero(), self.decimals);
+        let mut rounded = Vec::with_capacity(amounts.len());
+        let mut rounded_sum = zero;
+        for amount in amounts {
+            let line = amount.try_rescale(self.decimals, self.rounding)?;
+            rounded_sum = rounded_sum.try_add(line)?;
+            rounded.push(line);
+        }
+        if self.strategy == InvoiceRounding::PerLine {
+            return Ok((rounded, rounded_sum));
+        }
+        let total = amounts
+            .iter()
+            .try_fold(zero, |sum, &amount| sum.try_add(amount))?
+            .try_rescale(self.decimals, self.rounding)?;
+        // Every line is less than a unit away from its exact amount, and so is the total, so
+        // the lines are never more than one unit per line away from the total.
+        let short = rounded_sum.cmp_value(&total) == Ordering::Less;
+        let mut gap = if short {
+            total.try_sub(rounded_sum)?
+        } else {
+            rounded_sum.try_sub(total)?
+        };
+        // The line rounded down the most, `exact - rounded`, comes first when short, and the
+        // line rounded up the most comes first otherwise. Comparing `exact_a + rounded_b` with
+        // `exact_b + rounded_a` avoids negative differences with unsigned types.
+        let mut order: Vec<_> = amounts
+            .iter()
+            .copied()
+            .zip(rounded.iter().copied())
+            .enumerate()
+            .collect();
+        order.sort_by(|&(_, (exact_a, rounded_a)), &(_, (exact_b, rounded_b))| {
+            let ordering = match (exact_a.try_add(rounded_b), exact_b.try_add(rounded_a)) {
+                (Ok(left), Ok(right)) => left.cmp_value(&right),
+                _ => Ordering::Equal,
+            };
+            if short {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+        let unit = Decimal::new(
+            T::checked_power_of_ten(0).ok_or(DecimalOperationError::ScaleTooLarge)?,
+            self.decimals,
+        );
+        for (index, _) in order {
+            if gap.value().is_zero() {
+                break;
+            }
+            if let Some(line) = rounded.get_mut(index) {
+                *line = if short {
+                    line.try_add(unit)?
+                } else {
+                    line.try_sub(unit)?
+                };
+                gap = gap.try_sub(unit)?;
+            }
+        }
+        Ok((rounded, total))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn invoice() -> Invoice<i64> {
+        Invoice::new(2)
+            .line(
+                InvoiceLine::new(Decimal::new(3_333, 3), 3, Percent::new(20, 0))
+                    .discount(Discount::Percent(Percent::new(10, 0))),
+            )
+            .line(InvoiceLine::new(
+                Decimal::new(0_125, 3),
+                1,
+                Percent::new(7_5, 1),
+            ))
+    }
+
+    fn assert_lines_add_up(totals: &InvoiceTotals<i64>) -> Result<(), DecimalOperationError> {
+        let zero = Decimal::new(0, 0);
+        let (mut subtotal, mut tax, mut total) = (zero, zero, zero);
+        for line in &totals.lines {
+            subtotal = subtotal.try_add(line.net)?;
+            tax = tax.try_add(line.tax)?;
+            total = total.try_add(line.total)?;
+        }
+        assert!(subtotal.eq_value(&totals.subtotal));
+        assert!(tax.eq_value(&totals.tax));
+        assert!(total.eq_value(&totals.total));
+        Ok(())
+    }
+
+    #[test]
+    fn test_invoice_per_line() -> Result<(), DecimalOperationError> {
+        let invoice = invoice();
+        assert_eq!(invoice.lines().len(), 2);
+        assert_eq!(invoice.lines()[0].discounts().len(), 1);
+        let totals = invoice.totals()?;
+        // 9.999 - 1.00 = 8.999, then 0.125
+        assert_eq!(totals.lines[0].priced.original, Decimal::new(9_999, 3));
+        assert_eq!(totals.lines[0].net, Decimal::new(9_00, 2));
+        assert_eq!(totals.lines[0].tax, Decimal::new(1_80, 2));
+        assert_eq!(totals.lines[1].net, Decimal::new(0_12, 2));
+        assert_eq!(totals.lines[1].tax, Decimal::new(0_01, 2));
+        assert_eq!(totals.subtotal, Decimal::new(9_12, 2));
+        assert_eq!(totals.tax, Decimal::new(1_81, 2));
+        assert_eq!(totals.total, Decimal::new(10_93, 2));
+        assert_lines_add_up(&totals)
+    }
+
+    #[test]
+    fn test_invoice_per_invoice() -> Result<(), DecimalOperationError> {
+        let totals = invoice().strategy(InvoiceRounding::PerInvoice).totals()?;
+        // 8.999 + 0.125 = 9.124, and 1.7998 + 0.009375 = 1.809175
+        assert_eq!(totals.subtotal, Decimal::new(9_12, 2));
+        assert_eq!(totals.tax, Decimal::new(1_81, 2));
+        assert_eq!(totals.lines[0].net, Decimal::new(9_00, 2));
+        assert_eq!(totals.lines[1].net, Decimal::new(0_12, 2));
+        assert_lines_add_up(&totals)?;
+
+        // Three lines of 0.005 add up to 0.015, rounded to 0.02: one line gives its cent back.
+        let line = InvoiceLine::new(Decimal::new(0_005u64, 3), 1, Percent::new(0, 0));
+        let totals = Invoice::new(2)
+            .strategy(InvoiceRounding::PerInvoice)
+            .rounding(RoundingMode::HalfUp)
+            .line(line.clone())
+            .line(line.clone())
+            .line(line)
+            .totals()?;
+        assert_eq!(totals.subtotal, Decimal::new(0_02, 2));
+        assert_eq!(
+            totals
+                .lines
+                .iter()
+                .map(|line| line.net.value())
+                .collect::<Vec<_>>(),
+            [0, 1, 1]
+        );
+
+        let empty = Invoice::<i64>::new(2)
+            .strategy(InvoiceRounding::PerInvoice)
+            .totals()?;
+        assert!(empty.lines.is_empty());
+        assert_eq!(empty.total, Decimal::new(0, 2));
+        Ok(())
+    }
+}
@@ -0,0 +1,228 @@
+use alloc::vec::Vec;
+
+use crate::core::{
+    daycount::to_date, interest::compound::FACTOR_DECIMALS,
+    money::exchange_rate::multiply_to_scale, percentage::bps::bps_to_fraction,
+    CheckedDecimalOperations, CheckedMulDiv, CheckedPowDecimals, Date, Decimal,
+    DecimalOperationError, Percent, PowerOfTen, RescaleDecimals, RoundingMode, Zero,
+};
+
+/// A fee charged on a late payment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LateFee<T = i128> {
+    /// A fixed amount.
+    Flat(Decimal<T>),
+    /// A percentage of the outstanding amount.
+    Percent(Percent<T>),
+    /// Interest on the outstanding amount at a daily rate in basis points, compounded every day
+    /// late: `outstanding * ((1 + rate)^days_late - 1)`.
+    DailyCompounding(Decimal<T>),
+}
+
+/// One fee of a [`LateFees`] breakdown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LateFeeItem<T = i128> {
+    /// The fee charged.
+    pub fee: LateFee<T>,
+    /// The amount of the fee, rounded to the decimals of the outstanding amount.
+    pub amount: Decimal<T>,
+}
+
+/// The late fees charged on a payment, itemized.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LateFees<T = i128> {
+    /// The number of days from the due date to the payment date, zero for a payment on time.
+    pub days_late: u32,
+    /// The fees charged, in the order of the policy, or none within the grace period.
+    pub items: Vec<LateFeeItem<T>>,
+    /// The sum of the fees.
+    pub total: Decimal<T>,
+}
+
+/// The fees charged on payments made after their due date and a grace period.
+///
+/// Payments made within the grace period aren't charged anything. Past it, every fee of the
+/// policy applies and counts the days late from the due date, not from the end of the grace
+/// period. Every fee is rounded once to the decimals of the outstanding amount.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{Decimal, LateFee, LateFeePolicy, Percent};
+///
+/// let policy = LateFeePolicy::new()
+///     .grace_days(5)
+///     .fee(LateFee::Flat(Decimal::new(25_00i128, 2)))
+///     .fee(LateFee::Percent(Percent::new(1_5, 1)))
+///     .fee(LateFee::DailyCompounding(Decimal::new(5, 0)));
+///
+/// let outstanding = Decimal::new(1_000_00, 2);
+/// let fees = policy.assess(outstanding, (2024, 3, 1), (2024, 3, 4)).unwrap();
+/// assert!(fees.items.is_empty());
+///
+/// // 10 days late: 25 + 15 + 1,000 * (1.0005^10 - 1)
+/// let fees = policy.assess(outstanding, (2024, 3, 1), (2024, 3, 11)).unwrap();
+/// assert_eq!(fees.days_late, 10);
+/// assert_eq!(fees.items[2].amount, Decimal::new(5_01, 2));
+/// assert_eq!(fees.total, Decimal::new(45_01, 2));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LateFeePolicy<T = i128> {
+    fees: Vec<LateFee<T>>,
+    grace_days: u32,
+    rounding: RoundingMode,
+}
+
+impl<T> Default for LateFeePolicy<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> LateFeePolicy<T> {
+    /// Creates a policy without fees or grace period, rounding half to even.
+    pub const fn new() -> Self {
+        LateFeePolicy {
+            fees: Vec::new(),
+            grace_days: 0,
+            rounding: RoundingMode::HalfEven,
+        }
+    }
+
+    /// Sets the number of days after the due date a payment can be made without fees.
+    pub fn grace_days(mut self, grace_days: u32) -> Self {
+        self.grace_days = grace_days;
+        self
+    }
+
+    /// Adds a fee after the ones already in the policy.
+    pub fn fee(mut self, fee: LateFee<T>) -> Self {
+        self.fees.push(fee);
+        self
+    }
+
+    /// Sets the rounding applied to every fee.
+    pub fn rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Returns the fees of the policy, in order.
+    pub fn fees(&self) -> &[LateFee<T>] {
+        &self.fees
+    }
+
+    /// Returns the grace period, in days.
+    pub fn grace_period(&self) -> u32 {
+        self.grace_days
+    }
+}
+
+impl<T> LateFeePolicy<T>
+where
+    T: CheckedDecimalOperations
+        + CheckedMulDiv
+        + CheckedPowDecimals
+        + RescaleDecimals
+        + PowerOfTen
+        + Zero
+        + Copy,
+{
+    /// Computes the late fees on an outstanding amount due on `due` and paid on `paid`.
+    ///
+    /// The dates are [`Date`]s, `(year, month, day)` tuples, or `chrono::NaiveDate`s with the
+    /// `chrono` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `outstanding` - The amount left unpaid on the due date.
+    /// * `due` - The due date.
+    /// * `paid` - The payment date.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the itemized fees, or a `DecimalOperationError` if a date
+    /// doesn't exist, reported as `OutOfDomain`, or the operation fails.
+    pub fn assess<D: TryInto<Date>>(
+        &self,
+        outstanding: Decimal<T>,
+        due: D,
+        paid: D,
+    ) -> Result<LateFees<T>, DecimalOperationError> {
+        let days_late = to_date(due)?.days_until(&to_date(paid)?).max(0);
+        let days_late = u32::try_from(days_late).map_err(|_| DecimalOperationError::Overflow)?;
+        let scale = outstanding.scale();
+        let mut fees = LateFees {
+            days_late,
+            items: Vec::new(),
+            total: Decimal::zero(scale),
+        };
+        if days_late <= self.grace_days {
+            return Ok(fees);
+        }
+        for &fee in &self.fees {
+            let amount = match fee {
+                LateFee::Flat(amount) => amount.try_rescale(scale, self.rounding)?,
+                LateFee::Percent(percent) => percent.of(outstanding, self.rounding)?,
+                LateFee::DailyCompounding(rate_bps) => {
+                    let one = Decimal::one(FACTOR_DECIMALS)?;
+                    let growth = one
+                        .try_add(bps_to_fraction(rate_bps)?)?
+                        .try_pow_rounded(days_late, RoundingMode::HalfEven)?
+                        .try_sub(one)?;
+                    Decimal::new(
+                        multiply_to_scale(outstanding, growth, scale, self.rounding)?,
+                        scale,
+                    )
+                }
+            };
+            fees.total = fees.total.try_add(amount)?;
+            fees.items.push(LateFeeItem { fee, amount });
+        }
+        Ok(fees)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_late_fees() -> Result<(), DecimalOperationError> {
+        let policy = LateFeePolicy::new()
+            .grace_days(3)
+            .fee(LateFee::Flat(Decimal::new(10u64, 0)))
+            .fee(LateFee::DailyCompounding(Decimal::new(10, 0)));
+        let outstanding = Decimal::new(2_500_00, 2);
+
+        // Early and on-time payments, and payments within the grace period, are free.
+        for paid in [(2024, 2, 20), (2024, 2, 28), (2024, 3, 2)] {
+            let fees = policy.assess(outstanding, (2024, 2, 28), paid)?;
+            assert!(fees.items.is_empty());
+            assert_eq!(fees.total, Decimal::new(0, 2));
+        }
+
+        // Over the leap day, 4 days late: 2,500 * (1.001^4 - 1) = 10.015...
+        let fees = policy.assess(outstanding, (2024, 2, 28), (2024, 3, 3))?;
+        assert_eq!(fees.days_late, 4);
+        assert_eq!(fees.items[0].amount, Decimal::new(10_00, 2));
+        assert_eq!(fees.items[1].amount, Decimal::new(10_02, 2));
+        assert_eq!(fees.total, Decimal::new(20_02, 2));
+
+        let fees =
+            policy
+                .rounding(RoundingMode::Down)
+                .assess(outstanding, (2024, 2, 28), (2024, 3, 3))?;
+        assert_eq!(fees.items[1].amount, Decimal::new(10_01, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_late_fees_errors() {
+        let policy = LateFeePolicy::<i64>::new().fee(LateFee::Percent(Percent::new(5, 0)));
+        assert_eq!(
+            policy.assess(Decimal::new(100, 0), (2023, 2, 29), (2023, 3, 1)),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+    }
+}
@@ -0,0 +1,344 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::core::{
+    CheckedDecimalOperations, CheckedDivideToScale, CompareDecimals, Decimal,
+    DecimalOperationError, RoundingMode, Zero,
+};
+
+/// How a [`LotLedger`] matches a disposal with the lots it holds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum LotMatching {
+    /// First in, first out: the oldest lots are disposed of first.
+    #[default]
+    Fifo,
+    /// Last in, first out: the newest lots are disposed of first.
+    Lifo,
+    /// Highest in, first out: the lots with the highest unit cost are disposed of first, the
+    /// oldest first among equal costs.
+    Hifo,
+    /// Average cost: the lots are pooled, and a disposal takes its share of the pooled cost.
+    AverageCost,
+}
+
+/// A quantity held by a [`LotLedger`], with what it cost.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Lot<T = i128> {
+    /// The quantity left in the lot.
+    pub quantity: Decimal<T>,
+    /// The cost of the quantity left.
+    pub cost: Decimal<T>,
+}
+
+/// A disposal recorded by a [`LotLedger`], with its gain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Disposal<T = i128> {
+    /// The quantity disposed of.
+    pub quantity: Decimal<T>,
+    /// The quantity times the price it was disposed of at.
+    pub proceeds: Decimal<T>,
+    /// The cost of the lots matched.
+    pub cost: Decimal<T>,
+    /// The proceeds minus the cost, negative for a loss.
+    pub gain: Decimal<T>,
+}
+
+/// A ledger of the lots of an asset, matching disposals with acquisitions to compute their cost
+/// basis and gains.
+///
+/// Every acquisition adds a lot costing its quantity times its price, computed exactly, and every
+/// disposal consumes lots in the order of the [`LotMatching`] policy. The cost of a lot partly
+/// disposed of is its share of the cost of the lot, rounded half to even to the decimals of that
+/// cost, and the rounding stays in the cost of the rest of the lot: a lot disposed of in parts
+/// costs exactly as much as in one go. With [`LotMatching::AverageCost`], the lots are pooled into
+/// one. Losses need a signed type.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{Decimal, LotLedger, LotMatching};
+///
+/// let mut ledger = LotLedger::new(LotMatching::Hifo);
+/// ledger.acquire(Decimal::new(10i64, 0), Decimal::new(100_00, 2)).unwrap();
+/// ledger.acquire(Decimal::new(10, 0), Decimal::new(120_00, 2)).unwrap();
+///
+/// // The 10 at 120.00 are disposed of first, then 5 of those at 100.00.
+/// let disposal = ledger
+///     .dispose(Decimal::new(15, 0), Decimal::new(110_00, 2))
+///     .unwrap();
+/// assert_eq!(disposal.cost, Decimal::new(1_700_00, 2));
+/// assert_eq!(disposal.gain, Decimal::new(-50_00, 2));
+/// assert_eq!(ledger.quantity().unwrap(), Decimal::new(5, 0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LotLedger<T = i128> {
+    matching: LotMatching,
+    lots: Vec<Lot<T>>,
+    disposals: Vec<Disposal<T>>,
+}
+
+impl<T> LotLedger<T> {
+    /// Creates an empty ledger matching disposals with `matching`.
+    pub const fn new(matching: LotMatching) -> Self {
+        LotLedger {
+            matching,
+            lots: Vec::new(),
+            disposals: Vec::new(),
+        }
+    }
+
+    /// Returns how the ledger matches disposals with lots.
+    pub fn matching(&self) -> LotMatching {
+        self.matching
+    }
+
+    /// Returns the lots held, from the oldest, or the pooled lot with
+    /// [`LotMatching::AverageCost`].
+    pub fn lots(&self) -> &[Lot<T>] {
+        &self.lots
+    }
+
+    /// Returns the disposals recorded, in order.
+    pub fn disposals(&self) -> &[Disposal<T>] {
+        &self.disposals
+    }
+}
+
+impl<T> LotLedger<T>
+where
+    T: CheckedDecimalOperations + CheckedDivideToScale + CompareDecimals + Zero + Copy,
+{
+    /// Records the acquisition of `quantity` at `price` a unit.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing nothing, or a `DecimalOperationError` if the quantity isn't
+    /// positive or the price is negative, reported as `OutOfDomain`, or the operation fails.
+    pub fn acquire(
+        &mut self,
+        quantity: Decimal<T>,
+        price: Decimal<T>,
+    ) -> Result<(), DecimalOperationError> {
+        validate(quantity, price)?;
+        let lot = Lot {
+            quantity,
+            cost: quantity.try_mul(price)?,
+        };
+        match (self.matching, self.lots.first_mut()) {
+            (LotMatching::AverageCost, Some(pool)) => {
+                *pool = Lot {
+                    quantity: pool.quantity.try_add(lot.quantity)?,
+                    cost: pool.cost.try_add(lot.cost)?,
+                };
+            }
+            _ => self.lots.push(lot),
+        }
+        Ok(())
+    }
+
+    /// Records the disposal of `quantity` at `price` a unit, matching it with the lots held.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the disposal, or a `DecimalOperationError` if the quantity
+    /// isn't positive or is more than the ledger holds or the price is negative, reported as
+    /// `OutOfDomain`, or the operation fails. The ledger is left unchanged on error.
+    pub fn dispose(
+        &mut self,
+        quantity: Decimal<T>,
+        price: Decimal<T>,
+    ) -> Result<Disposal<T>, DecimalOperationError> {
+        validate(quantity, price)?;
+        if quantity.cmp_value(&self.quantity()?) == Ordering::Greater {
+            return Err(DecimalOperationError::OutOfDomain);
+        }
+        let mut lots = self.lots.clone();
+        let mut remaining = quantity;
+        let mut cost = Decimal::zero(0);
+        while !remaining.is_zero() {
+            let index = self.next_lot(&lots)?;
+            let lot = lots
+                .get_mut(index)
+                .ok_or(DecimalOperationError::OutOfDomain)?;
+            if remaining.cmp_value(&lot.quantity) == Ordering::Less {
+                let share = lot.cost.try_mul(remaining)?.try_div_to_scale(
+                    lot.quantity,
+                    lot.cost.scale(),
+                    RoundingMode::HalfEven,
+                )?;
+                lot.quantity = lot.quantity.try_sub(remaining)?;
+                lot.cost = lot.cost.try_sub(share)?;
+                cost = cost.try_add(share)?;
+                break;
+            }
+            remaining = remaining.try_sub(lot.quantity)?;
+            cost = cost.try_add(lot.cost)?;
+            lots.remove(index);
+        }
+        let proceeds = quantity.try_mul(price)?;
+        let disposal = Disposal {
+            quantity,
+            proceeds,
+            cost,
+            gain: proceeds.try_sub(cost)?,
+        };
+        self.lots = lots;
+        self.disposals.push(disposal);
+        Ok(disposal)
+    }
+
+    /// Returns the quantity held.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the quantity, or a `DecimalOperationError` if the operation
+    /// fails.
+    pub fn quantity(&self) -> Result<Decimal<T>, DecimalOperationError> {
+        self.lots
+            .iter()
+            .try_fold(Decimal::zero(0), |total, lot| total.try_add(lot.quantity))
+    }
+
+    /// Returns the cost basis of the quantity held.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the cost, or a `DecimalOperationError` if the operation
+    /// fails.
+    pub fn cost_basis(&self) -> Result<Decimal<T>, DecimalOperationError> {
+        self.lots
+            .iter()
+            .try_fold(Decimal::zero(0), |total, lot| total.try_add(lot.cost))
+    }
+
+    /// Returns the gains of the disposals recorded, added up.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the gain, or a `DecimalOperationError` if the operation
+    /// fails.
+    pub fn realized_gain(&self) -> Result<Decimal<T>, DecimalOperationError> {
+        self.disposals
+            .iter()
+            .try_fold(Decimal::zero(0), |total, disposal| {
+                total.try_add(disposal.gain)
+            })
+    }
+
+    /// Returns the index of the lot to dispose of next, or `OutOfDomain` if there are no lots.
+    fn next_lot(&self, lots: &[Lot<T>]) -> Result<usize, DecimalOperationError> {
+        let Some(mut highest) = lots.first() else {
+            return Err(DecimalOperationError::OutOfDomain);
+        };
+        Ok(match self.matching {
+            LotMatching::Fifo | LotMatching::AverageCost => 0,
+            LotMatching::Lifo => lots.len().saturating_sub(1),
+            LotMatching::Hifo => {
+                let mut highest_index = 0;
+                for (index, lot) in (0..lots.len()).zip(lots).skip(1) {
+                    // Compares the unit costs without dividing them.
+                    let ordering = lot
+                        .cost
+                        .try_mul(highest.quantity)?
+                        .cmp_value(&highest.cost.try_mul(lot.quantity)?);
+                    if ordering == Ordering::Greater {
+                        highest = lot;
+                        highest_index = index;
+                    }
+                }
+                highest_index
+            }
+        })
+    }
+}
+
+fn validate<T>(quantity: Decimal<T>, price: Decimal<T>) -> Result<(), DecimalOperationError>
+where
+    T: CompareDecimals + Zero,
+{
+    let zero = Decimal::zero(0);
+    if quantity.cmp_value(&zero) != Ordering::Greater || price.cmp_value(&zero) == Ordering::Less {
+        return Err(DecimalOperationError::OutOfDomain);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ledger(matching: LotMatching) -> Result<LotLedger<i64>, DecimalOperationError> {
+        let mut ledger = LotLedger::new(matching);
+        ledger.acquire(Decimal::new(10, 0), Decimal::new(10_00, 2))?;
+        ledger.acquire(Decimal::new(10, 0), Decimal::new(13_00, 2))?;
+        ledger.acquire(Decimal::new(10, 0), Decimal::new(11_00, 2))?;
+        Ok(ledger)
+    }
+
+    #[test]
+    fn test_lot_matching() -> Result<(), DecimalOperationError> {
+        // Disposing of 15 at 12.00, for 180.00
+        for (matching, cost, basis) in [
+            (LotMatching::Fifo, 165_00, 175_00),
+            (LotMatching::Lifo, 175_00, 165_00),
+            (LotMatching::Hifo, 185_00, 155_00),
+            (LotMatching::AverageCost, 170_00, 170_00),
+        ] {
+            let mut ledger = ledger(matching)?;
+            let disposal = ledger.dispose(Decimal::new(15, 0), Decimal::new(12_00, 2))?;
+            assert_eq!(disposal.proceeds, Decimal::new(180_00, 2));
+            assert_eq!(disposal.cost, Decimal::new(cost, 2));
+            assert_eq!(disposal.gain, Decimal::new(180_00 - cost, 2));
+            assert_eq!(ledger.cost_basis()?, Decimal::new(basis, 2));
+            assert_eq!(ledger.quantity()?, Decimal::new(15, 0));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_average_cost_remainders() -> Result<(), DecimalOperationError> {
+        // 3 units costing 31.00 in total, disposed of one at a time at 10.00
+        let mut ledger = LotLedger::new(LotMatching::AverageCost);
+        ledger.acquire(Decimal::new(1i64, 0), Decimal::new(11_00, 2))?;
+        ledger.acquire(Decimal::new(2, 0), Decimal::new(10_00, 2))?;
+        assert_eq!(ledger.lots().len(), 1);
+        let costs: Vec<_> = (0..3)
+            .map(|_| ledger.dispose(Decimal::new(1, 0), Decimal::new(10_00, 2)))
+            .map(|disposal| disposal.map(|disposal| disposal.cost))
+            .collect::<Result<_, _>>()?;
+        assert_eq!(
+            costs,
+            [
+                Decimal::new(10_33, 2),
+                Decimal::new(10_34, 2),
+                Decimal::new(10_33, 2)
+            ]
+        );
+        assert_eq!(ledger.realized_gain()?, Decimal::new(-1_00, 2));
+        assert!(ledger.lots().is_empty());
+        assert_eq!(ledger.disposals().len(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lot_ledger_errors() -> Result<(), DecimalOperationError> {
+        let mut ledger = ledger(LotMatching::Fifo)?;
+        let before = ledger.clone();
+        assert_eq!(
+            ledger.dispose(Decimal::new(31, 0), Decimal::new(1, 0)),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        assert_eq!(
+            ledger.dispose(Decimal::new(0, 0), Decimal::new(1, 0)),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        assert_eq!(
+            ledger.acquire(Decimal::new(1, 0), Decimal::new(-1, 0)),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        assert_eq!(ledger, before);
+        ledger.dispose(Decimal::new(30, 0), Decimal::new(1, 0))?;
+        assert!(ledger.lots().is_empty());
+        Ok(())
+    }
+}
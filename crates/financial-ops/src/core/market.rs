@@ -0,0 +1,332 @@
+use core::cmp::Ordering;
+
+use crate::core::{
+    percentage::bps::fraction_scale, CheckedDecimalOperations, CheckedDivideToScale,
+    CompareDecimals, Decimal, DecimalOperationError, PowerOfTen, RoundingMode, Zero, U256,
+};
+
+/// Computes the mid price of a quote, halfway between the bid and the ask.
+///
+/// The prices are aligned to the larger of their scales, and the mid price has one more decimal
+/// so it is always exact. A crossed quote, with the bid above the ask, has a mid price all the
+/// same.
+///
+/// # Arguments
+///
+/// * `bid` - The best bid.
+/// * `ask` - The best ask.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the mid price, or a `DecimalOperationError` if the operation
+/// fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{mid_price, Decimal};
+///
+/// let mid = mid_price(Decimal::new(100_25i64, 2), Decimal::new(100_5, 1)).unwrap();
+/// assert_eq!(mid, Decimal::new(100_375, 3));
+/// ```
+pub fn mid_price<T>(bid: Decimal<T>, ask: Decimal<T>) -> Result<Decimal<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations + CheckedDivideToScale + PowerOfTen + Copy,
+{
+    let sum = bid.try_add(ask)?;
+    let scale = sum
+        .scale()
+        .checked_add(1)
+        .ok_or(DecimalOperationError::ScaleTooLarge)?;
+    sum.try_div_to_scale(Decimal::new(two()?, 0), scale, RoundingMode::HalfEven)
+}
+
+/// Computes the spread of a quote in basis points of its mid price: `(ask - bid) / mid`.
+///
+/// # Arguments
+///
+/// * `bid` - The best bid.
+/// * `ask` - The best ask.
+/// * `scale` - The number of decimals of the spread, in basis points.
+/// * `rounding` - The rounding applied to the spread.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the spread in basis points, negative for a crossed quote, or a
+/// `DecimalOperationError` if the mid price isn't positive, reported as `OutOfDomain`, or the
+/// operation fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{spread_bps, Decimal, RoundingMode};
+///
+/// // A spread of 0.02 on a mid price of 50.00
+/// let spread = spread_bps(
+///     Decimal::new(49_99i64, 2),
+///     Decimal::new(50_01, 2),
+///     2,
+///     RoundingMode::HalfEven,
+/// )
+/// .unwrap();
+/// assert_eq!(spread, Decimal::new(4_00, 2));
+/// ```
+pub fn spread_bps<T>(
+    bid: Decimal<T>,
+    ask: Decimal<T>,
+    scale: u32,
+    rounding: RoundingMode,
+) -> Result<Decimal<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations + CheckedDivideToScale + CompareDecimals + Zero + Copy,
+{
+    let sum = bid.try_add(ask)?;
+    if sum.cmp_value(&Decimal::zero(0)) != Ordering::Greater {
+        return Err(DecimalOperationError::OutOfDomain);
+    }
+    // The spread over the mid price is twice the spread over the sum of the prices.
+    let spread = ask.try_sub(bid)?;
+    let rate = spread
+        .try_add(spread)?
+        .try_div_to_scale(sum, fraction_scale(scale)?, rounding)?;
+    Ok(Decimal::new(rate.value(), scale))
+}
+
+/// Computes the mid price of a quote weighted by the sizes on both sides, the microprice:
+/// `(bid * ask_size + ask * bid_size) / (bid_size + ask_size)`.
+///
+/// The price leans towards the side with less size, the one more likely to trade through. It is
+/// computed exactly and rounded once to `scale` decimals.
+///
+/// # Arguments
+///
+/// * `bid` - The best bid.
+/// * `bid_size` - The size at the best bid.
+/// * `ask` - The best ask.
+/// * `ask_size` - The size at the best ask.
+/// * `scale` - The number of decimals of the price.
+/// * `rounding` - The rounding applied to the price.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the weighted mid price, or a `DecimalOperationError` if a size
+/// is negative, reported as `OutOfDomain`, both sizes are zero, reported as `DivisionByZero`, or
+/// the operation fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{weighted_mid, Decimal, RoundingMode};
+///
+/// // Three times as much size on the bid pulls the price towards the ask.
+/// let mid = weighted_mid(
+///     Decimal::new(100_00i64, 2),
+///     Decimal::new(300, 0),
+///     Decimal::new(100_10, 2),
+///     Decimal::new(100, 0),
+///     4,
+///     RoundingMode::HalfEven,
+/// )
+/// .unwrap();
+/// assert_eq!(mid, Decimal::new(100_0750, 4));
+/// ```
+pub fn weighted_mid<T>(
+    bid: Decimal<T>,
+    bid_size: Decimal<T>,
+    ask: Decimal<T>,
+    ask_size: Decimal<T>,
+    scale: u32,
+    rounding: RoundingMode,
+) -> Result<Decimal<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations + CheckedDivideToScale + CompareDecimals + Zero + Copy,
+{
+    let zero = Decimal::zero(0);
+    if bid_size.cmp_value(&zero) == Ordering::Less || ask_size.cmp_value(&zero) == Ordering::Less {
+        return Err(DecimalOperationError::OutOfDomain);
+    }
+    bid.try_mul(ask_size)?
+        .try_add(ask.try_mul(bid_size)?)?
+        .try_div_to_scale(bid_size.try_add(ask_size)?, scale, rounding)
+}
+
+/// Computes the volume-weighted average price of trades: `sum(price * size) / sum(size)`.
+///
+/// The trades are raw prices and sizes, with `price_decimals` decimals for every price. The
+/// notional and the volume are added up in a [`U256`], so large volumes of trades with many
+/// decimals don't overflow, and the average is rounded once to `output_decimals` decimals. The
+/// decimals of the sizes cancel out and don't need to be given.
+///
+/// # Arguments
+///
+/// * `trades` - The price and the size of every trade.
+/// * `price_decimals` - The number of decimals of the prices.
+/// * `output_decimals` - The number of decimals of the average price.
+/// * `rounding` - The rounding applied to the average price.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the average price, or a `DecimalOperationError` if there is no
+/// volume, reported as `DivisionByZero`, or the operation fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{vwap, Decimal, RoundingMode};
+///
+/// // 2.5 at 100.10 and 7.5 at 100.20
+/// let trades = [(100_10, 2_5), (100_20, 7_5)];
+/// let average = vwap(&trades, 2, 3, RoundingMode::HalfEven).unwrap();
+/// assert_eq!(average, Decimal::new(100_175, 3));
+/// ```
+pub fn vwap(
+    trades: &[(u128, u128)],
+    price_decimals: u32,
+    output_decimals: u32,
+    rounding: RoundingMode,
+) -> Result<Decimal<u128>, DecimalOperationError> {
+    let (mut notional, mut volume) = (U256::ZERO, U256::ZERO);
+    for &(price, size) in trades {
+        notional = notional
+            .checked_add(U256::widening_mul(price, size))
+            .ok_or(DecimalOperationError::Overflow)?;
+        volume = volume
+            .checked_add(U256::from_u128(size))
+            .ok_or(DecimalOperationError::Overflow)?;
+    }
+    // Scales the notional or the volume so the quotient has `output_decimals` decimals.
+    let (numerator, denominator) = match output_decimals.checked_sub(price_decimals) {
+        Some(excess) => (notional.checked_mul(power_of_ten(excess)?), Some(volume)),
+        None => (
+            Some(notional),
+            volume.checked_mul(power_of_ten(price_decimals - output_decimals)?),
+        ),
+    };
+    let (numerator, denominator) = numerator
+        .zip(denominator)
+        .ok_or(DecimalOperationError::Overflow)?;
+    let (quotient, remainder) = numerator
+        .div_rem(denominator)
+        .ok_or(DecimalOperationError::DivisionByZero)?;
+    let discarded = (!remainder.is_zero()).then(|| {
+        // The remainder is below the denominator.
+        remainder.cmp(&denominator.wrapping_sub(remainder))
+    });
+    let quotient = if rounding.rounds_away_from_zero(false, quotient.is_odd(), discarded)? {
+        quotient.checked_increment()
+    } else {
+        Some(quotient)
+    };
+    let value = quotient
+        .and_then(U256::to_u128)
+        .ok_or(DecimalOperationError::Overflow)?;
+    Ok(Decimal::new(value, output_decimals))
+}
+
+fn power_of_ten(exponent: u32) -> Result<U256, DecimalOperationError> {
+    U256::checked_power_of_ten(exponent).ok_or(DecimalOperationError::ScaleTooLarge)
+}
+
+fn two<T: CheckedDecimalOperations + PowerOfTen + Copy>() -> Result<T, DecimalOperationError> {
+    let one = T::checked_power_of_ten(0).ok_or(DecimalOperationError::ScaleTooLarge)?;
+    Ok(one.add_decimals_checked(one, 0, 0)?.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mid_price() -> Result<(), DecimalOperationError> {
+        assert_eq!(
+            mid_price(Decimal::new(99u16, 0), Decimal::new(101, 0))?,
+            Decimal::new(100_0, 1)
+        );
+        // A crossed quote and negative prices
+        assert_eq!(
+            mid_price(Decimal::new(-1_01i64, 2), Decimal::new(-1_02, 2))?,
+            Decimal::new(-1_015, 3)
+        );
+        assert_eq!(
+            mid_price(Decimal::new(u16::MAX, 0), Decimal::new(1, 0)),
+            Err(DecimalOperationError::Overflow)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_spread_bps() -> Result<(), DecimalOperationError> {
+        let spread = |bid, ask| {
+            spread_bps(
+                Decimal::new(bid, 2),
+                Decimal::new(ask, 2),
+                4,
+                RoundingMode::HalfEven,
+            )
+        };
+        // 0.03 over a mid price of 1.135
+        assert_eq!(spread(1_12i128, 1_15)?, Decimal::new(264_3172, 4));
+        assert_eq!(spread(1_15, 1_12)?, Decimal::new(-264_3172, 4));
+        assert_eq!(spread(1_00, 1_00)?, Decimal::new(0, 4));
+        assert_eq!(spread(-1_00, 1_00), Err(DecimalOperationError::OutOfDomain));
+        Ok(())
+    }
+
+    #[test]
+    fn test_weighted_mid() -> Result<(), DecimalOperationError> {
+        let mid = |bid_size, ask_size| {
+            weighted_mid(
+                Decimal::new(10_00i64, 2),
+                Decimal::new(bid_size, 1),
+                Decimal::new(10_01, 2),
+                Decimal::new(ask_size, 1),
+                3,
+                RoundingMode::HalfEven,
+            )
+        };
+        // Equal sizes give the mid price, and all the size on one side the price on the other.
+        assert_eq!(mid(5_0, 5_0)?, Decimal::new(10_005, 3));
+        assert_eq!(mid(5_0, 0)?, Decimal::new(10_010, 3));
+        assert_eq!(mid(1_0, 2_0)?, Decimal::new(10_003, 3));
+        assert_eq!(mid(0, 0), Err(DecimalOperationError::DivisionByZero));
+        assert_eq!(mid(-1, 1), Err(DecimalOperationError::OutOfDomain));
+        Ok(())
+    }
+
+    #[test]
+    fn test_vwap() -> Result<(), DecimalOperationError> {
+        // 1/3 at 1.00 and 2/3 at 2.00 average 1.666...
+        let trades = [(1_00, 1), (2_00, 2)];
+        assert_eq!(
+            vwap(&trades, 2, 4, RoundingMode::HalfEven)?,
+            Decimal::new(1_6667, 4)
+        );
+        assert_eq!(
+            vwap(&trades, 2, 1, RoundingMode::Down)?,
+            Decimal::new(1_6, 1)
+        );
+        assert_eq!(
+            vwap(&trades, 2, 0, RoundingMode::Unnecessary),
+            Err(DecimalOperationError::PrecisionLoss)
+        );
+        // Notionals of 2^128 and more, with 18 decimals on both the prices and the sizes
+        let price = 3_000 * 10u128.pow(18);
+        let trades = [
+            (price, u128::MAX / 2),
+            (price + 10u128.pow(18), u128::MAX / 2),
+        ];
+        assert_eq!(
+            vwap(&trades, 18, 2, RoundingMode::HalfEven)?,
+            Decimal::new(3_000_50, 2)
+        );
+        assert_eq!(
+            vwap(&[(1, 0)], 0, 0, RoundingMode::HalfEven),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+        assert_eq!(
+            vwap(&[(u128::MAX, 1)], 0, 1, RoundingMode::HalfEven),
+            Err(DecimalOperationError::Overflow)
+        );
+        Ok(())
+    }
+}
@@ -1,9 +1,115 @@
+#[cfg(feature = "bigint")]
+pub mod bigint;
+pub mod billing;
+pub mod bond;
+#[cfg(feature = "borsh")]
+pub mod borsh_serialization;
 pub mod checked;
+pub mod const_operations;
+pub mod conversion;
+#[cfg(feature = "alloc")]
+pub mod curve;
+pub mod daycount;
+pub mod decimal;
+#[cfg(feature = "alloc")]
+pub mod depreciation;
 pub mod error;
+#[cfg(feature = "alloc")]
+pub mod escrow;
+#[cfg(feature = "arbitrary")]
+pub mod fuzzing;
+#[cfg(feature = "alloc")]
+pub mod fx;
 pub mod helpers;
+pub mod interest;
+#[cfg(feature = "alloc")]
+pub mod invoice;
+#[cfg(feature = "alloc")]
+pub mod late_fee;
+#[cfg(feature = "alloc")]
+pub mod lots;
+pub mod market;
+pub mod money;
+pub mod moving_average;
+#[cfg(feature = "num-traits")]
+pub mod num_traits_bridge;
+#[cfg(not(feature = "strict-no-panic"))]
+pub mod overflowing;
+#[cfg(feature = "alloc")]
+pub mod payroll;
+pub mod percentage;
+pub mod pnl;
+pub mod power_of_ten;
+#[cfg(feature = "alloc")]
+pub mod pricing;
+pub mod ratio;
+pub mod rounding;
+#[cfg(feature = "serde")]
+pub mod serialization;
+pub mod statistics;
+#[cfg(feature = "alloc")]
+pub mod tax;
+#[cfg(feature = "proptest")]
+pub mod testing;
+pub mod transcendental;
+#[cfg(not(feature = "strict-no-panic"))]
 pub mod unchecked;
+#[cfg(feature = "alloc")]
+pub mod valuation;
+pub mod wide;
+#[cfg(not(feature = "strict-no-panic"))]
+pub mod wrapping;
 
+pub use billing::*;
+pub use bond::*;
 pub use checked::*;
-pub use unchecked::*;
+pub use const_operations::*;
+pub use conversion::*;
+#[cfg(feature = "alloc")]
+pub use curve::*;
+pub use daycount::*;
+pub use decimal::*;
+#[cfg(feature = "alloc")]
+pub use depreciation::*;
 pub use error::*;
+#[cfg(feature = "alloc")]
+pub use escrow::*;
+#[cfg(feature = "alloc")]
+pub use fx::*;
 pub use helpers::*;
+pub use interest::*;
+#[cfg(feature = "alloc")]
+pub use invoice::*;
+#[cfg(feature = "alloc")]
+pub use late_fee::*;
+#[cfg(feature = "alloc")]
+pub use lots::*;
+pub use market::*;
+pub use money::*;
+pub use moving_average::*;
+#[cfg(feature = "num-traits")]
+pub use num_traits_bridge::*;
+#[cfg(not(feature = "strict-no-panic"))]
+pub use overflowing::*;
+#[cfg(feature = "alloc")]
+pub use payroll::*;
+pub use percentage::*;
+pub use pnl::*;
+pub use power_of_ten::*;
+#[cfg(feature = "alloc")]
+pub use pricing::*;
+pub use ratio::*;
+pub use rounding::*;
+#[cfg(feature = "serde")]
+pub use serialization::*;
+pub use statistics::*;
+#[cfg(feature = "alloc")]
+pub use tax::*;
+pub use transcendental::*;
+#[cfg(not(feature = "strict-no-panic"))]
+pub use unchecked::*;
+#[cfg(feature = "alloc")]
+pub use valuation::*;
+pub use wide::*;
+#[cfg(not(feature = "strict-no-panic"))]
+pub use wrapping::*;
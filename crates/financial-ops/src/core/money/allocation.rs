@@ -0,0 +1,740 @@
+use alloc::{vec, vec::Vec};
+use core::{cmp::Ordering, fmt};
+
+use crate::core::{
+    AllocationError, CheckedAdd, CheckedDecimalOperations, CheckedMulDiv, CheckedMulDivRem,
+    CheckedNeg, CheckedSub, Currency, Decimal, DecimalOperationError, Money, Percent, PowerOfTen,
+    RescaleDecimals, RoundingMode, Signedness, Zero,
+};
+
+impl<C, T> Money<C, T>
+where
+    T: CheckedMulDiv + CheckedAdd + CheckedSub + CheckedNeg + Signedness + Zero + PowerOfTen + Copy,
+{
+    /// Splits the amount into parts proportional to `ratios`, all with the decimals of the amount,
+    /// whose sum is exactly the amount.
+    ///
+    /// Each part is first truncated towards zero, then the units left over, in the last decimal
+    /// of the amount, are handed out one at a time to the earliest parts with a non-zero ratio.
+    /// Round the amount to the minor units of its currency first to split it in cents.
+    ///
+    /// # Arguments
+    ///
+    /// * `ratios` - The non-negative weight of each part.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing one part per ratio, or a `DecimalOperationError` if a ratio
+    /// is negative, the ratios sum to zero or their sum overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use financial_ops::{Money, Usd};
+    ///
+    /// let parts = Money::<Usd, i64>::new(100_00, 2).allocate(&[70, 20, 10]).unwrap();
+    /// assert_eq!(parts, [Money::new(70_00, 2), Money::new(20_00, 2), Money::new(10_00, 2)]);
+    ///
+    /// let parts = Money::<Usd, i64>::new(0_05, 2).allocate(&[3, 7]).unwrap();
+    /// assert_eq!(parts, [Money::new(0_02, 2), Money::new(0_03, 2)]);
+    /// ```
+    pub fn allocate(self, ratios: &[T]) -> Result<Vec<Self>, DecimalOperationError> {
+        let (amount, decimals) = self.into_parts();
+        let total = total_weight(ratios)?;
+        let mut parts = Vec::with_capacity(ratios.len());
+        let mut remainder = amount;
+        for &ratio in ratios {
+            let part = amount.checked_mul_div(ratio, total, RoundingMode::Down)?;
+            // Truncated parts never add up to more than the amount.
+            remainder = remainder
+                .checked_sub(&part)
+                .ok_or(DecimalOperationError::Overflow)?;
+            parts.push(part);
+        }
+        let unit = unit_towards(&remainder)?;
+        for (part, ratio) in parts.iter_mut().zip(ratios) {
+            if remainder.is_zero() {
+                break;
+            }
+            if ratio.is_zero() {
+                continue;
+            }
+            *part = part
+                .checked_add(&unit)
+                .ok_or(DecimalOperationError::Overflow)?;
+            remainder = remainder
+                .checked_sub(&unit)
+                .ok_or(DecimalOperationError::Overflow)?;
+        }
+        Ok(parts
+            .into_iter()
+            .map(|part| Money::new(part, decimals))
+            .collect())
+    }
+
+    /// Splits the amount into `parts` equal parts whose sum is exactly the amount, the earliest
+    /// parts taking one more unit when it doesn't divide evenly.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the parts, or a `DecimalOperationError` if `parts` is zero
+    /// or doesn't fit in the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use financial_ops::{Money, Usd};
+    ///
+    /// let parts = Money::<Usd, i64>::new(100_00, 2).split(3).unwrap();
+    /// assert_eq!(parts, [Money::new(33_34, 2), Money::new(33_33, 2), Money::new(33_33, 2)]);
+    /// ```
+    pub fn split(self, parts: usize) -> Result<Vec<Self>, DecimalOperationError> {
+        let one = T::checked_power_of_ten(0).ok_or(DecimalOperationError::ScaleTooLarge)?;
+        self.allocate(&vec![one; parts])
+    }
+}
+
+impl<C, T> Money<C, T>
+where
+    T: CheckedMulDiv
+        + CheckedDecimalOperations
+        + RescaleDecimals
+        + CheckedAdd
+        + CheckedSub
+        + CheckedNeg
+        + Signedness
+        + Zero
+        + PowerOfTen
+        + Copy,
+{
+    /// Adds a tip to the bill and splits it between `people`, in parts with the decimals of the
+    /// bill whose sum is exactly the bill plus the tip.
+    ///
+    /// The tip is rounded once to the decimals of the bill with `rounding`, then the total is
+    /// split like [`Money::split`], the earliest people paying one more unit when it doesn't
+    /// divide evenly.
+    ///
+    /// # Arguments
+    ///
+    /// * `people` - The number of people sharing the bill.
+    /// * `tip` - The tip, as a percentage of the bill.
+    /// * `rounding` - The rounding applied to the tip.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the share of every person, or a `DecimalOperationError` if
+    /// `people` is zero or the total doesn't fit in the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use financial_ops::{Money, Percent, RoundingMode, Usd};
+    ///
+    /// // 47.85 plus a 15% tip of 7.1775, rounded to 7.18
+    /// let bill = Money::<Usd, i64>::new(47_85, 2);
+    /// let shares = bill.split_bill(2, Percent::new(15, 0), RoundingMode::HalfEven).unwrap();
+    /// assert_eq!(shares, [Money::new(27_52, 2), Money::new(27_51, 2)]);
+    /// ```
+    pub fn split_bill(
+        self,
+        people: usize,
+        tip: Percent<T>,
+        rounding: RoundingMode,
+    ) -> Result<Vec<Self>, DecimalOperationError> {
+        let (amount, decimals) = self.into_parts();
+        let tip = tip.of(Decimal::new(amount, decimals), rounding)?.value();
+        let (total, _) = amount.add_decimals_checked(tip, decimals, decimals)?;
+        Money::new(total, decimals).split(people)
+    }
+}
+
+impl<C, T> Money<C, T>
+where
+    T: CheckedMulDivRem
+        + CheckedAdd
+        + CheckedSub
+        + CheckedNeg
+        + Signedness
+        + Zero
+        + PowerOfTen
+        + PartialOrd
+        + Copy,
+{
+    /// Distributes the amount proportionally to `weights` with the largest remainder method, in
+    /// parts with the decimals of the amount whose sum is exactly the amount.
+    ///
+    /// Each part is first truncated towards zero, then the units left over, in the last decimal
+    /// of the amount, go one each to the parts that lost the largest fraction of a unit. Ties go
+    /// to the earliest part. Unlike [`Money::allocate`], the result doesn't favor the first
+    /// parts.
+    ///
+    /// # Arguments
+    ///
+    /// * `weights` - The non-negative weight of each part.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing one part per weight, or a `DecimalOperationError` if a
+    /// weight is negative, the weights sum to zero or their sum overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use financial_ops::{Money, Usd};
+    ///
+    /// // Shares of 0.011, 0.022 and 0.077: the cent left goes to the part that lost the most.
+    /// let parts = Money::<Usd, i64>::new(0_11, 2).prorate(&[1, 2, 7]).unwrap();
+    /// assert_eq!(parts, [Money::new(0_01, 2), Money::new(0_02, 2), Money::new(0_08, 2)]);
+    /// ```
+    pub fn prorate(self, weights: &[T]) -> Result<Vec<Self>, DecimalOperationError> {
+        let (amount, decimals) = self.into_parts();
+        let total = total_weight(weights)?;
+        let mut parts = Vec::with_capacity(weights.len());
+        let mut remainders = Vec::with_capacity(weights.len());
+        let mut leftover = amount;
+        for &weight in weights {
+            let (part, remainder) = amount.checked_mul_div_rem(weight, total)?;
+            leftover = leftover
+                .checked_sub(&part)
+                .ok_or(DecimalOperationError::Overflow)?;
+            parts.push(part);
+            remainders.push(remainder);
+        }
+        // The remainders have the sign of the amount, so the largest magnitudes come first
+        // when sorting towards zero.
+        let negative = amount.is_negative();
+        let mut order: Vec<usize> = (0..weights.len()).collect();
+        order.sort_by(|&a, &b| {
+            let ordering = remainders[a]
+                .partial_cmp(&remainders[b])
+                .unwrap_or(Ordering::Equal);
+            if negative {
+                ordering
+            } else {
+                ordering.reverse()
+            }
+        });
+        let unit = unit_towards(&leftover)?;
+        for index in order {
+            if leftover.is_zero() {
+                break;
+            }
+            if let Some(part) = parts.get_mut(index) {
+                *part = part
+                    .checked_add(&unit)
+                    .ok_or(DecimalOperationError::Overflow)?;
+                leftover = leftover
+                    .checked_sub(&unit)
+                    .ok_or(DecimalOperationError::Overflow)?;
+            }
+        }
+        Ok(parts
+            .into_iter()
+            .map(|part| Money::new(part, decimals))
+            .collect())
+    }
+}
+
+/// A recipient of a constrained allocation: a weight, and optionally the least and the most it
+/// can receive.
+pub struct Recipient<C, T = i128> {
+    weight: T,
+    floor: Option<Money<C, T>>,
+    cap: Option<Money<C, T>>,
+}
+
+impl<C, T> Recipient<C, T> {
+    /// Creates a recipient with a non-negative weight and no floor or cap.
+    pub const fn new(weight: T) -> Self {
+        Recipient {
+            weight,
+            floor: None,
+            cap: None,
+        }
+    }
+
+    /// Sets the least amount the recipient receives.
+    pub fn floor(mut self, floor: Money<C, T>) -> Self {
+        self.floor = Some(floor);
+        self
+    }
+
+    /// Sets the most the recipient receives.
+    pub fn cap(mut self, cap: Money<C, T>) -> Self {
+        self.cap = Some(cap);
+        self
+    }
+}
+
+impl<C, T> Money<C, T>
+where
+    T: CheckedMulDivRem
+        + CheckedAdd
+        + CheckedSub
+        + CheckedNeg
+        + Signedness
+        + Zero
+        + PowerOfTen
+        + RescaleDecimals
+        + PartialOrd
+        + Copy,
+{
+    /// Distributes the amount proportionally to the weights of `recipients` like
+    /// [`Money::prorate`], keeping every part between the floor and the cap of its recipient.
+    ///
+    /// Parts that fall outside their bounds are pinned to them and the rest of the amount is
+    /// prorated again between the remaining recipients, until every part fits. Floors are rounded
+    /// up and caps down to the decimals of the amount.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing one part per recipient, or an `AllocationError` if the
+    /// amount is negative, a floor is above its cap, the floors add up to more than the amount
+    /// or the caps and weights can't absorb all of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use financial_ops::{Money, Recipient, Usd};
+    ///
+    /// let pool = Money::<Usd, i64>::new(1000_00, 2);
+    /// let payouts = pool
+    ///     .allocate_constrained(&[
+    ///         Recipient::new(6).cap(Money::new(500_00, 2)),
+    ///         Recipient::new(3),
+    ///         Recipient::new(1).floor(Money::new(150_00, 2)),
+    ///     ])
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     payouts,
+    ///     [Money::new(500_00, 2), Money::new(350_00, 2), Money::new(150_00, 2)]
+    /// );
+    /// ```
+    pub fn allocate_constrained(
+        self,
+        recipients: &[Recipient<C, T>],
+    ) -> Result<Vec<Self>, AllocationError> {
+        let (amount, decimals) = self.into_parts();
+        if amount.is_negative() {
+            return Err(DecimalOperationError::OutOfDomain.into());
+        }
+        let mut bounds = Vec::with_capacity(recipients.len());
+        let mut floors = T::zero();
+        let mut caps = Some(T::zero());
+        for (index, recipient) in recipients.iter().enumerate() {
+            let floor = recipient
+                .floor
+                .map(|floor| floor.try_rescale(decimals, RoundingMode::Ceiling))
+                .transpose()?
+                .map(|floor| floor.amount());
+            let cap = recipient
+                .cap
+                .map(|cap| cap.try_rescale(decimals, RoundingMode::Floor))
+                .transpose()?
+                .map(|cap| cap.amount());
+            if let (Some(floor), Some(cap)) = (floor, cap) {
+                if floor > cap {
+                    return Err(AllocationError::FloorAboveCap { index });
+                }
+            }
+            if let Some(floor) = floor {
+                floors = floors
+                    .checked_add(&floor)
+                    .ok_or(AllocationError::Infeasible)?;
+            }
+            // Caps adding up past the maximum of the type can absorb any amount.
+            caps = caps.zip(cap).and_then(|(caps, cap)| caps.checked_add(&cap));
+            bounds.push((floor, cap));
+        }
+        if floors > amount || caps.is_some_and(|caps| caps < amount) {
+            return Err(AllocationError::Infeasible);
+        }
+
+        let mut pinned: Vec<Option<T>> = vec![None; recipients.len()];
+        // Every round pins at least one more recipient, or returns.
+        loop {
+            let mut remaining = amount;
+            for part in pinned.iter().flatten() {
+                remaining = remaining
+                    .checked_sub(part)
+                    .ok_or(DecimalOperationError::Overflow)?;
+            }
+            let active: Vec<usize> = (0..recipients.len())
+                .filter(|&index| pinned.get(index).is_some_and(Option::is_none))
+                .collect();
+            let weights: Vec<T> = active
+                .iter()
+                .filter_map(|&index| recipients.get(index))
+                .map(|recipient| recipient.weight)
+                .collect();
+            let shares: Vec<T> = if weights.iter().all(Zero::is_zero) {
+                if !remaining.is_zero() {
+                    return Err(AllocationError::Infeasible);
+                }
+                vec![T::zero(); active.len()]
+            } else {
+                Money::<C, T>::new(remaining, decimals)
+                    .prorate(&weights)?
+                    .into_iter()
+                    .map(|share| share.amount())
+                    .collect()
+            };
+
+            let mut excess = T::zero();
+            let mut deficit = T::zero();
+            for (share, &index) in shares.iter().zip(&active) {
+                let (floor, cap) = bounds.get(index).copied().unwrap_or((None, None));
+                if let Some(cap) = cap.filter(|cap| share > cap) {
+                    let over = share
+                        .checked_sub(&cap)
+                        .ok_or(DecimalOperationError::Overflow)?;
+                    excess = excess
+                        .checked_add(&over)
+                        .ok_or(DecimalOperationError::Overflow)?;
+                }
+                if let Some(floor) = floor.filter(|floor| share < floor) {
+                    let under = floor
+                        .checked_sub(share)
+                        .ok_or(DecimalOperationError::Overflow)?;
+                    deficit = deficit
+                        .checked_add(&under)
+                        .ok_or(DecimalOperationError::Overflow)?;
+                }
+            }
+            if excess.is_zero() && deficit.is_zero() {
+                for (share, &index) in shares.iter().zip(&active) {
+                    if let Some(part) = pinned.get_mut(index) {
+                        *part = Some(*share);
+                    }
+                }
+                return Ok(pinned
+                    .into_iter()
+                    .map(|part| Money::new(part.unwrap_or_else(T::zero), decimals))
+                    .collect());
+            }
+            // Pin the side that is furthest off, then prorate the rest again.
+            let pin_caps = excess >= deficit;
+            for (share, &index) in shares.iter().zip(&active) {
+                let (floor, cap) = bounds.get(index).copied().unwrap_or((None, None));
+                let bound = if pin_caps {
+                    cap.filter(|cap| share > cap)
+                } else {
+                    floor.filter(|floor| share < floor)
+                };
+                if let (Some(bound), Some(part)) = (bound, pinned.get_mut(index)) {
+                    *part = Some(bound);
+                }
+            }
+        }
+    }
+}
+
+// The traits below are implemented by hand so they don't require the currency marker to
+// implement them.
+
+impl<C, T: Clone> Clone for Recipient<C, T> {
+    fn clone(&self) -> Self {
+        Recipient {
+            weight: self.weight.clone(),
+            floor: self.floor.clone(),
+            cap: self.cap.clone(),
+        }
+    }
+}
+
+impl<C, T: Copy> Copy for Recipient<C, T> {}
+
+impl<C, T: PartialEq> PartialEq for Recipient<C, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.weight == other.weight && self.floor == other.floor && self.cap == other.cap
+    }
+}
+
+impl<C, T: Eq> Eq for Recipient<C, T> {}
+
+impl<C: Currency, T: fmt::Debug> fmt::Debug for Recipient<C, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Recipient")
+            .field("weight", &self.weight)
+            .field("floor", &self.floor)
+            .field("cap", &self.cap)
+            .finish()
+    }
+}
+
+/// Sums the weights of an allocation, rejecting negative weights and a zero total.
+fn total_weight<T: CheckedAdd + Signedness + Zero>(
+    weights: &[T],
+) -> Result<T, DecimalOperationError> {
+    let mut total = T::zero();
+    for weight in weights {
+        if weight.is_negative() {
+            return Err(DecimalOperationError::OutOfDomain);
+        }
+        total = total
+            .checked_add(weight)
+            .ok_or(DecimalOperationError::Overflow)?;
+    }
+    if total.is_zero() {
+        return Err(DecimalOperationError::DivisionByZero);
+    }
+    Ok(total)
+}
+
+/// Returns `1`, or `-1` if `value` is negative.
+fn unit_towards<T: CheckedNeg + Signedness + PowerOfTen>(
+    value: &T,
+) -> Result<T, DecimalOperationError> {
+    let one = T::checked_power_of_ten(0).ok_or(DecimalOperationError::ScaleTooLarge)?;
+    if value.is_negative() {
+        one.checked_neg().ok_or(DecimalOperationError::Underflow)
+    } else {
+        Ok(one)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Usd;
+
+    #[test]
+    fn test_money_allocate() -> Result<(), DecimalOperationError> {
+        let amount = Money::<Usd, i64>::new(100_00, 2);
+        assert_eq!(
+            amount.allocate(&[1, 1, 1])?,
+            [
+                Money::new(33_34, 2),
+                Money::new(33_33, 2),
+                Money::new(33_33, 2)
+            ]
+        );
+        // Zero ratios get nothing, not even leftover units.
+        assert_eq!(
+            Money::<Usd, u64>::new(0_05, 2).allocate(&[0, 1, 1])?,
+            [Money::new(0, 2), Money::new(0_03, 2), Money::new(0_02, 2)]
+        );
+        // Leftover units of a negative amount are negative too.
+        assert_eq!(
+            Money::<Usd, i64>::new(-0_05, 2).allocate(&[1, 1])?,
+            [Money::new(-0_03, 2), Money::new(-0_02, 2)]
+        );
+        // The product of the amount and a ratio doesn't need to fit in the type.
+        let parts = Money::<Usd, u64>::new(u64::MAX, 2).allocate(&[u64::MAX - 1, 1])?;
+        assert_eq!(parts, [Money::new(u64::MAX - 1, 2), Money::new(1, 2)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_money_allocate_errors() {
+        let amount = Money::<Usd, i64>::new(100_00, 2);
+        assert_eq!(
+            amount.allocate(&[]),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+        assert_eq!(
+            amount.allocate(&[0, 0]),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+        assert_eq!(
+            amount.allocate(&[2, -1]),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        assert_eq!(
+            amount.allocate(&[i64::MAX, 1]),
+            Err(DecimalOperationError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_money_split() -> Result<(), DecimalOperationError> {
+        let parts = Money::<Usd, u32>::new(10, 0).split(4)?;
+        assert_eq!(
+            parts,
+            [
+                Money::new(3, 0),
+                Money::new(3, 0),
+                Money::new(2, 0),
+                Money::new(2, 0)
+            ]
+        );
+        assert_eq!(
+            Money::<Usd, u32>::new(10, 0).split(0),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+        assert_eq!(
+            Money::<Usd, u8>::new(10, 0).split(256),
+            Err(DecimalOperationError::Overflow)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_money_split_bill() -> Result<(), DecimalOperationError> {
+        let bill = Money::<Usd, u64>::new(100_00, 2);
+        let shares = bill.split_bill(3, Percent::new(18, 0), RoundingMode::HalfEven)?;
+        assert_eq!(
+            shares,
+            [
+                Money::new(39_34, 2),
+                Money::new(39_33, 2),
+                Money::new(39_33, 2)
+            ]
+        );
+        // 10.01 plus 12.5% of it, 1.25125 rounded up to 1.26
+        let bill = Money::<Usd, u64>::new(10_01, 2);
+        let shares = bill.split_bill(4, Percent::new(12_5, 1), RoundingMode::Up)?;
+        assert_eq!(
+            shares,
+            [
+                Money::new(2_82, 2),
+                Money::new(2_82, 2),
+                Money::new(2_82, 2),
+                Money::new(2_81, 2)
+            ]
+        );
+        assert_eq!(
+            bill.split_bill(0, Percent::new(10, 0), RoundingMode::HalfEven),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+        assert_eq!(
+            Money::<Usd, u64>::new(u64::MAX, 2).split_bill(
+                2,
+                Percent::new(10, 0),
+                RoundingMode::HalfEven
+            ),
+            Err(DecimalOperationError::Overflow)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_money_prorate() -> Result<(), DecimalOperationError> {
+        // Equal remainders go to the earliest parts.
+        assert_eq!(
+            Money::<Usd, i64>::new(100_00, 2).prorate(&[1, 1, 1])?,
+            [
+                Money::new(33_34, 2),
+                Money::new(33_33, 2),
+                Money::new(33_33, 2)
+            ]
+        );
+        // Shares of 1.4, 2.1 and 3.5: the unit left goes to the last part.
+        assert_eq!(
+            Money::<Usd, u32>::new(7, 0).prorate(&[2, 3, 5])?,
+            [Money::new(1, 0), Money::new(2, 0), Money::new(4, 0)]
+        );
+        assert_eq!(
+            Money::<Usd, i64>::new(-0_11, 2).prorate(&[1, 2, 7])?,
+            [
+                Money::new(-0_01, 2),
+                Money::new(-0_02, 2),
+                Money::new(-0_08, 2)
+            ]
+        );
+        assert_eq!(
+            Money::<Usd, i64>::new(0_03, 2).prorate(&[0, 5, 5])?,
+            [Money::new(0, 2), Money::new(0_02, 2), Money::new(0_01, 2)]
+        );
+        // The product of the amount and a weight doesn't need to fit in the type.
+        assert_eq!(
+            Money::<Usd, u64>::new(u64::MAX, 2).prorate(&[1, u64::MAX - 1])?,
+            [Money::new(1, 2), Money::new(u64::MAX - 1, 2)]
+        );
+        assert_eq!(
+            Money::<Usd, i64>::new(1_00, 2).prorate(&[1, -1]),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_money_allocate_constrained() -> Result<(), AllocationError> {
+        let pool = Money::<Usd, i64>::new(1000_00, 2);
+        // Unconstrained, the parts are 600.00, 300.00 and 100.00.
+        let parts = pool.allocate_constrained(&[
+            Recipient::new(6).cap(Money::new(500_00, 2)),
+            Recipient::new(3),
+            Recipient::new(1).floor(Money::new(150_00, 2)),
+        ])?;
+        assert_eq!(
+            parts,
+            [
+                Money::new(500_00, 2),
+                Money::new(350_00, 2),
+                Money::new(150_00, 2)
+            ]
+        );
+
+        // The capped overflow cascades into the second cap.
+        let parts = pool.allocate_constrained(&[
+            Recipient::new(1).cap(Money::new(100_00, 2)),
+            Recipient::new(1).cap(Money::new(300_00, 2)),
+            Recipient::new(1),
+        ])?;
+        assert_eq!(
+            parts,
+            [
+                Money::new(100_00, 2),
+                Money::new(300_00, 2),
+                Money::new(600_00, 2)
+            ]
+        );
+
+        // Zero weights still receive their floor, and bounds are rounded inwards.
+        let parts = Money::<Usd, i64>::new(10_00, 2).allocate_constrained(&[
+            Recipient::new(0).floor(Money::new(1_001, 3)),
+            Recipient::new(1).cap(Money::new(4_509, 3)),
+            Recipient::new(1),
+        ])?;
+        assert_eq!(
+            parts,
+            [
+                Money::new(1_01, 2),
+                Money::new(4_50, 2),
+                Money::new(4_49, 2)
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_money_allocate_constrained_errors() {
+        let pool = Money::<Usd, i64>::new(100_00, 2);
+        assert_eq!(
+            pool.allocate_constrained(&[
+                Recipient::new(1),
+                Recipient::new(1)
+                    .floor(Money::new(20_00, 2))
+                    .cap(Money::new(10_00, 2)),
+            ]),
+            Err(AllocationError::FloorAboveCap { index: 1 })
+        );
+        assert_eq!(
+            pool.allocate_constrained(&[
+                Recipient::new(1).floor(Money::new(60_00, 2)),
+                Recipient::new(1).floor(Money::new(60_00, 2)),
+            ]),
+            Err(AllocationError::Infeasible)
+        );
+        assert_eq!(
+            pool.allocate_constrained(&[
+                Recipient::new(1).cap(Money::new(40_00, 2)),
+                Recipient::new(1).cap(Money::new(40_00, 2)),
+            ]),
+            Err(AllocationError::Infeasible)
+        );
+        // Only a recipient without weight is left to absorb the rest.
+        assert_eq!(
+            pool.allocate_constrained(&[
+                Recipient::new(1).cap(Money::new(40_00, 2)),
+                Recipient::new(0),
+            ]),
+            Err(AllocationError::Infeasible)
+        );
+        assert_eq!(
+            Money::<Usd, i64>::new(-1_00, 2).allocate_constrained(&[Recipient::new(1)]),
+            Err(AllocationError::Decimal(DecimalOperationError::OutOfDomain))
+        );
+    }
+}
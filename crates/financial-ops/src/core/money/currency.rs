@@ -0,0 +1,92 @@
+use crate::core::FormatLocale;
+
+/// A currency, used as a type parameter of [`Money`](crate::core::Money) so amounts in different
+/// currencies can't be mixed.
+///
+/// Implement it on a marker type to add a currency that isn't provided by the crate.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{Currency, Money};
+///
+/// struct Pyusd;
+///
+/// impl Currency for Pyusd {
+///     const CODE: &'static str = "PYUSD";
+///     const DECIMALS: u32 = 6;
+/// }
+///
+/// let balance = Money::<Pyusd, u64>::from_minor_units(1_500000);
+/// assert_eq!(balance.decimals(), 6);
+/// ```
+pub trait Currency {
+    /// The code of the currency, like `"USD"`.
+    const CODE: &'static str;
+
+    /// The number of decimals of the smallest unit of the currency, like `2` for cents.
+    const DECIMALS: u32;
+
+    /// The symbol rendered with formatted amounts, like `"$"`. Defaults to the code.
+    const SYMBOL: &'static str = Self::CODE;
+
+    /// The separators, grouping and symbol placement used to format amounts by default.
+    const LOCALE: FormatLocale = FormatLocale::EN_US;
+
+    /// The increment cash amounts are rounded to, in minor units, like `5` for the `0.05` steps
+    /// of Swiss francs. Defaults to `1`, where cash rounding doesn't change anything.
+    const CASH_INCREMENT: u8 = 1;
+}
+
+macro_rules! impl_currencies {
+    ($($name:ident $code:literal $decimals:literal $symbol:literal $locale:ident $cash_increment:literal $description:literal;)*) => ($(
+        #[doc = concat!("The ", $description, " (`", $code, "`), with ", $decimals, " decimals.")]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+        pub struct $name;
+
+        impl Currency for $name {
+            const CODE: &'static str = $code;
+            const DECIMALS: u32 = $decimals;
+            const SYMBOL: &'static str = $symbol;
+            const LOCALE: FormatLocale = FormatLocale::$locale;
+            const CASH_INCREMENT: u8 = $cash_increment;
+        }
+    )*)
+}
+
+impl_currencies! {
+    Usd "USD" 2 "$" EN_US 1 "United States dollar";
+    Eur "EUR" 2 "€" DE_DE 1 "Euro";
+    Gbp "GBP" 2 "£" EN_GB 1 "Pound sterling";
+    Jpy "JPY" 0 "¥" JA_JP 1 "Japanese yen";
+    Chf "CHF" 2 "CHF" DE_CH 5 "Swiss franc";
+    Cad "CAD" 2 "$" EN_US 5 "Canadian dollar";
+    Aud "AUD" 2 "$" EN_US 5 "Australian dollar";
+    Cny "CNY" 2 "¥" EN_US 1 "Renminbi";
+    Inr "INR" 2 "₹" EN_IN 1 "Indian rupee";
+    Brl "BRL" 2 "R$" PT_BR 1 "Brazilian real";
+    Mxn "MXN" 2 "$" EN_US 1 "Mexican peso";
+    Sol "SOL" 9 "◎" EN_US 1 "Solana native token";
+    Eth "ETH" 18 "Ξ" EN_US 1 "Ether";
+    Btc "BTC" 8 "₿" EN_US 1 "Bitcoin";
+    Usdc "USDC" 6 "USDC" EN_US 1 "USD Coin stablecoin";
+    Usdt "USDT" 6 "USDT" EN_US 1 "Tether stablecoin";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_currency_metadata() {
+        assert_eq!(Usd::CODE, "USD");
+        assert_eq!(Usd::DECIMALS, 2);
+        assert_eq!(Jpy::DECIMALS, 0);
+        assert_eq!(Eur::SYMBOL, "€");
+        assert_eq!(Eur::LOCALE, FormatLocale::DE_DE);
+        assert_eq!(Chf::CASH_INCREMENT, 5);
+        assert_eq!(Usd::CASH_INCREMENT, 1);
+        assert_eq!(Eth::DECIMALS, 18);
+        assert_eq!(Usdc::CODE, "USDC");
+    }
+}
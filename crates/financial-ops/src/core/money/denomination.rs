@@ -0,0 +1,281 @@
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
+use crate::core::{
+    Btc, Currency, DecimalOperationError, Eth, Money, RescaleDecimals, RoundingMode, Sol, Usdc,
+    Usdt,
+};
+
+/// A unit an amount of the currency `C` can be counted in, like lamports or SOL for Solana, with
+/// its scale relative to the major unit of the currency.
+///
+/// A count of a denomination is an amount of the currency with
+/// [`decimals`](Denomination::decimals) decimals, so `1_000_000_000` lamports and `1` SOL are
+/// both one SOL.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{Denomination, Eth, Money, RoundingMode};
+///
+/// let fee = Money::from_denomination(21_000u128, Denomination::<Eth>::GWEI);
+/// assert_eq!(fee.into_parts(), (21_000, 9));
+/// assert_eq!(
+///     fee.try_to_denomination(Denomination::WEI, RoundingMode::Unnecessary),
+///     Ok(21_000_000_000_000)
+/// );
+/// ```
+pub struct Denomination<C> {
+    name: &'static str,
+    decimals: u32,
+    currency: PhantomData<C>,
+}
+
+impl<C> Denomination<C> {
+    /// Creates a denomination.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the denomination, like `"gwei"`.
+    /// * `decimals` - The number of decimals of the major unit of the currency one unit of the
+    ///   denomination represents, like `9` for gwei.
+    pub const fn new(name: &'static str, decimals: u32) -> Self {
+        Denomination {
+            name,
+            decimals,
+            currency: PhantomData,
+        }
+    }
+
+    /// Returns the name of the denomination.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the number of decimals of the major unit of the currency one unit of the
+    /// denomination represents.
+    pub const fn decimals(&self) -> u32 {
+        self.decimals
+    }
+
+    /// Converts a count of this denomination into a count of `to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - The number of units of this denomination.
+    /// * `to` - The denomination to count in.
+    /// * `rounding` - The rounding applied when `to` is larger than this denomination; use
+    ///   [`RoundingMode::Unnecessary`] to fail instead of losing dust.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the number of units of `to`, or a `DecimalOperationError` if
+    /// the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use financial_ops::{Denomination, RoundingMode, Sol};
+    ///
+    /// let lamports = Denomination::<Sol>::SOL.convert(
+    ///     3u64,
+    ///     Denomination::LAMPORT,
+    ///     RoundingMode::Unnecessary,
+    /// );
+    /// assert_eq!(lamports, Ok(3_000_000_000));
+    /// ```
+    pub fn convert<T: RescaleDecimals>(
+        self,
+        amount: T,
+        to: Self,
+        rounding: RoundingMode,
+    ) -> Result<T, DecimalOperationError> {
+        let (amount, _) = amount.rescale_checked(self.decimals, to.decimals, rounding)?;
+        Ok(amount)
+    }
+}
+
+/// A currency with a registry of the denominations its amounts are commonly counted in.
+pub trait Denominations: Currency + Sized + 'static {
+    /// The denominations of the currency, from the major unit down to the smallest one.
+    const DENOMINATIONS: &'static [Denomination<Self>];
+}
+
+impl<C: Denominations> Denomination<C> {
+    /// Looks up a denomination of the currency by name, ignoring ASCII case.
+    pub fn find(name: &str) -> Option<Self> {
+        C::DENOMINATIONS
+            .iter()
+            .find(|denomination| denomination.name.eq_ignore_ascii_case(name))
+            .copied()
+    }
+}
+
+macro_rules! impl_denominations {
+    ($($currency:ident { $($name:ident $label:literal $decimals:literal;)* })*) => ($(
+        impl Denomination<$currency> {
+            $(
+                #[doc = concat!("The ", $label, ", with ", $decimals, " decimals.")]
+                pub const $name: Self = Denomination::new($label, $decimals);
+            )*
+        }
+
+        impl Denominations for $currency {
+            const DENOMINATIONS: &'static [Denomination<Self>] = &[$(Denomination::<$currency>::$name),*];
+        }
+    )*)
+}
+
+impl_denominations! {
+    Sol {
+        SOL "SOL" 0;
+        LAMPORT "lamport" 9;
+    }
+    Eth {
+        ETHER "ether" 0;
+        GWEI "gwei" 9;
+        WEI "wei" 18;
+    }
+    Btc {
+        BITCOIN "bitcoin" 0;
+        SATOSHI "satoshi" 8;
+    }
+    Usdc {
+        USDC "USDC" 0;
+        BASE_UNIT "USDC base unit" 6;
+    }
+    Usdt {
+        USDT "USDT" 0;
+        BASE_UNIT "USDT base unit" 6;
+    }
+}
+
+impl<C, T> Money<C, T> {
+    /// Creates an amount of money from a count of a denomination of its currency, like lamports.
+    pub const fn from_denomination(amount: T, denomination: Denomination<C>) -> Self {
+        Money::new(amount, denomination.decimals)
+    }
+}
+
+impl<C, T: RescaleDecimals> Money<C, T> {
+    /// Returns the amount as a count of a denomination of its currency, like lamports.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the count, or a `DecimalOperationError` if the operation
+    /// fails.
+    pub fn try_to_denomination(
+        self,
+        denomination: Denomination<C>,
+        rounding: RoundingMode,
+    ) -> Result<T, DecimalOperationError> {
+        let (amount, decimals) = self.into_parts();
+        let (amount, _) = amount.rescale_checked(decimals, denomination.decimals, rounding)?;
+        Ok(amount)
+    }
+}
+
+// The traits below are implemented by hand so they don't require the currency markers to
+// implement them.
+
+impl<C> Clone for Denomination<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> Copy for Denomination<C> {}
+
+impl<C> PartialEq for Denomination<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.decimals == other.decimals
+    }
+}
+
+impl<C> Eq for Denomination<C> {}
+
+impl<C> Hash for Denomination<C> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.decimals.hash(state);
+    }
+}
+
+impl<C: Currency> fmt::Debug for Denomination<C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Denomination")
+            .field("name", &self.name)
+            .field("decimals", &self.decimals)
+            .field("currency", &C::CODE)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_denomination_registry() {
+        assert_eq!(Denomination::<Eth>::find("Gwei"), Some(Denomination::GWEI));
+        assert_eq!(Denomination::<Eth>::find("satoshi"), None);
+        assert_eq!(Denomination::<Btc>::SATOSHI.decimals(), Btc::DECIMALS);
+        assert_eq!(Denomination::<Usdt>::BASE_UNIT.name(), "USDT base unit");
+        assert_eq!(Sol::DENOMINATIONS.len(), 2);
+    }
+
+    #[test]
+    fn test_denomination_convert() -> Result<(), DecimalOperationError> {
+        assert_eq!(
+            Denomination::<Eth>::GWEI.convert(
+                2_000_000_000u128,
+                Denomination::ETHER,
+                RoundingMode::Unnecessary
+            )?,
+            2
+        );
+        assert_eq!(
+            Denomination::<Btc>::SATOSHI.convert(
+                1_50u64,
+                Denomination::BITCOIN,
+                RoundingMode::Down
+            )?,
+            0
+        );
+        assert_eq!(
+            Denomination::<Btc>::SATOSHI.convert(
+                1_50u64,
+                Denomination::BITCOIN,
+                RoundingMode::Unnecessary
+            ),
+            Err(DecimalOperationError::PrecisionLoss)
+        );
+        assert_eq!(
+            Denomination::<Eth>::ETHER.convert(20u64, Denomination::WEI, RoundingMode::Down),
+            Err(DecimalOperationError::Overflow)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_money_denomination() -> Result<(), DecimalOperationError> {
+        let balance = Money::from_denomination(1_500_000_000u64, Denomination::<Sol>::LAMPORT);
+        assert_eq!(
+            balance,
+            Money::<Sol, u64>::new(1_5, 1).try_rescale(9, RoundingMode::Down)?
+        );
+        assert_eq!(
+            balance.try_to_denomination(Denomination::SOL, RoundingMode::HalfEven)?,
+            2
+        );
+        assert_eq!(
+            Money::<Usdc, u64>::new(12_34, 2)
+                .try_to_denomination(Denomination::<Usdc>::BASE_UNIT, RoundingMode::Unnecessary)?,
+            12_340000
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,264 @@
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
+use crate::core::{
+    CheckedDivideToScale, CheckedMulDiv, Currency, Decimal, DecimalOperationError, Money,
+    PowerOfTen, RescaleDecimals, RoundingMode,
+};
+
+/// The price of one unit of the currency `Base` in the currency `Quote`, stored as a raw value of
+/// type `T` and its number of decimals.
+///
+/// A `EUR/USD` rate of `1.0825` is an `ExchangeRate<Eur, Usd>`: it converts euros into dollars,
+/// and converting an amount in any other currency doesn't compile.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{Eur, ExchangeRate, Money, RoundingMode, Usd};
+///
+/// let eur_usd = ExchangeRate::<Eur, Usd, i64>::new(1_0825, 4);
+///
+/// let dollars = eur_usd.convert(Money::new(100_00, 2), RoundingMode::HalfEven).unwrap();
+/// assert_eq!(dollars.into_parts(), (108_25, 2));
+///
+/// let usd_eur = eur_usd.invert(6, RoundingMode::HalfEven).unwrap();
+/// assert_eq!(usd_eur.rate().into_parts(), (0_923788, 6));
+/// ```
+pub struct ExchangeRate<Base, Quote, T = i128> {
+    rate: Decimal<T>,
+    currencies: PhantomData<(Base, Quote)>,
+}
+
+impl<Base, Quote, T> ExchangeRate<Base, Quote, T> {
+    /// Creates an exchange rate from a raw value and the number of decimals it carries.
+    ///
+    /// # Arguments
+    ///
+    /// * `rate` - The raw integer value of the rate.
+    /// * `decimals` - The number of decimals in `rate`.
+    pub const fn new(rate: T, decimals: u32) -> Self {
+        ExchangeRate::from_decimal(Decimal::new(rate, decimals))
+    }
+
+    /// Creates an exchange rate from a decimal.
+    pub const fn from_decimal(rate: Decimal<T>) -> Self {
+        ExchangeRate {
+            rate,
+            currencies: PhantomData,
+        }
+    }
+
+    /// Returns the number of decimals carried by the rate.
+    pub const fn decimals(&self) -> u32 {
+        self.rate.scale()
+    }
+
+    /// Returns the rate as a decimal, dropping the currencies.
+    pub fn into_decimal(self) -> Decimal<T> {
+        self.rate
+    }
+}
+
+impl<Base, Quote, T: Copy> ExchangeRate<Base, Quote, T> {
+    /// Returns the rate as a decimal.
+    pub fn rate(&self) -> Decimal<T> {
+        self.rate
+    }
+}
+
+impl<Base, Quote: Currency, T> ExchangeRate<Base, Quote, T>
+where
+    T: CheckedMulDiv + RescaleDecimals + PowerOfTen + Copy,
+{
+    /// Converts an amount in the base currency into the quote currency, rounding the result once
+    /// to the decimals of the quote currency.
+    ///
+    /// The product of the amount and the rate is computed with a widened intermediate, so it
+    /// only fails when the converted amount doesn't fit in the type.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the converted amount, or a `DecimalOperationError` if the
+    /// operation fails.
+    pub fn convert(
+        &self,
+        money: Money<Base, T>,
+        rounding: RoundingMode,
+    ) -> Result<Money<Quote, T>, DecimalOperationError> {
+        let target_decimals = Quote::DECIMALS;
+        let value = multiply_to_scale(money.into_decimal(), self.rate, target_decimals, rounding)?;
+        Ok(Money::new(value, target_decimals))
+    }
+}
+
+impl<Base, Quote, T: CheckedDivideToScale + PowerOfTen + Copy> ExchangeRate<Base, Quote, T> {
+    /// Computes the reciprocal rate, converting the quote currency back into the base currency,
+    /// with `decimals` decimals.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the inverted rate, or a `DecimalOperationError` if the rate
+    /// is zero or the reciprocal doesn't fit in the type.
+    pub fn invert(
+        &self,
+        decimals: u32,
+        rounding: RoundingMode,
+    ) -> Result<ExchangeRate<Quote, Base, T>, DecimalOperationError> {
+        reciprocal(self.rate, decimals, rounding).map(ExchangeRate::from_decimal)
+    }
+}
+
+/// Computes `a * b` with `decimals` decimals, rounding once with `rounding`.
+pub(crate) fn multiply_to_scale<T>(
+    a: Decimal<T>,
+    b: Decimal<T>,
+    decimals: u32,
+    rounding: RoundingMode,
+) -> Result<T, DecimalOperationError>
+where
+    T: CheckedMulDiv + PowerOfTen + Copy,
+{
+    // The power of ten bringing the product to `decimals` is part of the widened product.
+    let exponent = i64::from(decimals) - i64::from(a.scale()) - i64::from(b.scale());
+    let one = T::checked_power_of_ten(0).ok_or(DecimalOperationError::ScaleTooLarge)?;
+    a.value()
+        .checked_mul_div_power_of_ten(b.value(), one, exponent, rounding)
+}
+
+/// Computes `a * b / divisor` with `decimals` decimals, rounding once with `rounding`.
+pub(crate) fn multiply_divide_to_scale<T>(
+    a: Decimal<T>,
+    b: Decimal<T>,
+    divisor: Decimal<T>,
+    decimals: u32,
+    rounding: RoundingMode,
+) -> Result<T, DecimalOperationError>
+where
+    T: CheckedMulDiv + PowerOfTen + Copy,
+{
+    let exponent = i64::from(decimals) - i64::from(a.scale()) - i64::from(b.scale())
+        + i64::from(divisor.scale());
+    a.value()
+        .checked_mul_div_power_of_ten(b.value(), divisor.value(), exponent, rounding)
+}
+
+/// Computes `1 / rate` with `decimals` decimals.
+pub(crate) fn reciprocal<T>(
+    rate: Decimal<T>,
+    decimals: u32,
+    rounding: RoundingMode,
+) -> Result<Decimal<T>, DecimalOperationError>
+where
+    T: CheckedDivideToScale + PowerOfTen + Copy,
+{
+    let one = T::checked_power_of_ten(0).ok_or(DecimalOperationError::ScaleTooLarge)?;
+    one.divide_decimals_to_scale_checked(rate.value(), 0, rate.scale(), decimals, rounding)
+        .map(|(value, decimals)| Decimal::new(value, decimals))
+}
+
+// The traits below are implemented by hand so they don't require the currency markers to
+// implement them.
+
+impl<Base, Quote, T: Clone> Clone for ExchangeRate<Base, Quote, T> {
+    fn clone(&self) -> Self {
+        ExchangeRate::from_decimal(self.rate.clone())
+    }
+}
+
+impl<Base, Quote, T: Copy> Copy for ExchangeRate<Base, Quote, T> {}
+
+impl<Base, Quote, T: PartialEq> PartialEq for ExchangeRate<Base, Quote, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.rate == other.rate
+    }
+}
+
+impl<Base, Quote, T: Eq> Eq for ExchangeRate<Base, Quote, T> {}
+
+impl<Base, Quote, T: Hash> Hash for ExchangeRate<Base, Quote, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.rate.hash(state);
+    }
+}
+
+impl<Base: Currency, Quote: Currency, T: fmt::Debug> fmt::Debug for ExchangeRate<Base, Quote, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ExchangeRate")
+            .field("rate", self.rate.value_ref())
+            .field("decimals", &self.rate.scale())
+            .field("base", &Base::CODE)
+            .field("quote", &Quote::CODE)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Eur, Jpy, Usd};
+
+    #[test]
+    fn test_exchange_rate_convert() -> Result<(), DecimalOperationError> {
+        let eur_usd = ExchangeRate::<Eur, Usd, i64>::new(1_0825, 4);
+        let dollars = eur_usd.convert(Money::new(29_99, 2), RoundingMode::HalfEven)?;
+        // 29.99 * 1.0825 = 32.464175
+        assert_eq!(dollars.into_parts(), (32_46, 2));
+        let dollars = eur_usd.convert(Money::new(29_99, 2), RoundingMode::Up)?;
+        assert_eq!(dollars.into_parts(), (32_47, 2));
+
+        let usd_jpy = ExchangeRate::<Usd, Jpy, u64>::new(151_25, 2);
+        let yen = usd_jpy.convert(Money::new(12_00, 2), RoundingMode::HalfEven)?;
+        assert_eq!(yen.into_parts(), (1815, 0));
+        let jpy_usd = ExchangeRate::<Jpy, Usd, u64>::new(66, 4);
+        let dollars = jpy_usd.convert(Money::new(1812, 0), RoundingMode::HalfEven)?;
+        assert_eq!(dollars.into_parts(), (11_96, 2));
+
+        // The product has fewer decimals than the quote currency
+        let jpy_usd = ExchangeRate::<Jpy, Usd, u64>::new(5, 1);
+        let dollars = jpy_usd.convert(Money::new(3, 0), RoundingMode::Unnecessary)?;
+        assert_eq!(dollars.into_parts(), (1_50, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_exchange_rate_convert_wide_intermediate() -> Result<(), DecimalOperationError> {
+        // The raw product overflows a u64, but the converted amount fits.
+        let rate = ExchangeRate::<Eur, Usd, u64>::new(1_000000000000, 12);
+        let amount = Money::new(u64::MAX / 100, 2);
+        assert_eq!(
+            rate.convert(amount, RoundingMode::Down)?.into_parts(),
+            (u64::MAX / 100, 2)
+        );
+
+        let rate = ExchangeRate::<Eur, Usd, u64>::new(2, 0);
+        assert_eq!(
+            rate.convert(Money::new(u64::MAX, 2), RoundingMode::Down),
+            Err(DecimalOperationError::Overflow)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_exchange_rate_invert() -> Result<(), DecimalOperationError> {
+        let eur_usd = ExchangeRate::<Eur, Usd, u64>::new(1_0825, 4);
+        let usd_eur = eur_usd.invert(8, RoundingMode::HalfEven)?;
+        assert_eq!(usd_eur.rate(), Decimal::new(0_92378753, 8));
+        let euros = usd_eur.convert(Money::new(108_25, 2), RoundingMode::HalfEven)?;
+        assert_eq!(euros.into_parts(), (100_00, 2));
+
+        assert_eq!(
+            ExchangeRate::<Eur, Usd, u64>::new(0, 4).invert(8, RoundingMode::HalfEven),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+        // `100.0` doesn't fit in a `u8` with one decimal
+        assert_eq!(
+            ExchangeRate::<Eur, Usd, u8>::new(1, 2).invert(1, RoundingMode::HalfEven),
+            Err(DecimalOperationError::Overflow)
+        );
+        Ok(())
+    }
+}
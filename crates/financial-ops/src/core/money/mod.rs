@@ -0,0 +1,25 @@
+#[cfg(feature = "alloc")]
+pub mod allocation;
+pub mod currency;
+pub mod denomination;
+pub mod exchange_rate;
+pub mod money_amount;
+#[cfg(feature = "alloc")]
+pub mod money_bag;
+#[cfg(feature = "alloc")]
+pub mod parse_money;
+pub mod price;
+pub mod quantity;
+pub mod signed_money;
+
+#[cfg(feature = "alloc")]
+pub use allocation::*;
+pub use currency::*;
+pub use denomination::*;
+pub use exchange_rate::*;
+pub use money_amount::*;
+#[cfg(feature = "alloc")]
+pub use money_bag::*;
+pub use price::*;
+pub use quantity::*;
+pub use signed_money::*;
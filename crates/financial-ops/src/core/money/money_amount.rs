@@ -0,0 +1,543 @@
+#[cfg(not(feature = "strict-no-panic"))]
+use core::ops::{Add, Div, Mul, Neg, Sub};
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use crate::core::{
+    CashRoundingDecimals, CheckedDecimalOperations, CheckedSignDecimals, CompareDecimals, Currency,
+    Decimal, DecimalOperationError, PowerOfTen, RescaleDecimals, RoundingMode, Signedness, Zero,
+};
+#[cfg(feature = "alloc")]
+use crate::core::{DecimalFormat, ToStringDecimals};
+
+/// An amount of money in the currency `C`, stored as a raw value of type `T` and its number of
+/// decimals.
+///
+/// The currency is part of the type, so adding dollars to euros doesn't compile. Arithmetic
+/// between amounts of the same currency delegates to [`CheckedDecimalOperations`], so the
+/// decimals of every result follow the same rules as [`Decimal`].
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{Money, Usd};
+///
+/// let price = Money::<Usd, i64>::new(19_99, 2);
+/// let shipping = Money::<Usd, i64>::new(4_5, 1);
+///
+/// let total = price.try_add(shipping).unwrap();
+/// assert_eq!(total.into_parts(), (24_49, 2));
+/// ```
+///
+/// ```compile_fail
+/// use financial_ops::{Eur, Money, Usd};
+///
+/// let dollars = Money::<Usd, i64>::new(1_00, 2);
+/// let euros = Money::<Eur, i64>::new(1_00, 2);
+///
+/// let _ = dollars.try_add(euros);
+/// ```
+pub struct Money<C, T = i128> {
+    amount: Decimal<T>,
+    currency: PhantomData<C>,
+}
+
+impl<C, T> Money<C, T> {
+    /// Creates an amount of money from a raw value and the number of decimals it carries.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - The raw integer value.
+    /// * `decimals` - The number of decimals in `amount`.
+    pub const fn new(amount: T, decimals: u32) -> Self {
+        Money::from_decimal(Decimal::new(amount, decimals))
+    }
+
+    /// Creates an amount of money from a decimal.
+    pub const fn from_decimal(amount: Decimal<T>) -> Self {
+        Money {
+            amount,
+            currency: PhantomData,
+        }
+    }
+
+    /// Returns the number of decimals carried by the amount.
+    pub const fn decimals(&self) -> u32 {
+        self.amount.scale()
+    }
+
+    /// Returns a reference to the raw integer value.
+    pub const fn amount_ref(&self) -> &T {
+        self.amount.value_ref()
+    }
+
+    /// Returns the amount as a decimal, dropping the currency.
+    pub fn into_decimal(self) -> Decimal<T> {
+        self.amount
+    }
+
+    /// Splits the amount into its raw value and number of decimals.
+    pub fn into_parts(self) -> (T, u32) {
+        self.amount.into_parts()
+    }
+}
+
+impl<C: Currency, T> Money<C, T> {
+    /// Creates an amount of money from a number of the smallest units of the currency, like
+    /// cents for dollars.
+    pub const fn from_minor_units(amount: T) -> Self {
+        Money::new(amount, C::DECIMALS)
+    }
+
+    /// Returns the code of the currency, like `"USD"`.
+    pub const fn currency_code(&self) -> &'static str {
+        C::CODE
+    }
+}
+
+impl<C, T: Copy> Money<C, T> {
+    /// Returns the raw integer value.
+    pub fn amount(&self) -> T {
+        self.amount.value()
+    }
+
+    /// Returns the amount as a decimal.
+    pub fn decimal(&self) -> Decimal<T> {
+        self.amount
+    }
+}
+
+impl<C, T: Zero> Money<C, T> {
+    /// Creates a zero amount with `decimals` decimals.
+    pub fn zero(decimals: u32) -> Self {
+        Money::from_decimal(Decimal::zero(decimals))
+    }
+
+    /// Returns `true` if the amount is zero, with any number of decimals.
+    pub fn is_zero(&self) -> bool {
+        self.amount.is_zero()
+    }
+}
+
+impl<C, T: PowerOfTen> Money<C, T> {
+    /// Creates one major unit of the currency with `decimals` decimals.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the amount, or a `DecimalOperationError` if `10^decimals`
+    /// doesn't fit in the type.
+    pub fn one(decimals: u32) -> Result<Self, DecimalOperationError> {
+        Decimal::one(decimals).map(Money::from_decimal)
+    }
+}
+
+impl<C, T: Signedness + Zero> Money<C, T> {
+    /// Returns `true` if the amount is greater than zero.
+    pub fn is_positive(&self) -> bool {
+        self.amount.is_positive()
+    }
+
+    /// Returns `true` if the amount is less than zero, like a refund or an overdraft.
+    pub fn is_negative(&self) -> bool {
+        self.amount.is_negative()
+    }
+}
+
+impl<C, T> From<Decimal<T>> for Money<C, T> {
+    fn from(amount: Decimal<T>) -> Self {
+        Money::from_decimal(amount)
+    }
+}
+
+impl<C, T: CheckedDecimalOperations> Money<C, T> {
+    /// Adds two amounts of the same currency, aligning their decimals.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the sum, or a `DecimalOperationError` if the operation fails.
+    pub fn try_add(self, other: Self) -> Result<Self, DecimalOperationError> {
+        self.amount.try_add(other.amount).map(Money::from)
+    }
+
+    /// Subtracts `other` from `self`, aligning their decimals.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the difference, or a `DecimalOperationError` if the operation fails.
+    pub fn try_sub(self, other: Self) -> Result<Self, DecimalOperationError> {
+        self.amount.try_sub(other.amount).map(Money::from)
+    }
+
+    /// Multiplies the amount by a factor, like a quantity or a rate. The decimals of the result
+    /// are the sum of both scales.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the product, or a `DecimalOperationError` if the operation fails.
+    pub fn try_mul(self, factor: Decimal<T>) -> Result<Self, DecimalOperationError> {
+        self.amount.try_mul(factor).map(Money::from)
+    }
+
+    /// Divides the amount by a divisor. The decimals of the result are the decimals of `self`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the quotient, or a `DecimalOperationError` if the operation fails.
+    pub fn try_div(self, divisor: Decimal<T>) -> Result<Self, DecimalOperationError> {
+        self.amount.try_div(divisor).map(Money::from)
+    }
+}
+
+impl<C, T: RescaleDecimals> Money<C, T> {
+    /// Rescales the amount to `decimals` decimals, rounding with `rounding` when scaling down.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the rescaled amount, or a `DecimalOperationError` if the operation fails.
+    pub fn try_rescale(
+        self,
+        decimals: u32,
+        rounding: RoundingMode,
+    ) -> Result<Self, DecimalOperationError> {
+        self.amount.try_rescale(decimals, rounding).map(Money::from)
+    }
+}
+
+impl<C: Currency, T: RescaleDecimals> Money<C, T> {
+    /// Rounds the amount to the smallest unit of the currency, like cents for dollars.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the rounded amount, or a `DecimalOperationError` if the operation fails.
+    pub fn try_round_to_minor_units(
+        self,
+        rounding: RoundingMode,
+    ) -> Result<Self, DecimalOperationError> {
+        self.try_rescale(C::DECIMALS, rounding)
+    }
+
+    /// Creates an amount of money from a number of whole units of the currency, like dollars,
+    /// scaled to the decimals of the currency.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the amount, or a `DecimalOperationError` if it doesn't fit
+    /// in the type once scaled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use financial_ops::{Jpy, Money, Usd};
+    ///
+    /// let price = Money::<Usd, u64>::from_major_units(25).unwrap();
+    /// assert_eq!(price.into_parts(), (25_00, 2));
+    /// let price = Money::<Jpy, u64>::from_major_units(25).unwrap();
+    /// assert_eq!(price.into_parts(), (25, 0));
+    /// ```
+    pub fn from_major_units(amount: T) -> Result<Self, DecimalOperationError> {
+        let (amount, decimals) =
+            amount.rescale_checked(0, C::DECIMALS, RoundingMode::Unnecessary)?;
+        Ok(Money::new(amount, decimals))
+    }
+
+    /// Returns the amount as a number of the smallest units of the currency, like cents for
+    /// dollars, rounding the digits below them with `rounding`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the number of minor units, or a `DecimalOperationError` if
+    /// the operation fails.
+    pub fn to_minor_units(self, rounding: RoundingMode) -> Result<T, DecimalOperationError> {
+        let (amount, _) = self.try_round_to_minor_units(rounding)?.into_parts();
+        Ok(amount)
+    }
+}
+
+impl<C: Currency, T: CashRoundingDecimals + From<u8>> Money<C, T> {
+    /// Rounds the amount to the cash increment of its currency, like `0.05` for Swiss francs,
+    /// keeping its decimals.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the rounded amount and the rounding difference, or a
+    /// `DecimalOperationError` if the operation fails.
+    pub fn try_round_to_cash(
+        self,
+        rounding: RoundingMode,
+    ) -> Result<(Self, Self), DecimalOperationError> {
+        let (amount, decimals) = self.into_parts();
+        let increment = Decimal::new(T::from(C::CASH_INCREMENT), C::DECIMALS);
+        let (rounded, difference) =
+            amount.round_to_cash_increment(decimals, increment, rounding)?;
+        Ok((
+            Money::new(rounded, decimals),
+            Money::new(difference, decimals),
+        ))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C: Currency, T: RescaleDecimals + ToStringDecimals> Money<C, T> {
+    /// Formats the amount with the symbol and default locale of its currency, like `"$1,234.56"`,
+    /// rounding half to even to the decimals of the currency.
+    ///
+    /// Use [`DecimalFormat::currency`] to change the locale or the rounding.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the formatted amount, or a `DecimalOperationError` if the
+    /// amount can't be rescaled.
+    pub fn try_format(self) -> Result<String, DecimalOperationError> {
+        DecimalFormat::currency::<C>().format_money(self)
+    }
+}
+
+impl<C, T: CheckedSignDecimals> Money<C, T> {
+    /// Negates the amount, keeping its decimals.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the negated amount, or a `DecimalOperationError` if the operation fails.
+    pub fn try_neg(self) -> Result<Self, DecimalOperationError> {
+        self.amount.try_neg().map(Money::from)
+    }
+
+    /// Returns the absolute value of the amount, keeping its decimals.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the absolute value, or a `DecimalOperationError` if the operation fails.
+    pub fn try_abs(self) -> Result<Self, DecimalOperationError> {
+        self.amount.try_abs().map(Money::from)
+    }
+}
+
+impl<C, T: CompareDecimals> Money<C, T> {
+    /// Compares two amounts of the same currency, aligning their decimals.
+    ///
+    /// Unlike `==`, this considers `1.00` and `1.0000` equal.
+    pub fn cmp_value(&self, other: &Self) -> Ordering {
+        self.amount.cmp_value(&other.amount)
+    }
+
+    /// Returns `true` if both amounts are equal, regardless of their decimals.
+    pub fn eq_value(&self, other: &Self) -> bool {
+        self.amount.eq_value(&other.amount)
+    }
+}
+
+// The traits below are implemented by hand so they don't require the currency marker to
+// implement them.
+
+impl<C, T: Clone> Clone for Money<C, T> {
+    fn clone(&self) -> Self {
+        Money::from_decimal(self.amount.clone())
+    }
+}
+
+impl<C, T: Copy> Copy for Money<C, T> {}
+
+impl<C, T: PartialEq> PartialEq for Money<C, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.amount == other.amount
+    }
+}
+
+impl<C, T: Eq> Eq for Money<C, T> {}
+
+impl<C, T: Hash> Hash for Money<C, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.amount.hash(state);
+    }
+}
+
+impl<C: Currency, T: fmt::Debug> fmt::Debug for Money<C, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Money")
+            .field("amount", self.amount.value_ref())
+            .field("decimals", &self.amount.scale())
+            .field("currency", &C::CODE)
+            .finish()
+    }
+}
+
+#[cfg(not(feature = "strict-no-panic"))]
+impl<C, T: CheckedSignDecimals> Neg for Money<C, T> {
+    type Output = Money<C, T>;
+
+    /// # Panics
+    ///
+    /// Panics if the amount can't be negated.
+    fn neg(self) -> Self::Output {
+        Money::from(-self.amount)
+    }
+}
+
+macro_rules! impl_money_ops {
+    ($($op:ident $method:ident $rhs:ty;)*) => ($(
+        #[cfg(not(feature = "strict-no-panic"))]
+        impl<C, T: CheckedDecimalOperations> $op<$rhs> for Money<C, T> {
+            type Output = Money<C, T>;
+
+            /// # Panics
+            ///
+            /// Panics if the underlying checked operation fails.
+            fn $method(self, other: $rhs) -> Self::Output {
+                Money::from(self.amount.$method(Decimal::from(other)))
+            }
+        }
+    )*)
+}
+
+impl_money_ops! {
+    Add add Money<C, T>;
+    Sub sub Money<C, T>;
+    Mul mul Decimal<T>;
+    Div div Decimal<T>;
+}
+
+impl<C, T> From<Money<C, T>> for Decimal<T> {
+    fn from(money: Money<C, T>) -> Self {
+        money.amount
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Chf, Eur, Jpy, Usd};
+
+    #[test]
+    fn test_money_parts() {
+        let price = Money::<Usd, i64>::new(19_99, 2);
+        assert_eq!(price.amount(), 19_99);
+        assert_eq!(price.decimals(), 2);
+        assert_eq!(price.currency_code(), "USD");
+        assert_eq!(price.decimal(), Decimal::new(19_99, 2));
+        assert_eq!(
+            Money::<Jpy, u64>::from_minor_units(500).into_parts(),
+            (500, 0)
+        );
+    }
+
+    #[test]
+    fn test_money_zero_and_one() -> Result<(), DecimalOperationError> {
+        let zero = Money::<Usd, u64>::zero(2);
+        assert!(zero.is_zero() && !zero.is_positive() && !zero.is_negative());
+        assert_eq!(Money::<Usd, u64>::one(2)?, Money::new(1_00, 2));
+        assert!(Money::<Jpy, i32>::new(-500, 0).is_negative());
+        assert!(Money::<Eur, i32>::new(1, 2).is_positive());
+        Ok(())
+    }
+
+    #[test]
+    fn test_money_arithmetic() -> Result<(), DecimalOperationError> {
+        let price = Money::<Eur, i64>::new(19_99, 2);
+        let fee = Money::<Eur, i64>::new(0_125, 3);
+
+        assert_eq!(price.try_add(fee)?.into_parts(), (20_115, 3));
+        assert_eq!(price.try_sub(fee)?.into_parts(), (19_865, 3));
+        assert_eq!(price.try_mul(Decimal::new(3, 0))?.into_parts(), (59_97, 2));
+        assert_eq!(price.try_div(Decimal::new(2_0, 1))?.into_parts(), (9_99, 2));
+        assert_eq!(
+            price
+                .try_sub(fee)?
+                .try_round_to_minor_units(RoundingMode::HalfEven)?
+                .into_parts(),
+            (19_86, 2)
+        );
+        assert_eq!(price.try_neg()?.try_abs()?, price);
+        assert!(price.eq_value(&Money::new(19_9900, 4)));
+        assert_eq!(price.cmp_value(&fee), Ordering::Greater);
+        Ok(())
+    }
+
+    #[test]
+    fn test_money_errors() {
+        let max = Money::<Usd, u8>::new(u8::MAX, 0);
+        assert_eq!(
+            max.try_add(Money::new(1, 0)),
+            Err(DecimalOperationError::Overflow)
+        );
+        assert_eq!(
+            max.try_div(Decimal::new(0, 0)),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_money_major_and_minor_units() -> Result<(), DecimalOperationError> {
+        assert_eq!(
+            Money::<Usd, i64>::from_major_units(-12)?.into_parts(),
+            (-12_00, 2)
+        );
+        assert_eq!(
+            Money::<Usd, i64>::new(12_345, 3).to_minor_units(RoundingMode::HalfEven)?,
+            12_34
+        );
+        assert_eq!(
+            Money::<Jpy, u64>::new(1_5, 1).to_minor_units(RoundingMode::HalfEven)?,
+            2
+        );
+        assert_eq!(
+            Money::<Usd, u8>::from_major_units(3),
+            Err(DecimalOperationError::Overflow)
+        );
+        assert_eq!(
+            Money::<Usd, i8>::from_major_units(-2),
+            Err(DecimalOperationError::Underflow)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_money_round_to_cash() -> Result<(), DecimalOperationError> {
+        let (total, difference) =
+            Money::<Chf, i64>::new(17_43, 2).try_round_to_cash(RoundingMode::HalfEven)?;
+        assert_eq!(total.into_parts(), (17_45, 2));
+        assert_eq!(difference.into_parts(), (0_02, 2));
+
+        let (total, difference) =
+            Money::<Usd, i64>::new(17_43, 2).try_round_to_cash(RoundingMode::HalfEven)?;
+        assert_eq!(total.into_parts(), (17_43, 2));
+        assert_eq!(difference.into_parts(), (0, 2));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_money_format() {
+        assert_eq!(
+            Money::<Usd, i64>::new(1234_56, 2).try_format(),
+            Ok("$1,234.56".into())
+        );
+        assert_eq!(
+            Money::<Eur, i64>::new(-1234_56, 2).try_format(),
+            Ok("-1.234,56 €".into())
+        );
+        assert_eq!(
+            Money::<Jpy, u64>::new(1234_56, 2).try_format(),
+            Ok("¥1,235".into())
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "strict-no-panic"))]
+    fn test_money_std_ops() {
+        let a = Money::<Usd, i64>::new(1_50, 2);
+        let b = Money::<Usd, i64>::new(2_5, 1);
+
+        assert_eq!((a + b).into_parts(), (4_00, 2));
+        assert_eq!((a - b).into_parts(), (-1_00, 2));
+        assert_eq!((a * Decimal::new(2, 0)).into_parts(), (3_00, 2));
+        assert_eq!((a / Decimal::new(3, 0)).into_parts(), (0_50, 2));
+        assert_eq!((-a).into_parts(), (-1_50, 2));
+    }
+}
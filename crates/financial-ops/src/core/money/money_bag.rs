@@ -0,0 +1,244 @@
+use alloc::collections::BTreeMap;
+
+use crate::core::{
+    money::exchange_rate::multiply_to_scale, CheckedDecimalOperations, CheckedDivideToScale,
+    CheckedMulDiv, Currency, Decimal, DecimalOperationError, FxError, Money, PowerOfTen, RateTable,
+    RescaleDecimals, RoundingMode, Zero,
+};
+
+/// Amounts in several currencies, accumulated per currency code, like the totals of a
+/// portfolio or a cart mixing currencies.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{Eur, ExchangeRate, Money, MoneyBag, RateTable, RoundingMode, Usd};
+///
+/// let mut cart = MoneyBag::<i64>::new();
+/// cart.try_add_money(Money::<Usd, i64>::new(19_99, 2)).unwrap();
+/// cart.try_add_money(Money::<Eur, i64>::new(10_00, 2)).unwrap();
+/// cart.try_add_money(Money::<Usd, i64>::new(5_01, 2)).unwrap();
+/// assert_eq!(cart.get::<Usd>(), Some(Money::new(25_00, 2)));
+///
+/// let mut rates = RateTable::new(6);
+/// rates.insert(ExchangeRate::<Eur, Usd, i64>::new(1_0825, 4), 1_700_000_000);
+/// let total = cart.try_reduce::<Usd>(&rates, RoundingMode::HalfEven).unwrap();
+/// assert_eq!(total.into_parts(), (35_82, 2));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct MoneyBag<T = i128> {
+    amounts: BTreeMap<&'static str, Decimal<T>>,
+}
+
+impl<T> MoneyBag<T> {
+    /// Creates an empty bag.
+    pub const fn new() -> Self {
+        MoneyBag {
+            amounts: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the number of currencies in the bag.
+    pub fn len(&self) -> usize {
+        self.amounts.len()
+    }
+
+    /// Returns `true` if the bag doesn't hold any currency.
+    pub fn is_empty(&self) -> bool {
+        self.amounts.is_empty()
+    }
+
+    /// Returns the codes of the currencies in the bag, in order.
+    pub fn currencies(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.amounts.keys().copied()
+    }
+}
+
+impl<T: Copy> MoneyBag<T> {
+    /// Returns the amount held in the currency `C`, if any.
+    pub fn get<C: Currency>(&self) -> Option<Money<C, T>> {
+        self.amounts.get(C::CODE).map(|&amount| Money::from(amount))
+    }
+
+    /// Returns the currency codes and the amounts held in them, in code order.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, Decimal<T>)> + '_ {
+        self.amounts.iter().map(|(&code, &amount)| (code, amount))
+    }
+}
+
+impl<T: CheckedDecimalOperations + Zero + Copy> MoneyBag<T> {
+    /// Adds an amount to the total held in its currency.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing nothing, or a `DecimalOperationError` if the addition fails,
+    /// in which case the bag is left unchanged.
+    pub fn try_add_money<C: Currency>(
+        &mut self,
+        money: Money<C, T>,
+    ) -> Result<(), DecimalOperationError> {
+        self.combine(C::CODE, money.into_decimal(), Decimal::try_add)
+    }
+
+    /// Subtracts an amount from the total held in its currency, starting from zero if the bag
+    /// doesn't hold it yet.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing nothing, or a `DecimalOperationError` if the subtraction
+    /// fails, in which case the bag is left unchanged.
+    pub fn try_sub_money<C: Currency>(
+        &mut self,
+        money: Money<C, T>,
+    ) -> Result<(), DecimalOperationError> {
+        self.combine(C::CODE, money.into_decimal(), Decimal::try_sub)
+    }
+
+    /// Adds every amount of `other` to this bag.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the combined bag, or a `DecimalOperationError` if an
+    /// addition fails.
+    pub fn try_add(mut self, other: &Self) -> Result<Self, DecimalOperationError> {
+        for (&code, &amount) in &other.amounts {
+            self.combine(code, amount, Decimal::try_add)?;
+        }
+        Ok(self)
+    }
+
+    /// Subtracts every amount of `other` from this bag.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the difference, or a `DecimalOperationError` if a
+    /// subtraction fails.
+    pub fn try_sub(mut self, other: &Self) -> Result<Self, DecimalOperationError> {
+        for (&code, &amount) in &other.amounts {
+            self.combine(code, amount, Decimal::try_sub)?;
+        }
+        Ok(self)
+    }
+
+    /// Replaces the total held in `code` with `operation(total, amount)`, starting from zero.
+    fn combine<F>(
+        &mut self,
+        code: &'static str,
+        amount: Decimal<T>,
+        operation: F,
+    ) -> Result<(), DecimalOperationError>
+    where
+        F: FnOnce(Decimal<T>, Decimal<T>) -> Result<Decimal<T>, DecimalOperationError>,
+    {
+        let current = self
+            .amounts
+            .get(code)
+            .copied()
+            .unwrap_or_else(|| Decimal::new(T::zero(), 0));
+        let total = operation(current, amount)?;
+        self.amounts.insert(code, total);
+        Ok(())
+    }
+}
+
+impl<T> MoneyBag<T>
+where
+    T: CheckedDecimalOperations
+        + CheckedMulDiv
+        + CheckedDivideToScale
+        + RescaleDecimals
+        + PowerOfTen
+        + Zero
+        + Copy,
+{
+    /// Converts every amount into the currency `To` with the rates of `rates` and adds them up.
+    ///
+    /// Each amount is converted and rounded to the decimals of `To` on its own, like
+    /// [`ExchangeRate::convert`](crate::core::ExchangeRate::convert), and amounts already in `To`
+    /// are rounded the same way.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the total, or a `FxError` if a rate is missing or a
+    /// conversion or the sum fails.
+    pub fn try_reduce<To: Currency>(
+        &self,
+        rates: &RateTable<T>,
+        rounding: RoundingMode,
+    ) -> Result<Money<To, T>, FxError> {
+        let mut total = Money::<To, T>::new(T::zero(), To::DECIMALS);
+        for (&code, &amount) in &self.amounts {
+            let converted = if code == To::CODE {
+                Money::from(amount).try_rescale(To::DECIMALS, rounding)?
+            } else {
+                let (rate, _) = rates.rate_by_code(code, To::CODE)?;
+                let value = multiply_to_scale(amount, rate, To::DECIMALS, rounding)?;
+                Money::new(value, To::DECIMALS)
+            };
+            total = total.try_add(converted)?;
+        }
+        Ok(total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Eur, ExchangeRate, Gbp, Jpy, Usd};
+
+    #[test]
+    fn test_money_bag_accumulate() -> Result<(), DecimalOperationError> {
+        let mut bag = MoneyBag::<i64>::new();
+        assert!(bag.is_empty());
+        bag.try_add_money(Money::<Usd, i64>::new(10_00, 2))?;
+        bag.try_add_money(Money::<Jpy, i64>::new(1500, 0))?;
+        bag.try_add_money(Money::<Usd, i64>::new(2_505, 3))?;
+        bag.try_sub_money(Money::<Eur, i64>::new(3_00, 2))?;
+        assert_eq!(bag.len(), 3);
+        assert_eq!(bag.currencies().collect::<Vec<_>>(), ["EUR", "JPY", "USD"]);
+        assert_eq!(bag.get::<Usd>(), Some(Money::new(12_505, 3)));
+        assert_eq!(bag.get::<Eur>(), Some(Money::new(-3_00, 2)));
+        assert_eq!(bag.get::<Gbp>(), None);
+
+        let mut other = MoneyBag::<i64>::new();
+        other.try_add_money(Money::<Eur, i64>::new(5_00, 2))?;
+        other.try_add_money(Money::<Gbp, i64>::new(1_00, 2))?;
+        let sum = bag.clone().try_add(&other)?;
+        assert_eq!(sum.get::<Eur>(), Some(Money::new(2_00, 2)));
+        assert_eq!(sum.get::<Gbp>(), Some(Money::new(1_00, 2)));
+        assert_eq!(sum.try_sub(&other)?.get::<Gbp>(), Some(Money::new(0, 2)));
+
+        let mut unsigned = MoneyBag::<u64>::new();
+        assert_eq!(
+            unsigned.try_sub_money(Money::<Usd, u64>::new(1_00, 2)),
+            Err(DecimalOperationError::NegativeResult)
+        );
+        assert!(unsigned.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_money_bag_reduce() -> Result<(), FxError> {
+        let mut rates = RateTable::<i64>::new(6);
+        rates.insert(ExchangeRate::<Eur, Usd, i64>::new(1_0825, 4), 100);
+        rates.insert(ExchangeRate::<Usd, Jpy, i64>::new(151_25, 2), 100);
+
+        let mut bag = MoneyBag::<i64>::new();
+        bag.try_add_money(Money::<Usd, i64>::new(10_005, 3))?;
+        bag.try_add_money(Money::<Eur, i64>::new(100_00, 2))?;
+        bag.try_add_money(Money::<Jpy, i64>::new(1513, 0))?;
+        // 10.00 + 108.25 + 10.00 (1513 / 151.25 = 10.0033)
+        let total = bag.try_reduce::<Usd>(&rates, RoundingMode::HalfEven)?;
+        assert_eq!(total.into_parts(), (128_25, 2));
+
+        bag.try_add_money(Money::<Gbp, i64>::new(1_00, 2))?;
+        assert_eq!(
+            bag.try_reduce::<Usd>(&rates, RoundingMode::HalfEven),
+            Err(FxError::MissingRate {
+                base: "GBP",
+                quote: "USD"
+            })
+        );
+        Ok(())
+    }
+}
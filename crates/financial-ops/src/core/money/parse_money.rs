@@ -0,0 +1,213 @@
+use alloc::{string::String, vec::Vec};
+use core::str::FromStr;
+
+use crate::core::{
+    Currency, FormatLocale, FromStrDecimals, GroupingStyle, Money, ParseDecimalError,
+    ParseMoneyError,
+};
+
+impl<C: Currency, T: FromStrDecimals> Money<C, T> {
+    /// Parses a formatted amount, like `"$1,234.56"`, `"1.234,56 EUR"` or `"(12.00)"`, into an
+    /// amount with the decimals of the currency.
+    ///
+    /// The amount may carry the symbol or the code of the currency before or after it, a leading
+    /// minus sign, or parentheses for a negative amount. When the amount contains both `.` and
+    /// `,`, the last one is the decimal separator and the other one groups digits. Otherwise the
+    /// separators of `locale` decide, so `"1,234"` is `1234` in `EN_US` and `1.234` in `DE_DE`.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The string to parse.
+    /// * `locale` - The locale whose separators and grouping are expected.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the amount, or a `ParseMoneyError` if the string carries
+    /// another currency, is grouped incorrectly or its digits can't be parsed, like an amount
+    /// with more decimals than the currency.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use financial_ops::{Eur, FormatLocale, Money, ParseMoneyError, Usd};
+    ///
+    /// let amount = Money::<Usd, i64>::parse_money("$1,234.56", FormatLocale::EN_US).unwrap();
+    /// assert_eq!(amount.into_parts(), (1_234_56, 2));
+    ///
+    /// let amount = Money::<Eur, i64>::parse_money("1.234,56 EUR", FormatLocale::EN_US).unwrap();
+    /// assert_eq!(amount.into_parts(), (1_234_56, 2));
+    ///
+    /// assert_eq!(
+    ///     Money::<Usd, i64>::parse_money("1,00 €", FormatLocale::DE_DE),
+    ///     Err(ParseMoneyError::CurrencyMismatch { expected: "USD" })
+    /// );
+    /// ```
+    pub fn parse_money(s: &str, locale: FormatLocale) -> Result<Self, ParseMoneyError> {
+        let mut text = s.trim();
+        let mut negative = false;
+        if let Some(inner) = text
+            .strip_prefix('(')
+            .and_then(|text| text.strip_suffix(')'))
+        {
+            negative = true;
+            text = inner.trim();
+        }
+        if let Some(rest) = text.strip_prefix('-') {
+            negative = !negative;
+            text = rest.trim_start();
+        }
+        let is_number = |c: char| {
+            c.is_ascii_digit()
+                || matches!(c, '+' | '-' | '.' | ',')
+                || c == locale.decimal_separator
+                || c == locale.group_separator
+        };
+        let start = text.find(is_number).unwrap_or(text.len());
+        // The last digit is a single byte, so the amount ends on the next one.
+        let end = text
+            .rfind(|c: char| c.is_ascii_digit())
+            .map_or(start, |index| index.saturating_add(1))
+            .max(start);
+        let (Some(prefix), Some(number), Some(suffix)) =
+            (text.get(..start), text.get(start..end), text.get(end..))
+        else {
+            return Err(ParseMoneyError::Decimal(ParseDecimalError::InvalidDigit));
+        };
+        for marker in [prefix, suffix] {
+            let marker = marker.trim();
+            if !marker.is_empty() && marker != C::SYMBOL && !marker.eq_ignore_ascii_case(C::CODE) {
+                return Err(ParseMoneyError::CurrencyMismatch { expected: C::CODE });
+            }
+        }
+
+        let (decimal_separator, group_separator) = match (number.rfind('.'), number.rfind(',')) {
+            (Some(dot), Some(comma)) if dot > comma => ('.', ','),
+            (Some(_), Some(_)) => (',', '.'),
+            _ => (locale.decimal_separator, locale.group_separator),
+        };
+        let (integer, fraction) = match number.split_once(decimal_separator) {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (number, None),
+        };
+        // A sign after the symbol, like `$-1.00`, is left in front of the digits.
+        let (sign, integer) = match integer.split_at_checked(1) {
+            Some((sign @ ("-" | "+"), unsigned)) => (sign, unsigned),
+            _ => ("", integer),
+        };
+        let groups: Vec<&str> = integer.split(group_separator).collect();
+        if groups.len() > 1 && !valid_groups(&groups, locale.grouping) {
+            return Err(ParseMoneyError::InvalidGrouping);
+        }
+
+        let mut normalized = String::with_capacity(number.len().saturating_add(1));
+        if negative {
+            normalized.push('-');
+        }
+        normalized.push_str(sign);
+        normalized.extend(groups);
+        if let Some(fraction) = fraction {
+            normalized.push('.');
+            normalized.push_str(fraction);
+        }
+        let amount = T::from_str_decimals_with_scale(&normalized, C::DECIMALS)?;
+        Ok(Money::new(amount, C::DECIMALS))
+    }
+}
+
+impl<C: Currency, T: FromStrDecimals> FromStr for Money<C, T> {
+    type Err = ParseMoneyError;
+
+    /// Parses a formatted amount with the default locale of the currency, see
+    /// [`Money::parse_money`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Money::parse_money(s, C::LOCALE)
+    }
+}
+
+/// Returns whether groups of integer digits follow a grouping style, counting thousands for
+/// amounts formatted without grouping.
+fn valid_groups(groups: &[&str], grouping: GroupingStyle) -> bool {
+    let digits = |group: &str| group.bytes().all(|byte| byte.is_ascii_digit());
+    let Some((first, rest)) = groups.split_first() else {
+        return false;
+    };
+    if !(1..=3).contains(&first.len()) || !groups.iter().all(|group| digits(group)) {
+        return false;
+    }
+    match grouping {
+        GroupingStyle::Indian => match rest.split_last() {
+            Some((last, middle)) => {
+                last.len() == 3
+                    && middle.iter().all(|group| group.len() == 2)
+                    && (middle.is_empty() || first.len() <= 2)
+            }
+            None => true,
+        },
+        GroupingStyle::Thousands | GroupingStyle::None => rest.iter().all(|group| group.len() == 3),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Chf, Eur, Inr, Jpy, ParseDecimalError, Usd};
+
+    #[test]
+    fn test_money_parse_money() -> Result<(), ParseMoneyError> {
+        let usd = |s| Money::<Usd, i64>::parse_money(s, FormatLocale::EN_US);
+        assert_eq!(usd("$1,234.56")?, Money::new(1_234_56, 2));
+        assert_eq!(usd("1234.5 USD")?, Money::new(1_234_50, 2));
+        assert_eq!(usd("usd 7")?, Money::new(7_00, 2));
+        assert_eq!(usd("-$1,000.00")?, Money::new(-1_000_00, 2));
+        assert_eq!(usd("$-0.05")?, Money::new(-0_05, 2));
+        assert_eq!(usd("(1,234.56)")?, Money::new(-1_234_56, 2));
+        // A single separator follows the locale, both separators are detected.
+        assert_eq!(usd("1,234")?, Money::new(1_234_00, 2));
+        assert_eq!(usd("1.234,5")?, Money::new(1_234_50, 2));
+
+        let eur = |s| Money::<Eur, i64>::parse_money(s, FormatLocale::DE_DE);
+        assert_eq!(eur("1.234.567,89 €")?, Money::new(1_234_567_89, 2));
+        assert_eq!(eur("1,5")?, Money::new(1_50, 2));
+        assert_eq!(
+            Money::<Eur, i64>::parse_money("1\u{202f}234,56 €", FormatLocale::FR_FR)?,
+            Money::new(1_234_56, 2)
+        );
+        assert_eq!(
+            Money::<Inr, u64>::parse_money("₹12,34,567.89", FormatLocale::EN_IN)?,
+            Money::new(12_34_567_89, 2)
+        );
+        assert_eq!(
+            "CHF 1’234.50".parse(),
+            Ok(Money::<Chf, i64>::new(1_234_50, 2))
+        );
+        assert_eq!("¥1,234".parse(), Ok(Money::<Jpy, u32>::new(1_234, 0)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_money_parse_money_errors() {
+        let usd = |s| Money::<Usd, u64>::parse_money(s, FormatLocale::EN_US);
+        let mismatch = Err(ParseMoneyError::CurrencyMismatch { expected: "USD" });
+        assert_eq!(usd("€1.00"), mismatch);
+        assert_eq!(usd("1.00 EUR"), mismatch);
+        assert_eq!(usd("$1.00 USD"), Ok(Money::new(1_00, 2)));
+        assert_eq!(usd("1,23,4.00"), Err(ParseMoneyError::InvalidGrouping));
+        assert_eq!(usd("1,2345"), Err(ParseMoneyError::InvalidGrouping));
+        assert_eq!(
+            usd("$1.005"),
+            Err(ParseMoneyError::Decimal(ParseDecimalError::TooManyDecimals))
+        );
+        assert_eq!(
+            usd("-$1.00"),
+            Err(ParseMoneyError::Decimal(ParseDecimalError::NegativeValue))
+        );
+        assert_eq!(
+            usd("$"),
+            Err(ParseMoneyError::Decimal(ParseDecimalError::Empty))
+        );
+        assert_eq!(
+            usd("1.2.3"),
+            Err(ParseMoneyError::Decimal(ParseDecimalError::InvalidDigit))
+        );
+    }
+}
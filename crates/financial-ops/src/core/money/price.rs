@@ -0,0 +1,190 @@
+#[cfg(not(feature = "strict-no-panic"))]
+use core::ops::Mul;
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
+use crate::core::{
+    money::exchange_rate::reciprocal, CheckedDecimalOperations, CheckedDivideToScale, Decimal,
+    DecimalOperationError, Money, PowerOfTen, Quantity, RoundingMode,
+};
+
+/// The price of one unit of the asset `Base` in the currency `Quote`, stored as a raw value of
+/// type `T` and its number of decimals.
+///
+/// A bitcoin quoted at `64,250.00` dollars is a `Price<Btc, Usd>`: multiplying it by a quantity
+/// of bitcoin gives dollars, and its inverse is a `Price<Usd, Btc>`, so using a price quoted the
+/// other way around doesn't compile.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{Btc, Price, Quantity, RoundingMode, Usd};
+///
+/// let btc_usd = Price::<Btc, Usd, i64>::new(64_250_00, 2);
+///
+/// let notional = btc_usd.try_mul(Quantity::new(0_015, 3)).unwrap();
+/// assert_eq!(notional.into_parts(), (963_75000, 5));
+///
+/// let usd_btc = btc_usd.invert(8, RoundingMode::HalfEven).unwrap();
+/// assert_eq!(usd_btc.price().into_parts(), (0_00001556, 8));
+/// ```
+pub struct Price<Base, Quote, T = i128> {
+    price: Decimal<T>,
+    assets: PhantomData<(Base, Quote)>,
+}
+
+impl<Base, Quote, T> Price<Base, Quote, T> {
+    /// Creates a price from a raw value and the number of decimals it carries.
+    ///
+    /// # Arguments
+    ///
+    /// * `price` - The raw integer value of the price.
+    /// * `decimals` - The number of decimals in `price`.
+    pub const fn new(price: T, decimals: u32) -> Self {
+        Price::from_decimal(Decimal::new(price, decimals))
+    }
+
+    /// Creates a price from a decimal.
+    pub const fn from_decimal(price: Decimal<T>) -> Self {
+        Price {
+            price,
+            assets: PhantomData,
+        }
+    }
+
+    /// Returns the number of decimals carried by the price.
+    pub const fn decimals(&self) -> u32 {
+        self.price.scale()
+    }
+
+    /// Returns the price as a decimal, dropping the assets.
+    pub fn into_decimal(self) -> Decimal<T> {
+        self.price
+    }
+}
+
+impl<Base, Quote, T: Copy> Price<Base, Quote, T> {
+    /// Returns the price as a decimal.
+    pub fn price(&self) -> Decimal<T> {
+        self.price
+    }
+}
+
+impl<Base, Quote, T: CheckedDecimalOperations> Price<Base, Quote, T> {
+    /// Multiplies the price by a quantity of the base asset, giving its value in the quote
+    /// currency. The decimals of the result are the sum of both scales, so nothing is rounded.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the value, or a `DecimalOperationError` if the operation
+    /// fails.
+    pub fn try_mul(
+        self,
+        quantity: Quantity<Base, T>,
+    ) -> Result<Money<Quote, T>, DecimalOperationError> {
+        self.price.try_mul(quantity.into_decimal()).map(Money::from)
+    }
+}
+
+impl<Base, Quote, T: CheckedDivideToScale + PowerOfTen + Copy> Price<Base, Quote, T> {
+    /// Computes the price of one unit of the quote currency in the base asset, with `decimals`
+    /// decimals.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the inverted price, or a `DecimalOperationError` if the
+    /// price is zero or the reciprocal doesn't fit in the type.
+    pub fn invert(
+        &self,
+        decimals: u32,
+        rounding: RoundingMode,
+    ) -> Result<Price<Quote, Base, T>, DecimalOperationError> {
+        reciprocal(self.price, decimals, rounding).map(Price::from_decimal)
+    }
+}
+
+#[cfg(not(feature = "strict-no-panic"))]
+impl<Base, Quote, T: CheckedDecimalOperations> Mul<Quantity<Base, T>> for Price<Base, Quote, T> {
+    type Output = Money<Quote, T>;
+
+    /// # Panics
+    ///
+    /// Panics if the underlying checked operation fails.
+    fn mul(self, quantity: Quantity<Base, T>) -> Self::Output {
+        Money::from(self.price * quantity.into_decimal())
+    }
+}
+
+// The traits below are implemented by hand so they don't require the asset markers to implement
+// them.
+
+impl<Base, Quote, T: Clone> Clone for Price<Base, Quote, T> {
+    fn clone(&self) -> Self {
+        Price::from_decimal(self.price.clone())
+    }
+}
+
+impl<Base, Quote, T: Copy> Copy for Price<Base, Quote, T> {}
+
+impl<Base, Quote, T: PartialEq> PartialEq for Price<Base, Quote, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.price == other.price
+    }
+}
+
+impl<Base, Quote, T: Eq> Eq for Price<Base, Quote, T> {}
+
+impl<Base, Quote, T: Hash> Hash for Price<Base, Quote, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.price.hash(state);
+    }
+}
+
+impl<Base, Quote, T: fmt::Debug> fmt::Debug for Price<Base, Quote, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Price")
+            .field("price", self.price.value_ref())
+            .field("decimals", &self.price.scale())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Eth, Usd};
+
+    #[test]
+    fn test_price_mul() -> Result<(), DecimalOperationError> {
+        let eth_usd = Price::<Eth, Usd, i64>::new(3_150_25, 2);
+        let notional: Money<Usd, i64> = eth_usd.try_mul(Quantity::new(-2_5, 1))?;
+        assert_eq!(notional.into_parts(), (-7_875_625, 3));
+        assert_eq!(
+            Price::<Eth, Usd, u8>::new(200, 0).try_mul(Quantity::new(2, 0)),
+            Err(DecimalOperationError::Overflow)
+        );
+        Ok(())
+    }
+
+    #[cfg(not(feature = "strict-no-panic"))]
+    #[test]
+    fn test_price_mul_operator() {
+        let eth_usd = Price::<Eth, Usd, i64>::new(3_150_25, 2);
+        assert_eq!((eth_usd * Quantity::new(2, 0)).into_parts(), (6_300_50, 2));
+    }
+
+    #[test]
+    fn test_price_invert() -> Result<(), DecimalOperationError> {
+        let usd_eth: Price<Usd, Eth, i64> =
+            Price::<Eth, Usd, i64>::new(2_500_00, 2).invert(6, RoundingMode::HalfEven)?;
+        assert_eq!(usd_eth.price().into_parts(), (0_000400, 6));
+        assert_eq!(
+            Price::<Eth, Usd, i64>::new(0, 2).invert(6, RoundingMode::HalfEven),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+        Ok(())
+    }
+}
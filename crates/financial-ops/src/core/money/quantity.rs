@@ -0,0 +1,206 @@
+#[cfg(not(feature = "strict-no-panic"))]
+use core::ops::Mul;
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
+use crate::core::{CheckedDecimalOperations, Decimal, DecimalOperationError, Money, Price};
+
+/// A quantity of the asset `A`, like a number of shares or an amount of bitcoin, stored as a raw
+/// value of type `T` and its number of decimals.
+///
+/// Multiplying a quantity by a [`Price`] of the same asset gives the notional value in the quote
+/// currency, with the decimals of both factors, and multiplying it by the price of another asset
+/// doesn't compile.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{Btc, Price, Quantity, Usd};
+///
+/// let size = Quantity::<Btc, i64>::new(0_015, 3);
+/// let btc_usd = Price::<Btc, Usd, i64>::new(64_250_00, 2);
+///
+/// let notional = size.try_mul(btc_usd).unwrap();
+/// assert_eq!(notional.into_parts(), (963_75000, 5));
+/// ```
+///
+/// ```compile_fail
+/// use financial_ops::{Btc, Eth, Price, Quantity, Usd};
+///
+/// let size = Quantity::<Eth, i64>::new(2, 0);
+/// let btc_usd = Price::<Btc, Usd, i64>::new(64_250_00, 2);
+///
+/// let _ = size.try_mul(btc_usd);
+/// ```
+pub struct Quantity<A, T = i128> {
+    quantity: Decimal<T>,
+    asset: PhantomData<A>,
+}
+
+impl<A, T> Quantity<A, T> {
+    /// Creates a quantity from a raw value and the number of decimals it carries.
+    ///
+    /// # Arguments
+    ///
+    /// * `quantity` - The raw integer value.
+    /// * `decimals` - The number of decimals in `quantity`.
+    pub const fn new(quantity: T, decimals: u32) -> Self {
+        Quantity::from_decimal(Decimal::new(quantity, decimals))
+    }
+
+    /// Creates a quantity from a decimal.
+    pub const fn from_decimal(quantity: Decimal<T>) -> Self {
+        Quantity {
+            quantity,
+            asset: PhantomData,
+        }
+    }
+
+    /// Returns the number of decimals carried by the quantity.
+    pub const fn decimals(&self) -> u32 {
+        self.quantity.scale()
+    }
+
+    /// Returns the quantity as a decimal, dropping the asset.
+    pub fn into_decimal(self) -> Decimal<T> {
+        self.quantity
+    }
+
+    /// Splits the quantity into its raw value and number of decimals.
+    pub fn into_parts(self) -> (T, u32) {
+        self.quantity.into_parts()
+    }
+}
+
+impl<A, T: Copy> Quantity<A, T> {
+    /// Returns the quantity as a decimal.
+    pub fn quantity(&self) -> Decimal<T> {
+        self.quantity
+    }
+}
+
+impl<A, T: CheckedDecimalOperations> Quantity<A, T> {
+    /// Adds two quantities of the same asset, aligning their decimals.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the sum, or a `DecimalOperationError` if the operation fails.
+    pub fn try_add(self, other: Self) -> Result<Self, DecimalOperationError> {
+        self.quantity
+            .try_add(other.quantity)
+            .map(Quantity::from_decimal)
+    }
+
+    /// Subtracts `other` from `self`, aligning their decimals.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the difference, or a `DecimalOperationError` if the
+    /// operation fails.
+    pub fn try_sub(self, other: Self) -> Result<Self, DecimalOperationError> {
+        self.quantity
+            .try_sub(other.quantity)
+            .map(Quantity::from_decimal)
+    }
+
+    /// Multiplies the quantity by a price of its asset, giving the notional value in the quote
+    /// currency. The decimals of the result are the sum of both scales, so nothing is rounded.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the notional value, or a `DecimalOperationError` if the
+    /// operation fails.
+    pub fn try_mul<Quote>(
+        self,
+        price: Price<A, Quote, T>,
+    ) -> Result<Money<Quote, T>, DecimalOperationError> {
+        price.try_mul(self)
+    }
+}
+
+impl<A, T> From<Decimal<T>> for Quantity<A, T> {
+    fn from(quantity: Decimal<T>) -> Self {
+        Quantity::from_decimal(quantity)
+    }
+}
+
+#[cfg(not(feature = "strict-no-panic"))]
+impl<A, Quote, T: CheckedDecimalOperations> Mul<Price<A, Quote, T>> for Quantity<A, T> {
+    type Output = Money<Quote, T>;
+
+    /// # Panics
+    ///
+    /// Panics if the underlying checked operation fails.
+    fn mul(self, price: Price<A, Quote, T>) -> Self::Output {
+        price * self
+    }
+}
+
+// The traits below are implemented by hand so they don't require the asset marker to implement
+// them.
+
+impl<A, T: Clone> Clone for Quantity<A, T> {
+    fn clone(&self) -> Self {
+        Quantity::from_decimal(self.quantity.clone())
+    }
+}
+
+impl<A, T: Copy> Copy for Quantity<A, T> {}
+
+impl<A, T: PartialEq> PartialEq for Quantity<A, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.quantity == other.quantity
+    }
+}
+
+impl<A, T: Eq> Eq for Quantity<A, T> {}
+
+impl<A, T: Hash> Hash for Quantity<A, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.quantity.hash(state);
+    }
+}
+
+impl<A, T: fmt::Debug> fmt::Debug for Quantity<A, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Quantity")
+            .field("quantity", self.quantity.value_ref())
+            .field("decimals", &self.quantity.scale())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Eth, Usd};
+
+    #[test]
+    fn test_quantity_arithmetic() -> Result<(), DecimalOperationError> {
+        let bought = Quantity::<Eth, i64>::new(1_5, 1);
+        let sold = Quantity::<Eth, i64>::new(0_25, 2);
+        assert_eq!(bought.try_add(sold)?.into_parts(), (1_75, 2));
+        assert_eq!(bought.try_sub(sold)?.into_parts(), (1_25, 2));
+
+        let eth_usd = Price::<Eth, Usd, i64>::new(3_150_25, 2);
+        let notional: Money<Usd, i64> = bought.try_mul(eth_usd)?;
+        assert_eq!(notional.into_parts(), (4_725_375, 3));
+        assert_eq!(
+            Quantity::<Eth, u8>::new(2, 0).try_mul(Price::<Eth, Usd, u8>::new(200, 0)),
+            Err(DecimalOperationError::Overflow)
+        );
+        Ok(())
+    }
+
+    #[cfg(not(feature = "strict-no-panic"))]
+    #[test]
+    fn test_quantity_mul_operator() {
+        let eth_usd = Price::<Eth, Usd, i64>::new(3_150_25, 2);
+        let size = Quantity::<Eth, i64>::new(2, 0);
+        assert_eq!((size * eth_usd).into_parts(), (6_300_50, 2));
+        assert_eq!(size * eth_usd, eth_usd * size);
+    }
+}
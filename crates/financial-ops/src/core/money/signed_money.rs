@@ -0,0 +1,254 @@
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+use crate::core::{
+    CheckedDecimalOperations, CheckedSignDecimals, CompareDecimals, Currency,
+    DecimalOperationError, Money, Signedness,
+};
+
+/// The side of a ledger an amount is posted to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// The left side, which increases assets and expenses.
+    Debit,
+    /// The right side, which increases liabilities, equity and income.
+    Credit,
+}
+
+impl Direction {
+    /// Returns the other side.
+    pub const fn opposite(self) -> Self {
+        match self {
+            Direction::Debit => Direction::Credit,
+            Direction::Credit => Direction::Debit,
+        }
+    }
+}
+
+/// An amount of money with an explicit debit or credit direction, so the direction of an
+/// accounting entry isn't encoded in the sign of its amount.
+///
+/// The amount is a magnitude, which works with unsigned types. Adding a debit and a credit nets
+/// them: the result takes the direction of the larger one.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{Direction, Money, SignedMoney, Usd};
+///
+/// let invoice = SignedMoney::debit(Money::<Usd, u64>::new(120_00, 2));
+/// let payment = SignedMoney::credit(Money::new(150_00, 2));
+///
+/// let balance = invoice.try_add(payment).unwrap();
+/// assert_eq!(balance.direction(), Direction::Credit);
+/// assert_eq!(balance.amount(), Money::new(30_00, 2));
+/// ```
+pub struct SignedMoney<C, T = i128> {
+    amount: Money<C, T>,
+    direction: Direction,
+}
+
+impl<C, T> SignedMoney<C, T> {
+    /// Creates an amount posted in `direction`.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - The non-negative magnitude of the amount.
+    /// * `direction` - The side the amount is posted to.
+    pub const fn new(amount: Money<C, T>, direction: Direction) -> Self {
+        SignedMoney { amount, direction }
+    }
+
+    /// Creates a debit of `amount`.
+    pub const fn debit(amount: Money<C, T>) -> Self {
+        SignedMoney::new(amount, Direction::Debit)
+    }
+
+    /// Creates a credit of `amount`.
+    pub const fn credit(amount: Money<C, T>) -> Self {
+        SignedMoney::new(amount, Direction::Credit)
+    }
+
+    /// Returns the side the amount is posted to.
+    pub const fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// Returns `true` if the amount is a debit.
+    pub const fn is_debit(&self) -> bool {
+        matches!(self.direction, Direction::Debit)
+    }
+
+    /// Returns `true` if the amount is a credit.
+    pub const fn is_credit(&self) -> bool {
+        matches!(self.direction, Direction::Credit)
+    }
+
+    /// Returns the same amount posted to the other side, like the reversal of an entry.
+    pub fn reverse(self) -> Self {
+        SignedMoney::new(self.amount, self.direction.opposite())
+    }
+}
+
+impl<C, T: Copy> SignedMoney<C, T> {
+    /// Returns the magnitude of the amount.
+    pub fn amount(&self) -> Money<C, T> {
+        self.amount
+    }
+}
+
+impl<C, T: CheckedDecimalOperations + CompareDecimals> SignedMoney<C, T> {
+    /// Adds two amounts, netting a debit against a credit.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the sum, in the direction of the larger amount or of `self`
+    /// when they cancel out, or a `DecimalOperationError` if the operation fails.
+    pub fn try_add(self, other: Self) -> Result<Self, DecimalOperationError> {
+        if self.direction == other.direction {
+            return Ok(SignedMoney::new(
+                self.amount.try_add(other.amount)?,
+                self.direction,
+            ));
+        }
+        match self.amount.cmp_value(&other.amount) {
+            Ordering::Less => Ok(SignedMoney::new(
+                other.amount.try_sub(self.amount)?,
+                other.direction,
+            )),
+            _ => Ok(SignedMoney::new(
+                self.amount.try_sub(other.amount)?,
+                self.direction,
+            )),
+        }
+    }
+
+    /// Subtracts `other` from `self`, which adds its reversal.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the difference, or a `DecimalOperationError` if the
+    /// operation fails.
+    pub fn try_sub(self, other: Self) -> Result<Self, DecimalOperationError> {
+        self.try_add(other.reverse())
+    }
+}
+
+impl<C, T: CheckedSignDecimals + Signedness + Copy> SignedMoney<C, T> {
+    /// Creates an amount from a signed amount, where positive amounts are posted to `positive`
+    /// and negative amounts to the other side.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the amount, or a `DecimalOperationError` if the magnitude of
+    /// `amount` doesn't fit in the type, like `i64::MIN`.
+    pub fn from_money(
+        amount: Money<C, T>,
+        positive: Direction,
+    ) -> Result<Self, DecimalOperationError> {
+        if amount.amount().is_negative() {
+            Ok(SignedMoney::new(amount.try_abs()?, positive.opposite()))
+        } else {
+            Ok(SignedMoney::new(amount, positive))
+        }
+    }
+
+    /// Converts the amount to a signed amount, positive when posted to `positive` and negative
+    /// otherwise, like the balance of an asset account with `Direction::Debit`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the signed amount, or a `DecimalOperationError` if it can't
+    /// be negated, like with an unsigned type.
+    pub fn to_money(self, positive: Direction) -> Result<Money<C, T>, DecimalOperationError> {
+        if self.direction == positive {
+            Ok(self.amount)
+        } else {
+            self.amount.try_neg()
+        }
+    }
+}
+
+// The traits below are implemented by hand so they don't require the currency marker to
+// implement them.
+
+impl<C, T: Clone> Clone for SignedMoney<C, T> {
+    fn clone(&self) -> Self {
+        SignedMoney::new(self.amount.clone(), self.direction)
+    }
+}
+
+impl<C, T: Copy> Copy for SignedMoney<C, T> {}
+
+impl<C, T: PartialEq> PartialEq for SignedMoney<C, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.amount == other.amount && self.direction == other.direction
+    }
+}
+
+impl<C, T: Eq> Eq for SignedMoney<C, T> {}
+
+impl<C, T: Hash> Hash for SignedMoney<C, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.amount.hash(state);
+        self.direction.hash(state);
+    }
+}
+
+impl<C: Currency, T: fmt::Debug> fmt::Debug for SignedMoney<C, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SignedMoney")
+            .field("amount", &self.amount)
+            .field("direction", &self.direction)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Usd;
+
+    #[test]
+    fn test_signed_money_netting() -> Result<(), DecimalOperationError> {
+        let debit = SignedMoney::debit(Money::<Usd, u64>::new(100_00, 2));
+        let credit = SignedMoney::credit(Money::new(40_0, 1));
+        assert!(debit.is_debit() && credit.is_credit());
+
+        let net = debit.try_add(credit)?;
+        assert_eq!(net, SignedMoney::debit(Money::new(60_00, 2)));
+        assert_eq!(
+            credit.try_add(credit)?,
+            SignedMoney::credit(Money::new(80_0, 1))
+        );
+        // Subtracting a debit from a smaller debit flips to a credit.
+        assert_eq!(
+            credit.reverse().try_sub(debit)?,
+            SignedMoney::credit(Money::new(60_00, 2))
+        );
+        let zero = debit.try_add(debit.reverse())?;
+        assert!(zero.is_debit());
+        assert_eq!(zero.amount().amount(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_signed_money_conversions() -> Result<(), DecimalOperationError> {
+        let refund = SignedMoney::from_money(Money::<Usd, i64>::new(-25_00, 2), Direction::Debit)?;
+        assert_eq!(refund, SignedMoney::credit(Money::new(25_00, 2)));
+        assert_eq!(refund.to_money(Direction::Credit)?, Money::new(25_00, 2));
+        assert_eq!(refund.to_money(Direction::Debit)?, Money::new(-25_00, 2));
+        assert_eq!(
+            SignedMoney::from_money(Money::<Usd, i64>::new(i64::MIN, 2), Direction::Debit),
+            Err(DecimalOperationError::Overflow)
+        );
+        assert_eq!(
+            SignedMoney::debit(Money::<Usd, u64>::new(1, 2)).to_money(Direction::Credit),
+            Err(DecimalOperationError::NegativeResult)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,329 @@
+#[cfg(feature = "alloc")]
+use alloc::collections::VecDeque;
+use core::cmp::Ordering;
+
+use crate::core::{
+    interest::{annuity::GUARD_DECIMALS, compound::FACTOR_DECIMALS},
+    money::exchange_rate::multiply_to_scale,
+    percentage::bps::bps_to_fraction,
+    CheckedDecimalOperations, CheckedDivideToScale, CheckedMulDiv, CompareDecimals, Decimal,
+    DecimalOperationError, PowerOfTen, RescaleDecimals, RoundingMode, Zero,
+};
+
+/// A simple moving average over the last `period` values pushed.
+///
+/// The sum of the window is kept exactly, adding the values pushed and subtracting the values
+/// leaving the window, so it never drifts however many values go through it. Only the average
+/// is rounded, once, to the scale of the moving average.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{Decimal, SimpleMovingAverage};
+///
+/// let mut sma = SimpleMovingAverage::new(3, 2).unwrap();
+/// assert_eq!(sma.push(Decimal::new(10_00i64, 2)).unwrap(), None);
+/// assert_eq!(sma.push(Decimal::new(11_00, 2)).unwrap(), None);
+/// assert_eq!(sma.push(Decimal::new(13_00, 2)).unwrap(), Some(Decimal::new(11_33, 2)));
+/// assert_eq!(sma.push(Decimal::new(12_50, 2)).unwrap(), Some(Decimal::new(12_17, 2)));
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SimpleMovingAverage<T = i128> {
+    period: u32,
+    window: VecDeque<Decimal<T>>,
+    sum: Decimal<T>,
+    scale: u32,
+    rounding: RoundingMode,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> SimpleMovingAverage<T>
+where
+    T: CheckedDecimalOperations + CheckedDivideToScale + Zero + From<u32> + Copy,
+{
+    /// Creates a moving average over `period` values, rounded half to even to `scale` decimals.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the moving average, or a `DecimalOperationError` if
+    /// `period` is zero, reported as `OutOfDomain`.
+    pub fn new(period: u32, scale: u32) -> Result<Self, DecimalOperationError> {
+        if period == 0 {
+            return Err(DecimalOperationError::OutOfDomain);
+        }
+        Ok(SimpleMovingAverage {
+            period,
+            window: VecDeque::new(),
+            sum: Decimal::zero(0),
+            scale,
+            rounding: RoundingMode::HalfEven,
+        })
+    }
+
+    /// Sets the rounding applied to the average.
+    pub fn rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Returns the number of values averaged.
+    pub fn period(&self) -> u32 {
+        self.period
+    }
+
+    /// Adds a value, dropping the oldest one once the window is full.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the average of the window, `None` until `period` values
+    /// were pushed, or a `DecimalOperationError` if the operation fails. The moving average is
+    /// left unchanged on error.
+    pub fn push(&mut self, value: Decimal<T>) -> Result<Option<Decimal<T>>, DecimalOperationError> {
+        let mut sum = self.sum.try_add(value)?;
+        if self.window.len() == self.period as usize {
+            if let Some(&oldest) = self.window.front() {
+                sum = sum.try_sub(oldest)?;
+            }
+            self.window.pop_front();
+        }
+        self.window.push_back(value);
+        self.sum = sum;
+        self.value()
+    }
+
+    /// Returns the average of the window.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the average, `None` until `period` values were pushed, or a
+    /// `DecimalOperationError` if the operation fails.
+    pub fn value(&self) -> Result<Option<Decimal<T>>, DecimalOperationError> {
+        if self.window.len() < self.period as usize {
+            return Ok(None);
+        }
+        self.sum
+            .try_div_to_scale(
+                Decimal::new(T::from(self.period), 0),
+                self.scale,
+                self.rounding,
+            )
+            .map(Some)
+    }
+}
+
+/// An exponential moving average with a smoothing factor in basis points: every value pushed
+/// moves the average by the factor of its distance to it, `average + alpha * (value - average)`.
+///
+/// The first value pushed seeds the average. Every update is rounded once to the scale of the
+/// moving average and the average carries that rounding forward, so the same values give the
+/// same averages on every platform, unlike a float; a few decimals more than the values keep the
+/// rounding from adding up.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{Decimal, ExponentialMovingAverage};
+///
+/// // A smoothing factor of 25%
+/// let mut ema = ExponentialMovingAverage::new(Decimal::new(2_500i64, 0), 4).unwrap();
+/// assert_eq!(ema.push(Decimal::new(10_00, 2)).unwrap(), Decimal::new(10_0000, 4));
+/// assert_eq!(ema.push(Decimal::new(11_00, 2)).unwrap(), Decimal::new(10_2500, 4));
+/// assert_eq!(ema.push(Decimal::new(13_00, 2)).unwrap(), Decimal::new(10_9375, 4));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExponentialMovingAverage<T = i128> {
+    alpha: Decimal<T>,
+    average: Option<Decimal<T>>,
+    scale: u32,
+    rounding: RoundingMode,
+}
+
+impl<T> ExponentialMovingAverage<T>
+where
+    T: CheckedDecimalOperations
+        + CheckedDivideToScale
+        + CheckedMulDiv
+        + CompareDecimals
+        + RescaleDecimals
+        + PowerOfTen
+        + Zero
+        + From<u32>
+        + Copy,
+{
+    /// Creates an exponential moving average with a smoothing factor of `smoothing_bps` basis
+    /// points, rounded half to even to `scale` decimals.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the moving average, or a `DecimalOperationError` if the
+    /// smoothing factor isn't above zero and at most `10_000` basis points, reported as
+    /// `OutOfDomain`, or the operation fails.
+    pub fn new(smoothing_bps: Decimal<T>, scale: u32) -> Result<Self, DecimalOperationError> {
+        let alpha = bps_to_fraction(smoothing_bps)?;
+        if alpha.cmp_value(&Decimal::zero(0)) != Ordering::Greater
+            || alpha.cmp_value(&Decimal::one(0)?) == Ordering::Greater
+        {
+            return Err(DecimalOperationError::OutOfDomain);
+        }
+        Ok(ExponentialMovingAverage {
+            alpha,
+            average: None,
+            scale,
+            rounding: RoundingMode::HalfEven,
+        })
+    }
+
+    /// Creates an exponential moving average over a number of periods, with the usual smoothing
+    /// factor of `2 / (period + 1)`, rounded half to even to 18 decimals.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the moving average, or a `DecimalOperationError` if
+    /// `period` is zero, reported as `OutOfDomain`, or the operation fails.
+    pub fn with_period(period: u32, scale: u32) -> Result<Self, DecimalOperationError> {
+        let periods = period
+            .checked_add(1)
+            .filter(|_| period > 0)
+            .ok_or(DecimalOperationError::OutOfDomain)?;
+        let alpha = Decimal::new(T::from(2), 0).try_div_to_scale(
+            Decimal::new(T::from(periods), 0),
+            FACTOR_DECIMALS,
+            RoundingMode::HalfEven,
+        )?;
+        Ok(ExponentialMovingAverage {
+            alpha,
+            average: None,
+            scale,
+            rounding: RoundingMode::HalfEven,
+        })
+    }
+
+    /// Sets the rounding applied to the average.
+    pub fn rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Returns the smoothing factor, as a fraction of one.
+    pub fn alpha(&self) -> Decimal<T> {
+        self.alpha
+    }
+
+    /// Returns the average, or `None` before the first value is pushed.
+    pub fn value(&self) -> Option<Decimal<T>> {
+        self.average
+    }
+
+    /// Adds a value and moves the average towards it, `average * (1 - alpha) + value * alpha`.
+    ///
+    /// Both products are computed with 6 more decimals than the average, in an intermediate
+    /// twice as wide as the type, and their sum is rounded once.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the new average, or a `DecimalOperationError` if the
+    /// operation fails. The moving average is left unchanged on error.
+    pub fn push(&mut self, value: Decimal<T>) -> Result<Decimal<T>, DecimalOperationError> {
+        let average = match self.average {
+            None => value.try_rescale(self.scale, self.rounding)?,
+            Some(average) => {
+                let decimals = self
+                    .scale
+                    .checked_add(GUARD_DECIMALS)
+                    .ok_or(DecimalOperationError::ScaleTooLarge)?;
+                let kept = Decimal::one(self.alpha.scale())?.try_sub(self.alpha)?;
+                let product = |amount, weight| {
+                    multiply_to_scale(amount, weight, decimals, RoundingMode::HalfEven)
+                        .map(|product| Decimal::new(product, decimals))
+                };
+                product(average, kept)?
+                    .try_add(product(value, self.alpha)?)?
+                    .try_rescale(self.scale, self.rounding)?
+            }
+        };
+        self.average = Some(average);
+        Ok(average)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_simple_moving_average() -> Result<(), DecimalOperationError> {
+        let mut sma = SimpleMovingAverage::new(2, 1)?.rounding(RoundingMode::Down);
+        assert_eq!(sma.period(), 2);
+        assert_eq!(sma.value()?, None);
+        assert_eq!(sma.push(Decimal::new(1i64, 0))?, None);
+        assert_eq!(sma.push(Decimal::new(2_25, 2))?, Some(Decimal::new(1_6, 1)));
+        // The window keeps the exact sum across scales, 2.25 + 0.001.
+        assert_eq!(
+            sma.push(Decimal::new(0_001, 3))?,
+            Some(Decimal::new(1_1, 1))
+        );
+        assert_eq!(sma.push(Decimal::new(-5, 0))?, Some(Decimal::new(-2_4, 1)));
+        assert_eq!(
+            sma.push(Decimal::new(i64::MAX, 0)),
+            Err(DecimalOperationError::Overflow)
+        );
+        assert_eq!(sma.value()?, Some(Decimal::new(-2_4, 1)));
+        assert_eq!(
+            SimpleMovingAverage::<i64>::new(0, 2),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_exponential_moving_average() -> Result<(), DecimalOperationError> {
+        // A 3-period average has a smoothing factor of 50%.
+        let mut ema = ExponentialMovingAverage::with_period(3, 2)?;
+        assert_eq!(ema.alpha(), Decimal::new(5 * 10i128.pow(17), 18));
+        assert_eq!(ema.value(), None);
+        assert_eq!(ema.push(Decimal::new(10_005, 3))?, Decimal::new(10_00, 2));
+        assert_eq!(ema.push(Decimal::new(11, 0))?, Decimal::new(10_50, 2));
+        assert_eq!(ema.push(Decimal::new(10_75, 2))?, Decimal::new(10_62, 2));
+        assert_eq!(ema.value(), Some(Decimal::new(10_62, 2)));
+
+        // 2 / 7 = 0.285714...
+        let mut ema = ExponentialMovingAverage::with_period(6, 4)?;
+        ema.push(Decimal::new(0i128, 0))?;
+        assert_eq!(ema.push(Decimal::new(1, 0))?, Decimal::new(0_2857, 4));
+
+        let mut full = ExponentialMovingAverage::new(Decimal::new(10_000i64, 0), 0)?;
+        full.push(Decimal::new(4, 0))?;
+        assert_eq!(full.push(Decimal::new(7, 0))?, Decimal::new(7, 0));
+        for smoothing in [0i64, 10_001, -1] {
+            assert_eq!(
+                ExponentialMovingAverage::new(Decimal::new(smoothing, 0), 2),
+                Err(DecimalOperationError::OutOfDomain)
+            );
+        }
+        assert_eq!(
+            ExponentialMovingAverage::<i64>::with_period(0, 2),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_exponential_moving_average_64_bits() -> Result<(), DecimalOperationError> {
+        // A falling unsigned average never goes through a negative difference.
+        let mut unsigned = ExponentialMovingAverage::new(Decimal::new(2_500u64, 0), 2)?;
+        unsigned.push(Decimal::new(100_00, 2))?;
+        assert_eq!(
+            unsigned.push(Decimal::new(90_00, 2))?,
+            Decimal::new(97_50, 2)
+        );
+
+        // The smoothing factor with 18 decimals times the values doesn't fit in an i64.
+        let mut ema = ExponentialMovingAverage::with_period(3, 2)?;
+        ema.push(Decimal::new(100_00i64, 2))?;
+        assert_eq!(ema.push(Decimal::new(110_00, 2))?, Decimal::new(105_00, 2));
+        assert_eq!(ema.push(Decimal::new(90_00, 2))?, Decimal::new(97_50, 2));
+        Ok(())
+    }
+}
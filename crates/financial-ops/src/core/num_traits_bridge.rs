@@ -0,0 +1,216 @@
+//! Bridge to the `num-traits` checked traits, enabled with the `num-traits` feature.
+//!
+//! This crate's helper traits can't be blanket implemented for every `num_traits::CheckedAdd`
+//! type, because that would conflict with the implementations for the primitive integers. Types
+//! opt in instead by implementing the [`NumTraitsBridge`] marker trait, which makes every helper
+//! trait, and through them [`CheckedDecimalOperations`](crate::core::CheckedDecimalOperations),
+//! available for any type implementing the matching `num-traits` traits.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use financial_ops::{CheckedDecimalOperations, NumTraitsBridge};
+//!
+//! impl NumTraitsBridge for MyFixedPointType {}
+//!
+//! let (sum, decimals) = a.add_decimals_checked(b, 2, 4)?;
+//! ```
+
+pub use num_traits;
+
+use crate::core::{
+    CheckedAbs, CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, PowerOfTen,
+    Signedness, Zero,
+};
+
+/// A marker trait opting a type into the helper trait implementations backed by `num-traits`.
+pub trait NumTraitsBridge {}
+
+macro_rules! impl_bridge_checked_arithmetic {
+    ($($local:ident $foreign:ident $method:ident;)*) => ($(
+        impl<T: NumTraitsBridge + num_traits::$foreign> $local for T {
+            fn $method(&self, v: &Self) -> Option<Self> {
+                num_traits::$foreign::$method(self, v)
+            }
+        }
+    )*)
+}
+
+impl_bridge_checked_arithmetic! {
+    CheckedAdd CheckedAdd checked_add;
+    CheckedSub CheckedSub checked_sub;
+    CheckedMul CheckedMul checked_mul;
+    CheckedDiv CheckedDiv checked_div;
+    CheckedRem CheckedRem checked_rem;
+}
+
+impl<T: NumTraitsBridge + num_traits::CheckedNeg> CheckedNeg for T {
+    fn checked_neg(&self) -> Option<Self> {
+        num_traits::CheckedNeg::checked_neg(self)
+    }
+}
+
+impl<T> CheckedAbs for T
+where
+    T: NumTraitsBridge + num_traits::CheckedNeg + num_traits::Zero + PartialOrd + Clone,
+{
+    fn checked_abs(&self) -> Option<Self> {
+        if *self < T::zero() {
+            num_traits::CheckedNeg::checked_neg(self)
+        } else {
+            Some(self.clone())
+        }
+    }
+}
+
+impl<T> Signedness for T
+where
+    T: NumTraitsBridge + num_traits::Zero + num_traits::One + num_traits::CheckedSub + PartialOrd,
+{
+    fn is_signed() -> bool {
+        // Only signed types can represent `0 - 1`.
+        num_traits::CheckedSub::checked_sub(&T::zero(), &T::one()).is_some()
+    }
+    fn is_negative(&self) -> bool {
+        *self < T::zero()
+    }
+}
+
+impl<T: NumTraitsBridge + num_traits::Zero> Zero for T {
+    fn zero() -> Self {
+        num_traits::Zero::zero()
+    }
+    fn is_zero(&self) -> bool {
+        num_traits::Zero::is_zero(self)
+    }
+}
+
+/// Returns `10` in the target type, built from `1` so no conversion trait is needed.
+fn ten<T>() -> Option<T>
+where
+    T: num_traits::One + num_traits::CheckedAdd,
+{
+    (1..10).try_fold(T::one(), |value, _| value.checked_add(&T::one()))
+}
+
+impl<T> PowerOfTen for T
+where
+    T: NumTraitsBridge
+        + num_traits::One
+        + num_traits::CheckedAdd
+        + num_traits::CheckedMul
+        + num_traits::WrappingMul
+        + num_traits::ops::overflowing::OverflowingMul
+        + Clone,
+{
+    fn checked_power_of_ten(exponent: u32) -> Option<Self> {
+        let ten = ten::<T>()?;
+        (0..exponent).try_fold(T::one(), |value, _| value.checked_mul(&ten))
+    }
+    fn wrapping_power_of_ten(exponent: u32) -> Self {
+        let ten = ten::<T>().unwrap_or_else(T::one);
+        (0..exponent).fold(T::one(), |value, _| value.wrapping_mul(&ten))
+    }
+    fn overflowing_power_of_ten(exponent: u32) -> (Self, bool) {
+        let ten = ten::<T>().unwrap_or_else(T::one);
+        (0..exponent).fold((T::one(), false), |(value, overflowed), _| {
+            let (value, overflow) = value.overflowing_mul(&ten);
+            (value, overflowed || overflow)
+        })
+    }
+    #[cfg(not(feature = "strict-no-panic"))]
+    fn power_of_ten(exponent: u32) -> Self {
+        Self::checked_power_of_ten(exponent).expect("attempt to multiply with overflow")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ops::{Add, Div, Mul, Rem, Sub};
+
+    use super::*;
+    use crate::core::{CheckedDecimalOperations, DecimalOperationError};
+
+    /// A third-party style numeric type that only implements the `num-traits` traits.
+    #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+    struct Units(i32);
+
+    macro_rules! impl_units_op {
+        ($($op:ident $method:ident $checked:ident $checked_method:ident;)*) => ($(
+            impl $op for Units {
+                type Output = Units;
+                fn $method(self, other: Units) -> Units {
+                    Units(self.0.$method(other.0))
+                }
+            }
+            impl num_traits::$checked for Units {
+                fn $checked_method(&self, v: &Units) -> Option<Units> {
+                    self.0.$checked_method(v.0).map(Units)
+                }
+            }
+        )*)
+    }
+
+    impl_units_op! {
+        Add add CheckedAdd checked_add;
+        Sub sub CheckedSub checked_sub;
+        Mul mul CheckedMul checked_mul;
+        Div div CheckedDiv checked_div;
+        Rem rem CheckedRem checked_rem;
+    }
+
+    impl num_traits::WrappingMul for Units {
+        fn wrapping_mul(&self, v: &Units) -> Units {
+            Units(self.0.wrapping_mul(v.0))
+        }
+    }
+
+    impl num_traits::ops::overflowing::OverflowingMul for Units {
+        fn overflowing_mul(&self, v: &Units) -> (Units, bool) {
+            let (value, overflow) = self.0.overflowing_mul(v.0);
+            (Units(value), overflow)
+        }
+    }
+
+    impl num_traits::Zero for Units {
+        fn zero() -> Units {
+            Units(0)
+        }
+        fn is_zero(&self) -> bool {
+            self.0 == 0
+        }
+    }
+
+    impl num_traits::One for Units {
+        fn one() -> Units {
+            Units(1)
+        }
+    }
+
+    impl NumTraitsBridge for Units {}
+
+    #[test]
+    fn test_bridged_helper_traits() {
+        assert!(Units::is_signed());
+        assert!(Units(-1).is_negative());
+        assert_eq!(Units::checked_power_of_ten(9), Some(Units(1_000_000_000)));
+        assert_eq!(Units::checked_power_of_ten(10), None);
+        assert!(Units::overflowing_power_of_ten(10).1);
+    }
+
+    #[test]
+    fn test_bridged_decimal_operations() {
+        assert_eq!(
+            Units(1_50).add_decimals_checked(Units(2_0000), 2, 4),
+            Ok((Units(3_5000), 4))
+        );
+        assert_eq!(
+            Units(i32::MIN).sub_decimals_checked(Units(1), 0, 0),
+            Err(DecimalOperationError::Underflow)
+        );
+        assert_eq!(
+            Units(1).divide_decimals_checked(Units(0), 0, 0),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+    }
+}
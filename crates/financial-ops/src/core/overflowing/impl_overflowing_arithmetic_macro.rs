@@ -0,0 +1,50 @@
+/// Implements overflowing arithmetic operations for the specified types.
+///
+/// This macro generates implementations of the `OverflowingAdd`, `OverflowingSub`,
+/// `OverflowingMul`, `OverflowingDiv`, and `OverflowingRem` traits for the given types.
+/// These traits provide methods that return the wrapped result of the operation
+/// together with a `bool` indicating whether an overflow happened.
+///
+/// # Examples
+///
+// `impl_overflowing_arithmetic!(u8 u16 u32 u64 i8 i16 i32 i64);`
+///
+/// In this example, the `impl_overflowing_arithmetic` macro is used to generate
+/// implementations of the `OverflowingAdd`, `OverflowingSub`, `OverflowingMul`,
+/// `OverflowingDiv`, and `OverflowingRem` traits for the types `u8`, `u16`, `u32`,
+/// `u64`, `i8`, `i16`, `i32`, and `i64`.
+///
+/// # Panics
+///
+/// Like their standard library counterparts, `overflowing_div` and `overflowing_rem`
+/// panic when dividing by zero.
+#[macro_export]
+macro_rules! impl_overflowing_arithmetic {
+    ($($t:ty)*) => ($(
+        impl $crate::core::OverflowingAdd for $t {
+            fn overflowing_add(&self, v: &Self) -> (Self, bool) {
+                <$t>::overflowing_add(*self, *v)
+            }
+        }
+        impl $crate::core::OverflowingSub for $t {
+            fn overflowing_sub(&self, v: &Self) -> (Self, bool) {
+                <$t>::overflowing_sub(*self, *v)
+            }
+        }
+        impl $crate::core::OverflowingMul for $t {
+            fn overflowing_mul(&self, v: &Self) -> (Self, bool) {
+                <$t>::overflowing_mul(*self, *v)
+            }
+        }
+        impl $crate::core::OverflowingDiv for $t {
+            fn overflowing_div(&self, v: &Self) -> (Self, bool) {
+                <$t>::overflowing_div(*self, *v)
+            }
+        }
+        impl $crate::core::OverflowingRem for $t {
+            fn overflowing_rem(&self, v: &Self) -> (Self, bool) {
+                <$t>::overflowing_rem(*self, *v)
+            }
+        }
+    )*)
+}
@@ -0,0 +1,6 @@
+pub mod impl_overflowing_arithmetic_macro;
+pub mod overflowing_operations;
+pub mod overflowing_traits;
+
+pub use overflowing_operations::*;
+pub use overflowing_traits::*;
@@ -0,0 +1,323 @@
+use crate::{
+    core::{
+        OverflowingAdd, OverflowingDiv, OverflowingMul, OverflowingRem, OverflowingSub, PowerOfTen,
+    },
+    impl_overflowing_arithmetic,
+};
+
+impl_overflowing_arithmetic! { u8 u16 u32 u64 u128 i8 i16 i32 i64 i128 usize isize }
+
+/// A trait for performing overflowing decimal operations.
+///
+/// Mirrors the standard `overflowing_*` family: every method returns the wrapped result together
+/// with a `bool` that is `true` if any step of the operation (including the scaling needed to align
+/// the decimals) overflowed. This allows detecting overflow in hot paths without `Result` handling.
+pub trait OverflowingDecimalOperations {
+    /// Adds two values with decimals.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The first value to add.
+    /// * `other` - The second value to add.
+    /// * `self_decimals` - The number of decimals in the first value.
+    /// * `other_decimals` - The number of decimals in the second value.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the wrapped sum and the number of decimals in the result, along with
+    /// a `bool` indicating whether an overflow occurred.
+    fn add_decimals_overflowing(
+        self,
+        other: Self,
+        self_decimals: u32,
+        other_decimals: u32,
+    ) -> ((Self, u32), bool)
+    where
+        Self: Sized;
+
+    /// Subtracts two values with decimals.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The value to subtract from.
+    /// * `other` - The value to subtract.
+    /// * `self_decimals` - The number of decimals in the first value.
+    /// * `other_decimals` - The number of decimals in the second value.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the wrapped difference and the number of decimals in the result, along with
+    /// a `bool` indicating whether an overflow occurred.
+    fn sub_decimals_overflowing(
+        self,
+        other: Self,
+        self_decimals: u32,
+        other_decimals: u32,
+    ) -> ((Self, u32), bool)
+    where
+        Self: Sized;
+
+    /// Multiplies two values with decimals.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The first value to multiply.
+    /// * `other` - The second value to multiply.
+    /// * `self_decimals` - The number of decimals in the first value.
+    /// * `other_decimals` - The number of decimals in the second value.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the wrapped product and the number of decimals in the result, along with
+    /// a `bool` indicating whether an overflow occurred.
+    fn multiply_decimals_overflowing(
+        self,
+        other: Self,
+        self_decimals: u32,
+        other_decimals: u32,
+    ) -> ((Self, u32), bool)
+    where
+        Self: Sized;
+
+    /// Divides two values with decimals.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The value to divide.
+    /// * `other` - The value to divide by.
+    /// * `self_decimals` - The number of decimals in the first value.
+    /// * `other_decimals` - The number of decimals in the second value.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the wrapped quotient and the number of decimals in the result, along with
+    /// a `bool` indicating whether an overflow occurred.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
+    fn divide_decimals_overflowing(
+        self,
+        other: Self,
+        self_decimals: u32,
+        other_decimals: u32,
+    ) -> ((Self, u32), bool)
+    where
+        Self: Sized;
+
+    /// Calculates the remainder of dividing two values with decimals.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The value to calculate the remainder for.
+    /// * `other` - The value to divide by.
+    /// * `self_decimals` - The number of decimals in the first value.
+    /// * `other_decimals` - The number of decimals in the second value.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the wrapped remainder and the number of decimals in the result, along with
+    /// a `bool` indicating whether an overflow occurred.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
+    fn rem_decimals_overflowing(
+        self,
+        other: Self,
+        self_decimals: u32,
+        other_decimals: u32,
+    ) -> ((Self, u32), bool)
+    where
+        Self: Sized;
+}
+
+// Blanket implementation of the OverflowingDecimalOperations trait for all types implementing overflowing operations
+impl<T> OverflowingDecimalOperations for T
+where
+    T: OverflowingAdd
+        + OverflowingSub
+        + OverflowingMul
+        + OverflowingDiv
+        + OverflowingRem
+        + PowerOfTen,
+{
+    fn add_decimals_overflowing(
+        self,
+        other: Self,
+        self_decimals: u32,
+        other_decimals: u32,
+    ) -> ((Self, u32), bool) {
+        if self_decimals > other_decimals {
+            let (factor, factor_overflow) =
+                T::overflowing_power_of_ten(self_decimals - other_decimals);
+            let (scaled, scale_overflow) = other.overflowing_mul(&factor);
+            let (value, overflow) = self.overflowing_add(&scaled);
+            (
+                (value, self_decimals),
+                factor_overflow || scale_overflow || overflow,
+            )
+        } else {
+            let (factor, factor_overflow) =
+                T::overflowing_power_of_ten(other_decimals - self_decimals);
+            let (scaled, scale_overflow) = self.overflowing_mul(&factor);
+            let (value, overflow) = scaled.overflowing_add(&other);
+            (
+                (value, other_decimals),
+                factor_overflow || scale_overflow || overflow,
+            )
+        }
+    }
+
+    fn sub_decimals_overflowing(
+        self,
+        other: Self,
+        self_decimals: u32,
+        other_decimals: u32,
+    ) -> ((Self, u32), bool) {
+        if self_decimals > other_decimals {
+            let (factor, factor_overflow) =
+                T::overflowing_power_of_ten(self_decimals - other_decimals);
+            let (scaled, scale_overflow) = other.overflowing_mul(&factor);
+            let (value, overflow) = self.overflowing_sub(&scaled);
+            (
+                (value, self_decimals),
+                factor_overflow || scale_overflow || overflow,
+            )
+        } else {
+            let (factor, factor_overflow) =
+                T::overflowing_power_of_ten(other_decimals - self_decimals);
+            let (scaled, scale_overflow) = self.overflowing_mul(&factor);
+            let (value, overflow) = scaled.overflowing_sub(&other);
+            (
+                (value, other_decimals),
+                factor_overflow || scale_overflow || overflow,
+            )
+        }
+    }
+
+    fn multiply_decimals_overflowing(
+        self,
+        other: Self,
+        self_decimals: u32,
+        other_decimals: u32,
+    ) -> ((Self, u32), bool) {
+        let (value, overflow) = self.overflowing_mul(&other);
+        let (decimals, decimals_overflow) = self_decimals.overflowing_add(other_decimals);
+        ((value, decimals), overflow || decimals_overflow)
+    }
+
+    fn divide_decimals_overflowing(
+        self,
+        other: Self,
+        self_decimals: u32,
+        other_decimals: u32,
+    ) -> ((Self, u32), bool) {
+        let (factor, factor_overflow) = T::overflowing_power_of_ten(other_decimals);
+        let (adjusted_value, scale_overflow) = self.overflowing_mul(&factor);
+        let (value, overflow) = adjusted_value.overflowing_div(&other);
+        (
+            (value, self_decimals),
+            factor_overflow || scale_overflow || overflow,
+        )
+    }
+
+    fn rem_decimals_overflowing(
+        self,
+        other: Self,
+        self_decimals: u32,
+        _other_decimals: u32,
+    ) -> ((Self, u32), bool) {
+        let (factor, factor_overflow) = T::overflowing_power_of_ten(self_decimals);
+        let (adjusted_value, scale_overflow) = self.overflowing_mul(&factor);
+        let (value, overflow) = adjusted_value.overflowing_rem(&other);
+        (
+            (value, self_decimals),
+            factor_overflow || scale_overflow || overflow,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_decimals_overflowing() {
+        let ((result, decimals), overflow) = 1_0000u64.add_decimals_overflowing(2_00, 4, 2);
+        assert_eq!(result, 3_0000);
+        assert_eq!(decimals, 4);
+        assert!(!overflow);
+
+        let ((result, decimals), overflow) = u32::MAX.add_decimals_overflowing(2, 0, 0);
+        assert_eq!(result, 1);
+        assert_eq!(decimals, 0);
+        assert!(overflow);
+    }
+
+    #[test]
+    fn test_sub_decimals_overflowing() {
+        let ((result, decimals), overflow) = 3_0000u64.sub_decimals_overflowing(2_00, 4, 2);
+        assert_eq!(result, 1_0000);
+        assert_eq!(decimals, 4);
+        assert!(!overflow);
+
+        let ((result, _), overflow) = 0u32.sub_decimals_overflowing(1, 0, 0);
+        assert_eq!(result, u32::MAX);
+        assert!(overflow);
+    }
+
+    #[test]
+    fn test_mul_decimals_overflowing() {
+        let ((result, decimals), overflow) = 12345u32.multiply_decimals_overflowing(45, 2, 2);
+        assert_eq!(result, 555525);
+        assert_eq!(decimals, 4);
+        assert!(!overflow);
+
+        let ((_, decimals), overflow) = u64::MAX.multiply_decimals_overflowing(2, 0, 3);
+        assert_eq!(decimals, 3);
+        assert!(overflow);
+
+        let ((result, decimals), overflow) = 5u64.multiply_decimals_overflowing(1, u32::MAX, 1);
+        assert_eq!(result, 5);
+        assert_eq!(decimals, 0);
+        assert!(overflow);
+    }
+
+    #[test]
+    fn test_div_decimals_overflowing() {
+        let ((result, decimals), overflow) = 123_45u32.divide_decimals_overflowing(0_45, 2, 2);
+        assert_eq!(result, 27433);
+        assert_eq!(decimals, 2);
+        assert!(!overflow);
+
+        // Scaling the dividend to align the decimals overflows.
+        let ((_, _), overflow) = u32::MAX.divide_decimals_overflowing(1_0, 0, 1);
+        assert!(overflow);
+    }
+
+    #[test]
+    fn test_rem_decimals_overflowing() {
+        let ((result, decimals), overflow) = 123_45u32.rem_decimals_overflowing(0_45, 2, 2);
+        assert_eq!(result, 15);
+        assert_eq!(decimals, 2);
+        assert!(!overflow);
+
+        // The scale factor itself does not fit in the type.
+        let ((_, _), overflow) = 1u32.rem_decimals_overflowing(3, 10, 0);
+        assert!(overflow);
+    }
+
+    #[test]
+    fn test_small_integer_decimals_overflowing() {
+        let ((result, decimals), overflow) = 1_5u8.add_decimals_overflowing(0_25, 1, 2);
+        assert_eq!(result, 1_75);
+        assert_eq!(decimals, 2);
+        assert!(!overflow);
+
+        let ((_, _), overflow) = 1i16.multiply_decimals_overflowing(1, 5, 0);
+        assert!(!overflow);
+        let ((_, _), overflow) = 1i16.add_decimals_overflowing(1, 5, 0);
+        assert!(overflow);
+    }
+}
@@ -0,0 +1,19 @@
+pub trait OverflowingAdd: Sized {
+    fn overflowing_add(&self, v: &Self) -> (Self, bool);
+}
+
+pub trait OverflowingSub: Sized {
+    fn overflowing_sub(&self, v: &Self) -> (Self, bool);
+}
+
+pub trait OverflowingMul: Sized {
+    fn overflowing_mul(&self, v: &Self) -> (Self, bool);
+}
+
+pub trait OverflowingDiv: Sized {
+    fn overflowing_div(&self, v: &Self) -> (Self, bool);
+}
+
+pub trait OverflowingRem: Sized {
+    fn overflowing_rem(&self, v: &Self) -> (Self, bool);
+}
@@ -0,0 +1,305 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::core::{
+    money::exchange_rate::multiply_to_scale, CheckedDecimalOperations, CheckedMulDiv,
+    CompareDecimals, Decimal, DecimalOperationError, Percent, PowerOfTen, RescaleDecimals,
+    RoundingMode, Zero,
+};
+
+/// How much a payroll item takes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PayrollCharge<T = i128> {
+    /// A percentage of the gross pay, or of the taxable pay for taxes.
+    Percent(Percent<T>),
+    /// A fixed amount per pay period.
+    Fixed(Decimal<T>),
+}
+
+/// When a payroll item applies, in the order the stages are computed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PayrollStage {
+    /// A deduction taken before taxes, which lowers the taxable pay, like a retirement plan.
+    PreTax,
+    /// A tax withheld from the taxable pay.
+    Tax,
+    /// A deduction taken after taxes, like a garnishment.
+    PostTax,
+    /// A contribution paid by the employer on top of the gross pay, like a pension match.
+    Employer,
+}
+
+/// A named deduction, tax or employer contribution of a [`Payroll`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PayrollItem<T = i128> {
+    name: &'static str,
+    stage: PayrollStage,
+    charge: PayrollCharge<T>,
+}
+
+impl<T> PayrollItem<T> {
+    /// Creates a payroll item.
+    pub const fn new(name: &'static str, stage: PayrollStage, charge: PayrollCharge<T>) -> Self {
+        PayrollItem {
+            name,
+            stage,
+            charge,
+        }
+    }
+
+    /// Returns the name the item is reported under.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the stage the item applies at.
+    pub fn stage(&self) -> PayrollStage {
+        self.stage
+    }
+}
+
+impl<T: Copy> PayrollItem<T> {
+    /// Returns how much the item takes.
+    pub fn charge(&self) -> PayrollCharge<T> {
+        self.charge
+    }
+}
+
+/// One computed item in a [`Payslip`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PayslipLine<T = i128> {
+    /// The item computed.
+    pub item: PayrollItem<T>,
+    /// The amount of the item, rounded to the decimals of the [`Payroll`].
+    pub amount: Decimal<T>,
+}
+
+/// The itemized breakdown of a gross pay computed by a [`Payroll`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Payslip<T = i128> {
+    /// The pay before any deduction.
+    pub gross: Decimal<T>,
+    /// The deductions, taxes and employer contributions, in the order they were computed.
+    pub lines: Vec<PayslipLine<T>>,
+    /// The gross pay minus the pre-tax deductions.
+    pub taxable: Decimal<T>,
+    /// The pay left to the employee after every deduction and tax.
+    pub net: Decimal<T>,
+    /// The gross pay plus the employer contributions.
+    pub employer_cost: Decimal<T>,
+}
+
+impl<T> Payslip<T> {
+    /// Returns the lines of a stage, in order.
+    pub fn stage(&self, stage: PayrollStage) -> impl Iterator<Item = &PayslipLine<T>> {
+        self.lines
+            .iter()
+            .filter(move |line| line.item.stage == stage)
+    }
+}
+
+/// A gross-to-net payroll calculation: pre-tax deductions, taxes on what they leave, post-tax
+/// deductions and employer contributions.
+///
+/// Every item is rounded once to the decimals of the payroll. Percentages apply to the gross
+/// pay, except taxes which apply to the taxable pay. A deduction or tax larger than the pay left
+/// to the employee takes it down to zero and no further.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{Decimal, Payroll, PayrollCharge, Percent};
+///
+/// let payroll = Payroll::new(2)
+///     .pre_tax("401(k)", PayrollCharge::Percent(Percent::new(5, 0)))
+///     .tax("Income tax", PayrollCharge::Percent(Percent::new(12, 0)))
+///     .post_tax("Union dues", PayrollCharge::Fixed(Decimal::new(15, 0)))
+///     .employer("401(k) match", PayrollCharge::Percent(Percent::new(2_5, 1)));
+///
+/// // 3,333.33 - 166.67 = 3,166.66 taxable, taxed 379.9992
+/// let payslip = payroll.pay(Decimal::new(3_333_33i64, 2)).unwrap();
+/// assert_eq!(payslip.taxable, Decimal::new(3_166_66, 2));
+/// assert_eq!(payslip.lines[1].amount, Decimal::new(380_00, 2));
+/// assert_eq!(payslip.net, Decimal::new(2_771_66, 2));
+/// assert_eq!(payslip.employer_cost, Decimal::new(3_416_66, 2));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Payroll<T = i128> {
+    items: Vec<PayrollItem<T>>,
+    decimals: u32,
+    rounding: RoundingMode,
+}
+
+impl<T> Payroll<T> {
+    /// Creates a payroll without items, whose amounts are rounded half to even to `decimals`
+    /// decimals.
+    pub const fn new(decimals: u32) -> Self {
+        Payroll {
+            items: Vec::new(),
+            decimals,
+            rounding: RoundingMode::HalfEven,
+        }
+    }
+
+    /// Sets the rounding applied to every item.
+    pub fn rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Adds an item after the ones already in the payroll. Items are computed stage by stage,
+    /// in the order they were added within a stage.
+    pub fn item(mut self, item: PayrollItem<T>) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Adds a deduction taken before taxes.
+    pub fn pre_tax(self, name: &'static str, charge: PayrollCharge<T>) -> Self {
+        self.item(PayrollItem::new(name, PayrollStage::PreTax, charge))
+    }
+
+    /// Adds a tax withheld from the taxable pay.
+    pub fn tax(self, name: &'static str, charge: PayrollCharge<T>) -> Self {
+        self.item(PayrollItem::new(name, PayrollStage::Tax, charge))
+    }
+
+    /// Adds a deduction taken after taxes.
+    pub fn post_tax(self, name: &'static str, charge: PayrollCharge<T>) -> Self {
+        self.item(PayrollItem::new(name, PayrollStage::PostTax, charge))
+    }
+
+    /// Adds a contribution paid by the employer.
+    pub fn employer(self, name: &'static str, charge: PayrollCharge<T>) -> Self {
+        self.item(PayrollItem::new(name, PayrollStage::Employer, charge))
+    }
+
+    /// Returns the items of the payroll, in the order they were added.
+    pub fn items(&self) -> &[PayrollItem<T>] {
+        &self.items
+    }
+}
+
+impl<T> Payroll<T>
+where
+    T: CheckedDecimalOperations
+        + CheckedMulDiv
+        + RescaleDecimals
+        + PowerOfTen
+        + CompareDecimals
+        + Zero
+        + Copy,
+{
+    /// Computes the payslip of a gross pay.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the payslip, or a `DecimalOperationError` if the operation
+    /// fails.
+    pub fn pay(&self, gross: Decimal<T>) -> Result<Payslip<T>, DecimalOperationError> {
+        let mut lines = Vec::with_capacity(self.items.len());
+        let mut net = gross;
+        let mut taxable = gross;
+        let mut employer_cost = gross;
+        for stage in [
+            PayrollStage::PreTax,
+            PayrollStage::Tax,
+            PayrollStage::PostTax,
+            PayrollStage::Employer,
+        ] {
+            if stage == PayrollStage::Tax {
+                taxable = net;
+            }
+            for &item in self.items.iter().filter(|item| item.stage == stage) {
+                let base = match stage {
+                    PayrollStage::Tax => taxable,
+                    _ => gross,
+                };
+                let amount = self.amount(item.charge, base)?;
+                let amount = if stage == PayrollStage::Employer {
+                    employer_cost = employer_cost.try_add(amount)?;
+                    amount
+                } else {
+                    let amount = if amount.cmp_value(&net) == Ordering::Greater {
+                        net
+                    } else {
+                        amount
+                    };
+                    net = net.try_sub(amount)?;
+                    amount
+                };
+                lines.push(PayslipLine { item, amount });
+            }
+        }
+        Ok(Payslip {
+            gross,
+            lines,
+            taxable,
+            net,
+            employer_cost,
+        })
+    }
+
+    /// Returns the amount a charge takes from `base`, rounded to the decimals of the payroll.
+    fn amount(
+        &self,
+        charge: PayrollCharge<T>,
+        base: Decimal<T>,
+    ) -> Result<Decimal<T>, DecimalOperationError> {
+        let value = match charge {
+            PayrollCharge::Percent(percent) => {
+                multiply_to_scale(base, percent.to_fraction()?, self.decimals, self.rounding)?
+            }
+            PayrollCharge::Fixed(amount) => {
+                amount.try_rescale(self.decimals, self.rounding)?.value()
+            }
+        };
+        Ok(Decimal::new(value, self.decimals))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payroll_stages() -> Result<(), DecimalOperationError> {
+        // Added out of stage order on purpose.
+        let payroll = Payroll::new(2)
+            .employer("Pension", PayrollCharge::Percent(Percent::new(3, 0)))
+            .post_tax("Garnishment", PayrollCharge::Percent(Percent::new(10, 0)))
+            .tax("Tax", PayrollCharge::Percent(Percent::new(20, 0)))
+            .pre_tax("Health", PayrollCharge::Fixed(Decimal::new(100, 0)));
+        assert_eq!(payroll.items().len(), 4);
+        let payslip = payroll.pay(Decimal::new(2_000u64, 0))?;
+        let names: Vec<_> = payslip.lines.iter().map(|line| line.item.name()).collect();
+        assert_eq!(names, ["Health", "Tax", "Garnishment", "Pension"]);
+        assert_eq!(payslip.taxable, Decimal::new(1_900_00, 2));
+        // 20% of the taxable 1,900, then 10% of the gross 2,000
+        assert_eq!(payslip.lines[1].amount, Decimal::new(380_00, 2));
+        assert_eq!(payslip.lines[2].amount, Decimal::new(200_00, 2));
+        assert_eq!(payslip.net, Decimal::new(1_320_00, 2));
+        assert_eq!(payslip.employer_cost, Decimal::new(2_060_00, 2));
+        assert_eq!(payslip.stage(PayrollStage::Employer).count(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_payroll_floor_and_rounding() -> Result<(), DecimalOperationError> {
+        let payroll = Payroll::new(2)
+            .rounding(RoundingMode::Down)
+            .tax("Tax", PayrollCharge::Percent(Percent::new(33_333, 3)))
+            .post_tax("Loan", PayrollCharge::Fixed(Decimal::new(1_000, 0)));
+        let payslip = payroll.pay(Decimal::new(1_000_01u64, 2))?;
+        // 333.336666... truncated, then the loan takes what is left
+        assert_eq!(payslip.lines[0].amount, Decimal::new(333_33, 2));
+        assert_eq!(payslip.lines[1].amount, Decimal::new(666_68, 2));
+        assert_eq!(payslip.net, Decimal::new(0, 2));
+
+        let empty = Payroll::<i64>::new(2).pay(Decimal::new(1_234_56, 2))?;
+        assert!(empty.lines.is_empty());
+        assert_eq!(empty.net, empty.gross);
+        assert_eq!(empty.taxable, empty.gross);
+        Ok(())
+    }
+}
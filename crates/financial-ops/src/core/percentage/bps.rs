@@ -0,0 +1,237 @@
+use crate::core::{
+    CheckedMulDiv, Decimal, DecimalOperationError, PowerOfTen, RescaleDecimals, RoundingMode,
+};
+
+/// The number of decimals of a basis point as a fraction: one basis point is `0.0001`.
+pub(crate) const BPS_DECIMALS: u32 = 4;
+
+/// The number of decimals of a basis point as a percentage: one basis point is `0.01%`.
+const BPS_PERCENT_DECIMALS: u32 = 2;
+
+/// A whole number of basis points, where `10_000` basis points are the whole amount.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{Bps, RoundingMode};
+///
+/// // 30 bps of a 1,234.56 notional
+/// let fee = Bps::new(30u64).apply_to(1_234_56, 2, RoundingMode::HalfEven).unwrap();
+/// assert_eq!(fee, (3_70, 2));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Bps<T = i128> {
+    bps: T,
+}
+
+impl<T> Bps<T> {
+    /// Creates a number of basis points.
+    pub const fn new(bps: T) -> Self {
+        Bps { bps }
+    }
+
+    /// Returns a reference to the number of basis points.
+    pub const fn value_ref(&self) -> &T {
+        &self.bps
+    }
+}
+
+impl<T: Copy> Bps<T> {
+    /// Returns the number of basis points.
+    pub fn value(&self) -> T {
+        self.bps
+    }
+
+    /// Returns the basis points as a fraction of the whole, like `0.0030` for 30 bps.
+    pub fn to_decimal(&self) -> Decimal<T> {
+        Decimal::new(self.bps, BPS_DECIMALS)
+    }
+
+    /// Returns the basis points as a percentage, like `0.30` for 30 bps.
+    pub fn to_percent(&self) -> Decimal<T> {
+        Decimal::new(self.bps, BPS_PERCENT_DECIMALS)
+    }
+}
+
+impl<T: CheckedMulDiv + PowerOfTen + Copy> Bps<T> {
+    /// Computes the basis points of an amount, keeping the decimals of the amount.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - The raw value of the amount.
+    /// * `decimals` - The number of decimals in `amount`.
+    /// * `rounding` - The rounding applied to the result.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the result and `decimals`, or a `DecimalOperationError` if
+    /// the operation fails.
+    pub fn apply_to(
+        &self,
+        amount: T,
+        decimals: u32,
+        rounding: RoundingMode,
+    ) -> Result<(T, u32), DecimalOperationError> {
+        let whole =
+            T::checked_power_of_ten(BPS_DECIMALS).ok_or(DecimalOperationError::ScaleTooLarge)?;
+        let value = amount.checked_mul_div(self.bps, whole, rounding)?;
+        Ok((value, decimals))
+    }
+
+    /// Computes the basis points `numerator` represents out of `denominator`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the basis points, or a `DecimalOperationError` if the
+    /// denominator is zero or the result doesn't fit in the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use financial_ops::{Bps, RoundingMode};
+    ///
+    /// let spread = Bps::from_ratio(5u64, 1_000, RoundingMode::HalfEven).unwrap();
+    /// assert_eq!(spread, Bps::new(50));
+    /// ```
+    pub fn from_ratio(
+        numerator: T,
+        denominator: T,
+        rounding: RoundingMode,
+    ) -> Result<Self, DecimalOperationError> {
+        let whole =
+            T::checked_power_of_ten(BPS_DECIMALS).ok_or(DecimalOperationError::ScaleTooLarge)?;
+        numerator
+            .checked_mul_div(whole, denominator, rounding)
+            .map(Bps::new)
+    }
+}
+
+impl<T: RescaleDecimals> Bps<T> {
+    /// Converts a percentage, like `0.25` for a quarter percent, into basis points.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the basis points, or a `DecimalOperationError` if the
+    /// operation fails.
+    pub fn from_percent(
+        percent: Decimal<T>,
+        rounding: RoundingMode,
+    ) -> Result<Self, DecimalOperationError> {
+        let (percent, decimals) = percent.into_parts();
+        let (bps, _) = percent.rescale_checked(decimals, BPS_PERCENT_DECIMALS, rounding)?;
+        Ok(Bps::new(bps))
+    }
+}
+
+/// Returns a number of basis points with its own scale as a fraction of the whole, like `0.0525`
+/// for `525` or `0.00125` for `12.5`, with the same raw value.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the fraction, or a `DecimalOperationError` if its number of
+/// decimals overflows, reported as `ScaleTooLarge`.
+pub(crate) fn bps_to_fraction<T>(bps: Decimal<T>) -> Result<Decimal<T>, DecimalOperationError> {
+    let (value, scale) = bps.into_parts();
+    Ok(Decimal::new(value, fraction_scale(scale)?))
+}
+
+/// Rounds a fraction of the whole to a number of basis points with `scale` decimals, the inverse
+/// of [`bps_to_fraction`].
+///
+/// # Returns
+///
+/// Returns a `Result` containing the basis points, or a `DecimalOperationError` if the operation
+/// fails.
+pub(crate) fn fraction_to_bps<T: RescaleDecimals>(
+    fraction: Decimal<T>,
+    scale: u32,
+    rounding: RoundingMode,
+) -> Result<Decimal<T>, DecimalOperationError> {
+    let (value, _) = fraction
+        .try_rescale(fraction_scale(scale)?, rounding)?
+        .into_parts();
+    Ok(Decimal::new(value, scale))
+}
+
+/// Returns the number of decimals of a fraction with the raw value of a number of basis points
+/// with `scale` decimals.
+pub(crate) fn fraction_scale(scale: u32) -> Result<u32, DecimalOperationError> {
+    scale
+        .checked_add(BPS_DECIMALS)
+        .ok_or(DecimalOperationError::ScaleTooLarge)
+}
+
+impl<T> From<T> for Bps<T> {
+    fn from(bps: T) -> Self {
+        Bps::new(bps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bps_fraction() -> Result<(), DecimalOperationError> {
+        let fraction = bps_to_fraction(Decimal::new(12_5i64, 1))?;
+        assert_eq!(fraction, Decimal::new(0_00125, 5));
+        assert_eq!(
+            fraction_to_bps(fraction, 0, RoundingMode::HalfEven)?,
+            Decimal::new(12, 0)
+        );
+        // A fraction with fewer than 4 decimals is scaled up.
+        assert_eq!(
+            fraction_to_bps(Decimal::new(0_5i64, 1), 2, RoundingMode::HalfEven)?,
+            Decimal::new(5_000_00, 2)
+        );
+        assert_eq!(
+            bps_to_fraction(Decimal::new(1i64, u32::MAX)),
+            Err(DecimalOperationError::ScaleTooLarge)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_bps_apply_to() -> Result<(), DecimalOperationError> {
+        let fee = Bps::new(25i64);
+        assert_eq!(
+            fee.apply_to(1_000_00, 2, RoundingMode::HalfEven)?,
+            (2_50, 2)
+        );
+        assert_eq!(fee.apply_to(1_999, 2, RoundingMode::Down)?, (4, 2));
+        assert_eq!(fee.apply_to(1_999, 2, RoundingMode::Up)?, (5, 2));
+        assert_eq!(fee.apply_to(-1_999, 2, RoundingMode::Floor)?, (-5, 2));
+        assert_eq!(
+            Bps::new(25u8).apply_to(100, 0, RoundingMode::Down),
+            Err(DecimalOperationError::ScaleTooLarge)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_bps_conversions() -> Result<(), DecimalOperationError> {
+        assert_eq!(
+            Bps::from_ratio(1u64, 3, RoundingMode::HalfEven)?,
+            Bps::new(3333)
+        );
+        assert_eq!(
+            Bps::from_ratio(1u64, 0, RoundingMode::HalfEven),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+        assert_eq!(Bps::new(30i64).to_decimal(), Decimal::new(0_0030, 4));
+        assert_eq!(Bps::new(30i64).to_percent(), Decimal::new(0_30, 2));
+        assert_eq!(
+            Bps::from_percent(Decimal::new(1_5i64, 1), RoundingMode::Unnecessary)?,
+            Bps::new(150)
+        );
+        assert_eq!(
+            Bps::from_percent(Decimal::new(0_125i64, 3), RoundingMode::HalfEven)?,
+            Bps::new(12)
+        );
+        assert_eq!(
+            Bps::from_percent(Decimal::new(0_125i64, 3), RoundingMode::Unnecessary),
+            Err(DecimalOperationError::PrecisionLoss)
+        );
+        Ok(())
+    }
+}
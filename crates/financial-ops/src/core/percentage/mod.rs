@@ -0,0 +1,5 @@
+pub mod bps;
+pub mod percent;
+
+pub use bps::*;
+pub use percent::*;
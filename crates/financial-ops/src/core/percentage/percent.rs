@@ -0,0 +1,351 @@
+use crate::core::{
+    Bps, CheckedDecimalOperations, CheckedDivideToScale, CheckedMulDiv, CheckedSignDecimals,
+    Decimal, DecimalOperationError, PercentChangeDecimals, PowerOfTen, RescaleDecimals,
+    RoundingMode, Zero,
+};
+
+/// A percentage stored as a raw value of type `T` and its number of decimals, so `12.5%` is
+/// `Percent::new(12_5, 1)`.
+///
+/// The number of decimals is chosen by the caller, like `2` for rates quoted to the hundredth of a
+/// percent. Applying a percentage keeps the decimals of the amount and rounds once.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{Decimal, Percent, RoundingMode};
+///
+/// let vat = Percent::new(20u64, 0);
+/// let net = Decimal::new(49_99, 2);
+///
+/// assert_eq!(vat.of(net, RoundingMode::HalfEven).unwrap(), Decimal::new(10_00, 2));
+/// assert_eq!(vat.increase_by(net, RoundingMode::HalfEven).unwrap(), Decimal::new(59_99, 2));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Percent<T = i128> {
+    percent: Decimal<T>,
+}
+
+impl<T> Percent<T> {
+    /// Creates a percentage from a raw value and the number of decimals it carries.
+    ///
+    /// # Arguments
+    ///
+    /// * `percent` - The raw integer value of the percentage.
+    /// * `decimals` - The number of decimals in `percent`.
+    pub const fn new(percent: T, decimals: u32) -> Self {
+        Percent::from_decimal(Decimal::new(percent, decimals))
+    }
+
+    /// Creates a percentage from a decimal, like `12.5` for `12.5%`.
+    pub const fn from_decimal(percent: Decimal<T>) -> Self {
+        Percent { percent }
+    }
+
+    /// Returns the number of decimals carried by the percentage.
+    pub const fn decimals(&self) -> u32 {
+        self.percent.scale()
+    }
+
+    /// Returns the percentage as a decimal, like `12.5` for `12.5%`.
+    pub fn into_decimal(self) -> Decimal<T> {
+        self.percent
+    }
+}
+
+impl<T: Copy> Percent<T> {
+    /// Returns the percentage as a decimal, like `12.5` for `12.5%`.
+    pub fn percent(&self) -> Decimal<T> {
+        self.percent
+    }
+
+    /// Returns the percentage as a fraction of the whole, like `0.125` for `12.5%`, with the
+    /// same raw value and two more decimals.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the fraction, or a `DecimalOperationError` if its number of
+    /// decimals overflows, reported as `ScaleTooLarge`.
+    pub fn to_fraction(&self) -> Result<Decimal<T>, DecimalOperationError> {
+        Ok(Decimal::new(
+            self.percent.value(),
+            fraction_decimals(self.decimals())?,
+        ))
+    }
+}
+
+impl<T> Percent<T>
+where
+    T: CheckedDecimalOperations + CheckedMulDiv + RescaleDecimals + PowerOfTen + Copy,
+{
+    /// Computes the percentage of an amount, keeping the decimals of the amount.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the share of the amount, or a `DecimalOperationError` if the
+    /// operation fails.
+    pub fn of(
+        &self,
+        amount: Decimal<T>,
+        rounding: RoundingMode,
+    ) -> Result<Decimal<T>, DecimalOperationError> {
+        let whole = whole::<T>(self.decimals())?;
+        let value = amount
+            .value()
+            .checked_mul_div(self.percent.value(), whole, rounding)?;
+        Ok(Decimal::new(value, amount.scale()))
+    }
+
+    /// Increases an amount by the percentage, keeping the decimals of the amount and rounding
+    /// once.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the increased amount, or a `DecimalOperationError` if the
+    /// operation fails.
+    pub fn increase_by(
+        &self,
+        amount: Decimal<T>,
+        rounding: RoundingMode,
+    ) -> Result<Decimal<T>, DecimalOperationError> {
+        let whole = whole::<T>(self.decimals())?;
+        let (factor, _) = whole.add_decimals_checked(self.percent.value(), 0, 0)?;
+        let value = amount.value().checked_mul_div(factor, whole, rounding)?;
+        Ok(Decimal::new(value, amount.scale()))
+    }
+
+    /// Decreases an amount by the percentage, keeping the decimals of the amount and rounding
+    /// once.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the decreased amount, or a `DecimalOperationError` if the
+    /// operation fails, like a decrease of more than `100%` with an unsigned type.
+    pub fn decrease_by(
+        &self,
+        amount: Decimal<T>,
+        rounding: RoundingMode,
+    ) -> Result<Decimal<T>, DecimalOperationError> {
+        let whole = whole::<T>(self.decimals())?;
+        let (factor, _) = whole.sub_decimals_checked(self.percent.value(), 0, 0)?;
+        let value = amount.value().checked_mul_div(factor, whole, rounding)?;
+        Ok(Decimal::new(value, amount.scale()))
+    }
+
+    /// Compounds this percentage with the one applied after it, so a `10%` increase followed by
+    /// another `10%` increase is a `21%` increase.
+    ///
+    /// The result carries the larger number of decimals of both percentages, and `rounding` is
+    /// applied to the cross term `self * next / 100`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the compounded percentage, or a `DecimalOperationError` if
+    /// the operation fails.
+    pub fn compound(
+        self,
+        next: Self,
+        rounding: RoundingMode,
+    ) -> Result<Self, DecimalOperationError> {
+        let decimals = self.decimals().max(next.decimals());
+        let (first, _) = self.percent.value().rescale_checked(
+            self.decimals(),
+            decimals,
+            RoundingMode::Unnecessary,
+        )?;
+        let (second, _) = next.percent.value().rescale_checked(
+            next.decimals(),
+            decimals,
+            RoundingMode::Unnecessary,
+        )?;
+        let cross = first.checked_mul_div(second, whole::<T>(decimals)?, rounding)?;
+        let (sum, _) = first.add_decimals_checked(second, 0, 0)?;
+        let (total, _) = sum.add_decimals_checked(cross, 0, 0)?;
+        Ok(Percent::new(total, decimals))
+    }
+
+    /// Converts the percentage into basis points.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the basis points, or a `DecimalOperationError` if the
+    /// operation fails.
+    pub fn to_bps(self, rounding: RoundingMode) -> Result<Bps<T>, DecimalOperationError> {
+        Bps::from_percent(self.percent, rounding)
+    }
+}
+
+impl<T> Percent<T>
+where
+    T: CheckedDecimalOperations + CheckedMulDiv + RescaleDecimals + PowerOfTen + Zero + Copy,
+{
+    /// Compounds successive percentages, zero for none.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the compounded percentage, or a `DecimalOperationError` if
+    /// the operation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use financial_ops::{Percent, RoundingMode};
+    ///
+    /// // 1.10 * 0.90 * 1.05 = 1.0395
+    /// let growth = [Percent::new(10_00i64, 2), Percent::new(-10_00, 2), Percent::new(5_00, 2)];
+    /// let total = Percent::compound_all(growth, RoundingMode::HalfEven).unwrap();
+    /// assert_eq!(total, Percent::new(3_95, 2));
+    /// ```
+    pub fn compound_all<I>(
+        percents: I,
+        rounding: RoundingMode,
+    ) -> Result<Self, DecimalOperationError>
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        percents
+            .into_iter()
+            .try_fold(Percent::new(T::zero(), 0), |total, next| {
+                total.compound(next, rounding)
+            })
+    }
+}
+
+impl<T> Percent<T>
+where
+    T: CheckedDecimalOperations + CheckedDivideToScale + CheckedSignDecimals + Copy,
+{
+    /// Computes the change from `old` to `new` as a percentage of `old`, with `decimals`
+    /// decimals. See [`PercentChangeDecimals::percent_change_checked`].
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the change, or a `DecimalOperationError` if `old` is zero or
+    /// the operation fails.
+    pub fn change(
+        old: Decimal<T>,
+        new: Decimal<T>,
+        decimals: u32,
+        rounding: RoundingMode,
+    ) -> Result<Self, DecimalOperationError> {
+        old.value()
+            .percent_change_checked(new.value(), old.scale(), new.scale(), decimals, rounding)
+            .map(Decimal::from)
+            .map(Percent::from_decimal)
+    }
+}
+
+impl<T: Copy> From<Bps<T>> for Percent<T> {
+    fn from(bps: Bps<T>) -> Self {
+        Percent::from_decimal(bps.to_percent())
+    }
+}
+
+/// Returns the number of decimals of a fraction with the raw value of a percentage with
+/// `decimals` decimals.
+pub(crate) fn fraction_decimals(decimals: u32) -> Result<u32, DecimalOperationError> {
+    decimals
+        .checked_add(2)
+        .ok_or(DecimalOperationError::ScaleTooLarge)
+}
+
+/// Returns `100%` as a raw value with `decimals` decimals.
+fn whole<T: PowerOfTen>(decimals: u32) -> Result<T, DecimalOperationError> {
+    T::checked_power_of_ten(fraction_decimals(decimals)?)
+        .ok_or(DecimalOperationError::ScaleTooLarge)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percent_apply() -> Result<(), DecimalOperationError> {
+        let discount = Percent::new(12_5i64, 1);
+        let price = Decimal::new(19_99, 2);
+        assert_eq!(
+            discount.of(price, RoundingMode::HalfEven)?,
+            Decimal::new(2_50, 2)
+        );
+        assert_eq!(
+            discount.of(price, RoundingMode::Down)?,
+            Decimal::new(2_49, 2)
+        );
+        assert_eq!(
+            discount.increase_by(price, RoundingMode::HalfEven)?,
+            Decimal::new(22_49, 2)
+        );
+        assert_eq!(
+            discount.decrease_by(price, RoundingMode::HalfEven)?,
+            Decimal::new(17_49, 2)
+        );
+        assert_eq!(
+            Percent::new(150i64, 0).decrease_by(price, RoundingMode::HalfEven)?,
+            Decimal::new(-10_00, 2)
+        );
+        assert_eq!(
+            Percent::new(150u64, 0).decrease_by(Decimal::new(19_99, 2), RoundingMode::HalfEven),
+            Err(DecimalOperationError::NegativeResult)
+        );
+        assert_eq!(
+            Percent::new(1u8, 1).of(Decimal::new(1, 0), RoundingMode::HalfEven),
+            Err(DecimalOperationError::ScaleTooLarge)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_percent_compound() -> Result<(), DecimalOperationError> {
+        let ten = Percent::new(10i64, 0);
+        assert_eq!(
+            ten.compound(ten, RoundingMode::HalfEven)?,
+            Percent::new(21, 0)
+        );
+        assert_eq!(
+            ten.compound(Percent::new(-2_50, 2), RoundingMode::HalfEven)?,
+            Percent::new(7_25, 2)
+        );
+        assert_eq!(
+            Percent::compound_all([ten; 3], RoundingMode::HalfEven)?,
+            Percent::new(33, 0)
+        );
+        assert_eq!(
+            Percent::<i64>::compound_all([], RoundingMode::HalfEven)?,
+            Percent::new(0, 0)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_percent_change() -> Result<(), DecimalOperationError> {
+        let old = Decimal::new(1_250i64, 3);
+        assert_eq!(
+            Percent::change(old, Decimal::new(1_00, 2), 1, RoundingMode::HalfEven)?,
+            Percent::new(-20_0, 1)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_percent_to_fraction() -> Result<(), DecimalOperationError> {
+        assert_eq!(
+            Percent::new(12_5i64, 1).to_fraction()?,
+            Decimal::new(0_125, 3)
+        );
+        assert_eq!(
+            Percent::new(1i64, u32::MAX).to_fraction(),
+            Err(DecimalOperationError::ScaleTooLarge)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_percent_bps() -> Result<(), DecimalOperationError> {
+        assert_eq!(Percent::from(Bps::new(30i64)), Percent::new(0_30, 2));
+        assert_eq!(
+            Percent::new(1_255i64, 3).to_bps(RoundingMode::HalfEven)?,
+            Bps::new(126)
+        );
+        Ok(())
+    }
+}
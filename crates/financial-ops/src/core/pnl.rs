@@ -0,0 +1,289 @@
+use core::cmp::Ordering;
+
+use crate::core::{
+    CheckedDecimalOperations, CheckedDivideToScale, CheckedSignDecimals, CompareDecimals, Decimal,
+    DecimalOperationError, RescaleDecimals, RoundingMode, Zero,
+};
+
+/// The side of a [`Fill`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Side {
+    /// A purchase, adding to a long position or covering a short one.
+    Buy,
+    /// A sale, reducing a long position or adding to a short one.
+    Sell,
+}
+
+/// A trade executed against a position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fill<T = i128> {
+    /// Whether the fill bought or sold.
+    pub side: Side,
+    /// The quantity traded, positive.
+    pub quantity: Decimal<T>,
+    /// The price of one unit.
+    pub price: Decimal<T>,
+    /// The fee paid on the fill.
+    pub fee: Decimal<T>,
+}
+
+impl<T> Fill<T> {
+    /// Creates a purchase of `quantity` at `price`, paying `fee`.
+    pub const fn buy(quantity: Decimal<T>, price: Decimal<T>, fee: Decimal<T>) -> Self {
+        Fill {
+            side: Side::Buy,
+            quantity,
+            price,
+            fee,
+        }
+    }
+
+    /// Creates a sale of `quantity` at `price`, paying `fee`.
+    pub const fn sell(quantity: Decimal<T>, price: Decimal<T>, fee: Decimal<T>) -> Self {
+        Fill {
+            side: Side::Sell,
+            quantity,
+            price,
+            fee,
+        }
+    }
+}
+
+/// The profit and loss of a sequence of fills, computed by [`pnl`](fn@pnl).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Pnl<T = i128> {
+    /// The open position, positive when long and negative when short.
+    pub position: Decimal<T>,
+    /// The cost of the open position, negative when short.
+    pub cost_basis: Decimal<T>,
+    /// The profit of the quantity closed, before fees.
+    pub realized: Decimal<T>,
+    /// The profit of the open position at the mark price, before fees.
+    pub unrealized: Decimal<T>,
+    /// The fees paid on the fills.
+    pub fees: Decimal<T>,
+}
+
+impl<T> Pnl<T>
+where
+    T: CheckedDecimalOperations + Copy,
+{
+    /// Returns the realized and unrealized profit, net of the fees.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the net profit, or a `DecimalOperationError` if the
+    /// operation fails.
+    pub fn net(&self) -> Result<Decimal<T>, DecimalOperationError> {
+        self.realized.try_add(self.unrealized)?.try_sub(self.fees)
+    }
+}
+
+/// Computes the realized and unrealized profit and loss of a sequence of fills at a mark price,
+/// with the average cost method.
+///
+/// The fills are applied in order. A fill on the side of the position adds to it at its price,
+/// and a fill on the other side closes it, realizing the difference between the price and the
+/// average cost of the quantity closed; a fill larger than the position closes it and opens the
+/// rest on the other side. Rather than an average price, the cost of the open position is kept
+/// as an exact amount, and the cost of a partial close is its share of that amount: whatever
+/// rounding the share takes stays in the cost of the rest, so a position closed in parts realizes
+/// exactly the same as one closed at once.
+///
+/// The amounts are computed exactly at the decimals of the quantities times the prices, then
+/// rounded once to `scale` decimals. Short positions need a signed type.
+///
+/// # Arguments
+///
+/// * `fills` - The fills, in the order they were executed.
+/// * `mark` - The price the open position is valued at.
+/// * `scale` - The number of decimals of the amounts.
+/// * `rounding` - The rounding applied to the amounts.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the profit and loss, or a `DecimalOperationError` if a quantity
+/// isn't positive or a price or a fee is negative, reported as `OutOfDomain`, or the operation
+/// fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{pnl, Decimal, Fill, RoundingMode};
+///
+/// let fee = Decimal::new(1_00i64, 2);
+/// let fills = [
+///     Fill::buy(Decimal::new(100, 0), Decimal::new(10_00, 2), fee),
+///     Fill::buy(Decimal::new(50, 0), Decimal::new(11_50, 2), fee),
+///     Fill::sell(Decimal::new(90, 0), Decimal::new(12_00, 2), fee),
+/// ];
+/// let pnl = pnl(&fills, Decimal::new(11_00, 2), 2, RoundingMode::HalfEven).unwrap();
+/// assert_eq!(pnl.position, Decimal::new(60, 0));
+/// // 90 * (12.00 - 10.50) and 60 * (11.00 - 10.50)
+/// assert_eq!(pnl.realized, Decimal::new(135_00, 2));
+/// assert_eq!(pnl.unrealized, Decimal::new(30_00, 2));
+/// assert_eq!(pnl.net().unwrap(), Decimal::new(162_00, 2));
+/// ```
+pub fn pnl<T>(
+    fills: &[Fill<T>],
+    mark: Decimal<T>,
+    scale: u32,
+    rounding: RoundingMode,
+) -> Result<Pnl<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations
+        + CheckedDivideToScale
+        + CheckedSignDecimals
+        + CompareDecimals
+        + RescaleDecimals
+        + Zero
+        + Copy,
+{
+    let zero = Decimal::zero(0);
+    let (mut position, mut cost) = (zero, zero);
+    let (mut realized, mut fees) = (zero, zero);
+    for fill in fills {
+        if fill.quantity.cmp_value(&zero) != Ordering::Greater
+            || fill.price.cmp_value(&zero) == Ordering::Less
+            || fill.fee.cmp_value(&zero) == Ordering::Less
+        {
+            return Err(DecimalOperationError::OutOfDomain);
+        }
+        fees = fees.try_add(fill.fee)?;
+        let mut quantity = match fill.side {
+            Side::Buy => fill.quantity,
+            Side::Sell => fill.quantity.try_neg()?,
+        };
+        let long = position.cmp_value(&zero);
+        if long != Ordering::Equal && long != quantity.cmp_value(&zero) {
+            // The quantity closed, with the sign of the position.
+            let closed = quantity.try_neg()?;
+            let (closed, removed) = if closed.cmp_value(&position) != long.reverse() {
+                (position, cost)
+            } else {
+                let removed = cost.try_mul(closed)?.try_div_to_scale(
+                    position,
+                    cost.scale(),
+                    RoundingMode::HalfEven,
+                )?;
+                (closed, removed)
+            };
+            realized = realized.try_add(closed.try_mul(fill.price)?.try_sub(removed)?)?;
+            position = position.try_sub(closed)?;
+            cost = cost.try_sub(removed)?;
+            quantity = quantity.try_add(closed)?;
+        }
+        position = position.try_add(quantity)?;
+        cost = cost.try_add(quantity.try_mul(fill.price)?)?;
+    }
+    let unrealized = position.try_mul(mark)?.try_sub(cost)?;
+    Ok(Pnl {
+        position,
+        cost_basis: cost.try_rescale(scale, rounding)?,
+        realized: realized.try_rescale(scale, rounding)?,
+        unrealized: unrealized.try_rescale(scale, rounding)?,
+        fees: fees.try_rescale(scale, rounding)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fill(side: Side, quantity: i128, price: i128) -> Fill<i128> {
+        Fill {
+            side,
+            quantity: Decimal::new(quantity, 0),
+            price: Decimal::new(price, 2),
+            fee: Decimal::new(0, 2),
+        }
+    }
+
+    #[test]
+    fn test_pnl_flips_position() -> Result<(), DecimalOperationError> {
+        // Long 10 at 100.00, sell 25 at 110.00 and cover 5 of the 15 short at 104.00.
+        let fills = [
+            fill(Side::Buy, 10, 100_00),
+            fill(Side::Sell, 25, 110_00),
+            fill(Side::Buy, 5, 104_00),
+        ];
+        let pnl = pnl(&fills, Decimal::new(112_00, 2), 2, RoundingMode::HalfEven)?;
+        assert_eq!(pnl.position, Decimal::new(-10, 0));
+        assert_eq!(pnl.cost_basis, Decimal::new(-1_100_00, 2));
+        // 10 * 10.00 on the long and 5 * 6.00 on the short
+        assert_eq!(pnl.realized, Decimal::new(130_00, 2));
+        assert_eq!(pnl.unrealized, Decimal::new(-20_00, 2));
+        assert_eq!(pnl.net()?, Decimal::new(110_00, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_pnl_closes_in_thirds() -> Result<(), DecimalOperationError> {
+        // An average cost of 10.003333... closed in three parts realizes as much as at once.
+        let (buy, sell) = (fill(Side::Buy, 1, 10_00), fill(Side::Sell, 1, 11_00));
+        let once = [
+            fill(Side::Buy, 1, 10_01),
+            buy,
+            buy,
+            fill(Side::Sell, 3, 11_00),
+        ];
+        let once = pnl(&once, Decimal::new(0, 0), 2, RoundingMode::HalfEven)?;
+        let thirds = [fill(Side::Buy, 1, 10_01), buy, buy, sell, sell, sell];
+        assert_eq!(once.realized, Decimal::new(2_99, 2));
+        assert_eq!(
+            pnl(&thirds, Decimal::new(0, 0), 2, RoundingMode::HalfEven)?,
+            once
+        );
+        // The first third costs 10.00 and leaves the 0.01 in the cost of the rest.
+        let partial = pnl(
+            &thirds[..4],
+            Decimal::new(11_00, 2),
+            4,
+            RoundingMode::HalfEven,
+        )?;
+        assert_eq!(partial.realized, Decimal::new(1_0000, 4));
+        assert_eq!(partial.cost_basis, Decimal::new(20_0100, 4));
+        assert_eq!(partial.unrealized, Decimal::new(1_9900, 4));
+        Ok(())
+    }
+
+    #[test]
+    fn test_pnl_fees_and_errors() -> Result<(), DecimalOperationError> {
+        let fills = [
+            Fill::buy(
+                Decimal::new(1_5, 1),
+                Decimal::new(2_000_00, 2),
+                Decimal::new(1_5, 1),
+            ),
+            Fill::sell(
+                Decimal::new(0_5, 1),
+                Decimal::new(2_100_00, 2),
+                Decimal::new(0_53, 2),
+            ),
+        ];
+        let result = pnl(
+            &fills,
+            Decimal::new(1_900_00i64, 2),
+            2,
+            RoundingMode::HalfEven,
+        )?;
+        assert_eq!(result.fees, Decimal::new(2_03, 2));
+        assert_eq!(result.realized, Decimal::new(50_00, 2));
+        assert_eq!(result.unrealized, Decimal::new(-100_00, 2));
+        assert_eq!(result.net()?, Decimal::new(-52_03, 2));
+        let empty = pnl::<i64>(&[], Decimal::new(1, 0), 2, RoundingMode::HalfEven)?;
+        assert_eq!(empty.position, Decimal::new(0, 0));
+        assert_eq!(empty.net()?, Decimal::new(0, 2));
+        for fill in [
+            Fill::buy(Decimal::new(0, 0), Decimal::new(1, 0), Decimal::new(0, 0)),
+            Fill::sell(Decimal::new(1, 0), Decimal::new(-1, 0), Decimal::new(0, 0)),
+            Fill::buy(Decimal::new(1, 0), Decimal::new(1, 0), Decimal::new(-1, 0)),
+        ] {
+            assert_eq!(
+                pnl(&[fill], Decimal::new(1i64, 0), 2, RoundingMode::HalfEven),
+                Err(DecimalOperationError::OutOfDomain)
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+/// A trait for computing powers of ten in the implementing type.
+///
+/// Powers of ten are the scale factors used to align the decimals of two values. Computing them
+/// in the target type, instead of going through `u32`, lets every integer width take part in the
+/// decimal operations and keeps large factors from being truncated.
+pub trait PowerOfTen: Sized {
+    /// Returns `10^exponent`, or `None` if it can't be represented by the type.
+    fn checked_power_of_ten(exponent: u32) -> Option<Self>;
+
+    /// Returns `10^exponent`, wrapping around at the boundary of the type.
+    fn wrapping_power_of_ten(exponent: u32) -> Self;
+
+    /// Returns `10^exponent` along with a `bool` indicating whether an overflow occurred.
+    fn overflowing_power_of_ten(exponent: u32) -> (Self, bool);
+
+    /// Returns `10^exponent`, carrying the underlying `pow` way of handling overflows.
+    ///
+    /// Not available with the `strict-no-panic` feature.
+    #[cfg(not(feature = "strict-no-panic"))]
+    fn power_of_ten(exponent: u32) -> Self;
+}
+
+macro_rules! impl_power_of_ten {
+    ($($t:ty)*) => ($(
+        impl PowerOfTen for $t {
+            fn checked_power_of_ten(exponent: u32) -> Option<Self> {
+                <$t>::checked_pow(10, exponent)
+            }
+            fn wrapping_power_of_ten(exponent: u32) -> Self {
+                <$t>::wrapping_pow(10, exponent)
+            }
+            fn overflowing_power_of_ten(exponent: u32) -> (Self, bool) {
+                <$t>::overflowing_pow(10, exponent)
+            }
+            #[cfg(not(feature = "strict-no-panic"))]
+            fn power_of_ten(exponent: u32) -> Self {
+                <$t>::pow(10, exponent)
+            }
+        }
+    )*)
+}
+
+impl_power_of_ten! { u8 u16 u32 u64 u128 i8 i16 i32 i64 i128 usize isize }
+
+/// The largest scale considered by [`max_scale`], the number of decimal digits of `U256::MAX` minus one.
+#[cfg(any(feature = "arbitrary", feature = "proptest"))]
+const MAX_GENERATED_SCALE: u32 = 77;
+
+/// Returns the largest scale whose factor can be represented by `T`, used to generate decimals
+/// with valid scales.
+#[cfg(any(feature = "arbitrary", feature = "proptest"))]
+pub(crate) fn max_scale<T: PowerOfTen>() -> u32 {
+    (0..=MAX_GENERATED_SCALE)
+        .take_while(|&scale| T::checked_power_of_ten(scale).is_some())
+        .last()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checked_power_of_ten() {
+        assert_eq!(u8::checked_power_of_ten(2), Some(100));
+        assert_eq!(u8::checked_power_of_ten(3), None);
+        assert_eq!(i16::checked_power_of_ten(4), Some(10_000));
+        assert_eq!(u128::checked_power_of_ten(38), Some(10u128.pow(38)));
+        assert_eq!(u128::checked_power_of_ten(39), None);
+    }
+
+    #[test]
+    fn test_wrapping_and_overflowing_power_of_ten() {
+        assert_eq!(u8::wrapping_power_of_ten(3), 232);
+        assert_eq!(u8::overflowing_power_of_ten(3), (232, true));
+        assert_eq!(u32::overflowing_power_of_ten(9), (1_000_000_000, false));
+    }
+
+    #[cfg(any(feature = "arbitrary", feature = "proptest"))]
+    #[test]
+    fn test_max_scale() {
+        assert_eq!(max_scale::<u8>(), 2);
+        assert_eq!(max_scale::<i64>(), 18);
+        assert_eq!(max_scale::<u128>(), 38);
+        assert_eq!(max_scale::<crate::core::U256>(), 77);
+    }
+}
@@ -0,0 +1,271 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::core::{
+    money::exchange_rate::multiply_to_scale, CheckedDecimalOperations, CheckedMulDiv,
+    CompareDecimals, Decimal, DecimalOperationError, Percent, PowerOfTen, RescaleDecimals,
+    RoundingMode,
+};
+
+/// A line of an order: a unit price and a number of units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Line<T = i128> {
+    /// The price of one unit.
+    pub unit_price: Decimal<T>,
+    /// The number of units.
+    pub quantity: T,
+}
+
+impl<T> Line<T> {
+    /// Creates a line.
+    pub const fn new(unit_price: Decimal<T>, quantity: T) -> Self {
+        Line {
+            unit_price,
+            quantity,
+        }
+    }
+}
+
+/// A discount applied to a line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Discount<T = i128> {
+    /// Takes a percentage off the line, of the amount left by the previous discounts or of the
+    /// original amount depending on the [`StackingPolicy`].
+    Percent(Percent<T>),
+    /// Takes a fixed amount off the line.
+    Fixed(Decimal<T>),
+    /// Makes `free` units out of every `buy + free` units free, valued at the unit price.
+    BuyXGetY {
+        /// The number of units paid in every group.
+        buy: T,
+        /// The number of free units in every group.
+        free: T,
+    },
+}
+
+/// How percentage discounts combine when several apply to the same line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum StackingPolicy {
+    /// Each percentage applies to the amount left by the previous discounts, so two `10%`
+    /// discounts take `19%` off.
+    #[default]
+    Compounding,
+    /// Each percentage applies to the original amount, so two `10%` discounts take `20%` off.
+    Additive,
+}
+
+/// One applied discount in the audit trail of a [`PricedLine`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DiscountStep<T = i128> {
+    /// The discount applied.
+    pub discount: Discount<T>,
+    /// The amount taken off the line, rounded to the decimals of the stack.
+    pub amount_off: Decimal<T>,
+    /// The amount of the line after the discount.
+    pub amount_after: Decimal<T>,
+}
+
+/// A line priced by a [`DiscountStack`], with the audit trail of every discount.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PricedLine<T = i128> {
+    /// The amount of the line before any discount.
+    pub original: Decimal<T>,
+    /// The amount of the line after every discount.
+    pub total: Decimal<T>,
+    /// The discounts applied, in order.
+    pub steps: Vec<DiscountStep<T>>,
+}
+
+/// An ordered list of discounts applied to order lines, with the policy combining percentages
+/// and the rounding of every step.
+///
+/// Each step takes its discount off the amount left by the previous ones, rounded once to the
+/// decimals of the stack. A discount larger than the amount left takes the line down to zero and
+/// no further.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{Decimal, Discount, DiscountStack, Line, Percent};
+///
+/// let stack = DiscountStack::new(2)
+///     .with(Discount::BuyXGetY { buy: 2, free: 1 })
+///     .with(Discount::Percent(Percent::new(10, 0)))
+///     .with(Discount::Fixed(Decimal::new(5_00, 2)));
+///
+/// // 7 units at 9.99: 2 free, 10% of 49.95 and 5.00 off
+/// let priced = stack.apply(&Line::new(Decimal::new(9_99i64, 2), 7)).unwrap();
+/// assert_eq!(priced.original, Decimal::new(69_93, 2));
+/// assert_eq!(priced.total, Decimal::new(39_95, 2));
+/// assert_eq!(priced.steps[1].amount_off, Decimal::new(5_00, 2));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DiscountStack<T = i128> {
+    discounts: Vec<Discount<T>>,
+    policy: StackingPolicy,
+    decimals: u32,
+    rounding: RoundingMode,
+}
+
+impl<T> DiscountStack<T> {
+    /// Creates an empty stack compounding percentages, whose steps are rounded half to even to
+    /// `decimals` decimals.
+    pub const fn new(decimals: u32) -> Self {
+        DiscountStack {
+            discounts: Vec::new(),
+            policy: StackingPolicy::Compounding,
+            decimals,
+            rounding: RoundingMode::HalfEven,
+        }
+    }
+
+    /// Sets how percentage discounts combine.
+    pub fn policy(mut self, policy: StackingPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Sets the rounding applied to every step.
+    pub fn rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Adds a discount after the ones already in the stack.
+    pub fn with(mut self, discount: Discount<T>) -> Self {
+        self.push(discount);
+        self
+    }
+
+    /// Adds a discount after the ones already in the stack.
+    pub fn push(&mut self, discount: Discount<T>) {
+        self.discounts.push(discount);
+    }
+
+    /// Returns the discounts of the stack, in order.
+    pub fn discounts(&self) -> &[Discount<T>] {
+        &self.discounts
+    }
+}
+
+impl<T> DiscountStack<T>
+where
+    T: CheckedDecimalOperations
+        + CheckedMulDiv
+        + RescaleDecimals
+        + PowerOfTen
+        + CompareDecimals
+        + Copy,
+{
+    /// Applies every discount of the stack to a line, in order.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the priced line, or a `DecimalOperationError` if a step
+    /// fails, like a buy-x-get-y discount with an empty group.
+    pub fn apply(&self, line: &Line<T>) -> Result<PricedLine<T>, DecimalOperationError> {
+        let original = line.unit_price.try_mul(Decimal::new(line.quantity, 0))?;
+        let mut total = original;
+        let mut steps = Vec::with_capacity(self.discounts.len());
+        for &discount in &self.discounts {
+            let amount_off = self.amount_off(discount, line, original, total)?;
+            let amount_off = if amount_off.cmp_value(&total) == Ordering::Greater {
+                total
+            } else {
+                amount_off
+            };
+            total = total.try_sub(amount_off)?;
+            steps.push(DiscountStep {
+                discount,
+                amount_off,
+                amount_after: total,
+            });
+        }
+        Ok(PricedLine {
+            original,
+            total,
+            steps,
+        })
+    }
+
+    /// Returns the amount a discount takes off a line whose amount is `total`, rounded to the
+    /// decimals of the stack.
+    fn amount_off(
+        &self,
+        discount: Discount<T>,
+        line: &Line<T>,
+        original: Decimal<T>,
+        total: Decimal<T>,
+    ) -> Result<Decimal<T>, DecimalOperationError> {
+        let value = match discount {
+            Discount::Percent(percent) => {
+                let base = match self.policy {
+                    StackingPolicy::Compounding => total,
+                    StackingPolicy::Additive => original,
+                };
+                multiply_to_scale(base, percent.to_fraction()?, self.decimals, self.rounding)?
+            }
+            Discount::Fixed(amount) => amount.try_rescale(self.decimals, self.rounding)?.value(),
+            Discount::BuyXGetY { buy, free } => {
+                let (group, _) = buy.add_decimals_checked(free, 0, 0)?;
+                let (groups, _) = line.quantity.divide_decimals_checked(group, 0, 0)?;
+                let (free_units, _) = groups.multiply_decimals_checked(free, 0, 0)?;
+                multiply_to_scale(
+                    line.unit_price,
+                    Decimal::new(free_units, 0),
+                    self.decimals,
+                    self.rounding,
+                )?
+            }
+        };
+        Ok(Decimal::new(value, self.decimals))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_discount_stack_policies() -> Result<(), DecimalOperationError> {
+        let line = Line::new(Decimal::new(33_33i64, 2), 3);
+        let ten = Discount::Percent(Percent::new(10, 0));
+        let compounding = DiscountStack::new(2).with(ten).with(ten).apply(&line)?;
+        assert_eq!(compounding.original, Decimal::new(99_99, 2));
+        // 99.99 - 10.00 - 9.00 (8.999)
+        assert_eq!(compounding.steps[0].amount_off, Decimal::new(10_00, 2));
+        assert_eq!(compounding.steps[1].amount_off, Decimal::new(9_00, 2));
+        assert_eq!(compounding.total, Decimal::new(80_99, 2));
+
+        let additive = DiscountStack::new(2)
+            .policy(StackingPolicy::Additive)
+            .rounding(RoundingMode::Down)
+            .with(ten)
+            .with(ten)
+            .apply(&line)?;
+        assert_eq!(additive.steps[1].amount_off, Decimal::new(9_99, 2));
+        assert_eq!(additive.total, Decimal::new(80_01, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_discount_stack_floor_and_errors() -> Result<(), DecimalOperationError> {
+        let line = Line::new(Decimal::new(4_00u64, 2), 2);
+        let priced = DiscountStack::new(2)
+            .with(Discount::Fixed(Decimal::new(10, 0)))
+            .with(Discount::Percent(Percent::new(50, 0)))
+            .apply(&line)?;
+        assert_eq!(priced.steps[0].amount_off, Decimal::new(8_00, 2));
+        assert_eq!(priced.steps[1].amount_off, Decimal::new(0, 2));
+        assert_eq!(priced.total, Decimal::new(0, 2));
+
+        let mut stack = DiscountStack::new(2);
+        stack.push(Discount::BuyXGetY { buy: 0, free: 0 });
+        assert_eq!(stack.discounts().len(), 1);
+        assert_eq!(
+            stack.apply(&line),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+        Ok(())
+    }
+}
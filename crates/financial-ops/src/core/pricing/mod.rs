@@ -0,0 +1,3 @@
+pub mod discount;
+
+pub use discount::*;
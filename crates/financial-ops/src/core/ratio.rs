@@ -0,0 +1,353 @@
+use crate::core::{
+    CheckedAbs, CheckedDecimalOperations, CheckedDiv, CheckedMulDiv, CheckedRem,
+    CheckedSignDecimals, Decimal, DecimalOperationError, PowerOfTen, RoundingMode, Signedness,
+    Zero,
+};
+
+/// An exact fraction `numerator / denominator`, kept in lowest terms with a positive
+/// denominator.
+///
+/// Intermediate divisions, like the share of a pool owned by one depositor, can be carried
+/// exactly through a computation and rounded once with [`resolve`](Ratio::resolve).
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{Decimal, Ratio, RoundingMode};
+///
+/// // A depositor owns 100 of the 300 pool shares, and the pool is worth 1,000.00.
+/// let share = Ratio::new(100i64, 300).unwrap();
+/// assert_eq!((share.numerator(), share.denominator()), (1, 3));
+///
+/// let value = share.try_mul(Ratio::new(1_000, 1).unwrap()).unwrap();
+/// assert_eq!(value.resolve(2, RoundingMode::Down), Ok(Decimal::new(333_33, 2)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ratio<T = i128> {
+    numerator: T,
+    denominator: T,
+}
+
+impl<T: Copy> Ratio<T> {
+    /// Returns the numerator, which carries the sign of the ratio.
+    pub fn numerator(&self) -> T {
+        self.numerator
+    }
+
+    /// Returns the denominator, which is always positive.
+    pub fn denominator(&self) -> T {
+        self.denominator
+    }
+}
+
+impl<T> Ratio<T>
+where
+    T: CheckedDecimalOperations
+        + CheckedSignDecimals
+        + CheckedAbs
+        + CheckedDiv
+        + CheckedRem
+        + Signedness
+        + Zero
+        + Copy,
+{
+    /// Creates a ratio, reducing it to lowest terms and moving the sign to the numerator.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the ratio, or a `DecimalOperationError` if the denominator
+    /// is zero or the sign can't be moved, like with a denominator of `i64::MIN`.
+    pub fn new(numerator: T, denominator: T) -> Result<Self, DecimalOperationError> {
+        if denominator.is_zero() {
+            return Err(DecimalOperationError::DivisionByZero);
+        }
+        let (numerator, denominator) = if denominator.is_negative() {
+            (
+                numerator.neg_decimals_checked(0)?.0,
+                denominator.neg_decimals_checked(0)?.0,
+            )
+        } else {
+            (numerator, denominator)
+        };
+        reduce(numerator, denominator)
+    }
+
+    /// Adds two ratios exactly.
+    ///
+    /// The denominators are divided by their greatest common divisor before multiplying, so
+    /// only a sum that doesn't fit in lowest terms fails.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the sum, or a `DecimalOperationError` if the sum doesn't fit
+    /// in the type.
+    pub fn try_add(self, other: Self) -> Result<Self, DecimalOperationError> {
+        let (left, right, divisor) = self.cross_terms(other)?;
+        self.combine(other, left.add_decimals_checked(right, 0, 0)?.0, divisor)
+    }
+
+    /// Subtracts `other` from `self` exactly.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the difference, or a `DecimalOperationError` if the
+    /// difference doesn't fit in the type.
+    pub fn try_sub(self, other: Self) -> Result<Self, DecimalOperationError> {
+        let (left, right, divisor) = self.cross_terms(other)?;
+        self.combine(other, left.sub_decimals_checked(right, 0, 0)?.0, divisor)
+    }
+
+    /// Multiplies two ratios exactly.
+    ///
+    /// Every numerator is divided by its common divisor with the other denominator before
+    /// multiplying, so the product is in lowest terms and only fails when it doesn't fit.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the product, or a `DecimalOperationError` if it doesn't fit
+    /// in the type.
+    pub fn try_mul(self, other: Self) -> Result<Self, DecimalOperationError> {
+        let left = common_divisor(self.numerator, other.denominator)?;
+        let right = common_divisor(other.numerator, self.denominator)?;
+        Ok(Ratio {
+            numerator: multiply(
+                divide(self.numerator, left)?,
+                divide(other.numerator, right)?,
+            )?,
+            denominator: multiply(
+                divide(self.denominator, right)?,
+                divide(other.denominator, left)?,
+            )?,
+        })
+    }
+
+    /// Divides `self` by `other` exactly.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the quotient, or a `DecimalOperationError` if `other` is
+    /// zero or the quotient doesn't fit in the type.
+    pub fn try_div(self, other: Self) -> Result<Self, DecimalOperationError> {
+        self.try_mul(Ratio::new(other.denominator, other.numerator)?)
+    }
+
+    /// Returns both numerators over the least common multiple of the denominators, with the
+    /// greatest common divisor of the denominators.
+    fn cross_terms(self, other: Self) -> Result<(T, T, T), DecimalOperationError> {
+        let divisor = common_divisor(self.denominator, other.denominator)?;
+        Ok((
+            multiply(self.numerator, divide(other.denominator, divisor)?)?,
+            multiply(other.numerator, divide(self.denominator, divisor)?)?,
+            divisor,
+        ))
+    }
+
+    /// Puts a numerator from [`cross_terms`](Ratio::cross_terms) over the least common multiple
+    /// of the denominators, in lowest terms.
+    ///
+    /// Only the common divisor of the denominators can divide the numerator and the least
+    /// common multiple, so it's cancelled before the denominator is built.
+    fn combine(self, other: Self, numerator: T, divisor: T) -> Result<Self, DecimalOperationError> {
+        if numerator.is_zero() {
+            // Zero over the divisor reduces to zero over one.
+            return reduce(numerator, divisor);
+        }
+        let common = common_divisor(numerator, divisor)?;
+        Ok(Ratio {
+            numerator: divide(numerator, common)?,
+            denominator: multiply(
+                divide(self.denominator, divisor)?,
+                divide(other.denominator, common)?,
+            )?,
+        })
+    }
+}
+
+impl<T> Ratio<T>
+where
+    T: CheckedDecimalOperations
+        + CheckedSignDecimals
+        + CheckedAbs
+        + CheckedDiv
+        + CheckedRem
+        + Signedness
+        + Zero
+        + PowerOfTen
+        + Copy,
+{
+    /// Creates the ratio equal to a decimal, like `5/4` for `1.25`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the ratio, or a `DecimalOperationError` if the scale of the
+    /// decimal can't be represented by the type.
+    pub fn from_decimal(decimal: Decimal<T>) -> Result<Self, DecimalOperationError> {
+        let denominator =
+            T::checked_power_of_ten(decimal.scale()).ok_or(DecimalOperationError::ScaleTooLarge)?;
+        Ratio::new(decimal.value(), denominator)
+    }
+}
+
+impl<T: CheckedMulDiv + PowerOfTen + Copy> Ratio<T> {
+    /// Rounds the ratio once to a decimal with `scale` decimals.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the decimal, or a `DecimalOperationError` if it doesn't fit
+    /// in the type.
+    pub fn resolve(
+        &self,
+        scale: u32,
+        rounding: RoundingMode,
+    ) -> Result<Decimal<T>, DecimalOperationError> {
+        let factor = T::checked_power_of_ten(scale).ok_or(DecimalOperationError::ScaleTooLarge)?;
+        let value = self
+            .numerator
+            .checked_mul_div(factor, self.denominator, rounding)?;
+        Ok(Decimal::new(value, scale))
+    }
+}
+
+/// Multiplies two integers, classifying the failure.
+fn multiply<T: CheckedDecimalOperations>(a: T, b: T) -> Result<T, DecimalOperationError> {
+    a.multiply_decimals_checked(b, 0, 0).map(|(value, _)| value)
+}
+
+/// Divides an integer by a divisor known to divide it.
+fn divide<T: CheckedDiv>(value: T, divisor: T) -> Result<T, DecimalOperationError> {
+    value
+        .checked_div(&divisor)
+        .ok_or(DecimalOperationError::Overflow)
+}
+
+/// Computes the greatest common divisor of an integer and a positive integer.
+///
+/// The integer is first reduced modulo the positive one, so a value without an absolute value,
+/// like `i64::MIN`, still has a divisor.
+fn common_divisor<T>(value: T, positive: T) -> Result<T, DecimalOperationError>
+where
+    T: CheckedAbs + CheckedRem + Zero + Copy,
+{
+    let remainder = value
+        .checked_rem(&positive)
+        .and_then(|remainder| remainder.checked_abs())
+        .ok_or(DecimalOperationError::Overflow)?;
+    gcd(positive, remainder).ok_or(DecimalOperationError::Overflow)
+}
+
+/// Divides a fraction with a positive denominator by the greatest common divisor of its terms.
+fn reduce<T>(numerator: T, denominator: T) -> Result<Ratio<T>, DecimalOperationError>
+where
+    T: CheckedAbs + CheckedDiv + CheckedRem + Zero + Copy,
+{
+    let divisor = common_divisor(numerator, denominator)?;
+    Ok(Ratio {
+        numerator: divide(numerator, divisor)?,
+        denominator: divide(denominator, divisor)?,
+    })
+}
+
+/// Computes the greatest common divisor of two non-negative integers with Euclid's algorithm.
+fn gcd<T: CheckedRem + Zero + Copy>(mut a: T, mut b: T) -> Option<T> {
+    while !b.is_zero() {
+        let remainder = a.checked_rem(&b)?;
+        a = b;
+        b = remainder;
+    }
+    Some(a)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ratio_new() -> Result<(), DecimalOperationError> {
+        let half = Ratio::new(2i64, -4)?;
+        assert_eq!((half.numerator(), half.denominator()), (-1, 2));
+        let zero = Ratio::new(0i64, 7)?;
+        assert_eq!((zero.numerator(), zero.denominator()), (0, 1));
+        assert_eq!(
+            Ratio::from_decimal(Decimal::new(1_25u64, 2))?,
+            Ratio::new(5, 4)?
+        );
+        assert_eq!(
+            Ratio::new(1i64, 0),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+        assert_eq!(
+            Ratio::new(1i64, i64::MIN),
+            Err(DecimalOperationError::Overflow)
+        );
+        // i64::MIN has no absolute value but still reduces.
+        let min = Ratio::new(i64::MIN, 2)?;
+        assert_eq!((min.numerator(), min.denominator()), (i64::MIN / 2, 1));
+        let min = Ratio::new(i64::MIN, 6)?;
+        assert_eq!((min.numerator(), min.denominator()), (i64::MIN / 2, 3));
+        Ok(())
+    }
+
+    #[test]
+    fn test_ratio_arithmetic() -> Result<(), DecimalOperationError> {
+        let third = Ratio::new(1i64, 3)?;
+        let sixth = Ratio::new(1i64, 6)?;
+        assert_eq!(third.try_add(sixth)?, Ratio::new(1, 2)?);
+        assert_eq!(sixth.try_sub(third)?, Ratio::new(-1, 6)?);
+        assert_eq!(third.try_mul(Ratio::new(3, 4)?)?, Ratio::new(1, 4)?);
+        assert_eq!(third.try_div(Ratio::new(-2, 3)?)?, Ratio::new(-1, 2)?);
+        assert_eq!(
+            third.try_div(Ratio::new(0, 1)?),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+        assert_eq!(
+            Ratio::new(200u8, 1)?.try_add(Ratio::new(100, 1)?),
+            Err(DecimalOperationError::Overflow)
+        );
+        assert_eq!(
+            Ratio::new(1u8, 3)?.try_sub(Ratio::new(1, 2)?),
+            Err(DecimalOperationError::NegativeResult)
+        );
+        assert_eq!(third.try_sub(third)?, Ratio::new(0, 1)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_ratio_cancels_before_multiplying() -> Result<(), DecimalOperationError> {
+        // The cross products of these overflow a u8, their results don't.
+        let big = Ratio::new(200u8, 3)?;
+        assert_eq!(big.try_mul(Ratio::new(3, 200)?)?, Ratio::new(1, 1)?);
+        assert_eq!(big.try_div(Ratio::new(100, 9)?)?, Ratio::new(6, 1)?);
+        let sixtieth = Ratio::new(1u8, 60)?;
+        assert_eq!(sixtieth.try_add(Ratio::new(1, 120)?)?, Ratio::new(1, 40)?);
+        // 1/100 - 1/150 is 1/300, which doesn't.
+        assert_eq!(
+            Ratio::new(1u8, 100)?.try_sub(Ratio::new(1, 150)?),
+            Err(DecimalOperationError::Overflow)
+        );
+        let half = Ratio::new(i64::MIN, 1)?.try_mul(Ratio::new(1, 2)?)?;
+        assert_eq!(half, Ratio::new(i64::MIN / 2, 1)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_ratio_resolve() -> Result<(), DecimalOperationError> {
+        let two_thirds = Ratio::new(2i64, 3)?;
+        assert_eq!(
+            two_thirds.resolve(2, RoundingMode::HalfEven)?,
+            Decimal::new(0_67, 2)
+        );
+        assert_eq!(
+            two_thirds.resolve(4, RoundingMode::Down)?,
+            Decimal::new(0_6666, 4)
+        );
+        assert_eq!(
+            Ratio::new(-1i64, 8)?.resolve(2, RoundingMode::HalfEven)?,
+            Decimal::new(-0_12, 2)
+        );
+        assert_eq!(
+            Ratio::new(1u8, 3)?.resolve(3, RoundingMode::Down),
+            Err(DecimalOperationError::ScaleTooLarge)
+        );
+        Ok(())
+    }
+}
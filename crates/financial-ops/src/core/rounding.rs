@@ -0,0 +1,105 @@
+use core::cmp::Ordering;
+
+use crate::core::DecimalOperationError;
+
+/// The strategy used to round a result that can't be represented exactly with the requested
+/// number of decimals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RoundingMode {
+    /// Rounds towards zero, discarding the extra digits.
+    #[default]
+    Down,
+    /// Rounds away from zero.
+    Up,
+    /// Rounds towards negative infinity.
+    Floor,
+    /// Rounds towards positive infinity.
+    Ceiling,
+    /// Rounds to the nearest value, rounding ties away from zero.
+    HalfUp,
+    /// Rounds to the nearest value, rounding ties towards zero.
+    HalfDown,
+    /// Rounds to the nearest value, rounding ties to the even neighbor (banker's rounding).
+    HalfEven,
+    /// Asserts that no rounding is necessary, failing with `PrecisionLoss` if the result is inexact.
+    Unnecessary,
+}
+
+impl RoundingMode {
+    /// Decides whether a quotient truncated towards zero has to be moved one unit away from zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `negative` - Whether the exact result is negative.
+    /// * `quotient_is_odd` - Whether the truncated quotient is odd.
+    /// * `discarded` - How the discarded remainder compares to half a unit, or `None` if the
+    ///   division was exact.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing `true` if the magnitude of the truncated quotient must be
+    /// incremented by one, or `PrecisionLoss` if rounding is `Unnecessary` and the division was inexact.
+    pub(crate) fn rounds_away_from_zero(
+        self,
+        negative: bool,
+        quotient_is_odd: bool,
+        discarded: Option<Ordering>,
+    ) -> Result<bool, DecimalOperationError> {
+        let Some(half) = discarded else {
+            return Ok(false);
+        };
+        Ok(match self {
+            RoundingMode::Down => false,
+            RoundingMode::Up => true,
+            RoundingMode::Floor => negative,
+            RoundingMode::Ceiling => !negative,
+            RoundingMode::HalfUp => half != Ordering::Less,
+            RoundingMode::HalfDown => half == Ordering::Greater,
+            RoundingMode::HalfEven => {
+                half == Ordering::Greater || (half == Ordering::Equal && quotient_is_odd)
+            }
+            RoundingMode::Unnecessary => return Err(DecimalOperationError::PrecisionLoss),
+        })
+    }
+
+    /// Returns the mode that rounds the magnitude of a negative value like `self` rounds the
+    /// value, swapping `Floor` and `Ceiling`.
+    pub(crate) const fn negated(self) -> Self {
+        match self {
+            RoundingMode::Floor => RoundingMode::Ceiling,
+            RoundingMode::Ceiling => RoundingMode::Floor,
+            rounding => rounding,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rounds_away_from_zero() -> Result<(), DecimalOperationError> {
+        use Ordering::*;
+
+        assert!(!RoundingMode::Up.rounds_away_from_zero(false, false, None)?);
+        assert!(!RoundingMode::Down.rounds_away_from_zero(false, false, Some(Greater))?);
+        assert!(RoundingMode::Up.rounds_away_from_zero(false, false, Some(Less))?);
+        assert!(RoundingMode::Floor.rounds_away_from_zero(true, false, Some(Less))?);
+        assert!(!RoundingMode::Floor.rounds_away_from_zero(false, false, Some(Greater))?);
+        assert!(RoundingMode::Ceiling.rounds_away_from_zero(false, false, Some(Less))?);
+        assert!(!RoundingMode::Ceiling.rounds_away_from_zero(true, false, Some(Greater))?);
+        assert!(RoundingMode::HalfUp.rounds_away_from_zero(false, false, Some(Equal))?);
+        assert!(!RoundingMode::HalfDown.rounds_away_from_zero(false, false, Some(Equal))?);
+        assert!(RoundingMode::HalfDown.rounds_away_from_zero(false, false, Some(Greater))?);
+        assert!(!RoundingMode::HalfEven.rounds_away_from_zero(false, false, Some(Equal))?);
+        assert!(RoundingMode::HalfEven.rounds_away_from_zero(false, true, Some(Equal))?);
+
+        assert!(!RoundingMode::Unnecessary.rounds_away_from_zero(false, false, None)?);
+        assert_eq!(
+            RoundingMode::Unnecessary.rounds_away_from_zero(false, false, Some(Less)),
+            Err(DecimalOperationError::PrecisionLoss)
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,417 @@
+//! Serde support for [`Decimal`], enabled with the `serde` feature.
+//!
+//! Decimals serialize as strings like `"123.45"` in human-readable formats, so JSON payloads
+//! never round-trip through a float. Deserialization accepts those strings and objects with
+//! `value` and `scale` fields. Use [`decimal_parts`] with `#[serde(with = "...")]` to serialize
+//! the object form instead. Binary formats like bincode and postcard, which aren't
+//! self-describing, get a `(value, scale)` tuple both ways.
+//!
+//! [`Money`] serializes as `{ "amount": "12.34", "currency": "USD" }`, with the decimals of its
+//! currency. Deserialization checks the currency code against the currency of the type and
+//! accepts the amount as a decimal string or as an integer number of minor units, like `1234`
+//! cents. Strings with more decimals than the currency are rejected, and a bare amount without
+//! the object is accepted too. Binary formats get the amount in minor units and the code as a
+//! struct both ways.
+
+use alloc::string::String;
+use core::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{self, IntoDeserializer, MapAccess, SeqAccess, Visitor},
+    ser::{self, SerializeStruct, SerializeTuple},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::core::{
+    Currency, Decimal, FromStrDecimals, Money, RescaleDecimals, RoundingMode, ToStringDecimals,
+};
+
+const FIELDS: &[&str] = &["value", "scale"];
+const MONEY_FIELDS: &[&str] = &["amount", "currency"];
+
+impl<T: ToStringDecimals + Serialize + Clone> Serialize for Decimal<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if !serializer.is_human_readable() {
+            let mut state = serializer.serialize_tuple(2)?;
+            state.serialize_element(self.value_ref())?;
+            state.serialize_element(&self.scale())?;
+            return state.end();
+        }
+        serializer.serialize_str(&self.value_ref().clone().to_string_decimals(self.scale()))
+    }
+}
+
+impl<'de, T: FromStrDecimals + Deserialize<'de>> Deserialize<'de> for Decimal<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if !deserializer.is_human_readable() {
+            return deserializer.deserialize_tuple(2, PartsVisitor(PhantomData));
+        }
+        deserializer.deserialize_any(DecimalVisitor(PhantomData))
+    }
+}
+
+impl<C: Currency, T: ToStringDecimals + RescaleDecimals + Serialize + Clone> Serialize
+    for Money<C, T>
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Only the decimals of the currency are read back, so the amount is written with them.
+        let (minor_units, _) = self
+            .amount_ref()
+            .clone()
+            .rescale_checked(self.decimals(), C::DECIMALS, RoundingMode::Unnecessary)
+            .map_err(ser::Error::custom)?;
+        let human_readable = serializer.is_human_readable();
+        let mut state = serializer.serialize_struct("Money", 2)?;
+        if human_readable {
+            state.serialize_field("amount", &minor_units.to_string_decimals(C::DECIMALS))?;
+        } else {
+            state.serialize_field("amount", &minor_units)?;
+        }
+        state.serialize_field("currency", C::CODE)?;
+        state.end()
+    }
+}
+
+impl<'de, C: Currency, T: FromStrDecimals + Deserialize<'de>> Deserialize<'de> for Money<C, T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if !deserializer.is_human_readable() {
+            return deserializer.deserialize_struct(
+                "Money",
+                MONEY_FIELDS,
+                MoneyVisitor(PhantomData),
+            );
+        }
+        deserializer.deserialize_any(MoneyVisitor(PhantomData))
+    }
+}
+
+/// Checks a serialized currency code against the currency of the type.
+fn check_currency<C: Currency, E: de::Error>(code: &str) -> Result<(), E> {
+    match code == C::CODE {
+        true => Ok(()),
+        false => Err(E::invalid_value(de::Unexpected::Str(code), &C::CODE)),
+    }
+}
+
+struct DecimalVisitor<T>(PhantomData<T>);
+
+impl<'de, T: FromStrDecimals + Deserialize<'de>> Visitor<'de> for DecimalVisitor<T> {
+    type Value = Decimal<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a decimal string or a map with `value` and `scale`")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        T::from_str_decimals(v)
+            .map(Decimal::from)
+            .map_err(E::custom)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        PartsVisitor(PhantomData).visit_map(map)
+    }
+}
+
+struct PartsVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for PartsVisitor<T> {
+    type Value = Decimal<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map or a tuple with `value` and `scale`")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let value = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let scale = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        Ok(Decimal::new(value, scale))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut value = None;
+        let mut scale = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "value" if value.is_some() => return Err(de::Error::duplicate_field("value")),
+                "value" => value = Some(map.next_value()?),
+                "scale" if scale.is_some() => return Err(de::Error::duplicate_field("scale")),
+                "scale" => scale = Some(map.next_value()?),
+                other => return Err(de::Error::unknown_field(other, FIELDS)),
+            }
+        }
+        let value = value.ok_or_else(|| de::Error::missing_field("value"))?;
+        let scale = scale.ok_or_else(|| de::Error::missing_field("scale"))?;
+        Ok(Decimal::new(value, scale))
+    }
+}
+
+/// Deserializes the amount of a [`Money`] from a decimal string or an integer number of minor
+/// units, with the decimals of its currency.
+struct AmountVisitor<C, T>(PhantomData<(C, T)>);
+
+impl<'de, C: Currency, T: FromStrDecimals + Deserialize<'de>> Visitor<'de> for AmountVisitor<C, T> {
+    type Value = Money<C, T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a decimal string or an integer number of minor units")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        T::from_str_decimals_with_scale(v, C::DECIMALS)
+            .map(Money::from_minor_units)
+            .map_err(E::custom)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        T::deserialize(v.into_deserializer()).map(Money::from_minor_units)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        T::deserialize(v.into_deserializer()).map(Money::from_minor_units)
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+        T::deserialize(v.into_deserializer()).map(Money::from_minor_units)
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Self::Value, E> {
+        T::deserialize(v.into_deserializer()).map(Money::from_minor_units)
+    }
+}
+
+/// The `amount` field of a serialized [`Money`].
+struct Amount<C, T>(Money<C, T>);
+
+impl<'de, C: Currency, T: FromStrDecimals + Deserialize<'de>> Deserialize<'de> for Amount<C, T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_any(AmountVisitor(PhantomData))
+            .map(Amount)
+    }
+}
+
+struct MoneyVisitor<C, T>(PhantomData<(C, T)>);
+
+impl<'de, C: Currency, T: FromStrDecimals + Deserialize<'de>> Visitor<'de> for MoneyVisitor<C, T> {
+    type Value = Money<C, T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map or a struct with `amount` and `currency`, or an amount")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        AmountVisitor(PhantomData).visit_str(v)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        AmountVisitor(PhantomData).visit_u64(v)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        AmountVisitor(PhantomData).visit_i64(v)
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+        AmountVisitor(PhantomData).visit_u128(v)
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Self::Value, E> {
+        AmountVisitor(PhantomData).visit_i128(v)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let amount = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let code = seq
+            .next_element::<String>()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        check_currency::<C, _>(&code)?;
+        Ok(Money::from_minor_units(amount))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut amount = None;
+        let mut currency = false;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "amount" if amount.is_some() => return Err(de::Error::duplicate_field("amount")),
+                "amount" => amount = Some(map.next_value::<Amount<C, T>>()?.0),
+                "currency" if currency => return Err(de::Error::duplicate_field("currency")),
+                "currency" => {
+                    check_currency::<C, _>(&map.next_value::<String>()?)?;
+                    currency = true;
+                }
+                other => return Err(de::Error::unknown_field(other, MONEY_FIELDS)),
+            }
+        }
+        if !currency {
+            return Err(de::Error::missing_field("currency"));
+        }
+        amount.ok_or_else(|| de::Error::missing_field("amount"))
+    }
+}
+
+/// Serializes a [`Decimal`] as an object with `value` and `scale` fields.
+///
+/// # Examples
+///
+/// ```ignore
+/// #[derive(Serialize, Deserialize)]
+/// struct Quote {
+///     #[serde(with = "financial_ops::decimal_parts")]
+///     price: Decimal<u64>,
+/// }
+/// ```
+pub mod decimal_parts {
+    use core::marker::PhantomData;
+
+    use serde::{ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{PartsVisitor, FIELDS};
+    use crate::core::Decimal;
+
+    /// Serializes the decimal as `{ "value": ..., "scale": ... }`.
+    pub fn serialize<T: Serialize, S: Serializer>(
+        decimal: &Decimal<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Decimal", 2)?;
+        state.serialize_field("value", decimal.value_ref())?;
+        state.serialize_field("scale", &decimal.scale())?;
+        state.end()
+    }
+
+    /// Deserializes a decimal from `{ "value": ..., "scale": ... }`.
+    pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Decimal<T>, D::Error> {
+        deserializer.deserialize_struct("Decimal", FIELDS, PartsVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Usd;
+
+    #[test]
+    fn test_serialize_as_string() {
+        let value = Decimal::new(-123_45i64, 2);
+        assert_eq!(serde_json::to_string(&value).unwrap(), r#""-123.45""#);
+
+        // Values above 2^53 survive without a float round trip
+        let value = Decimal::new(u128::MAX, 18);
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, r#""340282366920938463463.374607431768211455""#);
+        assert_eq!(serde_json::from_str::<Decimal<u128>>(&json).unwrap(), value);
+    }
+
+    #[test]
+    fn test_binary_round_trip() {
+        // bincode isn't self-describing, so the decimal goes through a `(value, scale)` tuple.
+        let value = Decimal::new(-123_45i64, 2);
+        let bytes = bincode::serialize(&value).unwrap();
+        assert_eq!(bytes, bincode::serialize(&(-123_45i64, 2u32)).unwrap());
+        assert_eq!(bincode::deserialize::<Decimal<i64>>(&bytes).unwrap(), value);
+
+        let value = Decimal::new(u128::MAX, 38);
+        let bytes = bincode::serialize(&value).unwrap();
+        assert_eq!(
+            bincode::deserialize::<Decimal<u128>>(&bytes).unwrap(),
+            value
+        );
+    }
+
+    #[test]
+    fn test_deserialize() {
+        assert_eq!(
+            serde_json::from_str::<Decimal<u64>>(r#""1_000.50""#).unwrap(),
+            Decimal::new(1_000_50, 2)
+        );
+        assert_eq!(
+            serde_json::from_str::<Decimal<u64>>(r#"{"value": 100050, "scale": 2}"#).unwrap(),
+            Decimal::new(1_000_50, 2)
+        );
+        assert!(serde_json::from_str::<Decimal<u8>>(r#""2.56""#).is_err());
+        assert!(serde_json::from_str::<Decimal<u64>>("1.5").is_err());
+        assert!(serde_json::from_str::<Decimal<u64>>(r#"{"value": 1}"#).is_err());
+    }
+
+    #[test]
+    fn test_money_serde() {
+        let value = Money::<Usd, i64>::new(-19_99, 2);
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, r#"{"amount":"-19.99","currency":"USD"}"#);
+        assert_eq!(
+            serde_json::from_str::<Money<Usd, i64>>(&json).unwrap(),
+            value
+        );
+        // Integer amounts are minor units, and bare amounts are accepted.
+        assert_eq!(
+            serde_json::from_str::<Money<Usd, u64>>(r#"{"currency": "USD", "amount": 1234}"#)
+                .unwrap(),
+            Money::new(12_34, 2)
+        );
+        assert_eq!(
+            serde_json::from_str::<Money<Usd, u64>>(r#""12.3""#).unwrap(),
+            Money::new(12_30, 2)
+        );
+    }
+
+    #[test]
+    fn test_money_serialize_with_currency_decimals() {
+        let value = Money::<Usd, i64>::new(12_3, 1);
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, r#"{"amount":"12.30","currency":"USD"}"#);
+        assert_eq!(
+            serde_json::from_str::<Money<Usd, i64>>(&json).unwrap(),
+            Money::new(12_30, 2)
+        );
+        // A thousandth of a dollar can't be written with the decimals of the dollar.
+        assert!(serde_json::to_string(&Money::<Usd, i64>::new(12_345, 3)).is_err());
+    }
+
+    #[test]
+    fn test_money_binary_round_trip() {
+        let value = Money::<Usd, i64>::new(-19_99, 2);
+        let bytes = bincode::serialize(&value).unwrap();
+        assert_eq!(bytes, bincode::serialize(&(-19_99i64, "USD")).unwrap());
+        assert_eq!(
+            bincode::deserialize::<Money<Usd, i64>>(&bytes).unwrap(),
+            value
+        );
+        let euros = bincode::serialize(&(-19_99i64, "EUR")).unwrap();
+        assert!(bincode::deserialize::<Money<Usd, i64>>(&euros).is_err());
+    }
+
+    #[test]
+    fn test_money_deserialize_errors() {
+        let parse = serde_json::from_str::<Money<Usd, u64>>;
+        assert!(parse(r#"{"amount": "12.34", "currency": "EUR"}"#).is_err());
+        assert!(parse(r#"{"amount": "12.345", "currency": "USD"}"#).is_err());
+        assert!(parse(r#"{"amount": "12.34"}"#).is_err());
+        assert!(parse(r#"{"currency": "USD"}"#).is_err());
+        assert!(parse(r#"{"amount": -1, "currency": "USD"}"#).is_err());
+        assert!(parse(r#"{"amount": 1.5, "currency": "USD"}"#).is_err());
+        assert!(parse(r#"{"amount": "1", "currency": "USD", "memo": ""}"#).is_err());
+    }
+
+    #[test]
+    fn test_decimal_parts() {
+        let value = Decimal::new(1_50u64, 2);
+        let mut serializer = serde_json::Serializer::new(Vec::new());
+        decimal_parts::serialize(&value, &mut serializer).unwrap();
+        let json = String::from_utf8(serializer.into_inner()).unwrap();
+        assert_eq!(json, r#"{"value":150,"scale":2}"#);
+
+        let mut deserializer = serde_json::Deserializer::from_str(&json);
+        let parsed: Decimal<u64> = decimal_parts::deserialize(&mut deserializer).unwrap();
+        assert_eq!(parsed, value);
+    }
+}
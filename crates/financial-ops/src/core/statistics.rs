@@ -0,0 +1,489 @@
+use core::cmp::Ordering;
+
+use crate::core::{
+    CheckedDecimalOperations, CheckedDivideToScale, CheckedSignDecimals, CheckedSqrtDecimals,
+    CompareDecimals, Decimal, DecimalOperationError, RoundingMode, Zero,
+};
+
+/// Whether a variance describes a whole population or estimates it from a sample.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum VarianceKind {
+    /// Divides the sum of squared deviations by the number of values.
+    #[default]
+    Population,
+    /// Divides the sum of squared deviations by the number of values minus one, Bessel's
+    /// correction.
+    Sample,
+}
+
+/// Computes the mean of values with decimals.
+///
+/// The values are added up exactly and the mean is rounded once to `target_decimals` decimals.
+///
+/// # Arguments
+///
+/// * `values` - The values, with `decimals` decimals each.
+/// * `decimals` - The number of decimals in the values.
+/// * `target_decimals` - The number of decimals of the mean.
+/// * `rounding` - The rounding applied to the mean.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the mean and `target_decimals`, or a `DecimalOperationError` if
+/// there are no values, reported as `OutOfDomain`, or the operation fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{mean_decimals, RoundingMode};
+///
+/// let returns = [1_25i64, -0_40, 2_10];
+/// assert_eq!(mean_decimals(&returns, 2, 4, RoundingMode::HalfEven), Ok((0_9833, 4)));
+/// ```
+pub fn mean_decimals<T>(
+    values: &[T],
+    decimals: u32,
+    target_decimals: u32,
+    rounding: RoundingMode,
+) -> Result<(T, u32), DecimalOperationError>
+where
+    T: CheckedDecimalOperations + CheckedDivideToScale + Zero + From<u32> + Copy,
+{
+    let count = count(values, 1)?;
+    let (sum, _) = sums(values, decimals)?;
+    sum.try_div_to_scale(count, target_decimals, rounding)
+        .map(Decimal::into_parts)
+}
+
+/// Computes the variance of values with decimals.
+///
+/// The sum of the values and the sum of their squares are accumulated exactly, and the variance
+/// is `(n * sum(x^2) - sum(x)^2) / (n * n)`, or `/ (n * (n - 1))` for a sample, rounded once to
+/// `target_decimals` decimals. Nothing is rounded before that, so the result doesn't depend on
+/// the order of the values or the platform.
+///
+/// # Arguments
+///
+/// * `values` - The values, with `decimals` decimals each.
+/// * `decimals` - The number of decimals in the values.
+/// * `kind` - Whether the values are a population or a sample.
+/// * `target_decimals` - The number of decimals of the variance.
+/// * `rounding` - The rounding applied to the variance.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the variance and `target_decimals`, or a
+/// `DecimalOperationError` if there are no values, or fewer than two for a sample, reported as
+/// `OutOfDomain`, or the operation fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{variance_decimals, RoundingMode, VarianceKind};
+///
+/// let returns = [1_25i64, -0_40, 2_10];
+/// assert_eq!(
+///     variance_decimals(&returns, 2, VarianceKind::Sample, 4, RoundingMode::HalfEven),
+///     Ok((1_6158, 4))
+/// );
+/// ```
+pub fn variance_decimals<T>(
+    values: &[T],
+    decimals: u32,
+    kind: VarianceKind,
+    target_decimals: u32,
+    rounding: RoundingMode,
+) -> Result<(T, u32), DecimalOperationError>
+where
+    T: CheckedDecimalOperations + CheckedDivideToScale + Zero + From<u32> + Copy,
+{
+    variance(values, decimals, kind, target_decimals, rounding).map(Decimal::into_parts)
+}
+
+/// Computes the standard deviation of values with decimals, the square root of their
+/// [`variance_decimals`].
+///
+/// The variance is computed exactly and the root with the fixed-point
+/// [`sqrt_decimals_checked`](CheckedSqrtDecimals::sqrt_decimals_checked), so the result is the
+/// exact standard deviation rounded down to `target_decimals` decimals.
+///
+/// # Arguments
+///
+/// * `values` - The values, with `decimals` decimals each.
+/// * `decimals` - The number of decimals in the values.
+/// * `kind` - Whether the values are a population or a sample.
+/// * `target_decimals` - The number of decimals of the standard deviation.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the standard deviation and `target_decimals`, or a
+/// `DecimalOperationError` if there are no values, or fewer than two for a sample, reported as
+/// `OutOfDomain`, or the operation fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{stddev_decimals, VarianceKind};
+///
+/// let returns = [1_25i64, -0_40, 2_10];
+/// assert_eq!(stddev_decimals(&returns, 2, VarianceKind::Sample, 4), Ok((1_2711, 4)));
+/// ```
+pub fn stddev_decimals<T>(
+    values: &[T],
+    decimals: u32,
+    kind: VarianceKind,
+    target_decimals: u32,
+) -> Result<(T, u32), DecimalOperationError>
+where
+    T: CheckedDecimalOperations
+        + CheckedDivideToScale
+        + CheckedSqrtDecimals
+        + Zero
+        + From<u32>
+        + Copy,
+{
+    // The root of the variance truncated to twice the decimals, truncated, is the truncated root.
+    let variance_decimals = target_decimals
+        .checked_mul(2)
+        .ok_or(DecimalOperationError::ScaleTooLarge)?;
+    variance(
+        values,
+        decimals,
+        kind,
+        variance_decimals,
+        RoundingMode::Down,
+    )?
+    .try_sqrt(target_decimals)
+    .map(Decimal::into_parts)
+}
+
+/// Computes the variance of values with decimals, rounded to `target_decimals` decimals.
+pub(crate) fn variance<T>(
+    values: &[T],
+    decimals: u32,
+    kind: VarianceKind,
+    target_decimals: u32,
+    rounding: RoundingMode,
+) -> Result<Decimal<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations + CheckedDivideToScale + Zero + From<u32> + Copy,
+{
+    let (minimum, correction) = match kind {
+        VarianceKind::Population => (1, 0),
+        VarianceKind::Sample => (2, 1),
+    };
+    let count = count(values, minimum)?;
+    let (sum, squares) = sums(values, decimals)?;
+    let deviations = squares.try_mul(count)?.try_sub(sum.try_mul(sum)?)?;
+    let denominator = count.try_mul(count.try_sub(Decimal::new(T::from(correction), 0))?)?;
+    deviations.try_div_to_scale(denominator, target_decimals, rounding)
+}
+
+/// Computes the Sharpe ratio of returns: their mean in excess of the risk-free rate over their
+/// sample standard deviation, `(mean - risk_free_rate) / stddev`.
+///
+/// The returns and the risk-free rate are per period and have `decimals` decimals. Rather than
+/// dividing by a rounded [`stddev_decimals`], the square of the ratio is computed exactly from the
+/// sums of the returns and of their squares, and its root taken with the fixed-point square root,
+/// so the ratio is exact up to its last decimal, rounded towards zero.
+///
+/// # Arguments
+///
+/// * `returns` - The returns, with `decimals` decimals each.
+/// * `risk_free_rate` - The risk-free rate, with `decimals` decimals.
+/// * `decimals` - The number of decimals in the returns and the rate.
+/// * `scale` - The number of decimals of the ratio.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the ratio and `scale`, or a `DecimalOperationError` if there are
+/// fewer than two returns, reported as `OutOfDomain`, they are all equal, reported as
+/// `DivisionByZero`, or the operation fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::sharpe;
+///
+/// // Monthly returns in percent, against a risk-free rate of 0.25% a month
+/// let returns = [1_25i64, -0_40, 2_10, 0_85];
+/// assert_eq!(sharpe(&returns, 0_25, 2, 4), Ok((0_6730, 4)));
+/// ```
+pub fn sharpe<T>(
+    returns: &[T],
+    risk_free_rate: T,
+    decimals: u32,
+    scale: u32,
+) -> Result<(T, u32), DecimalOperationError>
+where
+    T: CheckedDecimalOperations
+        + CheckedDivideToScale
+        + CheckedSignDecimals
+        + CheckedSqrtDecimals
+        + CompareDecimals
+        + Zero
+        + From<u32>
+        + Copy,
+{
+    let count = count(returns, 2)?;
+    let (sum, squares) = sums(returns, decimals)?;
+    let excess = excess_sum(sum, count, Decimal::new(risk_free_rate, decimals))?;
+    // (mean - rate)^2 / variance, with the mean and the variance over n and n * (n - 1)
+    let corrected = count.try_sub(Decimal::new(T::from(1), 0))?;
+    let deviations = squares.try_mul(count)?.try_sub(sum.try_mul(sum)?)?;
+    ratio(
+        excess,
+        excess.try_mul(excess)?.try_mul(corrected)?,
+        count.try_mul(deviations)?,
+        scale,
+    )
+}
+
+/// Computes the Sortino ratio of returns: their mean in excess of the risk-free rate over their
+/// downside deviation, the root mean square of the shortfalls of the returns below the rate.
+///
+/// Unlike the [`sharpe`] ratio, returns above the risk-free rate don't count as risk. The
+/// downside deviation averages the squared shortfalls over all the returns, and the ratio is
+/// computed exactly and rounded towards zero like the Sharpe ratio.
+///
+/// # Arguments
+///
+/// * `returns` - The returns, with `decimals` decimals each.
+/// * `risk_free_rate` - The risk-free rate, with `decimals` decimals, also the target the
+///   shortfalls are measured from.
+/// * `decimals` - The number of decimals in the returns and the rate.
+/// * `scale` - The number of decimals of the ratio.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the ratio and `scale`, or a `DecimalOperationError` if there are
+/// no returns, reported as `OutOfDomain`, none of them is below the risk-free rate, reported as
+/// `DivisionByZero`, or the operation fails.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::sortino;
+///
+/// let returns = [1_25i64, -0_40, 2_10, 0_85];
+/// assert_eq!(sortino(&returns, 0_25, 2, 4), Ok((2_1538, 4)));
+/// ```
+pub fn sortino<T>(
+    returns: &[T],
+    risk_free_rate: T,
+    decimals: u32,
+    scale: u32,
+) -> Result<(T, u32), DecimalOperationError>
+where
+    T: CheckedDecimalOperations
+        + CheckedDivideToScale
+        + CheckedSignDecimals
+        + CheckedSqrtDecimals
+        + CompareDecimals
+        + Zero
+        + From<u32>
+        + Copy,
+{
+    let count = count(returns, 1)?;
+    let (sum, _) = sums(returns, decimals)?;
+    let rate = Decimal::new(risk_free_rate, decimals);
+    let excess = excess_sum(sum, count, rate)?;
+    let shortfalls = returns
+        .iter()
+        .try_fold(Decimal::zero(decimals), |total, &value| {
+            let shortfall = Decimal::new(value, decimals).try_sub(rate)?;
+            if shortfall.cmp_value(&Decimal::zero(0)) == Ordering::Less {
+                total.try_add(shortfall.try_mul(shortfall)?)
+            } else {
+                Ok(total)
+            }
+        })?;
+    // (mean - rate)^2 / (shortfalls / n)
+    ratio(
+        excess,
+        excess.try_mul(excess)?,
+        count.try_mul(shortfalls)?,
+        scale,
+    )
+}
+
+/// Returns `sum - count * rate`, `count` times the mean of the values in excess of the rate.
+fn excess_sum<T>(
+    sum: Decimal<T>,
+    count: Decimal<T>,
+    rate: Decimal<T>,
+) -> Result<Decimal<T>, DecimalOperationError>
+where
+    T: CheckedDecimalOperations + Copy,
+{
+    sum.try_sub(count.try_mul(rate)?)
+}
+
+/// Returns the root of `squared / denominator` rounded down to `scale` decimals, with the sign of
+/// `excess`.
+fn ratio<T>(
+    excess: Decimal<T>,
+    squared: Decimal<T>,
+    denominator: Decimal<T>,
+    scale: u32,
+) -> Result<(T, u32), DecimalOperationError>
+where
+    T: CheckedDivideToScale
+        + CheckedSignDecimals
+        + CheckedSqrtDecimals
+        + CompareDecimals
+        + Zero
+        + Copy,
+{
+    let squared_scale = scale
+        .checked_mul(2)
+        .ok_or(DecimalOperationError::ScaleTooLarge)?;
+    let ratio = squared
+        .try_div_to_scale(denominator, squared_scale, RoundingMode::Down)?
+        .try_sqrt(scale)?;
+    if excess.cmp_value(&Decimal::zero(0)) == Ordering::Less {
+        ratio.try_neg().map(Decimal::into_parts)
+    } else {
+        Ok(ratio.into_parts())
+    }
+}
+
+/// Returns the number of values, or `OutOfDomain` if there are fewer than `minimum`.
+fn count<T: From<u32>>(values: &[T], minimum: usize) -> Result<Decimal<T>, DecimalOperationError> {
+    if values.len() < minimum {
+        return Err(DecimalOperationError::OutOfDomain);
+    }
+    let count = u32::try_from(values.len()).map_err(|_| DecimalOperationError::Overflow)?;
+    Ok(Decimal::new(T::from(count), 0))
+}
+
+/// Returns the sum of the values and the sum of their squares.
+fn sums<T>(values: &[T], decimals: u32) -> Result<(Decimal<T>, Decimal<T>), DecimalOperationError>
+where
+    T: CheckedDecimalOperations + Zero + Copy,
+{
+    values.iter().try_fold(
+        (Decimal::zero(decimals), Decimal::zero(decimals)),
+        |(sum, squares), &value| {
+            let value = Decimal::new(value, decimals);
+            Ok((sum.try_add(value)?, squares.try_add(value.try_mul(value)?)?))
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mean_decimals() -> Result<(), DecimalOperationError> {
+        let values = [1_005u64, 2_000, 3_001];
+        assert_eq!(
+            mean_decimals(&values, 3, 2, RoundingMode::HalfEven)?,
+            (2_00, 2)
+        );
+        assert_eq!(
+            mean_decimals(&values, 3, 5, RoundingMode::Up)?,
+            (2_00200, 5)
+        );
+        assert_eq!(
+            mean_decimals::<u64>(&[], 3, 2, RoundingMode::HalfEven),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_variance_decimals() -> Result<(), DecimalOperationError> {
+        // Squared deviations of 9, 1, 1, 1, 0, 0, 4 and 16 from a mean of 5
+        let values = [2i128, 4, 4, 4, 5, 5, 7, 9];
+        let variance = |kind| variance_decimals(&values, 0, kind, 6, RoundingMode::HalfEven);
+        assert_eq!(variance(VarianceKind::Population)?, (4_000000, 6));
+        assert_eq!(variance(VarianceKind::Sample)?, (4_571429, 6));
+        // The same values with two decimals, in another order
+        let scaled = [9_00i128, 2_00, 5_00, 4_00, 7_00, 4_00, 5_00, 4_00];
+        assert_eq!(
+            variance_decimals(&scaled, 2, VarianceKind::Sample, 6, RoundingMode::HalfEven)?,
+            (4_571429, 6)
+        );
+        assert_eq!(
+            variance_decimals(
+                &[7i64],
+                2,
+                VarianceKind::Population,
+                2,
+                RoundingMode::HalfEven
+            )?,
+            (0, 2)
+        );
+        assert_eq!(
+            variance_decimals(&[7i64], 2, VarianceKind::Sample, 2, RoundingMode::HalfEven),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        assert_eq!(
+            variance_decimals(
+                &[i64::MAX, 0],
+                0,
+                VarianceKind::Population,
+                0,
+                RoundingMode::HalfEven
+            ),
+            Err(DecimalOperationError::Overflow)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_stddev_decimals() -> Result<(), DecimalOperationError> {
+        let values = [2i128, 4, 4, 4, 5, 5, 7, 9];
+        assert_eq!(
+            stddev_decimals(&values, 0, VarianceKind::Population, 4)?,
+            (2_0000, 4)
+        );
+        // sqrt(32 / 7) = 2.138089935...
+        assert_eq!(
+            stddev_decimals(&values, 0, VarianceKind::Sample, 8)?,
+            (2_13808993, 8)
+        );
+        assert_eq!(
+            stddev_decimals::<i128>(&[], 0, VarianceKind::Population, 4),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_sharpe() -> Result<(), DecimalOperationError> {
+        let returns = [2i128, 4, 4, 4, 5, 5, 7, 9];
+        // (5 - 1) / sqrt(32 / 7) = 1.870828693...
+        assert_eq!(sharpe(&returns, 1, 0, 6)?, (1_870828, 6));
+        assert_eq!(sharpe(&returns, 9, 0, 6)?, (-1_870828, 6));
+        assert_eq!(sharpe(&returns, 5, 0, 6)?, (0, 6));
+        assert_eq!(
+            sharpe(&[3i128, 3, 3], 1, 0, 6),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+        assert_eq!(
+            sharpe(&[3i128], 1, 0, 6),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_sortino() -> Result<(), DecimalOperationError> {
+        let returns = [2i128, 4, 4, 4, 5, 5, 7, 9];
+        // (5 - 4) / sqrt(4 / 8) and (5 - 6) / sqrt((16 + 4 + 4 + 4 + 1 + 1) / 8)
+        assert_eq!(sortino(&returns, 4, 0, 6)?, (1_414213, 6));
+        assert_eq!(sortino(&returns, 6, 0, 6)?, (-0_516397, 6));
+        assert_eq!(
+            sortino(&returns, 2, 0, 6),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+        assert_eq!(
+            sortino::<i128>(&[], 0, 0, 6),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,248 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::core::{
+    percentage::percent::fraction_decimals, CheckedDecimalOperations, CheckedDivideToScale,
+    CompareDecimals, Decimal, DecimalOperationError, Percent, RescaleDecimals, RoundingMode, Zero,
+};
+
+/// A marginal tax bracket: the rate applied to the part of the income above `threshold`, up to
+/// the threshold of the next bracket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bracket<T = i128> {
+    threshold: Decimal<T>,
+    rate: Percent<T>,
+}
+
+impl<T> Bracket<T> {
+    /// Creates a bracket starting at `threshold`.
+    pub const fn new(threshold: Decimal<T>, rate: Percent<T>) -> Self {
+        Bracket { threshold, rate }
+    }
+}
+
+impl<T: Copy> Bracket<T> {
+    /// Returns the income the bracket starts at.
+    pub fn threshold(&self) -> Decimal<T> {
+        self.threshold
+    }
+
+    /// Returns the rate applied within the bracket.
+    pub fn rate(&self) -> Percent<T> {
+        self.rate
+    }
+}
+
+/// A progressive tax schedule of marginal brackets.
+///
+/// The tax of every bracket is computed exactly and the total is rounded once to the decimals of
+/// the schedule, so an income on a bracket boundary doesn't drift by a cent depending on how the
+/// brackets are split.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{Brackets, Decimal, Percent};
+///
+/// let brackets = Brackets::new(2)
+///     .bracket(Decimal::new(0, 0), Percent::new(10, 0))
+///     .bracket(Decimal::new(11_600, 0), Percent::new(12, 0))
+///     .bracket(Decimal::new(47_150, 0), Percent::new(22, 0));
+///
+/// // 10% of 11,600 + 12% of 35,550 + 22% of 2,850.55
+/// let income = Decimal::new(50_000_55i64, 2);
+/// assert_eq!(brackets.tax(income).unwrap(), Decimal::new(6_053_12, 2));
+/// assert_eq!(
+///     brackets.effective_rate(income, 2).unwrap(),
+///     Percent::new(12_11, 2)
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Brackets<T = i128> {
+    brackets: Vec<Bracket<T>>,
+    decimals: u32,
+    rounding: RoundingMode,
+}
+
+impl<T> Brackets<T> {
+    /// Creates a schedule without brackets whose taxes are rounded half to even to `decimals`
+    /// decimals.
+    pub const fn new(decimals: u32) -> Self {
+        Brackets {
+            brackets: Vec::new(),
+            decimals,
+            rounding: RoundingMode::HalfEven,
+        }
+    }
+
+    /// Sets the rounding applied to the total tax.
+    pub fn rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Returns the brackets, ordered by threshold.
+    pub fn brackets(&self) -> &[Bracket<T>] {
+        &self.brackets
+    }
+}
+
+impl<T: CompareDecimals> Brackets<T> {
+    /// Adds a bracket starting at `threshold`, keeping the brackets ordered by threshold.
+    pub fn bracket(mut self, threshold: Decimal<T>, rate: Percent<T>) -> Self {
+        let index = self.brackets.partition_point(|bracket| {
+            bracket.threshold.cmp_value(&threshold) != Ordering::Greater
+        });
+        self.brackets.insert(index, Bracket::new(threshold, rate));
+        self
+    }
+}
+
+impl<T> Brackets<T>
+where
+    T: CheckedDecimalOperations + CompareDecimals + RescaleDecimals + Zero + Copy,
+{
+    /// Computes the tax owed on `income`, rounded to the decimals of the schedule.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the tax, or a `DecimalOperationError` if the operation
+    /// fails.
+    pub fn tax(&self, income: Decimal<T>) -> Result<Decimal<T>, DecimalOperationError> {
+        self.exact_tax(income)?
+            .try_rescale(self.decimals, self.rounding)
+    }
+
+    /// Returns the rate of the bracket `income` falls in, or `None` below the first threshold.
+    ///
+    /// An income on a threshold falls in the bracket below it, since none of it is taxed at the
+    /// rate of the bracket starting there.
+    pub fn marginal_rate(&self, income: Decimal<T>) -> Option<Percent<T>> {
+        self.brackets
+            .iter()
+            .take_while(|bracket| income.cmp_value(&bracket.threshold) == Ordering::Greater)
+            .last()
+            .map(|bracket| bracket.rate)
+    }
+
+    /// Computes the tax owed on `income` without rounding.
+    fn exact_tax(&self, income: Decimal<T>) -> Result<Decimal<T>, DecimalOperationError> {
+        let mut tax = Decimal::new(T::zero(), 0);
+        for (index, bracket) in self.brackets.iter().enumerate() {
+            if income.cmp_value(&bracket.threshold) != Ordering::Greater {
+                break;
+            }
+            let upper = match self.brackets.get(index + 1) {
+                Some(next) if income.cmp_value(&next.threshold) == Ordering::Greater => {
+                    next.threshold
+                }
+                _ => income,
+            };
+            let taxed = upper
+                .try_sub(bracket.threshold)?
+                .try_mul(bracket.rate.to_fraction()?)?;
+            tax = tax.try_add(taxed)?;
+        }
+        Ok(tax)
+    }
+}
+
+impl<T> Brackets<T>
+where
+    T: CheckedDecimalOperations
+        + CheckedDivideToScale
+        + CompareDecimals
+        + RescaleDecimals
+        + Zero
+        + Copy,
+{
+    /// Computes the tax owed on `income` as a percentage of it, with `decimals` decimals.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the effective rate, or a `DecimalOperationError` if the
+    /// income is zero or the operation fails.
+    pub fn effective_rate(
+        &self,
+        income: Decimal<T>,
+        decimals: u32,
+    ) -> Result<Percent<T>, DecimalOperationError> {
+        let tax = self.exact_tax(income)?;
+        let (rate, _) = tax.value().divide_decimals_to_scale_checked(
+            income.value(),
+            tax.scale(),
+            income.scale(),
+            fraction_decimals(decimals)?,
+            self.rounding,
+        )?;
+        Ok(Percent::new(rate, decimals))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brackets() -> Brackets<i64> {
+        // Added out of order on purpose.
+        Brackets::new(2)
+            .bracket(Decimal::new(50_000_00, 2), Percent::new(40, 0))
+            .bracket(Decimal::new(10_000, 0), Percent::new(20, 0))
+            .bracket(Decimal::new(0, 0), Percent::new(0, 0))
+    }
+
+    #[test]
+    fn test_brackets_tax() -> Result<(), DecimalOperationError> {
+        let brackets = brackets();
+        assert_eq!(brackets.brackets()[1].threshold(), Decimal::new(10_000, 0));
+        assert_eq!(brackets.tax(Decimal::new(9_999_99, 2))?, Decimal::new(0, 2));
+        assert_eq!(brackets.tax(Decimal::new(10_000, 0))?, Decimal::new(0, 2));
+        assert_eq!(
+            brackets.tax(Decimal::new(10_000_01, 2))?,
+            Decimal::new(0_00, 2)
+        );
+        assert_eq!(
+            brackets.tax(Decimal::new(10_000_03, 2))?,
+            Decimal::new(0_01, 2)
+        );
+        assert_eq!(
+            brackets.tax(Decimal::new(50_000, 0))?,
+            Decimal::new(8_000_00, 2)
+        );
+        assert_eq!(
+            brackets.tax(Decimal::new(50_000_05, 2))?,
+            Decimal::new(8_000_02, 2)
+        );
+        assert_eq!(
+            brackets
+                .clone()
+                .rounding(RoundingMode::Up)
+                .tax(Decimal::new(10_000_01, 2))?,
+            Decimal::new(0_01, 2)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_brackets_rates() -> Result<(), DecimalOperationError> {
+        let brackets = brackets();
+        assert_eq!(brackets.marginal_rate(Decimal::new(-1, 0)), None);
+        assert_eq!(
+            brackets.marginal_rate(Decimal::new(10_000, 0)),
+            Some(Percent::new(0, 0))
+        );
+        assert_eq!(
+            brackets.marginal_rate(Decimal::new(60_000, 0)),
+            Some(Percent::new(40, 0))
+        );
+        assert_eq!(
+            brackets.effective_rate(Decimal::new(100_000, 0), 1)?,
+            Percent::new(28_0, 1)
+        );
+        assert_eq!(
+            brackets.effective_rate(Decimal::new(0, 0), 1),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+pub mod brackets;
+pub mod sales;
+pub mod vat;
+
+pub use brackets::*;
+pub use sales::*;
+pub use vat::*;
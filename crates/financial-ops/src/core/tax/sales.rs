@@ -0,0 +1,223 @@
+use alloc::vec::Vec;
+
+use crate::core::{
+    money::exchange_rate::multiply_to_scale, CheckedDecimalOperations, CheckedMulDiv, Decimal,
+    DecimalOperationError, Percent, PowerOfTen, RescaleDecimals, RoundingMode, Zero,
+};
+
+/// A tax levied by one jurisdiction, like a state, county or city sales tax.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TaxComponent<T = i128> {
+    name: &'static str,
+    rate: Percent<T>,
+}
+
+impl<T> TaxComponent<T> {
+    /// Creates a tax component.
+    pub const fn new(name: &'static str, rate: Percent<T>) -> Self {
+        TaxComponent { name, rate }
+    }
+
+    /// Returns the name the component is reported under.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl<T: Copy> TaxComponent<T> {
+    /// Returns the rate of the component.
+    pub fn rate(&self) -> Percent<T> {
+        self.rate
+    }
+}
+
+/// How the components of a [`SalesTax`] apply to an amount.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TaxApplication {
+    /// Every component applies to the amount alone, so `5%` and `2%` take `7%`.
+    #[default]
+    Parallel,
+    /// Every component applies to the amount plus the taxes of the components before it, so
+    /// `5%` and `2%` take `7.1%`.
+    Compounding,
+}
+
+/// The tax levied by one component in a [`SalesTaxBreakdown`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ComponentTax<T = i128> {
+    /// The component levying the tax.
+    pub component: TaxComponent<T>,
+    /// The amount the rate of the component applied to.
+    pub base: Decimal<T>,
+    /// The tax, rounded to the decimals of the [`SalesTax`].
+    pub tax: Decimal<T>,
+}
+
+/// An amount taxed by a [`SalesTax`], with the tax of every component.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SalesTaxBreakdown<T = i128> {
+    /// The amount before taxes.
+    pub amount: Decimal<T>,
+    /// The taxes of the components, in order.
+    pub components: Vec<ComponentTax<T>>,
+    /// The sum of the taxes of the components.
+    pub total_tax: Decimal<T>,
+    /// The amount including every tax.
+    pub total: Decimal<T>,
+}
+
+/// A sales tax made of the components of several jurisdictions.
+///
+/// The tax of every component is rounded on its own to the decimals of the sales tax, so each one
+/// can be reported and remitted separately, and the total tax is the sum of the rounded
+/// components.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{Decimal, Percent, SalesTax, TaxApplication};
+///
+/// let sales_tax = SalesTax::new(2)
+///     .component("state", Percent::new(6_25i64, 2))
+///     .component("county", Percent::new(1_00, 2))
+///     .component("city", Percent::new(0_50, 2));
+///
+/// let breakdown = sales_tax.apply(Decimal::new(19_99, 2)).unwrap();
+/// assert_eq!(breakdown.components[0].tax, Decimal::new(1_25, 2)); // 1.249375
+/// assert_eq!(breakdown.components[2].tax, Decimal::new(0_10, 2)); // 0.09995
+/// assert_eq!(breakdown.total, Decimal::new(21_54, 2));
+///
+/// let compounding = sales_tax.application(TaxApplication::Compounding);
+/// let breakdown = compounding.apply(Decimal::new(19_99, 2)).unwrap();
+/// assert_eq!(breakdown.components[1].base, Decimal::new(21_24, 2));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SalesTax<T = i128> {
+    components: Vec<TaxComponent<T>>,
+    application: TaxApplication,
+    decimals: u32,
+    rounding: RoundingMode,
+}
+
+impl<T> SalesTax<T> {
+    /// Creates a sales tax without components, applied in parallel, whose component taxes are
+    /// rounded half to even to `decimals` decimals.
+    pub const fn new(decimals: u32) -> Self {
+        SalesTax {
+            components: Vec::new(),
+            application: TaxApplication::Parallel,
+            decimals,
+            rounding: RoundingMode::HalfEven,
+        }
+    }
+
+    /// Sets how the components apply to an amount.
+    pub fn application(mut self, application: TaxApplication) -> Self {
+        self.application = application;
+        self
+    }
+
+    /// Sets the rounding applied to the tax of every component.
+    pub fn rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Adds a component after the ones already in the sales tax.
+    pub fn component(mut self, name: &'static str, rate: Percent<T>) -> Self {
+        self.components.push(TaxComponent::new(name, rate));
+        self
+    }
+
+    /// Returns the components of the sales tax, in order.
+    pub fn components(&self) -> &[TaxComponent<T>] {
+        &self.components
+    }
+}
+
+impl<T> SalesTax<T>
+where
+    T: CheckedDecimalOperations + CheckedMulDiv + RescaleDecimals + PowerOfTen + Zero + Copy,
+{
+    /// Applies every component to `amount`, in order.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the breakdown of the taxes, or a `DecimalOperationError` if
+    /// the operation fails.
+    pub fn apply(&self, amount: Decimal<T>) -> Result<SalesTaxBreakdown<T>, DecimalOperationError> {
+        let mut components = Vec::with_capacity(self.components.len());
+        let mut total_tax = Decimal::new(T::zero(), self.decimals);
+        for &component in &self.components {
+            let base = match self.application {
+                TaxApplication::Parallel => amount,
+                TaxApplication::Compounding => amount.try_add(total_tax)?,
+            };
+            let fraction = component.rate.to_fraction()?;
+            let tax = multiply_to_scale(base, fraction, self.decimals, self.rounding)?;
+            let tax = Decimal::new(tax, self.decimals);
+            total_tax = total_tax.try_add(tax)?;
+            components.push(ComponentTax {
+                component,
+                base,
+                tax,
+            });
+        }
+        Ok(SalesTaxBreakdown {
+            amount,
+            components,
+            total_tax,
+            total: amount.try_add(total_tax)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn canada() -> SalesTax<i64> {
+        SalesTax::new(2)
+            .component("GST", Percent::new(5, 0))
+            .component("PST", Percent::new(9_975, 3))
+    }
+
+    #[test]
+    fn test_sales_tax_parallel() -> Result<(), DecimalOperationError> {
+        let breakdown = canada().apply(Decimal::new(10_10, 2))?;
+        assert_eq!(breakdown.components[0].component.name(), "GST");
+        assert_eq!(breakdown.components[0].tax, Decimal::new(0_50, 2));
+        // 1.007475
+        assert_eq!(breakdown.components[1].base, Decimal::new(10_10, 2));
+        assert_eq!(breakdown.components[1].tax, Decimal::new(1_01, 2));
+        assert_eq!(breakdown.total_tax, Decimal::new(1_51, 2));
+        assert_eq!(breakdown.total, Decimal::new(11_61, 2));
+
+        let empty = SalesTax::<i64>::new(2).apply(Decimal::new(10, 0))?;
+        assert!(empty.components.is_empty());
+        assert_eq!(empty.total, Decimal::new(10_00, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sales_tax_compounding() -> Result<(), DecimalOperationError> {
+        let sales_tax = canada()
+            .application(TaxApplication::Compounding)
+            .rounding(RoundingMode::Down);
+        assert_eq!(sales_tax.components().len(), 2);
+        let breakdown = sales_tax.apply(Decimal::new(10_10, 2))?;
+        // 0.505, then 10.60 * 9.975% = 1.05735
+        assert_eq!(breakdown.components[0].tax, Decimal::new(0_50, 2));
+        assert_eq!(breakdown.components[1].base, Decimal::new(10_60, 2));
+        assert_eq!(breakdown.components[1].tax, Decimal::new(1_05, 2));
+        assert_eq!(breakdown.total, Decimal::new(11_65, 2));
+
+        assert_eq!(
+            SalesTax::new(2)
+                .component("max", Percent::new(100, 0))
+                .apply(Decimal::new(i64::MAX, 2)),
+            Err(DecimalOperationError::Overflow)
+        );
+        Ok(())
+    }
+}
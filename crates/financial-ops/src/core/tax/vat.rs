@@ -0,0 +1,252 @@
+use crate::core::{
+    money::exchange_rate::multiply_to_scale, CheckedDecimalOperations, CheckedDivideToScale,
+    CheckedMulDiv, Decimal, DecimalOperationError, Percent, PowerOfTen, RescaleDecimals,
+    RoundingMode, Zero,
+};
+
+/// The net amount, VAT and gross amount of a sale, where `net + vat == gross` exactly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VatAmounts<T = i128> {
+    /// The amount before VAT.
+    pub net: Decimal<T>,
+    /// The VAT, rounded to the decimals of the [`Vat`].
+    pub vat: Decimal<T>,
+    /// The amount including VAT.
+    pub gross: Decimal<T>,
+}
+
+/// Where the VAT of an invoice with several lines is rounded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum VatRounding {
+    /// Rounds the VAT of every line and adds the rounded amounts up.
+    #[default]
+    PerLine,
+    /// Adds the lines up and rounds the VAT of the total once.
+    PerInvoice,
+}
+
+/// A VAT rate with the decimals and rounding rule its amounts are computed with.
+///
+/// The VAT is the only rounded amount: going from gross to net rounds the VAT included in the
+/// gross amount and subtracts it, so the net amount and the VAT always add back up to the gross
+/// amount, unlike truncating the net amount and the VAT separately.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{Decimal, Percent, Vat};
+///
+/// let vat = Vat::new(Percent::new(20i64, 0), 2);
+///
+/// // 10.05 * 20 / 120 = 1.675
+/// let amounts = vat.from_gross(Decimal::new(10_05, 2)).unwrap();
+/// assert_eq!(amounts.vat, Decimal::new(1_68, 2));
+/// assert_eq!(amounts.net, Decimal::new(8_37, 2));
+///
+/// // 8.37 * 20% = 1.674
+/// let amounts = vat.from_net(Decimal::new(8_37, 2)).unwrap();
+/// assert_eq!(amounts.gross, Decimal::new(10_04, 2));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Vat<T = i128> {
+    rate: Percent<T>,
+    decimals: u32,
+    rounding: RoundingMode,
+}
+
+impl<T> Vat<T> {
+    /// Creates a VAT rate whose amounts are rounded half to even to `decimals` decimals.
+    pub const fn new(rate: Percent<T>, decimals: u32) -> Self {
+        Vat {
+            rate,
+            decimals,
+            rounding: RoundingMode::HalfEven,
+        }
+    }
+
+    /// Sets the rounding applied to VAT amounts.
+    pub fn rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+}
+
+impl<T: Copy> Vat<T> {
+    /// Returns the VAT rate.
+    pub fn rate(&self) -> Percent<T> {
+        self.rate
+    }
+}
+
+impl<T> Vat<T>
+where
+    T: CheckedDecimalOperations
+        + CheckedDivideToScale
+        + CheckedMulDiv
+        + RescaleDecimals
+        + PowerOfTen
+        + Zero
+        + Copy,
+{
+    /// Adds VAT to a net amount.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the amounts, or a `DecimalOperationError` if the operation
+    /// fails.
+    pub fn from_net(&self, net: Decimal<T>) -> Result<VatAmounts<T>, DecimalOperationError> {
+        let vat = multiply_to_scale(net, self.rate.to_fraction()?, self.decimals, self.rounding)?;
+        let vat = Decimal::new(vat, self.decimals);
+        Ok(VatAmounts {
+            net,
+            vat,
+            gross: net.try_add(vat)?,
+        })
+    }
+
+    /// Extracts the VAT included in a gross amount.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the amounts, or a `DecimalOperationError` if the operation
+    /// fails, like a rate of `-100%`.
+    pub fn from_gross(&self, gross: Decimal<T>) -> Result<VatAmounts<T>, DecimalOperationError> {
+        let whole = T::checked_power_of_ten(self.rate.to_fraction()?.scale())
+            .ok_or(DecimalOperationError::ScaleTooLarge)?;
+        let rate = self.rate.percent().value();
+        // vat = gross * rate / (100% + rate), rounded once with an intermediate twice as wide as
+        // the type, like `Percent::of`.
+        let (included, _) = whole.add_decimals_checked(rate, 0, 0)?;
+        let exponent = i64::from(self.decimals) - i64::from(gross.scale());
+        let vat =
+            gross
+                .value()
+                .checked_mul_div_power_of_ten(rate, included, exponent, self.rounding)?;
+        let vat = Decimal::new(vat, self.decimals);
+        Ok(VatAmounts {
+            net: gross.try_sub(vat)?,
+            vat,
+            gross,
+        })
+    }
+
+    /// Computes the amounts of an invoice whose lines are net amounts.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the amounts of the invoice, or a `DecimalOperationError` if
+    /// the operation fails.
+    pub fn invoice_from_net(
+        &self,
+        lines: &[Decimal<T>],
+        strategy: VatRounding,
+    ) -> Result<VatAmounts<T>, DecimalOperationError> {
+        self.invoice(lines, strategy, Vat::from_net)
+    }
+
+    /// Computes the amounts of an invoice whose lines are gross amounts.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the amounts of the invoice, or a `DecimalOperationError` if
+    /// the operation fails.
+    pub fn invoice_from_gross(
+        &self,
+        lines: &[Decimal<T>],
+        strategy: VatRounding,
+    ) -> Result<VatAmounts<T>, DecimalOperationError> {
+        self.invoice(lines, strategy, Vat::from_gross)
+    }
+
+    /// Applies `amounts` to every line or to their sum, depending on `strategy`.
+    fn invoice<F>(
+        &self,
+        lines: &[Decimal<T>],
+        strategy: VatRounding,
+        amounts: F,
+    ) -> Result<VatAmounts<T>, DecimalOperationError>
+    where
+        F: Fn(&Self, Decimal<T>) -> Result<VatAmounts<T>, DecimalOperationError>,
+    {
+        let zero = Decimal::new(T::zero(), 0);
+        match strategy {
+            VatRounding::PerLine => lines.iter().try_fold(
+                VatAmounts {
+                    net: zero,
+                    vat: zero,
+                    gross: zero,
+                },
+                |total, &line| {
+                    let line = amounts(self, line)?;
+                    Ok(VatAmounts {
+                        net: total.net.try_add(line.net)?,
+                        vat: total.vat.try_add(line.vat)?,
+                        gross: total.gross.try_add(line.gross)?,
+                    })
+                },
+            ),
+            VatRounding::PerInvoice => {
+                let total = lines
+                    .iter()
+                    .try_fold(zero, |total, &line| total.try_add(line))?;
+                amounts(self, total)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vat_net_and_gross() -> Result<(), DecimalOperationError> {
+        let vat = Vat::new(Percent::new(7_7i64, 1), 2);
+        let amounts = vat.from_net(Decimal::new(100_00, 2))?;
+        assert_eq!(amounts.vat, Decimal::new(7_70, 2));
+        assert_eq!(amounts.gross, Decimal::new(107_70, 2));
+
+        // 107.69 * 7.7 / 107.7 = 7.6992
+        let amounts = vat.from_gross(Decimal::new(107_69, 2))?;
+        assert_eq!(amounts.vat, Decimal::new(7_70, 2));
+        assert_eq!(amounts.net, Decimal::new(99_99, 2));
+        assert_eq!(amounts.net.try_add(amounts.vat)?, amounts.gross);
+
+        let truncated = vat
+            .rounding(RoundingMode::Down)
+            .from_gross(Decimal::new(107_69, 2))?;
+        assert_eq!(truncated.vat, Decimal::new(7_69, 2));
+        assert_eq!(truncated.net, Decimal::new(100_00, 2));
+
+        // The raw product of 1,000,000,000.000000 by 20.000 overflows an i64, the VAT doesn't.
+        let amounts = Vat::new(Percent::new(20_000i64, 3), 2)
+            .from_gross(Decimal::new(1_000_000_000_000_000, 6))?;
+        assert_eq!(amounts.vat, Decimal::new(166_666_666_67, 2));
+
+        assert_eq!(
+            Vat::new(Percent::new(-100i64, 0), 2).from_gross(Decimal::new(1_00, 2)),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_vat_invoice_rounding() -> Result<(), DecimalOperationError> {
+        let vat = Vat::new(Percent::new(19i64, 0), 2);
+        // 0.0038 rounds to 0.00 on every line, but 0.0114 rounds to 0.01 on the invoice.
+        let lines = [Decimal::new(0_02, 2); 3];
+        let per_line = vat.invoice_from_net(&lines, VatRounding::PerLine)?;
+        let per_invoice = vat.invoice_from_net(&lines, VatRounding::PerInvoice)?;
+        assert_eq!(per_line.vat, Decimal::new(0_00, 2));
+        assert_eq!(per_invoice.vat, Decimal::new(0_01, 2));
+        assert_eq!(per_invoice.gross, Decimal::new(0_07, 2));
+
+        let gross = vat.invoice_from_gross(
+            &[Decimal::new(1_19, 2), Decimal::new(2_38, 2)],
+            VatRounding::PerLine,
+        )?;
+        assert_eq!(gross.net, Decimal::new(3_00, 2));
+        assert_eq!(gross.vat, Decimal::new(0_57, 2));
+        Ok(())
+    }
+}
@@ -0,0 +1,142 @@
+//! Proptest strategies for decimals, enabled with the `proptest` feature.
+//!
+//! The strategies generate decimals with valid scales, pairs whose scales can be aligned without
+//! overflowing, values near the boundaries of the type and currency amounts, so downstream code
+//! can property-test its own invariants on top of the decimal operations.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use financial_ops::{testing, CheckedDecimalOperations};
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     #[test]
+//!     fn fees_never_exceed_the_amount(amount in testing::currency_amount::<u64>(6)) {
+//!         // ...
+//!     }
+//! }
+//! ```
+
+use core::fmt::Debug;
+
+use proptest::{
+    arbitrary::{any, Arbitrary},
+    prop_oneof,
+    strategy::{BoxedStrategy, Just, Strategy},
+};
+
+use crate::core::{power_of_ten::max_scale, Decimal, PowerOfTen};
+
+/// The number of values generated on each side of a boundary by [`StrategyValue::near_bounds`].
+const NEAR_BOUNDS: u8 = 100;
+
+/// The largest number of whole units generated by [`StrategyValue::amount`].
+const MAX_AMOUNT_UNITS_DIGITS: u32 = 12;
+
+/// A value type the strategies in this module can generate.
+pub trait StrategyValue: Arbitrary + PowerOfTen + Copy + Debug + 'static {
+    /// Values that can be multiplied by `10^scale` without overflowing.
+    fn scalable_by(scale: u32) -> BoxedStrategy<Self>;
+
+    /// Values close to the minimum and the maximum of the type.
+    fn near_bounds() -> BoxedStrategy<Self>;
+
+    /// Non-negative amounts with `decimals` decimals and up to a trillion whole units.
+    fn amount(decimals: u32) -> BoxedStrategy<Self>;
+}
+
+macro_rules! impl_strategy_value {
+    ($($t:ty)*) => ($(
+        impl StrategyValue for $t {
+            fn scalable_by(scale: u32) -> BoxedStrategy<Self> {
+                match <$t>::checked_pow(10, scale) {
+                    Some(factor) => ((<$t>::MIN / factor)..=(<$t>::MAX / factor)).boxed(),
+                    None => Just(0).boxed(),
+                }
+            }
+
+            fn near_bounds() -> BoxedStrategy<Self> {
+                let near = <$t>::try_from(NEAR_BOUNDS).unwrap_or(<$t>::MAX);
+                prop_oneof![
+                    (<$t>::MIN..=<$t>::MIN.saturating_add(near)),
+                    (<$t>::MAX.saturating_sub(near)..=<$t>::MAX),
+                ]
+                .boxed()
+            }
+
+            fn amount(decimals: u32) -> BoxedStrategy<Self> {
+                let max = 10u128
+                    .checked_pow(MAX_AMOUNT_UNITS_DIGITS + decimals)
+                    .and_then(|max| <$t>::try_from(max).ok())
+                    .unwrap_or(<$t>::MAX);
+                (0..=max).boxed()
+            }
+        }
+    )*)
+}
+
+impl_strategy_value! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
+
+/// Generates decimals with any value and a scale whose factor fits in the type.
+pub fn decimal<T: StrategyValue>() -> impl Strategy<Value = Decimal<T>> {
+    (any::<T>(), 0..=max_scale::<T>()).prop_map(|(value, scale)| Decimal::new(value, scale))
+}
+
+/// Generates pairs of decimals whose scales can be aligned without overflowing, so adding,
+/// subtracting and comparing them never fails because of the alignment.
+pub fn decimal_pair<T: StrategyValue>() -> impl Strategy<Value = (Decimal<T>, Decimal<T>)> {
+    let max_scale = max_scale::<T>();
+    (0..=max_scale, 0..=max_scale).prop_flat_map(|(a_scale, b_scale)| {
+        let a = T::scalable_by(b_scale.saturating_sub(a_scale));
+        let b = T::scalable_by(a_scale.saturating_sub(b_scale));
+        (a, b).prop_map(move |(a, b)| (Decimal::new(a, a_scale), Decimal::new(b, b_scale)))
+    })
+}
+
+/// Generates decimals whose values are close to the minimum or the maximum of the type.
+pub fn near_overflow_decimal<T: StrategyValue>() -> impl Strategy<Value = Decimal<T>> {
+    (T::near_bounds(), 0..=max_scale::<T>()).prop_map(|(value, scale)| Decimal::new(value, scale))
+}
+
+/// Generates non-negative currency amounts with `decimals` decimals, like `2` for fiat or `6`
+/// for USDC, up to a trillion whole units or the maximum of the type.
+pub fn currency_amount<T: StrategyValue>(decimals: u32) -> impl Strategy<Value = Decimal<T>> {
+    T::amount(decimals).prop_map(move |value| Decimal::new(value, decimals))
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{prop_assert, prop_assert_eq, proptest};
+
+    use super::*;
+    use crate::core::{CheckedDecimalOperations, DecimalOperationError};
+
+    proptest! {
+        #[test]
+        fn test_decimal_scales_are_valid(decimal in decimal::<u32>()) {
+            prop_assert!(decimal.scale() <= 9);
+        }
+
+        #[test]
+        fn test_decimal_pair_aligns(pair in decimal_pair::<i64>()) {
+            let (a, b) = pair;
+            let result = a.value().add_decimals_checked(b.value(), a.scale(), b.scale());
+            prop_assert!(!matches!(result, Err(DecimalOperationError::ScaleTooLarge)));
+            prop_assert!(a.cmp_value(&b) == b.cmp_value(&a).reverse());
+        }
+
+        #[test]
+        fn test_near_overflow_decimal(decimal in near_overflow_decimal::<u16>()) {
+            let value = decimal.value();
+            prop_assert!(value <= 100 || value >= u16::MAX - 100);
+        }
+
+        #[test]
+        fn test_currency_amounts_add(a in currency_amount::<u64>(6), b in currency_amount::<u64>(6)) {
+            let (sum, decimals) = a.value().add_decimals_checked(b.value(), 6, 6).unwrap();
+            prop_assert_eq!(decimals, 6);
+            prop_assert_eq!(sum, a.value() + b.value());
+        }
+    }
+}
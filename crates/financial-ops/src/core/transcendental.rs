@@ -0,0 +1,484 @@
+//! Fixed-point natural logarithm, exponential and fractional powers for `u128` and `i128`.
+//!
+//! The functions work on an internal fixed-point representation with 30 decimals and round the
+//! result to the requested number of decimals with [`RoundingMode::HalfEven`]. The logarithm
+//! reduces its argument to `m * 2^k` with `m` in `[1, 2)` and sums the `atanh` series, while the
+//! exponential reduces its argument to `r + k * ln(2)` with `r` in `[0, ln(2))` and sums the Taylor
+//! series. For inputs with up to 38 decimals the error of the internal result stays below
+//! `10^-28`, so results with fewer than 28 significant digits are within one unit in the last
+//! place; larger results carry a relative error below `10^-28`.
+
+use crate::core::{
+    checked::mul_div::mul_div_magnitude, wide::U256, DecimalOperationError, RoundingMode,
+};
+
+/// The number of decimals of the internal fixed-point representation.
+pub const TRANSCENDENTAL_MAX_DECIMALS: u32 = 30;
+
+const ONE: i128 = 10i128.pow(TRANSCENDENTAL_MAX_DECIMALS);
+const LN_2: i128 = 0_693147180559945309417232121458;
+const LN_10: i128 = 2_302585092994045684017991454684;
+
+/// A trait for transcendental functions on values with decimals.
+///
+/// See the [module documentation](self) for the precision guarantees. The number of decimals of
+/// the result can't exceed [`TRANSCENDENTAL_MAX_DECIMALS`].
+pub trait TranscendentalDecimals {
+    /// Computes the natural logarithm of a value with decimals.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The value.
+    /// * `decimals` - The number of decimals in the value.
+    /// * `target_decimals` - The number of decimals of the result.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the logarithm and `target_decimals`, or a
+    /// `DecimalOperationError` if the operation fails. Values lower than or equal to zero return
+    /// `OutOfDomain`.
+    fn ln_decimals(
+        self,
+        decimals: u32,
+        target_decimals: u32,
+    ) -> Result<(Self, u32), DecimalOperationError>
+    where
+        Self: Sized;
+
+    /// Computes `e` raised to the power of a value with decimals.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The exponent.
+    /// * `decimals` - The number of decimals in the exponent.
+    /// * `target_decimals` - The number of decimals of the result.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the power and `target_decimals`, or a `DecimalOperationError`
+    /// if the operation fails.
+    fn exp_decimals(
+        self,
+        decimals: u32,
+        target_decimals: u32,
+    ) -> Result<(Self, u32), DecimalOperationError>
+    where
+        Self: Sized;
+
+    /// Raises a value with decimals to a fractional power, computed as `exp(exponent * ln(self))`.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The base.
+    /// * `exponent` - The power to raise the base to.
+    /// * `self_decimals` - The number of decimals in the base.
+    /// * `exponent_decimals` - The number of decimals in the exponent.
+    /// * `target_decimals` - The number of decimals of the result.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the power and `target_decimals`, or a `DecimalOperationError`
+    /// if the operation fails. Negative bases return `OutOfDomain`, and zero raised to a negative
+    /// power returns `DivisionByZero`.
+    fn powf_decimals(
+        self,
+        exponent: Self,
+        self_decimals: u32,
+        exponent_decimals: u32,
+        target_decimals: u32,
+    ) -> Result<(Self, u32), DecimalOperationError>
+    where
+        Self: Sized;
+}
+
+/// Computes `a * b / denominator` on internal values, rounding half to even.
+fn mul_div(a: i128, b: i128, denominator: i128) -> Result<i128, DecimalOperationError> {
+    let negative = (a < 0) ^ (b < 0) ^ (denominator < 0);
+    let magnitude = mul_div_magnitude(
+        a.unsigned_abs(),
+        b.unsigned_abs(),
+        denominator.unsigned_abs(),
+        negative,
+        RoundingMode::HalfEven,
+    )?;
+    signed(magnitude, negative)
+}
+
+/// Converts a magnitude and a sign into an `i128`.
+fn signed(magnitude: u128, negative: bool) -> Result<i128, DecimalOperationError> {
+    if !negative {
+        i128::try_from(magnitude).map_err(|_| DecimalOperationError::Overflow)
+    } else if magnitude == i128::MIN.unsigned_abs() {
+        Ok(i128::MIN)
+    } else {
+        i128::try_from(magnitude)
+            .map(|value| -value)
+            .map_err(|_| DecimalOperationError::Underflow)
+    }
+}
+
+/// Converts a magnitude with `decimals` decimals and its sign into the internal representation.
+fn to_internal(magnitude: u128, negative: bool, decimals: u32) -> Option<i128> {
+    let value = if decimals <= TRANSCENDENTAL_MAX_DECIMALS {
+        magnitude.checked_mul(10u128.checked_pow(TRANSCENDENTAL_MAX_DECIMALS - decimals)?)?
+    } else {
+        match 10u128.checked_pow(decimals - TRANSCENDENTAL_MAX_DECIMALS) {
+            Some(divisor) => {
+                mul_div_magnitude(magnitude, 1, divisor, false, RoundingMode::HalfEven).ok()?
+            }
+            None => 0,
+        }
+    };
+    let value = i128::try_from(value).ok()?;
+    if negative {
+        value.checked_neg()
+    } else {
+        Some(value)
+    }
+}
+
+/// Converts an internal value into a magnitude with `target_decimals` decimals and its sign.
+fn from_internal(value: i128, target_decimals: u32) -> Result<(u128, bool), DecimalOperationError> {
+    let negative = value < 0;
+    let magnitude = mul_div_magnitude(
+        value.unsigned_abs(),
+        scale_factor(target_decimals)?,
+        ONE as u128,
+        negative,
+        RoundingMode::HalfEven,
+    )?;
+    Ok((magnitude, negative && magnitude != 0))
+}
+
+fn check_target_decimals(target_decimals: u32) -> Result<(), DecimalOperationError> {
+    if target_decimals > TRANSCENDENTAL_MAX_DECIMALS {
+        Err(DecimalOperationError::ScaleTooLarge)
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns `10^decimals`, the factor of a magnitude with `decimals` decimals.
+fn scale_factor(decimals: u32) -> Result<u128, DecimalOperationError> {
+    10u128
+        .checked_pow(decimals)
+        .ok_or(DecimalOperationError::ScaleTooLarge)
+}
+
+/// Computes `ln(value / 10^decimals)` in the internal representation. `value` must not be zero.
+fn ln_internal(value: u128, decimals: u32) -> Result<i128, DecimalOperationError> {
+    // value = m * 2^k with m in [1, 2)
+    let k = value
+        .checked_ilog2()
+        .ok_or(DecimalOperationError::OutOfDomain)?;
+    let m = signed(
+        mul_div_magnitude(value, ONE as u128, 1 << k, false, RoundingMode::HalfEven)?,
+        false,
+    )?;
+
+    // ln(m) = 2 * atanh(z) with z = (m - 1) / (m + 1) in [0, 1/3)
+    let (Some(numerator), Some(denominator)) = (m.checked_sub(ONE), m.checked_add(ONE)) else {
+        return Err(DecimalOperationError::Overflow);
+    };
+    let z = mul_div(numerator, ONE, denominator)?;
+    let z_squared = mul_div(z, z, ONE)?;
+    let mut atanh: i128 = 0;
+    let mut power = z;
+    let mut n: i128 = 1;
+    while power != 0 {
+        atanh = power
+            .checked_div(n)
+            .and_then(|term| atanh.checked_add(term))
+            .ok_or(DecimalOperationError::Overflow)?;
+        power = mul_div(power, z_squared, ONE)?;
+        n = n.checked_add(2).ok_or(DecimalOperationError::Overflow)?;
+    }
+
+    let scale = i128::from(decimals)
+        .checked_mul(LN_10)
+        .ok_or(DecimalOperationError::ScaleTooLarge)?;
+    i128::from(k)
+        .checked_mul(LN_2)
+        .zip(atanh.checked_mul(2))
+        .and_then(|(integer, fraction)| integer.checked_add(fraction)?.checked_sub(scale))
+        .ok_or(DecimalOperationError::Overflow)
+}
+
+/// Computes `exp(value)` for an internal value, scaled to `target_decimals` decimals.
+fn exp_internal(value: i128, target_decimals: u32) -> Result<u128, DecimalOperationError> {
+    // value = r + k * ln(2) with r in [0, ln(2))
+    let k = value.div_euclid(LN_2);
+    let r = value.rem_euclid(LN_2);
+
+    let mut mantissa = ONE;
+    let mut term = ONE;
+    let mut n: i128 = 1;
+    loop {
+        let divisor = ONE.checked_mul(n).ok_or(DecimalOperationError::Overflow)?;
+        term = mul_div(term, r, divisor)?;
+        if term == 0 {
+            break;
+        }
+        mantissa = mantissa
+            .checked_add(term)
+            .ok_or(DecimalOperationError::Overflow)?;
+        n = n.checked_add(1).ok_or(DecimalOperationError::Overflow)?;
+    }
+
+    // result = mantissa * 2^k * 10^target_decimals / ONE
+    let numerator = U256::widening_mul(mantissa as u128, scale_factor(target_decimals)?);
+    let numerator = if k >= 0 {
+        u32::try_from(k)
+            .ok()
+            .and_then(|shift| numerator.checked_shl(shift))
+            .ok_or(DecimalOperationError::Overflow)?
+    } else {
+        numerator.shr(u32::try_from(k.unsigned_abs()).unwrap_or(u32::MAX))
+    };
+    let denominator = ONE as u128;
+    let (quotient, remainder) = numerator
+        .div_rem_u128(denominator)
+        .ok_or(DecimalOperationError::DivisionByZero)?;
+    let discarded = (remainder != 0).then(|| remainder.cmp(&denominator.saturating_sub(remainder)));
+    let quotient =
+        if RoundingMode::HalfEven.rounds_away_from_zero(false, quotient.is_odd(), discarded)? {
+            quotient.checked_increment()
+        } else {
+            Some(quotient)
+        };
+    quotient
+        .and_then(U256::to_u128)
+        .ok_or(DecimalOperationError::Overflow)
+}
+
+/// Computes `ln(value / 10^decimals)` as a magnitude with `target_decimals` decimals and its sign.
+fn ln_magnitude(
+    value: u128,
+    decimals: u32,
+    target_decimals: u32,
+) -> Result<(u128, bool), DecimalOperationError> {
+    check_target_decimals(target_decimals)?;
+    if value == 0 {
+        return Err(DecimalOperationError::OutOfDomain);
+    }
+    from_internal(ln_internal(value, decimals)?, target_decimals)
+}
+
+/// Computes `exp(±magnitude / 10^decimals)` with `target_decimals` decimals.
+fn exp_magnitude(
+    magnitude: u128,
+    negative: bool,
+    decimals: u32,
+    target_decimals: u32,
+) -> Result<u128, DecimalOperationError> {
+    check_target_decimals(target_decimals)?;
+    match to_internal(magnitude, negative, decimals) {
+        Some(value) => exp_internal(value, target_decimals),
+        // The exponent is so negative that the result rounds to zero.
+        None if negative => Ok(0),
+        None => Err(DecimalOperationError::Overflow),
+    }
+}
+
+/// Computes `(base / 10^base_decimals)^(±exponent / 10^exponent_decimals)` with
+/// `target_decimals` decimals.
+fn powf_magnitude(
+    base: u128,
+    exponent: u128,
+    exponent_negative: bool,
+    base_decimals: u32,
+    exponent_decimals: u32,
+    target_decimals: u32,
+) -> Result<u128, DecimalOperationError> {
+    check_target_decimals(target_decimals)?;
+    if base == 0 {
+        return match (exponent, exponent_negative) {
+            (0, _) => scale_factor(target_decimals),
+            (_, false) => Ok(0),
+            (_, true) => Err(DecimalOperationError::DivisionByZero),
+        };
+    }
+    let ln_base = ln_internal(base, base_decimals)?;
+    let exponent = to_internal(exponent, exponent_negative, exponent_decimals)
+        .ok_or(DecimalOperationError::Overflow)?;
+    match mul_div(ln_base, exponent, ONE) {
+        Ok(value) => exp_internal(value, target_decimals),
+        // The result is so small that it rounds to zero.
+        Err(DecimalOperationError::Underflow) => Ok(0),
+        Err(error) => Err(error),
+    }
+}
+
+impl TranscendentalDecimals for u128 {
+    fn ln_decimals(
+        self,
+        decimals: u32,
+        target_decimals: u32,
+    ) -> Result<(Self, u32), DecimalOperationError> {
+        match ln_magnitude(self, decimals, target_decimals)? {
+            (_, true) => Err(DecimalOperationError::NegativeResult),
+            (magnitude, false) => Ok((magnitude, target_decimals)),
+        }
+    }
+
+    fn exp_decimals(
+        self,
+        decimals: u32,
+        target_decimals: u32,
+    ) -> Result<(Self, u32), DecimalOperationError> {
+        let value = exp_magnitude(self, false, decimals, target_decimals)?;
+        Ok((value, target_decimals))
+    }
+
+    fn powf_decimals(
+        self,
+        exponent: Self,
+        self_decimals: u32,
+        exponent_decimals: u32,
+        target_decimals: u32,
+    ) -> Result<(Self, u32), DecimalOperationError> {
+        let value = powf_magnitude(
+            self,
+            exponent,
+            false,
+            self_decimals,
+            exponent_decimals,
+            target_decimals,
+        )?;
+        Ok((value, target_decimals))
+    }
+}
+
+impl TranscendentalDecimals for i128 {
+    fn ln_decimals(
+        self,
+        decimals: u32,
+        target_decimals: u32,
+    ) -> Result<(Self, u32), DecimalOperationError> {
+        if self < 0 {
+            return Err(DecimalOperationError::OutOfDomain);
+        }
+        let (magnitude, negative) = ln_magnitude(self as u128, decimals, target_decimals)?;
+        Ok((signed(magnitude, negative)?, target_decimals))
+    }
+
+    fn exp_decimals(
+        self,
+        decimals: u32,
+        target_decimals: u32,
+    ) -> Result<(Self, u32), DecimalOperationError> {
+        let value = exp_magnitude(self.unsigned_abs(), self < 0, decimals, target_decimals)?;
+        Ok((signed(value, false)?, target_decimals))
+    }
+
+    fn powf_decimals(
+        self,
+        exponent: Self,
+        self_decimals: u32,
+        exponent_decimals: u32,
+        target_decimals: u32,
+    ) -> Result<(Self, u32), DecimalOperationError> {
+        if self < 0 {
+            return Err(DecimalOperationError::OutOfDomain);
+        }
+        let value = powf_magnitude(
+            self as u128,
+            exponent.unsigned_abs(),
+            exponent < 0,
+            self_decimals,
+            exponent_decimals,
+            target_decimals,
+        )?;
+        Ok((signed(value, false)?, target_decimals))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ln_decimals() -> Result<(), DecimalOperationError> {
+        assert_eq!(2u128.ln_decimals(0, 18)?, (0_693147180559945309, 18));
+        assert_eq!(1_5u128.ln_decimals(1, 18)?, (0_405465108108164382, 18));
+        assert_eq!(1_000000i128.ln_decimals(6, 18)?, (0, 18));
+        assert_eq!(0_5i128.ln_decimals(1, 18)?, (-0_693147180559945309, 18));
+        assert_eq!(1i128.ln_decimals(40, 18)?, (-92_103403719761827361, 18));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ln_decimals_errors() {
+        assert_eq!(
+            0u128.ln_decimals(0, 18),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        assert_eq!(
+            (-1i128).ln_decimals(0, 18),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+        assert_eq!(
+            0_5u128.ln_decimals(1, 18),
+            Err(DecimalOperationError::NegativeResult)
+        );
+        assert_eq!(
+            2u128.ln_decimals(0, 31),
+            Err(DecimalOperationError::ScaleTooLarge)
+        );
+    }
+
+    #[test]
+    fn test_exp_decimals() -> Result<(), DecimalOperationError> {
+        assert_eq!(0u128.exp_decimals(0, 18)?, (1_000000000000000000, 18));
+        assert_eq!(1u128.exp_decimals(0, 18)?, (2_718281828459045235, 18));
+        assert_eq!((-1i128).exp_decimals(0, 18)?, (0_367879441171442322, 18));
+        assert_eq!(
+            20i128.exp_decimals(0, 18)?,
+            (485165195_409790277969106831, 18)
+        );
+        // Continuous compounding of 5% over a year.
+        assert_eq!(0_05u128.exp_decimals(2, 18)?, (1_051271096376024040, 18));
+
+        assert_eq!(i128::MIN.exp_decimals(0, 18)?, (0, 18));
+        assert_eq!(
+            100u128.exp_decimals(0, 0),
+            Err(DecimalOperationError::Overflow)
+        );
+        assert_eq!(
+            i128::MAX.exp_decimals(0, 0),
+            Err(DecimalOperationError::Overflow)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_powf_decimals() -> Result<(), DecimalOperationError> {
+        assert_eq!(
+            2u128.powf_decimals(0_5, 0, 1, 18)?,
+            (1_414213562373095049, 18)
+        );
+        assert_eq!(
+            1_1u128.powf_decimals(2_5, 1, 1, 18)?,
+            (1_269058706285883372, 18)
+        );
+        assert_eq!(
+            2i128.powf_decimals(-3, 0, 0, 18)?,
+            (0_125000000000000000, 18)
+        );
+        assert_eq!(0u128.powf_decimals(0, 0, 0, 2)?, (1_00, 2));
+        assert_eq!(0u128.powf_decimals(1_5, 0, 1, 2)?, (0, 2));
+
+        assert_eq!(
+            0i128.powf_decimals(-1, 0, 0, 2),
+            Err(DecimalOperationError::DivisionByZero)
+        );
+        assert_eq!(
+            (-2i128).powf_decimals(2, 0, 0, 2),
+            Err(DecimalOperationError::OutOfDomain)
+        );
+
+        Ok(())
+    }
+}
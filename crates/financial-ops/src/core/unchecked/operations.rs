@@ -1,4 +1,6 @@
-use std::ops::{Add, Div, Mul, Rem, Sub};
+use core::ops::{Add, Div, Mul, Rem, Sub};
+
+use crate::core::PowerOfTen;
 
 /// A trait for performing decimal operations.
 pub trait DecimalOperations {
@@ -91,24 +93,24 @@ where
         + Mul<Output = T>
         + Div<Output = T>
         + Rem<Output = T>
-        + From<u32>,
+        + PowerOfTen,
 {
     fn add_decimals(self, other: Self, self_decimals: u32, other_decimals: u32) -> (Self, u32) {
         if self_decimals > other_decimals {
-            let factor = T::from(10u32.pow(self_decimals - other_decimals));
+            let factor = T::power_of_ten(self_decimals - other_decimals);
             (self + other * factor, self_decimals)
         } else {
-            let factor = T::from(10u32.pow(other_decimals - self_decimals));
+            let factor = T::power_of_ten(other_decimals - self_decimals);
             (self * factor + other, other_decimals)
         }
     }
 
     fn sub_decimals(self, other: Self, self_decimals: u32, other_decimals: u32) -> (Self, u32) {
         if self_decimals > other_decimals {
-            let factor = T::from(10u32.pow(self_decimals - other_decimals));
+            let factor = T::power_of_ten(self_decimals - other_decimals);
             (self - other * factor, self_decimals)
         } else {
-            let factor = T::from(10u32.pow(other_decimals - self_decimals));
+            let factor = T::power_of_ten(other_decimals - self_decimals);
             (self * factor - other, other_decimals)
         }
     }
@@ -123,13 +125,13 @@ where
     }
 
     fn divide_decimals(self, other: Self, self_decimals: u32, other_decimals: u32) -> (Self, u32) {
-        let factor = T::from(10u32.pow(other_decimals));
+        let factor = T::power_of_ten(other_decimals);
         let adjusted_value = self * factor;
         (adjusted_value / other, self_decimals)
     }
 
     fn rem_decimals(self, other: Self, self_decimals: u32, _other_decimals: u32) -> (Self, u32) {
-        let factor = T::from(10u32.pow(self_decimals));
+        let factor = T::power_of_ten(self_decimals);
         let adjusted_value = self * factor;
         (adjusted_value % other, self_decimals)
     }
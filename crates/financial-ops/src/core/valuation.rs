@@ -0,0 +1,166 @@
+use alloc::vec::Vec;
+
+use crate::core::{
+    money::exchange_rate::multiply_to_scale, CheckedDecimalOperations, CheckedMulDiv, Decimal,
+    DecimalOperationError, PowerOfTen, RescaleDecimals, RoundingMode, ValuationError, Zero,
+};
+
+/// The values of positions marked to market by [`value_positions`], with their total.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Valuation<T = i128> {
+    /// The value of every position, in the order given, at the scale of the valuation.
+    pub values: Vec<Decimal<T>>,
+    /// The sum of the values.
+    pub total: Decimal<T>,
+}
+
+/// Marks positions to market: the value of every position is its quantity times its price,
+/// rounded once to `scale` decimals, and the total is their exact sum.
+///
+/// Every position is a quantity and a price given as raw values with their number of decimals,
+/// `(quantity, quantity_decimals, price, price_decimals)`. The products go through
+/// [`CheckedMulDiv`], with an intermediate twice as wide as the type, so a position only fails
+/// when its value doesn't fit at `scale` decimals, not when the raw product does. Since every
+/// value has the same scale, the total is the sum of the rounded values.
+///
+/// # Arguments
+///
+/// * `positions` - The quantities and prices of the positions, with their decimals.
+/// * `scale` - The number of decimals of the values and the total.
+/// * `rounding` - The rounding applied to the values.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the values and their total, or a `ValuationError` identifying
+/// the position whose value overflows, reported as `PositionOverflow`, whose value overflows the
+/// total, reported as `TotalOverflow`, or whose value can't be computed otherwise, reported as
+/// `Position` with the error of the operation.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{value_positions, Decimal, RoundingMode, ValuationError};
+///
+/// // 1.5 BTC at 64,250.125, 120 shares at 187.30 and 0.25 oz of gold at 2,350.5
+/// let positions = [(1_5i64, 1, 64_250_125, 3), (120, 0, 187_30, 2), (25, 2, 2_350_5, 1)];
+/// let valuation = value_positions(&positions, 2, RoundingMode::HalfEven).unwrap();
+/// assert_eq!(valuation.values[0], Decimal::new(96_375_19, 2));
+/// assert_eq!(valuation.total, Decimal::new(119_438_81, 2));
+///
+/// let positions = [(1i64, 0, 1, 0), (i64::MAX, 0, 2, 0)];
+/// assert_eq!(
+///     value_positions(&positions, 0, RoundingMode::HalfEven),
+///     Err(ValuationError::PositionOverflow { index: 1 })
+/// );
+/// ```
+pub fn value_positions<T>(
+    positions: &[(T, u32, T, u32)],
+    scale: u32,
+    rounding: RoundingMode,
+) -> Result<Valuation<T>, ValuationError>
+where
+    T: CheckedDecimalOperations + CheckedMulDiv + RescaleDecimals + PowerOfTen + Zero + Copy,
+{
+    let mut valuation = Valuation {
+        values: Vec::with_capacity(positions.len()),
+        total: Decimal::zero(scale),
+    };
+    for (index, &(quantity, quantity_decimals, price, price_decimals)) in
+        positions.iter().enumerate()
+    {
+        let value = multiply_to_scale(
+            Decimal::new(quantity, quantity_decimals),
+            Decimal::new(price, price_decimals),
+            scale,
+            rounding,
+        )
+        .map_err(|error| match error {
+            DecimalOperationError::Overflow | DecimalOperationError::Underflow => {
+                ValuationError::PositionOverflow { index }
+            }
+            error => ValuationError::Position { index, error },
+        })?;
+        let value = Decimal::new(value, scale);
+        valuation.total = valuation
+            .total
+            .try_add(value)
+            .map_err(|error| match error {
+                DecimalOperationError::Overflow | DecimalOperationError::Underflow => {
+                    ValuationError::TotalOverflow { index }
+                }
+                error => ValuationError::Position { index, error },
+            })?;
+        valuation.values.push(value);
+    }
+    Ok(valuation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value_positions() -> Result<(), ValuationError> {
+        // A short position and a value rounded half to even
+        let positions = [(-3i128, 0, 19_99, 2), (1_25, 2, 0_1, 1), (0, 0, 5, 0)];
+        let valuation = value_positions(&positions, 1, RoundingMode::HalfEven)?;
+        assert_eq!(
+            valuation.values,
+            [
+                Decimal::new(-60_0, 1),
+                Decimal::new(0_1, 1),
+                Decimal::new(0, 1)
+            ]
+        );
+        assert_eq!(valuation.total, Decimal::new(-59_9, 1));
+        assert!(value_positions::<i128>(&[], 2, RoundingMode::HalfEven)?
+            .values
+            .is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_value_positions_widens() -> Result<(), ValuationError> {
+        // The raw product of 10^20 with 20 decimals by itself overflows i128, its value of 1
+        // doesn't.
+        let one = 10i128.pow(20);
+        let valuation = value_positions(&[(one, 20, one, 20)], 2, RoundingMode::HalfEven)?;
+        assert_eq!(valuation.total, Decimal::new(1_00, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_value_positions_scales_down() -> Result<(), ValuationError> {
+        // 1 with 9 decimals at 1.0 with 12 decimals, a product with 21 decimals
+        let positions = [(1_000_000_000i64, 9, 1_000_000_000_000, 12)];
+        let valuation = value_positions(&positions, 2, RoundingMode::HalfEven)?;
+        assert_eq!(valuation.total, Decimal::new(1_00, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_value_positions_overflow() {
+        let half = u64::MAX / 2 + 1;
+        assert_eq!(
+            value_positions(
+                &[(half, 0, 1, 0), (half, 0, 1, 0)],
+                0,
+                RoundingMode::HalfEven
+            ),
+            Err(ValuationError::TotalOverflow { index: 1 })
+        );
+        assert_eq!(
+            value_positions(
+                &[(1, 0, 1, 0), (u64::MAX, 0, 1, 0)],
+                1,
+                RoundingMode::HalfEven
+            ),
+            Err(ValuationError::PositionOverflow { index: 1 })
+        );
+        // 1 with 19 decimals doesn't fit in an i64.
+        assert_eq!(
+            value_positions(&[(1i64, 0, 1, 0)], 19, RoundingMode::HalfEven),
+            Err(ValuationError::PositionOverflow { index: 0 })
+        );
+    }
+}
@@ -0,0 +1,394 @@
+//! A signed 256-bit integer.
+
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+};
+
+use crate::core::{
+    CheckedAbs, CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, PowerOfTen,
+    Signedness, Zero, U256,
+};
+
+use super::u256::MAX_DECIMAL_DIGITS;
+
+const SIGN_BIT: u128 = 1 << 127;
+
+/// A signed 256-bit integer stored in two's complement.
+///
+/// `I256` is the signed counterpart of [`U256`] and implements the same helper traits, so signed
+/// values can go through the checked decimal operations when they don't fit in an `i128`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct I256 {
+    bits: U256,
+}
+
+impl I256 {
+    /// The value `0`.
+    pub const ZERO: I256 = I256 { bits: U256::ZERO };
+    /// The value `1`.
+    pub const ONE: I256 = I256 { bits: U256::ONE };
+    /// The smallest value representable by an `I256`.
+    pub const MIN: I256 = I256 {
+        bits: U256::from_parts(SIGN_BIT, 0),
+    };
+    /// The largest value representable by an `I256`.
+    pub const MAX: I256 = I256 {
+        bits: U256::from_parts(!SIGN_BIT, u128::MAX),
+    };
+
+    /// Creates an `I256` from an `i128`.
+    pub const fn from_i128(value: i128) -> Self {
+        let high = if value < 0 { u128::MAX } else { 0 };
+        I256 {
+            bits: U256::from_parts(high, value as u128),
+        }
+    }
+
+    /// Returns the value as an `i128`, or `None` if it doesn't fit.
+    pub const fn to_i128(self) -> Option<i128> {
+        let (high, low) = self.bits.into_parts();
+        let low_negative = (low as i128) < 0;
+        if (high == 0 && !low_negative) || (high == u128::MAX && low_negative) {
+            Some(low as i128)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the two's complement memory representation of the value as a little-endian byte
+    /// array.
+    pub const fn to_le_bytes(self) -> [u8; 32] {
+        self.bits.to_le_bytes()
+    }
+
+    /// Creates an `I256` from its two's complement little-endian memory representation.
+    pub const fn from_le_bytes(bytes: [u8; 32]) -> Self {
+        I256 {
+            bits: U256::from_le_bytes(bytes),
+        }
+    }
+
+    /// Creates an `I256` from a magnitude and a sign, or returns `None` if it doesn't fit.
+    pub const fn from_magnitude(magnitude: U256, negative: bool) -> Option<Self> {
+        let (high, low) = magnitude.into_parts();
+        if !negative {
+            if high & SIGN_BIT == 0 {
+                Some(I256 { bits: magnitude })
+            } else {
+                None
+            }
+        } else if high < SIGN_BIT || (high == SIGN_BIT && low == 0) {
+            Some(I256 {
+                bits: U256::ZERO.wrapping_sub(magnitude),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the value is negative.
+    pub const fn is_negative(self) -> bool {
+        self.bits.into_parts().0 & SIGN_BIT != 0
+    }
+
+    /// Returns `true` if the value is zero.
+    pub const fn is_zero(self) -> bool {
+        self.bits.is_zero()
+    }
+
+    /// Returns the absolute value as a `U256`, which can't overflow.
+    pub const fn unsigned_abs(self) -> U256 {
+        if self.is_negative() {
+            U256::ZERO.wrapping_sub(self.bits)
+        } else {
+            self.bits
+        }
+    }
+
+    /// Calculates `self + other` along with a `bool` indicating whether an overflow occurred.
+    pub const fn overflowing_add(self, other: Self) -> (Self, bool) {
+        let result = I256 {
+            bits: self.bits.wrapping_add(other.bits),
+        };
+        let overflow =
+            self.is_negative() == other.is_negative() && result.is_negative() != self.is_negative();
+        (result, overflow)
+    }
+
+    /// Calculates `self - other` along with a `bool` indicating whether an overflow occurred.
+    pub const fn overflowing_sub(self, other: Self) -> (Self, bool) {
+        let result = I256 {
+            bits: self.bits.wrapping_sub(other.bits),
+        };
+        let overflow =
+            self.is_negative() != other.is_negative() && result.is_negative() != self.is_negative();
+        (result, overflow)
+    }
+
+    /// Calculates `self * other` along with a `bool` indicating whether an overflow occurred.
+    pub const fn overflowing_mul(self, other: Self) -> (Self, bool) {
+        let result = I256 {
+            bits: self.bits.wrapping_mul(other.bits),
+        };
+        let negative = self.is_negative() != other.is_negative();
+        let overflow = match self.unsigned_abs().checked_mul(other.unsigned_abs()) {
+            Some(magnitude) => I256::from_magnitude(magnitude, negative).is_none(),
+            None => true,
+        };
+        (result, overflow)
+    }
+
+    /// Checked addition. Returns `None` on overflow.
+    pub const fn checked_add(self, other: Self) -> Option<Self> {
+        match self.overflowing_add(other) {
+            (value, false) => Some(value),
+            (_, true) => None,
+        }
+    }
+
+    /// Checked subtraction. Returns `None` on overflow.
+    pub const fn checked_sub(self, other: Self) -> Option<Self> {
+        match self.overflowing_sub(other) {
+            (value, false) => Some(value),
+            (_, true) => None,
+        }
+    }
+
+    /// Checked multiplication. Returns `None` on overflow.
+    pub const fn checked_mul(self, other: Self) -> Option<Self> {
+        match self.overflowing_mul(other) {
+            (value, false) => Some(value),
+            (_, true) => None,
+        }
+    }
+
+    /// Checked division, truncating towards zero. Returns `None` if `divisor` is zero or the
+    /// division overflows.
+    pub fn checked_div(self, divisor: Self) -> Option<Self> {
+        let (quotient, _) = self.unsigned_abs().div_rem(divisor.unsigned_abs())?;
+        I256::from_magnitude(quotient, self.is_negative() != divisor.is_negative())
+    }
+
+    /// Checked remainder, taking the sign of `self`. Returns `None` if `divisor` is zero or the
+    /// division overflows.
+    pub fn checked_rem(self, divisor: Self) -> Option<Self> {
+        if self == I256::MIN && divisor == I256::from_i128(-1) {
+            return None;
+        }
+        let (_, remainder) = self.unsigned_abs().div_rem(divisor.unsigned_abs())?;
+        I256::from_magnitude(remainder, self.is_negative())
+    }
+
+    /// Checked negation. Returns `None` for `I256::MIN`.
+    pub const fn checked_neg(self) -> Option<Self> {
+        I256::from_magnitude(self.unsigned_abs(), !self.is_negative())
+    }
+
+    /// Checked absolute value. Returns `None` for `I256::MIN`.
+    pub const fn checked_abs(self) -> Option<Self> {
+        I256::from_magnitude(self.unsigned_abs(), false)
+    }
+
+    /// Raises `self` to the power of `exponent`, along with a `bool` indicating whether an
+    /// overflow occurred.
+    pub const fn overflowing_pow(self, exponent: u32) -> (Self, bool) {
+        let (bits, _) = self.bits.overflowing_pow(exponent);
+        let negative = self.is_negative() && exponent & 1 == 1;
+        let overflow = match self.unsigned_abs().checked_pow(exponent) {
+            Some(magnitude) => I256::from_magnitude(magnitude, negative).is_none(),
+            None => true,
+        };
+        (I256 { bits }, overflow)
+    }
+
+    /// Checked exponentiation. Returns `None` on overflow.
+    pub const fn checked_pow(self, exponent: u32) -> Option<Self> {
+        match self.overflowing_pow(exponent) {
+            (value, false) => Some(value),
+            (_, true) => None,
+        }
+    }
+}
+
+impl PartialOrd for I256 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for I256 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Flipping the sign bit maps two's complement onto the unsigned order.
+        let flip = |value: &I256| {
+            let (high, low) = value.bits.into_parts();
+            U256::from_parts(high ^ SIGN_BIT, low)
+        };
+        flip(self).cmp(&flip(other))
+    }
+}
+
+impl Display for I256 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut buffer = [0u8; MAX_DECIMAL_DIGITS];
+        let digits = self.unsigned_abs().decimal_digits(&mut buffer);
+        f.pad_integral(!self.is_negative(), "", digits)
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty)*) => ($(
+        impl From<$t> for I256 {
+            fn from(value: $t) -> Self {
+                I256::from_i128(value as i128)
+            }
+        }
+    )*)
+}
+
+impl_from_integer! { u8 u16 u32 u64 i8 i16 i32 i64 i128 isize }
+
+impl From<u128> for I256 {
+    fn from(value: u128) -> Self {
+        I256 {
+            bits: U256::from_u128(value),
+        }
+    }
+}
+
+impl CheckedAdd for I256 {
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        I256::checked_add(*self, *v)
+    }
+}
+
+impl CheckedSub for I256 {
+    fn checked_sub(&self, v: &Self) -> Option<Self> {
+        I256::checked_sub(*self, *v)
+    }
+}
+
+impl CheckedMul for I256 {
+    fn checked_mul(&self, v: &Self) -> Option<Self> {
+        I256::checked_mul(*self, *v)
+    }
+}
+
+impl CheckedDiv for I256 {
+    fn checked_div(&self, v: &Self) -> Option<Self> {
+        I256::checked_div(*self, *v)
+    }
+}
+
+impl CheckedRem for I256 {
+    fn checked_rem(&self, v: &Self) -> Option<Self> {
+        I256::checked_rem(*self, *v)
+    }
+}
+
+impl CheckedNeg for I256 {
+    fn checked_neg(&self) -> Option<Self> {
+        I256::checked_neg(*self)
+    }
+}
+
+impl CheckedAbs for I256 {
+    fn checked_abs(&self) -> Option<Self> {
+        I256::checked_abs(*self)
+    }
+}
+
+impl Signedness for I256 {
+    fn is_signed() -> bool {
+        true
+    }
+    fn is_negative(&self) -> bool {
+        I256::is_negative(*self)
+    }
+}
+
+impl Zero for I256 {
+    fn zero() -> Self {
+        I256::ZERO
+    }
+    fn is_zero(&self) -> bool {
+        I256::is_zero(*self)
+    }
+}
+
+impl PowerOfTen for I256 {
+    fn checked_power_of_ten(exponent: u32) -> Option<Self> {
+        I256::from_i128(10).checked_pow(exponent)
+    }
+    fn wrapping_power_of_ten(exponent: u32) -> Self {
+        I256::from_i128(10).overflowing_pow(exponent).0
+    }
+    fn overflowing_power_of_ten(exponent: u32) -> (Self, bool) {
+        I256::from_i128(10).overflowing_pow(exponent)
+    }
+    #[cfg(not(feature = "strict-no-panic"))]
+    fn power_of_ten(exponent: u32) -> Self {
+        I256::from_i128(10)
+            .checked_pow(exponent)
+            .expect("attempt to multiply with overflow")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{CheckedDecimalOperations, DecimalOperationError};
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(I256::from_i128(-5).to_i128(), Some(-5));
+        assert_eq!(I256::from_i128(i128::MIN).to_i128(), Some(i128::MIN));
+        assert_eq!(I256::from(u128::MAX).to_i128(), None);
+        assert_eq!(I256::from_i128(-5).unsigned_abs(), U256::from_u128(5));
+        assert_eq!(I256::MIN.unsigned_abs(), U256::from_parts(SIGN_BIT, 0));
+        assert_eq!(I256::from_i128(-42).to_string(), "-42");
+        assert_eq!(I256::MAX.to_string().len(), 77);
+        assert_eq!(I256::from_i128(-1).to_le_bytes(), [0xff; 32]);
+        assert_eq!(I256::from_le_bytes(I256::MIN.to_le_bytes()), I256::MIN);
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let a = I256::from_i128(i128::MIN);
+        let product = a.checked_mul(a).unwrap();
+        assert_eq!(product.unsigned_abs(), U256::from_parts(1 << 126, 0));
+        assert_eq!(product.checked_div(a), Some(a));
+        assert_eq!(
+            I256::from_i128(-7).checked_rem(I256::from_i128(2)),
+            Some(I256::from_i128(-1))
+        );
+
+        assert_eq!(I256::MAX.checked_add(I256::ONE), None);
+        assert_eq!(I256::MIN.checked_sub(I256::ONE), None);
+        assert_eq!(I256::MIN.checked_neg(), None);
+        assert_eq!(I256::MIN.checked_div(I256::from_i128(-1)), None);
+        assert_eq!(I256::MIN.checked_rem(I256::from_i128(-1)), None);
+        assert_eq!(I256::ONE.checked_div(I256::ZERO), None);
+        assert_eq!(I256::MIN.checked_mul(I256::ONE), Some(I256::MIN));
+        assert_eq!(I256::MIN.checked_mul(I256::from_i128(-1)), None);
+        assert!(I256::MIN < I256::from_i128(-1) && I256::from_i128(-1) < I256::ONE);
+    }
+
+    #[test]
+    fn test_decimal_operations() {
+        // -1,000,000.5 * 2,000,000.25 with 18 decimals each doesn't fit in an i128.
+        let a = I256::from_i128(-1_000_000_500000000000000000);
+        let b = I256::from_i128(2_000_000_250000000000000000);
+        let (product, decimals) = a.multiply_decimals_checked(b, 18, 18).unwrap();
+        assert_eq!(decimals, 36);
+        assert_eq!(
+            product.to_string(),
+            "-2000001250000125000000000000000000000000000000000"
+        );
+
+        assert_eq!(
+            I256::MIN.multiply_decimals_checked(I256::from_i128(2), 0, 0),
+            Err(DecimalOperationError::Underflow)
+        );
+    }
+}
@@ -0,0 +1,5 @@
+pub mod i256;
+pub mod u256;
+
+pub use i256::*;
+pub use u256::*;
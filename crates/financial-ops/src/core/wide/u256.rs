@@ -0,0 +1,675 @@
+//! An unsigned 256-bit integer.
+
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+};
+
+use crate::core::{
+    CheckedAbs, CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, PowerOfTen,
+    Signedness, Zero,
+};
+
+/// An unsigned 256-bit integer stored as two `u128` halves.
+///
+/// `U256` implements the checked helper traits and [`PowerOfTen`], so it can be used with the
+/// checked decimal operations when products of 18 decimal values don't fit in a `u128`.
+///
+/// # Examples
+///
+/// ```
+/// use financial_ops::{CheckedDecimalOperations, U256};
+///
+/// let amount = U256::from(1_000_000_000000000000000000u128);
+/// let (product, decimals) = amount.multiply_decimals_checked(amount, 18, 18).unwrap();
+/// assert_eq!(decimals, 36);
+/// assert_eq!(product.to_string(), "1000000000000000000000000000000000000000000000000");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct U256 {
+    high: u128,
+    low: u128,
+}
+
+impl U256 {
+    /// The value `0`.
+    pub const ZERO: U256 = U256 { high: 0, low: 0 };
+    /// The value `1`.
+    pub const ONE: U256 = U256 { high: 0, low: 1 };
+    /// The largest value representable by a `U256`.
+    pub const MAX: U256 = U256 {
+        high: u128::MAX,
+        low: u128::MAX,
+    };
+
+    /// Creates a `U256` from a `u128`.
+    pub const fn from_u128(value: u128) -> Self {
+        U256 {
+            high: 0,
+            low: value,
+        }
+    }
+
+    /// Creates a `U256` from its high and low 128-bit halves.
+    pub const fn from_parts(high: u128, low: u128) -> Self {
+        U256 { high, low }
+    }
+
+    /// Splits the value into its high and low 128-bit halves.
+    pub const fn into_parts(self) -> (u128, u128) {
+        (self.high, self.low)
+    }
+
+    /// Returns the memory representation of the value as a little-endian byte array.
+    pub const fn to_le_bytes(self) -> [u8; 32] {
+        let low = self.low.to_le_bytes();
+        let high = self.high.to_le_bytes();
+        let mut bytes = [0u8; 32];
+        let mut index = 0;
+        while index < 16 {
+            bytes[index] = low[index];
+            bytes[index + 16] = high[index];
+            index += 1;
+        }
+        bytes
+    }
+
+    /// Creates a `U256` from its little-endian memory representation.
+    pub const fn from_le_bytes(bytes: [u8; 32]) -> Self {
+        let mut low = [0u8; 16];
+        let mut high = [0u8; 16];
+        let mut index = 0;
+        while index < 16 {
+            low[index] = bytes[index];
+            high[index] = bytes[index + 16];
+            index += 1;
+        }
+        U256 {
+            high: u128::from_le_bytes(high),
+            low: u128::from_le_bytes(low),
+        }
+    }
+
+    /// Returns the value as a `u128`, or `None` if it doesn't fit.
+    pub const fn to_u128(self) -> Option<u128> {
+        if self.high == 0 {
+            Some(self.low)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the value is zero.
+    pub const fn is_zero(self) -> bool {
+        self.high == 0 && self.low == 0
+    }
+
+    /// Computes the full 256-bit product of two `u128` values.
+    pub const fn widening_mul(a: u128, b: u128) -> Self {
+        const MASK: u128 = u64::MAX as u128;
+        let (a_high, a_low) = (a >> 64, a & MASK);
+        let (b_high, b_low) = (b >> 64, b & MASK);
+
+        let low_low = a_low * b_low;
+        let low_high = a_low * b_high;
+        let high_low = a_high * b_low;
+        let high_high = a_high * b_high;
+
+        let middle = (low_low >> 64) + (low_high & MASK) + (high_low & MASK);
+        U256 {
+            high: high_high + (low_high >> 64) + (high_low >> 64) + (middle >> 64),
+            low: (low_low & MASK) | (middle << 64),
+        }
+    }
+
+    /// Calculates `self + other` along with a `bool` indicating whether an overflow occurred.
+    pub const fn overflowing_add(self, other: Self) -> (Self, bool) {
+        let (low, carry) = self.low.overflowing_add(other.low);
+        let (high, overflow_high) = self.high.overflowing_add(other.high);
+        let (high, overflow_carry) = high.overflowing_add(carry as u128);
+        (U256 { high, low }, overflow_high || overflow_carry)
+    }
+
+    /// Calculates `self - other` along with a `bool` indicating whether an overflow occurred.
+    pub const fn overflowing_sub(self, other: Self) -> (Self, bool) {
+        let (low, borrow) = self.low.overflowing_sub(other.low);
+        let (high, overflow_high) = self.high.overflowing_sub(other.high);
+        let (high, overflow_borrow) = high.overflowing_sub(borrow as u128);
+        (U256 { high, low }, overflow_high || overflow_borrow)
+    }
+
+    /// Calculates `self * other` along with a `bool` indicating whether an overflow occurred.
+    pub const fn overflowing_mul(self, other: Self) -> (Self, bool) {
+        let product = U256::widening_mul(self.low, other.low);
+        let (cross_a, overflow_a) = self.high.overflowing_mul(other.low);
+        let (cross_b, overflow_b) = self.low.overflowing_mul(other.high);
+        let (cross, overflow_cross) = cross_a.overflowing_add(cross_b);
+        let (high, overflow_high) = product.high.overflowing_add(cross);
+        let overflow = (self.high != 0 && other.high != 0)
+            || overflow_a
+            || overflow_b
+            || overflow_cross
+            || overflow_high;
+        (
+            U256 {
+                high,
+                low: product.low,
+            },
+            overflow,
+        )
+    }
+
+    /// Checked addition. Returns `None` on overflow.
+    pub const fn checked_add(self, other: Self) -> Option<Self> {
+        match self.overflowing_add(other) {
+            (value, false) => Some(value),
+            (_, true) => None,
+        }
+    }
+
+    /// Checked subtraction. Returns `None` on overflow.
+    pub const fn checked_sub(self, other: Self) -> Option<Self> {
+        match self.overflowing_sub(other) {
+            (value, false) => Some(value),
+            (_, true) => None,
+        }
+    }
+
+    /// Checked multiplication. Returns `None` on overflow.
+    pub const fn checked_mul(self, other: Self) -> Option<Self> {
+        match self.overflowing_mul(other) {
+            (value, false) => Some(value),
+            (_, true) => None,
+        }
+    }
+
+    /// Wrapping addition, wrapping around at the boundary of the type.
+    pub const fn wrapping_add(self, other: Self) -> Self {
+        self.overflowing_add(other).0
+    }
+
+    /// Wrapping subtraction, wrapping around at the boundary of the type.
+    pub const fn wrapping_sub(self, other: Self) -> Self {
+        self.overflowing_sub(other).0
+    }
+
+    /// Wrapping multiplication, wrapping around at the boundary of the type.
+    pub const fn wrapping_mul(self, other: Self) -> Self {
+        self.overflowing_mul(other).0
+    }
+
+    /// Divides by `divisor`, returning the quotient and the remainder, or `None` if `divisor` is zero.
+    pub fn div_rem(self, divisor: Self) -> Option<(Self, Self)> {
+        if divisor.is_zero() {
+            return None;
+        }
+        if divisor.high == 0 {
+            let (quotient, remainder) = self.div_rem_u128(divisor.low)?;
+            return Some((quotient, U256::from_u128(remainder)));
+        }
+        let mut quotient = U256::ZERO;
+        let mut remainder = U256::ZERO;
+        for bit in (0..self.bits()).rev() {
+            let carry = remainder.high >> 127;
+            remainder = remainder.wrapping_add(remainder);
+            remainder.low |= self.bit(bit);
+            if carry == 1 || remainder >= divisor {
+                remainder = remainder.wrapping_sub(divisor);
+                quotient.set_bit(bit);
+            }
+        }
+        Some((quotient, remainder))
+    }
+
+    /// Checked division. Returns `None` if `divisor` is zero.
+    pub fn checked_div(self, divisor: Self) -> Option<Self> {
+        self.div_rem(divisor).map(|(quotient, _)| quotient)
+    }
+
+    /// Checked remainder. Returns `None` if `divisor` is zero.
+    pub fn checked_rem(self, divisor: Self) -> Option<Self> {
+        self.div_rem(divisor).map(|(_, remainder)| remainder)
+    }
+
+    /// Raises `self` to the power of `exponent`, along with a `bool` indicating whether an
+    /// overflow occurred.
+    pub const fn overflowing_pow(self, exponent: u32) -> (Self, bool) {
+        let mut result = U256::ONE;
+        let mut base = self;
+        let mut exponent = exponent;
+        let mut overflow = false;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                let (value, overflowed) = result.overflowing_mul(base);
+                result = value;
+                overflow |= overflowed;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                let (value, overflowed) = base.overflowing_mul(base);
+                base = value;
+                overflow |= overflowed;
+            }
+        }
+        (result, overflow)
+    }
+
+    /// Checked exponentiation. Returns `None` on overflow.
+    pub const fn checked_pow(self, exponent: u32) -> Option<Self> {
+        match self.overflowing_pow(exponent) {
+            (value, false) => Some(value),
+            (_, true) => None,
+        }
+    }
+
+    /// Wrapping exponentiation, wrapping around at the boundary of the type.
+    pub const fn wrapping_pow(self, exponent: u32) -> Self {
+        self.overflowing_pow(exponent).0
+    }
+
+    /// Returns `self + 1`, or `None` on overflow.
+    pub(crate) const fn checked_increment(self) -> Option<Self> {
+        match self.low.checked_add(1) {
+            Some(low) => Some(U256 {
+                high: self.high,
+                low,
+            }),
+            None => match self.high.checked_add(1) {
+                Some(high) => Some(U256 { high, low: 0 }),
+                None => None,
+            },
+        }
+    }
+
+    /// Returns `true` if the lowest bit is set.
+    pub(crate) const fn is_odd(self) -> bool {
+        self.low & 1 == 1
+    }
+
+    /// Divides by a `u128`, returning the quotient and the remainder, or `None` if `divisor` is
+    /// zero.
+    pub(crate) fn div_rem_u128(self, divisor: u128) -> Option<(Self, u128)> {
+        if divisor == 0 {
+            return None;
+        }
+        if self.high == 0 {
+            return Some((U256::from_u128(self.low / divisor), self.low % divisor));
+        }
+        let mut quotient = U256::ZERO;
+        let mut remainder: u128 = 0;
+        for bit in (0..256).rev() {
+            let carry = remainder >> 127;
+            remainder = (remainder << 1) | self.bit(bit);
+            if carry == 1 || remainder >= divisor {
+                remainder = remainder.wrapping_sub(divisor);
+                quotient.set_bit(bit);
+            }
+        }
+        Some((quotient, remainder))
+    }
+
+    /// Shifts left by `shift` bits, or returns `None` if a set bit would be shifted out.
+    pub(crate) const fn checked_shl(self, shift: u32) -> Option<Self> {
+        if shift == 0 {
+            return Some(self);
+        }
+        if shift >= 256 || self.bits() + shift > 256 {
+            return if self.high == 0 && self.low == 0 {
+                Some(self)
+            } else {
+                None
+            };
+        }
+        Some(if shift >= 128 {
+            U256 {
+                high: self.low << (shift - 128),
+                low: 0,
+            }
+        } else {
+            U256 {
+                high: (self.high << shift) | (self.low >> (128 - shift)),
+                low: self.low << shift,
+            }
+        })
+    }
+
+    /// Shifts right by `shift` bits, discarding the shifted out bits.
+    pub(crate) const fn shr(self, shift: u32) -> Self {
+        if shift == 0 {
+            self
+        } else if shift >= 256 {
+            U256::ZERO
+        } else if shift >= 128 {
+            U256 {
+                high: 0,
+                low: self.high >> (shift - 128),
+            }
+        } else {
+            U256 {
+                high: self.high >> shift,
+                low: (self.low >> shift) | (self.high << (128 - shift)),
+            }
+        }
+    }
+
+    /// Returns the number of significant bits.
+    pub(crate) const fn bits(self) -> u32 {
+        if self.high != 0 {
+            256 - self.high.leading_zeros()
+        } else {
+            128 - self.low.leading_zeros()
+        }
+    }
+
+    /// Computes the square root rounded down with Newton's method.
+    pub(crate) fn isqrt(self) -> u128 {
+        if self == U256::ZERO {
+            return 0;
+        }
+        // Start from a power of two above the root, so every iteration decreases towards it.
+        let half_bits = self.bits().div_ceil(2);
+        let mut root = if half_bits < 128 {
+            1u128 << half_bits
+        } else {
+            u128::MAX
+        };
+        loop {
+            // `root` is at least the square root, so the quotient fits in a u128.
+            let Some((quotient, _)) = self.div_rem_u128(root) else {
+                return root;
+            };
+            let quotient = quotient.low;
+            let next = (root >> 1) + (quotient >> 1) + (root & quotient & 1);
+            if next >= root {
+                return root;
+            }
+            root = next;
+        }
+    }
+
+    /// Returns the bit at `index`, zero from bit 256 up.
+    fn bit(&self, index: u32) -> u128 {
+        let (word, shift) = if index >= 128 {
+            (self.high, index - 128)
+        } else {
+            (self.low, index)
+        };
+        word.checked_shr(shift).unwrap_or(0) & 1
+    }
+
+    /// Sets the bit at `index`, leaving the value unchanged from bit 256 up.
+    fn set_bit(&mut self, index: u32) {
+        let (word, shift) = if index >= 128 {
+            (&mut self.high, index - 128)
+        } else {
+            (&mut self.low, index)
+        };
+        *word |= 1u128.checked_shl(shift).unwrap_or(0);
+    }
+}
+
+impl PartialOrd for U256 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for U256 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.high
+            .cmp(&other.high)
+            .then_with(|| self.low.cmp(&other.low))
+    }
+}
+
+/// The number of decimal digits of `U256::MAX`.
+pub(crate) const MAX_DECIMAL_DIGITS: usize = 78;
+
+impl U256 {
+    /// Writes the decimal digits of the value at the end of `buffer`, returning them as a `str`.
+    pub(crate) fn decimal_digits(self, buffer: &mut [u8; MAX_DECIMAL_DIGITS]) -> &str {
+        const CHUNK: u128 = 10u128.pow(19);
+        let mut written = 0usize;
+        let mut slots = buffer.iter_mut().rev();
+        let mut value = self;
+        // Split into base 10^19 digits, least significant first, and write every digit of the
+        // inner chunks but only the significant digits of the last one.
+        while let Some((quotient, remainder)) = value.div_rem_u128(CHUNK) {
+            value = quotient;
+            let mut chunk = remainder;
+            for slot in slots.by_ref().take(19) {
+                *slot = b'0' + (chunk % 10) as u8;
+                written = written.saturating_add(1);
+                chunk /= 10;
+                if chunk == 0 && value.is_zero() {
+                    break;
+                }
+            }
+            if value.is_zero() {
+                break;
+            }
+        }
+        let digits = buffer
+            .get(MAX_DECIMAL_DIGITS.saturating_sub(written)..)
+            .unwrap_or_default();
+        core::str::from_utf8(digits).unwrap_or_default()
+    }
+}
+
+impl Display for U256 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut buffer = [0u8; MAX_DECIMAL_DIGITS];
+        f.pad_integral(true, "", self.decimal_digits(&mut buffer))
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($($t:ty)*) => ($(
+        impl From<$t> for U256 {
+            fn from(value: $t) -> Self {
+                U256::from_u128(value as u128)
+            }
+        }
+    )*)
+}
+
+impl_from_unsigned! { u8 u16 u32 u64 u128 usize }
+
+impl CheckedAdd for U256 {
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        U256::checked_add(*self, *v)
+    }
+}
+
+impl CheckedSub for U256 {
+    fn checked_sub(&self, v: &Self) -> Option<Self> {
+        U256::checked_sub(*self, *v)
+    }
+}
+
+impl CheckedMul for U256 {
+    fn checked_mul(&self, v: &Self) -> Option<Self> {
+        U256::checked_mul(*self, *v)
+    }
+}
+
+impl CheckedDiv for U256 {
+    fn checked_div(&self, v: &Self) -> Option<Self> {
+        U256::checked_div(*self, *v)
+    }
+}
+
+impl CheckedRem for U256 {
+    fn checked_rem(&self, v: &Self) -> Option<Self> {
+        U256::checked_rem(*self, *v)
+    }
+}
+
+impl CheckedNeg for U256 {
+    fn checked_neg(&self) -> Option<Self> {
+        self.is_zero().then_some(*self)
+    }
+}
+
+impl CheckedAbs for U256 {
+    fn checked_abs(&self) -> Option<Self> {
+        Some(*self)
+    }
+}
+
+impl Signedness for U256 {
+    fn is_signed() -> bool {
+        false
+    }
+    fn is_negative(&self) -> bool {
+        false
+    }
+}
+
+impl Zero for U256 {
+    fn zero() -> Self {
+        U256::ZERO
+    }
+    fn is_zero(&self) -> bool {
+        U256::is_zero(*self)
+    }
+}
+
+impl PowerOfTen for U256 {
+    fn checked_power_of_ten(exponent: u32) -> Option<Self> {
+        U256::from_u128(10).checked_pow(exponent)
+    }
+    fn wrapping_power_of_ten(exponent: u32) -> Self {
+        U256::from_u128(10).wrapping_pow(exponent)
+    }
+    fn overflowing_power_of_ten(exponent: u32) -> (Self, bool) {
+        U256::from_u128(10).overflowing_pow(exponent)
+    }
+    #[cfg(not(feature = "strict-no-panic"))]
+    fn power_of_ten(exponent: u32) -> Self {
+        U256::from_u128(10)
+            .checked_pow(exponent)
+            .expect("attempt to multiply with overflow")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_widening_mul() {
+        assert_eq!(U256::widening_mul(3, 4).to_u128(), Some(12));
+        let product = U256::widening_mul(u128::MAX, u128::MAX);
+        assert_eq!(product.high, u128::MAX - 1);
+        assert_eq!(product.low, 1);
+    }
+
+    #[test]
+    fn test_le_bytes() {
+        let value = U256::from_parts(1, 2);
+        let bytes = value.to_le_bytes();
+        assert_eq!(bytes[0], 2);
+        assert_eq!(bytes[16], 1);
+        assert_eq!(U256::from_le_bytes(bytes), value);
+    }
+
+    #[test]
+    fn test_div_rem_u128() {
+        let product = U256::widening_mul(u128::MAX, 1_000);
+        let (quotient, remainder) = product.div_rem_u128(1_000).unwrap();
+        assert_eq!(quotient.to_u128(), Some(u128::MAX));
+        assert_eq!(remainder, 0);
+
+        let (quotient, remainder) = product.div_rem_u128(u128::MAX).unwrap();
+        assert_eq!(quotient.to_u128(), Some(1_000));
+        assert_eq!(remainder, 0);
+
+        let (quotient, remainder) = U256::widening_mul(u128::MAX, 100).div_rem_u128(7).unwrap();
+        assert_eq!(quotient.to_u128(), None);
+        assert_eq!(remainder, 6);
+        assert_eq!(product.div_rem_u128(0), None);
+    }
+
+    #[test]
+    fn test_shifts() {
+        let one = U256::from_u128(1);
+        assert_eq!(one.checked_shl(200).map(|value| value.shr(200)), Some(one));
+        assert_eq!(one.checked_shl(255).map(U256::bits), Some(256));
+        assert_eq!(one.checked_shl(256), None);
+        assert_eq!(U256::ZERO.checked_shl(300), Some(U256::ZERO));
+
+        let value = U256::widening_mul(u128::MAX, 3);
+        assert_eq!(value.checked_shl(1).map(|value| value.shr(1)), Some(value));
+        assert_eq!(value.shr(1).to_u128(), None);
+        assert_eq!(value.shr(2).to_u128(), Some((3 << 126) - 1));
+        assert_eq!(value.shr(129).to_u128(), Some(1));
+        assert_eq!(value.shr(300), U256::ZERO);
+    }
+
+    #[test]
+    fn test_isqrt() {
+        assert_eq!(U256::ZERO.isqrt(), 0);
+        assert_eq!(U256::from_u128(1).isqrt(), 1);
+        assert_eq!(U256::from_u128(15).isqrt(), 3);
+        assert_eq!(U256::from_u128(16).isqrt(), 4);
+        assert_eq!(
+            U256::widening_mul(u128::MAX, 1_000_000_000_000_000_000).isqrt(),
+            18446744073709551615999999999
+        );
+        assert_eq!(U256::widening_mul(u128::MAX, u128::MAX).isqrt(), u128::MAX);
+        assert_eq!(U256::widening_mul(1 << 127, 2).isqrt(), 1 << 64);
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let big = U256::widening_mul(u128::MAX, u128::MAX);
+        assert_eq!(
+            big.checked_add(U256::ONE),
+            Some(U256::from_parts(u128::MAX - 1, 2))
+        );
+        assert_eq!(U256::MAX.checked_add(U256::ONE), None);
+        assert_eq!(U256::ZERO.checked_sub(U256::ONE), None);
+        assert_eq!(
+            U256::from_parts(1, 0).checked_sub(U256::ONE),
+            Some(U256::from_u128(u128::MAX))
+        );
+        assert_eq!(
+            U256::from_u128(u128::MAX).checked_mul(U256::from_u128(u128::MAX)),
+            Some(big)
+        );
+        assert_eq!(
+            U256::from_parts(1, 0).checked_mul(U256::from_parts(1, 0)),
+            None
+        );
+        assert_eq!(U256::MAX.overflowing_add(U256::ONE), (U256::ZERO, true));
+    }
+
+    #[test]
+    fn test_div_rem() {
+        let big = U256::widening_mul(u128::MAX, u128::MAX);
+        let divisor = U256::from_parts(1, 5);
+        let (quotient, remainder) = big.div_rem(divisor).unwrap();
+        assert!(remainder < divisor);
+        assert_eq!(
+            quotient
+                .checked_mul(divisor)
+                .and_then(|value| value.checked_add(remainder)),
+            Some(big)
+        );
+        assert_eq!(U256::MAX.div_rem(U256::MAX), Some((U256::ONE, U256::ZERO)));
+        assert_eq!(U256::ONE.div_rem(U256::ZERO), None);
+    }
+
+    #[test]
+    fn test_power_of_ten_and_display() {
+        assert_eq!(
+            U256::checked_power_of_ten(77).map(|value| value.to_string()),
+            Some(format!("1{}", "0".repeat(77)))
+        );
+        assert_eq!(U256::checked_power_of_ten(78), None);
+        assert_eq!(U256::MAX.to_string().len(), 78);
+        assert_eq!(U256::from(42u8).to_string(), "42");
+        assert_eq!(format!("{:>5}", U256::from(42u8)), "   42");
+    }
+}
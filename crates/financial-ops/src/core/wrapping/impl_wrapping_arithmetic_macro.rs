@@ -0,0 +1,50 @@
+/// Implements wrapping arithmetic operations for the specified types.
+///
+/// This macro generates implementations of the `WrappingAdd`, `WrappingSub`,
+/// `WrappingMul`, `WrappingDiv`, and `WrappingRem` traits for the given types.
+/// These traits provide methods for performing arithmetic operations that
+/// wrap around at the boundary of the type instead of panicking on overflow.
+///
+/// # Examples
+///
+// `impl_wrapping_arithmetic!(u8 u16 u32 u64 i8 i16 i32 i64);`
+///
+/// In this example, the `impl_wrapping_arithmetic` macro is used to generate
+/// implementations of the `WrappingAdd`, `WrappingSub`, `WrappingMul`,
+/// `WrappingDiv`, and `WrappingRem` traits for the types `u8`, `u16`, `u32`,
+/// `u64`, `i8`, `i16`, `i32`, and `i64`.
+///
+/// # Panics
+///
+/// Like their standard library counterparts, `wrapping_div` and `wrapping_rem`
+/// panic when dividing by zero.
+#[macro_export]
+macro_rules! impl_wrapping_arithmetic {
+    ($($t:ty)*) => ($(
+        impl $crate::core::WrappingAdd for $t {
+            fn wrapping_add(&self, v: &Self) -> Self {
+                <$t>::wrapping_add(*self, *v)
+            }
+        }
+        impl $crate::core::WrappingSub for $t {
+            fn wrapping_sub(&self, v: &Self) -> Self {
+                <$t>::wrapping_sub(*self, *v)
+            }
+        }
+        impl $crate::core::WrappingMul for $t {
+            fn wrapping_mul(&self, v: &Self) -> Self {
+                <$t>::wrapping_mul(*self, *v)
+            }
+        }
+        impl $crate::core::WrappingDiv for $t {
+            fn wrapping_div(&self, v: &Self) -> Self {
+                <$t>::wrapping_div(*self, *v)
+            }
+        }
+        impl $crate::core::WrappingRem for $t {
+            fn wrapping_rem(&self, v: &Self) -> Self {
+                <$t>::wrapping_rem(*self, *v)
+            }
+        }
+    )*)
+}
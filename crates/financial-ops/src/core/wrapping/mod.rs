@@ -0,0 +1,6 @@
+pub mod impl_wrapping_arithmetic_macro;
+pub mod wrapping_operations;
+pub mod wrapping_traits;
+
+pub use wrapping_operations::*;
+pub use wrapping_traits::*;
@@ -0,0 +1,284 @@
+use crate::{
+    core::{PowerOfTen, WrappingAdd, WrappingDiv, WrappingMul, WrappingRem, WrappingSub},
+    impl_wrapping_arithmetic,
+};
+
+impl_wrapping_arithmetic! { u8 u16 u32 u64 u128 i8 i16 i32 i64 i128 usize isize }
+
+/// A trait for performing wrapping decimal operations.
+///
+/// The decimals are aligned exactly like [`CheckedDecimalOperations`](crate::core::CheckedDecimalOperations)
+/// does, but every intermediate step wraps around at the boundary of the type instead of failing.
+/// This is meant for simulations and fuzzing that intentionally explore overflow behavior.
+pub trait WrappingDecimalOperations {
+    /// Adds two values with decimals, wrapping around at the boundary of the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The first value to add.
+    /// * `other` - The second value to add.
+    /// * `self_decimals` - The number of decimals in the first value.
+    /// * `other_decimals` - The number of decimals in the second value.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the wrapped sum and the number of decimals in the result.
+    fn add_decimals_wrapping(
+        self,
+        other: Self,
+        self_decimals: u32,
+        other_decimals: u32,
+    ) -> (Self, u32)
+    where
+        Self: Sized;
+
+    /// Subtracts two values with decimals, wrapping around at the boundary of the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The value to subtract from.
+    /// * `other` - The value to subtract.
+    /// * `self_decimals` - The number of decimals in the first value.
+    /// * `other_decimals` - The number of decimals in the second value.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the wrapped difference and the number of decimals in the result.
+    fn sub_decimals_wrapping(
+        self,
+        other: Self,
+        self_decimals: u32,
+        other_decimals: u32,
+    ) -> (Self, u32)
+    where
+        Self: Sized;
+
+    /// Multiplies two values with decimals, wrapping around at the boundary of the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The first value to multiply.
+    /// * `other` - The second value to multiply.
+    /// * `self_decimals` - The number of decimals in the first value.
+    /// * `other_decimals` - The number of decimals in the second value.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the wrapped product and the number of decimals in the result.
+    fn multiply_decimals_wrapping(
+        self,
+        other: Self,
+        self_decimals: u32,
+        other_decimals: u32,
+    ) -> (Self, u32)
+    where
+        Self: Sized;
+
+    /// Divides two values with decimals, wrapping around at the boundary of the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The value to divide.
+    /// * `other` - The value to divide by.
+    /// * `self_decimals` - The number of decimals in the first value.
+    /// * `other_decimals` - The number of decimals in the second value.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the wrapped quotient and the number of decimals in the result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
+    fn divide_decimals_wrapping(
+        self,
+        other: Self,
+        self_decimals: u32,
+        other_decimals: u32,
+    ) -> (Self, u32)
+    where
+        Self: Sized;
+
+    /// Calculates the remainder of dividing two values with decimals, wrapping around at the boundary of the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `self` - The value to calculate the remainder for.
+    /// * `other` - The value to divide by.
+    /// * `self_decimals` - The number of decimals in the first value.
+    /// * `other_decimals` - The number of decimals in the second value.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the wrapped remainder and the number of decimals in the result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
+    fn rem_decimals_wrapping(
+        self,
+        other: Self,
+        self_decimals: u32,
+        other_decimals: u32,
+    ) -> (Self, u32)
+    where
+        Self: Sized;
+}
+
+// Blanket implementation of the WrappingDecimalOperations trait for all types implementing wrapping operations
+impl<T> WrappingDecimalOperations for T
+where
+    T: WrappingAdd + WrappingSub + WrappingMul + WrappingDiv + WrappingRem + PowerOfTen,
+{
+    fn add_decimals_wrapping(
+        self,
+        other: Self,
+        self_decimals: u32,
+        other_decimals: u32,
+    ) -> (Self, u32) {
+        if self_decimals > other_decimals {
+            let factor = T::wrapping_power_of_ten(self_decimals - other_decimals);
+            (
+                self.wrapping_add(&other.wrapping_mul(&factor)),
+                self_decimals,
+            )
+        } else {
+            let factor = T::wrapping_power_of_ten(other_decimals - self_decimals);
+            (
+                self.wrapping_mul(&factor).wrapping_add(&other),
+                other_decimals,
+            )
+        }
+    }
+
+    fn sub_decimals_wrapping(
+        self,
+        other: Self,
+        self_decimals: u32,
+        other_decimals: u32,
+    ) -> (Self, u32) {
+        if self_decimals > other_decimals {
+            let factor = T::wrapping_power_of_ten(self_decimals - other_decimals);
+            (
+                self.wrapping_sub(&other.wrapping_mul(&factor)),
+                self_decimals,
+            )
+        } else {
+            let factor = T::wrapping_power_of_ten(other_decimals - self_decimals);
+            (
+                self.wrapping_mul(&factor).wrapping_sub(&other),
+                other_decimals,
+            )
+        }
+    }
+
+    fn multiply_decimals_wrapping(
+        self,
+        other: Self,
+        self_decimals: u32,
+        other_decimals: u32,
+    ) -> (Self, u32) {
+        (
+            self.wrapping_mul(&other),
+            self_decimals.wrapping_add(other_decimals),
+        )
+    }
+
+    fn divide_decimals_wrapping(
+        self,
+        other: Self,
+        self_decimals: u32,
+        other_decimals: u32,
+    ) -> (Self, u32) {
+        let factor = T::wrapping_power_of_ten(other_decimals);
+        (
+            self.wrapping_mul(&factor).wrapping_div(&other),
+            self_decimals,
+        )
+    }
+
+    fn rem_decimals_wrapping(
+        self,
+        other: Self,
+        self_decimals: u32,
+        _other_decimals: u32,
+    ) -> (Self, u32) {
+        let factor = T::wrapping_power_of_ten(self_decimals);
+        (
+            self.wrapping_mul(&factor).wrapping_rem(&other),
+            self_decimals,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_decimals_wrapping() {
+        let (result, decimals) = 1_0000u64.add_decimals_wrapping(2_00, 4, 2);
+        assert_eq!(result, 3_0000);
+        assert_eq!(decimals, 4);
+
+        let (result, decimals) = u32::MAX.add_decimals_wrapping(2, 0, 0);
+        assert_eq!(result, 1);
+        assert_eq!(decimals, 0);
+    }
+
+    #[test]
+    fn test_sub_decimals_wrapping() {
+        let (result, decimals) = 3_0000u64.sub_decimals_wrapping(2_00, 4, 2);
+        assert_eq!(result, 1_0000);
+        assert_eq!(decimals, 4);
+
+        let (result, decimals) = 0_00u32.sub_decimals_wrapping(0_1, 2, 1);
+        assert_eq!(result, u32::MAX - 9);
+        assert_eq!(decimals, 2);
+    }
+
+    #[test]
+    fn test_mul_decimals_wrapping() {
+        let (result, decimals) = 3_0000u64.multiply_decimals_wrapping(2_00, 4, 2);
+        assert_eq!(result, 6_000000);
+        assert_eq!(decimals, 6);
+
+        let (result, decimals) = i64::MAX.multiply_decimals_wrapping(2, 0, 0);
+        assert_eq!(result, -2);
+        assert_eq!(decimals, 0);
+
+        let (result, decimals) = 5u64.multiply_decimals_wrapping(1, u32::MAX, 1);
+        assert_eq!(result, 5);
+        assert_eq!(decimals, 0);
+    }
+
+    #[test]
+    fn test_div_decimals_wrapping() {
+        let (result, decimals) = 123_45u32.divide_decimals_wrapping(0_45, 2, 2);
+        assert_eq!(result, 27433);
+        assert_eq!(decimals, 2);
+
+        // The scaled dividend wraps before the division happens.
+        let (result, decimals) = u32::MAX.divide_decimals_wrapping(1_0, 0, 1);
+        assert_eq!(result, u32::MAX.wrapping_mul(10) / 10);
+        assert_eq!(decimals, 0);
+    }
+
+    #[test]
+    fn test_rem_decimals_wrapping() {
+        let (result, decimals) = 123_45u32.rem_decimals_wrapping(0_45, 2, 2);
+        assert_eq!(result, 15);
+        assert_eq!(decimals, 2);
+    }
+
+    #[test]
+    fn test_small_integer_decimals_wrapping() {
+        let (result, decimals) = 1_5u8.add_decimals_wrapping(0_25, 1, 2);
+        assert_eq!(result, 1_75);
+        assert_eq!(decimals, 2);
+
+        let (result, decimals) = 2_5i8.add_decimals_wrapping(0_05, 1, 2);
+        assert_eq!(result, 2_55i16 as i8);
+        assert_eq!(decimals, 2);
+    }
+}
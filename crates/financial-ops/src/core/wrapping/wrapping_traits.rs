@@ -0,0 +1,19 @@
+pub trait WrappingAdd: Sized {
+    fn wrapping_add(&self, v: &Self) -> Self;
+}
+
+pub trait WrappingSub: Sized {
+    fn wrapping_sub(&self, v: &Self) -> Self;
+}
+
+pub trait WrappingMul: Sized {
+    fn wrapping_mul(&self, v: &Self) -> Self;
+}
+
+pub trait WrappingDiv: Sized {
+    fn wrapping_div(&self, v: &Self) -> Self;
+}
+
+pub trait WrappingRem: Sized {
+    fn wrapping_rem(&self, v: &Self) -> Self;
+}
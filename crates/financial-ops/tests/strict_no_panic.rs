@@ -0,0 +1,122 @@
+//! Builds the library with the `strict-no-panic` feature and fails if its compiled code calls a
+//! panic entry point of the standard library.
+//!
+//! Indexing, slicing and overflowing arithmetic panic without a `panic!` in the source, so the
+//! check looks at the artifact instead: the library is built in release with overflow checks on,
+//! and a panic the compiler couldn't prove unreachable is left as a call in the `rlib`. Generic
+//! functions are compiled by the crates instantiating them, so only the code the library compiles
+//! for itself is covered. Allocation failures are left to the allocation error handler.
+//!
+//! The symbols are read with `nm`; the test is skipped when it isn't installed or can't read the
+//! artifact of the target.
+#![cfg(feature = "strict-no-panic")]
+
+use std::{
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// Builds the library with the feature in release, returning the path of the `rlib`.
+fn build() -> PathBuf {
+    let target = Path::new(env!("CARGO_TARGET_TMPDIR")).join("strict_no_panic");
+    let status = Command::new(env!("CARGO"))
+        .args([
+            "build",
+            "--lib",
+            "--release",
+            "--features",
+            "strict-no-panic",
+        ])
+        .arg("--manifest-path")
+        .arg(Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(&target)
+        .env("CARGO_PROFILE_RELEASE_OVERFLOW_CHECKS", "true")
+        .env_remove("CARGO_TARGET_DIR")
+        .status()
+        .expect("cargo runs");
+    assert!(status.success(), "building the library failed");
+    target.join("release/libfinancial_ops.rlib")
+}
+
+/// Returns whether `symbol`, demangled, is an entry point of the panic machinery.
+///
+/// The hash some demanglers keep at the end of the path is ignored.
+fn is_panic(symbol: &str) -> bool {
+    let symbol = match symbol.rsplit_once("::h") {
+        Some((path, hash)) if hash.len() == 16 && hash.bytes().all(|b| b.is_ascii_hexdigit()) => {
+            path
+        }
+        _ => symbol,
+    };
+    symbol.contains("panicking::") || symbol.ends_with("_fail") || symbol.ends_with("_failed")
+}
+
+/// Returns the panic entry points referenced by the output of `nm --undefined-only`.
+fn panic_references(symbols: &str) -> Vec<&str> {
+    let mut panics: Vec<&str> = symbols
+        .lines()
+        .filter_map(|line| line.trim_start().strip_prefix("U "))
+        .map(str::trim)
+        .filter(|symbol| is_panic(symbol))
+        .collect();
+    panics.sort_unstable();
+    panics.dedup();
+    panics
+}
+
+#[test]
+fn test_library_calls_no_panic() {
+    let library = build();
+    if !library.exists() {
+        eprintln!("skipped: no rlib at {}", library.display());
+        return;
+    }
+    let output = match Command::new("nm")
+        .args(["--demangle", "--undefined-only"])
+        .arg(&library)
+        .output()
+    {
+        Ok(output) => output,
+        Err(error) if error.kind() == ErrorKind::NotFound => {
+            eprintln!("skipped: nm isn't installed");
+            return;
+        }
+        Err(error) => panic!("nm failed to run: {error}"),
+    };
+    if !output.status.success() {
+        eprintln!(
+            "skipped: nm can't read {}: {}",
+            library.display(),
+            String::from_utf8_lossy(&output.stderr)
+        );
+        return;
+    }
+    let symbols = String::from_utf8_lossy(&output.stdout);
+    let panics = panic_references(&symbols);
+    assert!(panics.is_empty(), "panics called: {panics:#?}");
+}
+
+#[test]
+fn test_panic_references() {
+    let symbols = "
+financial_ops-1.cgu.0.rcgu.o:
+                 U core::fmt::write
+                 U core::panicking::panic_const::panic_const_add_overflow
+                 U core::result::unwrap_failed::h0123456789abcdef
+                 U alloc::raw_vec::handle_error
+
+financial_ops-1.cgu.1.rcgu.o:
+                 U core::slice::index::slice_index_fail
+                 U core::panicking::panic_const::panic_const_add_overflow
+";
+    assert_eq!(
+        panic_references(symbols),
+        [
+            "core::panicking::panic_const::panic_const_add_overflow",
+            "core::result::unwrap_failed::h0123456789abcdef",
+            "core::slice::index::slice_index_fail",
+        ]
+    );
+}